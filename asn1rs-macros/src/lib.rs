@@ -8,13 +8,27 @@ use asn1rs_model::model::Model;
 use asn1rs_model::parser::Tokenizer;
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
+use syn::Error;
 use syn::LitStr;
 
 #[proc_macro]
 pub fn asn_to_rust(item: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(item as LitStr).value();
-    let tokens = Tokenizer::default().parse(&input);
-    let model = Model::try_from(tokens).unwrap();
+    let input = parse_macro_input!(item as LitStr);
+    let tokens = match Tokenizer::default().parse(&input.value()) {
+        Ok(tokens) => tokens,
+        Err(e) => return Error::new(input.span(), e).to_compile_error().into(),
+    };
+    let model = match Model::try_from(tokens) {
+        Ok(model) => model,
+        Err(e) => return Error::new(input.span(), e).to_compile_error().into(),
+    };
+
+    if let Err(errors) = model.validate() {
+        let mut errors = errors.into_iter().map(|e| Error::new(input.span(), e));
+        let mut error = errors.next().unwrap();
+        errors.for_each(|e| error.combine(e));
+        return error.to_compile_error().into();
+    }
 
     let mut generator = RustGenerator::default();
     generator.add_model(model.to_rust());
@@ -1,7 +1,10 @@
 use super::range::ident_or_literal_or_punct;
 use super::range::MaybeRanged;
 use super::tag::AttrTag;
-use crate::model::{Choice, ChoiceVariant, Enumerated, EnumeratedVariant, Range, Tag, Type};
+use crate::model::{
+    Asn, BitString, CharacterString, Charset, Choice, ChoiceVariant, Enumerated, EnumeratedVariant,
+    Field, Integer, LiteralValue, OpenType, OpenTypeVariant, Range, Size, Tag, TaggingMode, Type,
+};
 use std::fmt::Display;
 use std::marker::PhantomData;
 use std::ops::Deref;
@@ -12,7 +15,12 @@ use syn::parse::{Parse, ParseBuffer};
 pub(crate) struct AsnAttribute<C: Context> {
     pub(crate) primary: C::Primary,
     pub(crate) tag: Option<Tag>,
+    pub(crate) tagging: Option<TaggingMode>,
+    pub(crate) default: Option<LiteralValue>,
     pub(crate) extensible_after: Option<String>,
+    pub(crate) extension_groups: Vec<(String, String)>,
+    pub(crate) key: Option<i64>,
+    pub(crate) name: Option<String>,
     _c: PhantomData<C>,
 }
 
@@ -21,7 +29,12 @@ impl<C: Context> AsnAttribute<C> {
         Self {
             primary,
             tag: None,
+            tagging: None,
+            default: None,
             extensible_after: None,
+            extension_groups: Vec::new(),
+            key: None,
+            name: None,
             _c: Default::default(),
         }
     }
@@ -30,7 +43,9 @@ impl<C: Context> AsnAttribute<C> {
 impl<C: Context> Parse for AsnAttribute<C> {
     fn parse<'a>(input: &'a ParseBuffer<'a>) -> syn::Result<Self> {
         let mut asn = Self::new(C::Primary::parse(input)?);
-        eof_or_comma(&input, "Primary attribute must be separated by comma")?;
+        if asn.primary.is_present() {
+            eof_or_comma(&input, "Primary attribute must be separated by comma")?;
+        }
 
         while !input.cursor().eof() {
             let lowercase_ident = input
@@ -45,6 +60,17 @@ impl<C: Context> Parse for AsnAttribute<C> {
                     let tag = AttrTag::parse(input)?;
                     asn.tag = Some(tag.0);
                 }
+                "implicit" if C::TAGGABLE && asn.tagging.is_none() => {
+                    asn.tagging = Some(TaggingMode::Implicit);
+                }
+                "explicit" if C::TAGGABLE && asn.tagging.is_none() => {
+                    asn.tagging = Some(TaggingMode::Explicit);
+                }
+                "default" if C::DEFAULTABLE => {
+                    let content;
+                    parenthesized!(content in input);
+                    asn.default = Some(parse_literal_value(&content)?);
+                }
                 "extensible_after" if C::EXTENSIBLE => {
                     let content;
                     parenthesized!(content in input);
@@ -52,6 +78,30 @@ impl<C: Context> Parse for AsnAttribute<C> {
                         .step(|s| s.ident().ok_or_else(|| content.error("Not a valid ident")))?;
                     asn.extensible_after = Some(ident.to_string());
                 }
+                "extension_group" if C::EXTENSIBLE => {
+                    let content;
+                    parenthesized!(content in input);
+                    let first = content
+                        .step(|s| s.ident().ok_or_else(|| content.error("Not a valid ident")))?;
+                    eof_or_comma(&content, "Fields must be separated by comma")?;
+                    let last = content
+                        .step(|s| s.ident().ok_or_else(|| content.error("Not a valid ident")))?;
+                    asn.extension_groups
+                        .push((first.to_string(), last.to_string()));
+                }
+                "key" if C::KEYED => {
+                    let content;
+                    parenthesized!(content in input);
+                    match parse_literal_value(&content)? {
+                        LiteralValue::Integer(key) => asn.key = Some(key),
+                        _ => return Err(content.error("Expected integer as key")),
+                    }
+                }
+                "name" if C::NAMED && asn.name.is_none() => {
+                    let content;
+                    parenthesized!(content in input);
+                    asn.name = Some(content.parse::<syn::LitStr>()?.value());
+                }
                 attribute => {
                     return Err(
                         input.error(format!("Unexpected or repeated attribute: `{}`", attribute))
@@ -78,12 +128,41 @@ fn parse_type_pre_stepped<'a>(
     input: &'a ParseBuffer<'a>,
 ) -> syn::Result<Type> {
     match lowercase_ident {
-        "utf8string" => Ok(Type::UTF8String),
-        "ia5string" => Ok(Type::IA5String),
-        "octet_string" => Ok(Type::OctetString),
+        "utf8string" => Ok(Type::UTF8String(parse_optional_size(input)?)),
+        "ia5string" => parse_character_string(Charset::Ia5, input),
+        "numeric_string" => parse_character_string(Charset::Numeric, input),
+        "printable_string" => parse_character_string(Charset::Printable, input),
+        "visible_string" => parse_character_string(Charset::Visible, input),
+        "bmp_string" => parse_character_string(Charset::Bmp, input),
+        "universal_string" => parse_character_string(Charset::Universal, input),
+        "octet_string" => Ok(Type::OctetString(parse_optional_size(input)?)),
+        "bit_string" => {
+            let mut bit_string = BitString::default();
+            if input.peek(syn::token::Paren) {
+                let content;
+                parenthesized!(content in input);
+                while !content.cursor().eof() {
+                    let ident = content
+                        .step(|c| c.ident().ok_or_else(|| c.error("Expected size or const")))?
+                        .to_string()
+                        .to_lowercase();
+                    match ident.as_str() {
+                        "size" => bit_string.size = parse_size(&content)?,
+                        "const" => bit_string.constants = parse_constants(&content)?,
+                        other => {
+                            return Err(content.error(format!("Unexpected attribute: `{}`", other)))
+                        }
+                    }
+                    eof_or_comma(&content, "Attributes must be separated by comma")?;
+                }
+            }
+            Ok(Type::BitString(bit_string))
+        }
         "integer" => {
-            let range = MaybeRanged::parse(input)?;
-            Ok(Type::Integer(range.0.map(|(min, max)| Range(min, max))))
+            let MaybeRanged(min, max, extensible) = MaybeRanged::parse(input)?;
+            Ok(Type::Integer(Integer {
+                range: Range(min, max, extensible),
+            }))
         }
         "complex" => {
             let content;
@@ -92,23 +171,181 @@ fn parse_type_pre_stepped<'a>(
                 content.step(|c| c.ident().ok_or_else(|| c.error("Expected type identifier")))?;
             Ok(Type::TypeReference(ident.to_string()))
         }
+        "open_type" => {
+            let content;
+            parenthesized!(content in input);
+            let key_field =
+                content.step(|c| c.ident().ok_or_else(|| c.error("Expected key field")))?;
+            Ok(Type::OpenType(OpenType {
+                key_field: key_field.to_string(),
+                ..Default::default()
+            }))
+        }
         "option" => {
             let content;
             parenthesized!(content in input);
             let inner = parse_type(&content)?;
             Ok(Type::Optional(Box::new(inner)))
         }
-        "boolean" => Ok(Type::Boolean),
-        "sequence_of" => {
+        "boxed" => {
             let content;
             parenthesized!(content in input);
             let inner = parse_type(&content)?;
-            Ok(Type::SequenceOf(Box::new(inner)))
+            Ok(Type::Boxed(Box::new(inner)))
+        }
+        "boolean" => Ok(Type::Boolean),
+        "null" => Ok(Type::Null),
+        "real" => Ok(Type::Real),
+        "object_identifier" => Ok(Type::ObjectIdentifier),
+        "generalized_time" => Ok(Type::GeneralizedTime),
+        "utc_time" => Ok(Type::UTCTime),
+        "sequence_of" | "set_of" => {
+            let content;
+            parenthesized!(content in input);
+            let mut size = Size::Any;
+            if content
+                .cursor()
+                .ident()
+                .is_some_and(|(ident, _)| ident.to_string().eq_ignore_ascii_case("size"))
+            {
+                content.step(|c| c.ident().ok_or_else(|| c.error("Expected size")))?;
+                size = parse_size(&content)?;
+                eof_or_comma(&content, "Attributes must be separated by comma")?;
+            }
+            let inner = Box::new(parse_type(&content)?);
+            if lowercase_ident == "set_of" {
+                Ok(Type::SetOf(inner, size))
+            } else {
+                Ok(Type::SequenceOf(inner, size))
+            }
         }
         r#type => Err(input.error(format!("Unexpected attribute: `{}`", r#type))),
     }
 }
 
+fn parse_character_string<'a>(charset: Charset, input: &'a ParseBuffer<'a>) -> syn::Result<Type> {
+    let mut string = CharacterString::new(charset);
+    if input.peek(syn::token::Paren) {
+        let content;
+        parenthesized!(content in input);
+        while !content.cursor().eof() {
+            let ident = content
+                .step(|c| c.ident().ok_or_else(|| c.error("Expected size or from")))?
+                .to_string()
+                .to_lowercase();
+            match ident.as_str() {
+                "size" => string.size = parse_size(&content)?,
+                "from" => {
+                    let alphabet;
+                    parenthesized!(alphabet in content);
+                    let alphabet = alphabet.parse::<syn::LitStr>()?.value();
+                    string = string.with_permitted_alphabet(alphabet.chars());
+                }
+                other => return Err(content.error(format!("Unexpected attribute: `{}`", other))),
+            }
+            eof_or_comma(&content, "Attributes must be separated by comma")?;
+        }
+    }
+    Ok(Type::CharacterString(string))
+}
+
+/// Parses an optional `(size(..))`
+fn parse_optional_size<'a>(input: &'a ParseBuffer<'a>) -> syn::Result<Size> {
+    let mut size = Size::Any;
+    if input.peek(syn::token::Paren) {
+        let content;
+        parenthesized!(content in input);
+        while !content.cursor().eof() {
+            let ident = content
+                .step(|c| c.ident().ok_or_else(|| c.error("Expected size")))?
+                .to_string()
+                .to_lowercase();
+            match ident.as_str() {
+                "size" => size = parse_size(&content)?,
+                other => return Err(content.error(format!("Unexpected attribute: `{}`", other))),
+            }
+            eof_or_comma(&content, "Attributes must be separated by comma")?;
+        }
+    }
+    Ok(size)
+}
+
+/// Parses `(8)`, `(1..8)` or `(MIN..MAX)`, each optionally followed by `, ...` if extensible
+fn parse_size<'a>(input: &'a ParseBuffer<'a>) -> syn::Result<Size> {
+    let content;
+    parenthesized!(content in input);
+    let min = content.step(|c| {
+        ident_or_literal_or_punct(*c).ok_or_else(|| c.error("Expected size or min value"))
+    })?;
+    let size = if content.peek(syn::Token![..]) {
+        content.parse::<syn::Token![..]>()?;
+        let max = content
+            .step(|c| ident_or_literal_or_punct(*c).ok_or_else(|| c.error("Expected max value")))?;
+        let min = if min.eq_ignore_ascii_case("min") {
+            0
+        } else {
+            parse_usize(&content, &min)?
+        };
+        let max = if max.eq_ignore_ascii_case("max") {
+            usize::MAX
+        } else {
+            parse_usize(&content, &max)?
+        };
+        (Some(min), max)
+    } else {
+        (None, parse_usize(&content, &min)?)
+    };
+    let extensible = content.peek(syn::token::Comma);
+    if extensible {
+        content.parse::<syn::token::Comma>()?;
+        content.parse::<syn::Token![...]>()?;
+    }
+    Ok(match size {
+        (None, size) => Size::Fix(size, extensible),
+        (Some(min), max) => Size::Range(min, max, extensible),
+    })
+}
+
+fn parse_usize(input: &ParseBuffer, value: &str) -> syn::Result<usize> {
+    value
+        .parse()
+        .map_err(|e| input.error(format!("Invalid number: {}", e)))
+}
+
+/// Parses `true`, `false`, an integer, a string literal or the identifier of a variant
+fn parse_literal_value<'a>(input: &'a ParseBuffer<'a>) -> syn::Result<LiteralValue> {
+    if input.peek(syn::Token![-]) {
+        input.parse::<syn::Token![-]>()?;
+        let value = input.parse::<syn::LitInt>()?.base10_parse::<i64>()?;
+        Ok(LiteralValue::Integer(-value))
+    } else if input.peek(syn::Lit) {
+        match input.parse::<syn::Lit>()? {
+            syn::Lit::Bool(value) => Ok(LiteralValue::Boolean(value.value)),
+            syn::Lit::Int(value) => Ok(LiteralValue::Integer(value.base10_parse()?)),
+            syn::Lit::Str(value) => Ok(LiteralValue::String(value.value())),
+            _ => Err(input.error("Expected boolean, integer or string as default value")),
+        }
+    } else {
+        let ident = input.step(|c| c.ident().ok_or_else(|| c.error("Expected default value")))?;
+        Ok(LiteralValue::EnumeratedVariant(ident.to_string()))
+    }
+}
+
+fn parse_constants<'a>(input: &'a ParseBuffer<'a>) -> syn::Result<Vec<(String, u64)>> {
+    let content;
+    parenthesized!(content in input);
+    let mut constants = Vec::new();
+    while !content.cursor().eof() {
+        let name = content.step(|c| c.ident().ok_or_else(|| c.error("Expected name")))?;
+        let value;
+        parenthesized!(value in content);
+        let number = value.parse::<syn::LitInt>()?.base10_parse::<u64>()?;
+        constants.push((name.to_string(), number));
+        eof_or_comma(&content, "Constants must be separated by comma")?;
+    }
+    Ok(constants)
+}
+
 fn eof_or_comma<T: Display>(input: &ParseBuffer, msg: T) -> syn::Result<()> {
     if !input.cursor().eof() && !input.peek(syn::token::Comma) {
         Err(input.error(msg))
@@ -125,6 +362,11 @@ fn eof_or_comma<T: Display>(input: &ParseBuffer, msg: T) -> syn::Result<()> {
 
 pub trait PrimaryContext: Sized {
     fn parse(input: &ParseBuffer<'_>) -> syn::Result<Self>;
+
+    /// Whether the primary attribute was given and needs to be separated from the following
+    fn is_present(&self) -> bool {
+        true
+    }
 }
 
 impl PrimaryContext for Type {
@@ -142,22 +384,15 @@ impl PrimaryContext for Type {
 
 impl PrimaryContext for Option<usize> {
     fn parse(input: &ParseBuffer<'_>) -> syn::Result<Self> {
-        input
-            .step(|c| {
-                ident_or_literal_or_punct(*c)
-                    .ok_or_else(|| c.error("Expected type, number or extension marker"))
-            })
-            .ok()
-            .as_ref()
-            .map(ToString::to_string)
-            .as_deref()
-            .map(str::to_lowercase)
-            .map(|lowercase_ident| {
-                lowercase_ident
-                    .parse()
-                    .map_err(|e| input.error(format!("Invalid number: {}", e)))
-            })
-            .transpose()
+        if input.peek(syn::LitInt) {
+            input.parse::<syn::LitInt>()?.base10_parse().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn is_present(&self) -> bool {
+        self.is_some()
     }
 }
 
@@ -165,30 +400,63 @@ pub trait Context {
     type Primary: PrimaryContext;
     const EXTENSIBLE: bool;
     const TAGGABLE: bool;
+    const DEFAULTABLE: bool;
+    const KEYED: bool;
+    const NAMED: bool;
 }
 
 impl Context for Choice {
     type Primary = Type;
     const EXTENSIBLE: bool = true;
     const TAGGABLE: bool = true;
+    const DEFAULTABLE: bool = false;
+    const KEYED: bool = false;
+    const NAMED: bool = false;
 }
 
 impl Context for ChoiceVariant {
     type Primary = Type;
     const EXTENSIBLE: bool = false;
     const TAGGABLE: bool = true;
+    const DEFAULTABLE: bool = false;
+    const KEYED: bool = false;
+    const NAMED: bool = true;
 }
 
 impl Context for Enumerated {
     type Primary = Type;
     const EXTENSIBLE: bool = true;
     const TAGGABLE: bool = true;
+    const DEFAULTABLE: bool = false;
+    const KEYED: bool = false;
+    const NAMED: bool = false;
 }
 
 impl Context for EnumeratedVariant {
     type Primary = Option<usize>;
     const EXTENSIBLE: bool = false;
     const TAGGABLE: bool = false;
+    const DEFAULTABLE: bool = false;
+    const KEYED: bool = false;
+    const NAMED: bool = true;
+}
+
+impl Context for Field<Asn> {
+    type Primary = Type;
+    const EXTENSIBLE: bool = false;
+    const TAGGABLE: bool = true;
+    const DEFAULTABLE: bool = true;
+    const KEYED: bool = false;
+    const NAMED: bool = true;
+}
+
+impl Context for OpenTypeVariant {
+    type Primary = Type;
+    const EXTENSIBLE: bool = false;
+    const TAGGABLE: bool = false;
+    const DEFAULTABLE: bool = false;
+    const KEYED: bool = true;
+    const NAMED: bool = false;
 }
 
 pub struct Transparent;
@@ -196,6 +464,9 @@ impl Context for Transparent {
     type Primary = Type;
     const EXTENSIBLE: bool = false;
     const TAGGABLE: bool = true;
+    const DEFAULTABLE: bool = false;
+    const KEYED: bool = false;
+    const NAMED: bool = false;
 }
 
 pub struct DefinitionHeader(String);
@@ -212,6 +483,9 @@ impl Context for DefinitionHeader {
     type Primary = Self;
     const EXTENSIBLE: bool = true;
     const TAGGABLE: bool = true;
+    const DEFAULTABLE: bool = false;
+    const KEYED: bool = false;
+    const NAMED: bool = false;
 }
 
 impl PrimaryContext for DefinitionHeader {
@@ -3,8 +3,11 @@ mod range;
 mod tag;
 
 use crate::ast::attribute::{Context, DefinitionHeader, Transparent};
-use crate::model::{Asn as AsnModelType, EnumeratedVariant, TagProperty};
-use crate::model::{Choice, ChoiceVariant, Definition, Enumerated, Field, Model, Type};
+use crate::model::rust::OPEN_TYPE_UNKNOWN_VARIANT;
+use crate::model::{Asn as AsnModelType, ComponentTypeList, EnumeratedVariant, Range, TagProperty};
+use crate::model::{Choice, ChoiceVariant, Definition, Enumerated, Exports, Field, Model};
+use crate::model::{OpenType, OpenTypeVariant};
+use crate::model::{TagDefault, Type};
 use attribute::AsnAttribute;
 use quote::quote;
 use std::convert::Infallible;
@@ -61,13 +64,25 @@ pub fn expand(definition: Option<Definition<AsnModelType>>) -> Vec<TokenStream>
         name: "__proc_macro".to_string(),
         imports: vec![],
         definitions: vec![],
+        value_references: vec![],
+        tag_default: TagDefault::default(),
+        oid: None,
+        exports: Exports::default(),
+        extensibility_implied: false,
+        definition_spans: Default::default(),
+        component_spans: Default::default(),
+        imports_span: Default::default(),
     };
 
     if let Some(definition) = definition {
+        let name = definition.0.clone();
         model.definitions.push(definition);
         use crate::gen::rust::walker::AsnDefWriter;
-        additional_impl
-            .push(TokenStream::from_str(&AsnDefWriter::stringify(&model.to_rust())).unwrap());
+        // the fields of a `SET` are declared in the order in which they are encoded
+        let mut rust = Model::convert_asn_to_rust_in_given_order(&model);
+        // types of open type fields are annotated on their own
+        rust.definitions.retain(|Definition(n, _)| n == &name);
+        additional_impl.push(TokenStream::from_str(&AsnDefWriter::stringify(&rust)).unwrap());
     }
 
     additional_impl
@@ -96,7 +111,10 @@ pub fn parse_asn_definition(
 
     match item {
         Item::Struct(strct) if asn.primary.eq_ignore_ascii_case("sequence") => {
-            parse_sequence(strct, &asn, attr_span)
+            parse_sequence_or_set(strct, &asn, attr_span, Type::Sequence)
+        }
+        Item::Struct(strct) if asn.primary.eq_ignore_ascii_case("set") => {
+            parse_sequence_or_set(strct, &asn, attr_span, Type::Set)
         }
         Item::Struct(strct) if asn.primary.eq_ignore_ascii_case("transparent") => {
             parse_transparent(strct, &asn, attr_span)
@@ -107,15 +125,26 @@ pub fn parse_asn_definition(
         Item::Enum(enm) if asn.primary.eq_ignore_ascii_case("choice") => {
             parse_choice(enm, &asn, attr_span)
         }
+        Item::Enum(enm) if asn.primary.eq_ignore_ascii_case("open_type") => {
+            parse_open_type(enm, &asn, attr_span)
+        }
         item => Ok((None, item)),
     }
 }
 
-fn parse_sequence(
+/// The fields of a `SET` are expected in the canonical order of their tags, in which
+/// they are read and written
+fn parse_sequence_or_set(
     mut strct: syn::ItemStruct,
     asn: &AsnAttribute<DefinitionHeader>,
-    _asn_span: proc_macro2::Span,
+    asn_span: proc_macro2::Span,
+    list: fn(ComponentTypeList) -> Type,
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
+    let identifiers = strct
+        .fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref().map(ToString::to_string))
+        .collect::<Vec<_>>();
     let fields = strct
         .fields
         .iter_mut()
@@ -127,22 +156,46 @@ fn parse_sequence(
                 )?;
             }
 
-            parse_and_remove_first_asn_attribute_type::<Transparent>(
-                field.span(),
-                &field.ty,
-                &mut field.attrs,
-            )
-            .map(|asn| Field {
-                name: field.ident.as_ref().unwrap().to_string(),
-                role: asn,
-            })
+            let span = field.span();
+            parse_and_remove_first_asn_attribute::<Field<AsnModelType>>(span, &mut field.attrs)
+                .and_then(|asn| {
+                    let default = asn.default.clone();
+                    let name = asn
+                        .name
+                        .clone()
+                        .unwrap_or_else(|| field.ident.as_ref().unwrap().to_string());
+                    into_asn_or_err(span, &field.ty, asn).map(|role| Field {
+                        name,
+                        role,
+                        default,
+                    })
+                })
         })
         .vec_result()?;
 
+    // the extension marker and groups refer to the identifiers of the fields
+    let extension_after = find_extensible_index(asn, asn_span, identifiers.iter())?;
+    let index_of = |name: &String| {
+        identifiers
+            .iter()
+            .position(|identifier| identifier.eq(name))
+            .ok_or_else(|| compile_error_ts(asn_span, "Cannot find field for extension group"))
+    };
+    let mut extension_groups = Vec::with_capacity(asn.extension_groups.len());
+    for (first, last) in &asn.extension_groups {
+        extension_groups.push(Range::inclusive(index_of(first)?, index_of(last)?));
+    }
+
     Ok((
         Some(Definition(
             strct.ident.to_string(),
-            Type::Sequence(fields).opt_tagged(asn.tag),
+            list(
+                ComponentTypeList::from(fields)
+                    .with_extension_after(extension_after)
+                    .with_extension_groups(extension_groups),
+            )
+            .opt_tagged(asn.tag)
+            .with_tagging(asn.tagging),
         )),
         Item::Struct(strct),
     ))
@@ -197,7 +250,6 @@ fn parse_enumerated(
         .variants
         .iter_mut()
         .map(|v| {
-            let variant = EnumeratedVariant::from_name(v.ident.to_string());
             let attributes = index_of_first_asn_attribute(&v.attrs).map(|_index| {
                 parse_and_remove_first_asn_attribute::<EnumeratedVariant>(v.span(), &mut v.attrs)
             });
@@ -207,21 +259,30 @@ fn parse_enumerated(
                         compile_err_ts(v.span(), "ENUMERATED Variants must not have a Tag")?;
                     }
 
-                    Ok(variant.with_number_opt(attr.primary))
+                    Ok(EnumeratedVariant::from_name(
+                        attr.name.unwrap_or_else(|| v.ident.to_string()),
+                    )
+                    .with_number_opt(attr.primary))
                 })
             } else {
-                Ok(variant)
+                Ok(EnumeratedVariant::from_name(v.ident.to_string()))
             }
         })
         .vec_result()?;
 
-    let extension_after = find_extensible_index(asn, asn_span, variants.iter().map(|v| v.name()))?;
+    let extension_after = find_extensible_index(
+        asn,
+        asn_span,
+        enm.variants.iter().map(|v| v.ident.to_string()),
+    )?;
     let enumerated = Enumerated::from_variants(variants).with_extension_after(extension_after);
 
     Ok((
         Some(Definition(
             enm.ident.to_string(),
-            Type::Enumerated(enumerated).opt_tagged(asn.tag),
+            Type::Enumerated(enumerated)
+                .opt_tagged(asn.tag)
+                .with_tagging(asn.tagging),
         )),
         Item::Enum(enm),
     ))
@@ -254,25 +315,30 @@ fn parse_choice(
                 )?;
             }
 
-            parse_and_remove_first_asn_attribute_type::<ChoiceVariant>(
-                v.span(),
-                &v.fields.iter().next().unwrap().ty,
-                &mut v.attrs,
+            let span = v.span();
+            parse_and_remove_first_asn_attribute::<ChoiceVariant>(span, &mut v.attrs).and_then(
+                |asn| {
+                    let name = asn.name.clone().unwrap_or_else(|| v.ident.to_string());
+                    into_asn_or_err(span, &v.fields.iter().next().unwrap().ty, asn).map(|asn| {
+                        // TODO extensible
+                        // TODO tags
+                        ChoiceVariant {
+                            name,
+                            tag: asn.tag,
+                            tagging: asn.tagging,
+                            r#type: asn.r#type,
+                        }
+                    })
+                },
             )
-            .map(|asn| {
-                // TODO extensible
-                // TODO tags
-                ChoiceVariant {
-                    name: v.ident.to_string(),
-                    tag: asn.tag,
-                    r#type: asn.r#type,
-                }
-            })
         })
         .vec_result()?;
 
-    let extensible_after =
-        find_extensible_index(&asn, asn_span, variants.iter().map(|v| v.name()))?;
+    let extensible_after = find_extensible_index(
+        &asn,
+        asn_span,
+        enm.variants.iter().map(|v| v.ident.to_string()),
+    )?;
 
     Ok((
         Some(Definition(
@@ -280,7 +346,70 @@ fn parse_choice(
             Type::Choice(
                 Choice::from_variants(variants.into_iter()).with_extension_after(extensible_after),
             )
-            .opt_tagged(asn.tag),
+            .opt_tagged(asn.tag)
+            .with_tagging(asn.tagging),
+        )),
+        Item::Enum(enm),
+    ))
+}
+
+fn parse_open_type(
+    mut enm: syn::ItemEnum,
+    asn: &AsnAttribute<DefinitionHeader>,
+    _asn_span: proc_macro2::Span,
+) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
+    let mut variants = Vec::with_capacity(enm.variants.len());
+    let mut unknown = false;
+
+    for v in enm.variants.iter_mut() {
+        let span = v.span();
+        if v.fields.len() != 1 || v.fields.iter().next().unwrap().ident.is_some() {
+            compile_err_ts(
+                span,
+                "Variants of open types have to have exactly one unnamed field",
+            )?;
+        }
+
+        if index_of_first_asn_attribute(&v.attrs).is_none() {
+            if v.ident != OPEN_TYPE_UNKNOWN_VARIANT {
+                compile_err_ts(span, "Variants of open types need a key")?;
+            }
+            unknown = true;
+            continue;
+        }
+
+        let attribute =
+            parse_and_remove_first_asn_attribute::<OpenTypeVariant>(span, &mut v.attrs)?;
+        let key = attribute
+            .key
+            .ok_or_else(|| compile_error_ts(span, "Variants of open types need a key"))?;
+        let asn = into_asn_or_err(span, &v.fields.iter().next().unwrap().ty, attribute)?;
+        variants.push(OpenTypeVariant {
+            name: v.ident.to_string(),
+            key,
+            r#type: asn.r#type,
+        });
+    }
+
+    if !unknown {
+        compile_err_ts(
+            enm.span(),
+            format!(
+                "Open types need the variant {}(Vec<u8>) for the content of unknown types",
+                OPEN_TYPE_UNKNOWN_VARIANT
+            ),
+        )?;
+    }
+
+    Ok((
+        Some(Definition(
+            enm.ident.to_string(),
+            Type::OpenType(OpenType {
+                variants,
+                ..Default::default()
+            })
+            .opt_tagged(asn.tag)
+            .with_tagging(asn.tagging),
         )),
         Item::Enum(enm),
     ))
@@ -344,6 +473,7 @@ fn into_asn<C: Context<Primary = Type>>(
 ) -> Option<AsnModelType> {
     Some(AsnModelType {
         tag: asn.tag,
+        tagging: asn.tagging,
         r#type: if let Type::TypeReference(_) = asn.primary {
             Type::TypeReference(quote! { #ty }.to_string())
         } else {
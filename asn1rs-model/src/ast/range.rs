@@ -2,7 +2,9 @@ use proc_macro2::Delimiter;
 use syn::buffer::Cursor;
 use syn::parse::{Parse, ParseBuffer, StepCursor};
 
-pub struct MaybeRanged(pub Option<(i64, i64)>);
+/// A range whose bounds are absent if given as `min` or `max`, and whether it is
+/// extensible (`, ...`)
+pub struct MaybeRanged(pub Option<i64>, pub Option<i64>, pub bool);
 
 impl Parse for MaybeRanged {
     fn parse<'a>(input: &'a ParseBuffer<'a>) -> syn::Result<Self> {
@@ -17,22 +19,48 @@ impl Parse for MaybeRanged {
                 let (_, c) = c.punct().ok_or_else(|| stepper.error("Expected dot"))?;
                 let (_, c) = c.punct().ok_or_else(|| stepper.error("Expected dot"))?;
 
-                let (max, _c) = number_potentially_negative(&stepper, c, "Expected max value")?;
+                let (max, c) = number_potentially_negative(&stepper, c, "Expected max value")?;
+                let extensible = extension_marker(&stepper, c)?;
 
-                let min = min.to_lowercase();
-                let max = max.to_lowercase();
-
-                if min == "min" && max == "max" {
-                    Ok((MaybeRanged(None), outer))
-                } else {
-                    let min = min.parse::<i64>().map_err(|_| stepper.error("Not i64"))?;
-                    let max = max.parse::<i64>().map_err(|_| stepper.error("Not i64"))?;
-                    Ok((MaybeRanged(Some((min, max))), outer))
-                }
+                let min = parse_bound(&stepper, &min, "min")?;
+                let max = parse_bound(&stepper, &max, "max")?;
+                Ok((MaybeRanged(min, max, extensible), outer))
             })
         } else {
-            Ok(MaybeRanged(None))
+            Ok(MaybeRanged(None, None, false))
+        }
+    }
+}
+
+/// Whether the cursor points to the `, ...` of an extensible range
+fn extension_marker<'a>(stepper: &'a StepCursor<'_, 'a>, c: Cursor<'a>) -> syn::Result<bool> {
+    match c.punct() {
+        Some((comma, mut c)) if comma.as_char() == ',' => {
+            for _ in 0..3 {
+                c = c
+                    .punct()
+                    .filter(|(dot, _)| dot.as_char() == '.')
+                    .map(|(_, c)| c)
+                    .ok_or_else(|| stepper.error("Expected ..."))?;
+            }
+            Ok(true)
         }
+        _ => Ok(false),
+    }
+}
+
+fn parse_bound<'a>(
+    stepper: &'a StepCursor<'_, 'a>,
+    value: &str,
+    open: &str,
+) -> Result<Option<i64>, syn::Error> {
+    if value.eq_ignore_ascii_case(open) {
+        Ok(None)
+    } else {
+        value
+            .parse::<i64>()
+            .map(Some)
+            .map_err(|_| stepper.error("Not i64"))
     }
 }
 
@@ -155,6 +155,11 @@ impl ProtobufDefGenerator {
             ProtobufType::Repeated(inner) => {
                 format!("repeated {}", Self::role_to_full_type(inner, model))
             }
+            ProtobufType::BitsPrefixed => ProtobufType::Bytes.to_string(),
+            ProtobufType::Null => ProtobufType::Bytes.to_string(),
+            ProtobufType::ObjectIdentifier => ProtobufType::String.to_string(),
+            ProtobufType::GeneralizedTime => ProtobufType::String.to_string(),
+            ProtobufType::UtcTime => ProtobufType::String.to_string(),
             r => r.to_string(),
         }
     }
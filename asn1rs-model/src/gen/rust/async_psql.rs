@@ -317,7 +317,13 @@ fn insert_field(
             field_name_as_variable,
         )
     } else {
-        insert_complex_field(on_self, container, field_name, field_name_as_variable)
+        insert_complex_field(
+            on_self,
+            container,
+            field_name,
+            r_type,
+            field_name_as_variable,
+        )
     }
 }
 
@@ -485,7 +491,7 @@ fn insert_vec_field(
             MODULE_NAME
         ));
     }
-    let conversion = r_type.to_sql().to_rust().ne(r_type);
+    let conversion = !r_type.to_sql().to_rust().similar(r_type);
     many_insert.line("let prepared = &prepared;");
     many_insert.line(&format!(
         "{}::try_join_all(inserted.iter().map(|i| async move {{ context.query(prepared, &[&id, {}]).await }} )).await",
@@ -505,7 +511,7 @@ fn insert_sql_primitive_field(
     field_name_as_variable: Option<&str>,
 ) -> FieldInsert {
     let rerust = r_type.to_sql().to_rust();
-    let conversion = if rerust.ne(r_type) {
+    let conversion = if !rerust.similar(r_type) {
         Some(rerust)
     } else {
         None
@@ -530,19 +536,33 @@ fn insert_complex_field(
     on_self: bool,
     container: &mut impl Container,
     field_name: &str,
+    r_type: &RustType,
     field_name_as_variable: Option<&str>,
 ) -> FieldInsert {
     let variable_name = field_name_as_variable.unwrap_or(field_name).to_string();
+    let (boxed_prefix, boxed_suffix) = boxed_future_prefix_suffix(r_type);
     container.line(&format!(
-        "let {} = {}{}.{}(context);",
+        "let {} = {}{}{}.{}(context){};",
         variable_name,
+        boxed_prefix,
         if on_self { "self." } else { "" },
         field_name,
-        insert_fn_name()
+        insert_fn_name(),
+        boxed_suffix,
     ));
     FieldInsert::AsyncComplex(variable_name)
 }
 
+/// The futures of boxed values of recursive types need to be boxed as well, otherwise
+/// the future would contain itself
+fn boxed_future_prefix_suffix(rust: &RustType) -> (&'static str, &'static str) {
+    if let RustType::Box(_) = rust {
+        ("Box::pin(", ")")
+    } else {
+        ("", "")
+    }
+}
+
 enum FieldInsert {
     AsyncVec,
     AsyncComplex(String),
@@ -673,7 +693,7 @@ impl AsyncPsqlInserter {
                 &sql,
                 &**inner,
             )
-        } else if let RustType::Vec(inner) = f_type {
+        } else if let RustType::Vec(inner, _, _) = f_type {
             AsyncPsqlInserter::append_load_vec_field(
                 is_tuple_struct,
                 struct_name,
@@ -707,7 +727,7 @@ impl AsyncPsqlInserter {
             RustCodeGenerator::rust_field_name(field, true),
             sql.to_rust().to_inner_type_string(),
             index + 1,
-            if sql.to_rust().ne(f_type) {
+            if !sql.to_rust().similar(f_type) {
                 format!(" as {}", f_type.to_inner_type_string())
             } else {
                 String::default()
@@ -744,7 +764,7 @@ impl AsyncPsqlInserter {
                 "for row in rows {{ {}.push(row.try_get::<usize, {}>(0)?{}); }}",
                 RustCodeGenerator::rust_field_name(field, true),
                 inner.to_sql().to_rust().to_inner_type_string(),
-                if sql.to_rust().ne(f_type) {
+                if !sql.to_rust().similar(f_type) {
                     format!(" as {}", f_type.to_inner_type_string())
                 } else {
                     String::default()
@@ -805,7 +825,7 @@ impl AsyncPsqlInserter {
                 RustCodeGenerator::rust_field_name(field, true),
                 sql.to_rust().as_no_option().to_inner_type_string(),
                 index + 1,
-                if sql.to_rust().ne(f_type) {
+                if !sql.to_rust().similar(f_type) {
                     format!(".map(|v| v as {})", inner.to_inner_type_string())
                 } else {
                     String::default()
@@ -829,12 +849,22 @@ impl AsyncPsqlInserter {
     }
 
     fn append_load_complex_field(container: &mut impl Container, field: &str, f_type: &RustType) {
+        let (boxed_prefix, boxed_suffix) = if let RustType::Box(_) = f_type {
+            ("Box::new(", ")")
+        } else {
+            ("", "")
+        };
+        let (future_prefix, future_suffix) = boxed_future_prefix_suffix(f_type);
         container.line(format!(
-            "let {} = {}::{}(context, {}).await?;",
+            "let {} = {}{}{}::{}(context, {}){}.await?{};",
             RustCodeGenerator::rust_field_name(field, true),
-            f_type.to_inner_type_string(),
+            boxed_prefix,
+            future_prefix,
+            f_type.as_inner_type().to_string(),
             retrieve_fn_name(),
             RustCodeGenerator::rust_field_name(field, true),
+            future_suffix,
+            boxed_suffix,
         ));
     }
 }
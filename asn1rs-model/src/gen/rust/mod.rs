@@ -19,13 +19,16 @@ pub(crate) mod shared_psql;
 use self::protobuf::ProtobufSerializer;
 use crate::gen::Generator;
 use crate::model::rust::PlainEnum;
-use crate::model::rust::{DataEnum, Field};
+use crate::model::rust::{DataEnum, EncodingOrdering, Field};
 use crate::model::Model;
 use crate::model::Range;
 use crate::model::Rust;
 use crate::model::RustType;
 use crate::model::TagProperty;
-use crate::model::{Definition, Tag, Type as AsnType, Type};
+use crate::model::{
+    Charset, Definition, Integer, LiteralValue, Size, Tag, TaggingMode, Type as AsnType, Type,
+    ValueReference,
+};
 use codegen::Block;
 use codegen::Enum;
 use codegen::Function;
@@ -156,6 +159,10 @@ impl RustCodeGenerator {
             }
         }
 
+        for value_reference in &model.value_references {
+            scope.raw(&Self::value_reference_const(value_reference));
+        }
+
         for definition in &model.definitions {
             self.add_definition(&mut scope, definition);
             Self::impl_definition(&mut scope, definition, generators, self.getter_and_setter);
@@ -168,12 +175,30 @@ impl RustCodeGenerator {
         (file, scope.to_string())
     }
 
+    fn value_reference_const(ValueReference { name, role, value }: &ValueReference) -> String {
+        let r#type = Model::<Rust>::definition_type_to_rust_type(name, role, &mut Vec::new());
+        let (r#type, value) = Self::literal_value_const(&r#type, value);
+        format!("pub const {}: {} = {};", name, r#type, value)
+    }
+
     pub fn add_definition(&self, scope: &mut Scope, Definition(name, rust): &Definition<Rust>) {
+        let hashable = self.is_hashable(rust, &mut Vec::new());
         match rust {
-            Rust::Struct(fields) => {
-                scope.raw(&Self::asn_attribute("sequence", None, None));
+            Rust::Struct {
+                fields,
+                extension_after,
+                extension_groups,
+                ordering,
+            } => {
+                scope.raw(&Self::asn_sequence_attribute(
+                    fields,
+                    *extension_after,
+                    extension_groups,
+                    *ordering,
+                ));
+                let derive_default = fields.iter().all(|f| f.default_value().is_none());
                 Self::add_struct(
-                    self.new_struct(scope, name),
+                    self.new_struct(scope, name, hashable, derive_default),
                     name,
                     fields,
                     self.direct_field_access,
@@ -183,22 +208,48 @@ impl RustCodeGenerator {
                 scope.raw(&Self::asn_attribute(
                     "enumerated",
                     None,
-                    plain.extension_after_variant().cloned(),
+                    None,
+                    None,
+                    plain
+                        .extension_after_variant()
+                        .map(|v| v.name().to_string()),
+                    None,
                 ));
-                Self::add_enum(self.new_enum(scope, name, true), name, plain)
+                Self::add_enum(self.new_enum(scope, name, true, hashable), name, plain)
             }
             Rust::DataEnum(data) => {
                 scope.raw(&Self::asn_attribute(
                     "choice",
                     None,
+                    None,
+                    None,
                     data.extension_after_variant().map(|v| v.name().to_string()),
+                    None,
+                ));
+                Self::add_data_enum(self.new_enum(scope, name, false, hashable), name, data)
+            }
+            Rust::OpenType(data) => {
+                scope.raw(&Self::asn_attribute(
+                    "open_type",
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
                 ));
-                Self::add_data_enum(self.new_enum(scope, name, false), name, data)
+                Self::add_open_type(self.new_enum(scope, name, false, hashable), name, data)
             }
             Rust::TupleStruct(inner) => {
-                scope.raw(&Self::asn_attribute("transparent", None, None));
+                scope.raw(&Self::asn_attribute(
+                    "transparent",
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                ));
                 Self::add_tuple_struct(
-                    self.new_struct(scope, name),
+                    self.new_struct(scope, name, hashable, true),
                     name,
                     inner,
                     self.direct_field_access,
@@ -210,16 +261,24 @@ impl RustCodeGenerator {
 
     fn add_struct(str_ct: &mut Struct, _name: &str, fields: &[Field], pub_access: bool) {
         for field in fields {
+            let r#type = match field.key_field() {
+                Some(key_field) => format!("open_type({})", key_field),
+                None => Self::asn_attribute_type(&field.r#type().clone().into_asn()),
+            };
+            let field_name = Self::rust_field_name(field.name(), true);
             str_ct.field(
                 &format!(
                     "{} {}{}",
                     Self::asn_attribute(
-                        &Self::asn_attribute_type(&field.r#type().clone().into_asn()),
+                        &r#type,
                         field.tag(),
-                        None
+                        field.tagging(),
+                        field.default_value(),
+                        None,
+                        Self::asn_name_if_renamed(field.asn_name(), &field_name),
                     ),
                     if pub_access { "pub " } else { "" },
-                    Self::rust_field_name(field.name(), true),
+                    field_name,
                 ),
                 field.r#type().to_string(),
             );
@@ -228,25 +287,67 @@ impl RustCodeGenerator {
 
     fn add_enum(en_m: &mut Enum, _name: &str, rust_enum: &PlainEnum) {
         for variant in rust_enum.variants() {
-            en_m.new_variant(&Self::rust_variant_name(variant));
+            let variant_name = Self::rust_variant_name(variant.name());
+            let attributes = variant
+                .number()
+                .map(|number| number.to_string())
+                .into_iter()
+                .chain(
+                    Self::asn_name_if_renamed(variant.asn_name(), &variant_name)
+                        .map(Self::asn_attribute_name),
+                )
+                .collect::<Vec<_>>();
+            if attributes.is_empty() {
+                en_m.new_variant(&variant_name);
+            } else {
+                en_m.new_variant(&format!(
+                    "#[asn({})] {}",
+                    attributes.join(", "),
+                    variant_name
+                ));
+            }
         }
     }
 
     fn add_data_enum(en_m: &mut Enum, _name: &str, enumeration: &DataEnum) {
         for variant in enumeration.variants() {
+            let variant_name = Self::rust_variant_name(variant.name());
             en_m.new_variant(&format!(
                 "{} {}({})",
                 Self::asn_attribute(
                     Self::asn_attribute_type(&variant.r#type().clone().into_asn()),
                     variant.tag(),
-                    None
+                    variant.tagging(),
+                    None,
+                    None,
+                    Self::asn_name_if_renamed(variant.asn_name(), &variant_name),
                 ),
-                Self::rust_variant_name(variant.name()),
+                variant_name,
                 variant.r#type().to_string(),
             ));
         }
     }
 
+    fn add_open_type(en_m: &mut Enum, _name: &str, enumeration: &DataEnum) {
+        for variant in enumeration.variants() {
+            let name_type = format!(
+                "{}({})",
+                Self::rust_variant_name(variant.name()),
+                variant.r#type().to_string(),
+            );
+            match variant.key() {
+                Some(key) => en_m.new_variant(&format!(
+                    "#[asn({}, key({}))] {}",
+                    Self::asn_attribute_type(&variant.r#type().clone().into_asn()),
+                    key,
+                    name_type,
+                )),
+                // the variant for the content of unknown types
+                None => en_m.new_variant(&name_type),
+            };
+        }
+    }
+
     fn add_tuple_struct(
         str_ct: &mut Struct,
         _name: &str,
@@ -259,6 +360,9 @@ impl RustCodeGenerator {
             Self::asn_attribute(
                 Self::asn_attribute_type(&inner.clone().into_asn()),
                 tag,
+                None,
+                None,
+                None,
                 None
             ),
             if pub_access { "pub " } else { "" },
@@ -269,14 +373,20 @@ impl RustCodeGenerator {
     fn asn_attribute<T: ToString>(
         r#type: T,
         tag: Option<Tag>,
+        tagging: Option<TaggingMode>,
+        default: Option<&LiteralValue>,
         extensible_after: Option<String>,
+        name: Option<String>,
     ) -> String {
         format!(
             "#[asn({})]",
             vec![
                 Some(r#type.to_string()),
                 tag.map(Self::asn_attribute_tag),
-                extensible_after.map(Self::asn_attribute_extensible_after)
+                tagging.map(Self::asn_attribute_tagging),
+                default.map(Self::asn_attribute_default),
+                extensible_after.map(Self::asn_attribute_extensible_after),
+                name.map(Self::asn_attribute_name),
             ]
             .into_iter()
             .flatten()
@@ -285,22 +395,147 @@ impl RustCodeGenerator {
         )
     }
 
+    fn asn_sequence_attribute(
+        fields: &[Field],
+        extension_after: Option<usize>,
+        extension_groups: &[Range<usize>],
+        ordering: EncodingOrdering,
+    ) -> String {
+        let primary = match ordering {
+            EncodingOrdering::Keep => "sequence",
+            EncodingOrdering::Sort => "set",
+        };
+        format!(
+            "#[asn({})]",
+            Some(String::from(primary))
+                .into_iter()
+                .chain(
+                    extension_after
+                        .and_then(|index| fields.get(index))
+                        .map(|field| Self::rust_field_name(field.name(), true))
+                        .map(Self::asn_attribute_extensible_after),
+                )
+                .chain(extension_groups.iter().map(|Range(first, last, _)| {
+                    format!(
+                        "extension_group({}, {})",
+                        Self::rust_field_name(fields[*first].name(), true),
+                        Self::rust_field_name(fields[*last].name(), true)
+                    )
+                }))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
     fn asn_attribute_type(r#type: &AsnType) -> String {
         match r#type {
             Type::Boolean => String::from("boolean"),
-            Type::Integer(Some(Range(min, max))) => format!("integer({}..{})", min, max),
-            Type::Integer(None) => String::from("integer(min..max)"),
-            Type::UTF8String => String::from("utf8string"),
-            Type::IA5String => String::from("ia5string"),
-            Type::OctetString => String::from("octet_string"),
-            Type::Optional(inner) => format!("option({})", Self::asn_attribute_type(&*inner)),
-            Type::SequenceOf(inner) => {
-                format!("sequence_of({})", Self::asn_attribute_type(&*inner))
+            Type::Integer(Integer {
+                range: Range(min, max, extensible),
+            }) => format!(
+                "integer({}..{}{})",
+                min.map_or_else(|| String::from("min"), |min| min.to_string()),
+                max.map_or_else(|| String::from("max"), |max| max.to_string()),
+                if *extensible { ", ..." } else { "" }
+            ),
+            Type::UTF8String(size) => match Self::asn_attribute_size(*size) {
+                Some(size) => format!("utf8string({})", size),
+                None => String::from("utf8string"),
+            },
+            Type::CharacterString(string) => {
+                let name = match string.charset {
+                    Charset::Numeric => "numeric_string",
+                    Charset::Printable => "printable_string",
+                    Charset::Visible => "visible_string",
+                    Charset::Ia5 => "ia5string",
+                    Charset::Bmp => "bmp_string",
+                    Charset::Universal => "universal_string",
+                };
+                let args = Self::asn_attribute_size(string.size)
+                    .into_iter()
+                    .chain(
+                        string
+                            .permitted_alphabet
+                            .as_ref()
+                            .map(|alphabet| format!("from({:?})", alphabet)),
+                    )
+                    .collect::<Vec<_>>();
+                if args.is_empty() {
+                    String::from(name)
+                } else {
+                    format!("{}({})", name, args.join(", "))
+                }
             }
+            Type::OctetString(size) => match Self::asn_attribute_size(*size) {
+                Some(size) => format!("octet_string({})", size),
+                None => String::from("octet_string"),
+            },
+            Type::BitString(bit_string) => {
+                let args = Self::asn_attribute_size(bit_string.size)
+                    .into_iter()
+                    .chain(
+                        Some(&bit_string.constants)
+                            .filter(|c| !c.is_empty())
+                            .map(|c| {
+                                format!(
+                                    "const({})",
+                                    c.iter()
+                                        .map(|(name, position)| format!("{}({})", name, position))
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                )
+                            }),
+                    )
+                    .collect::<Vec<_>>();
+                if args.is_empty() {
+                    String::from("bit_string")
+                } else {
+                    format!("bit_string({})", args.join(", "))
+                }
+            }
+            Type::Null => String::from("null"),
+            Type::Real => String::from("real"),
+            Type::ObjectIdentifier => String::from("object_identifier"),
+            Type::GeneralizedTime => String::from("generalized_time"),
+            Type::UTCTime => String::from("utc_time"),
+            Type::Optional(inner) => format!("option({})", Self::asn_attribute_type(&*inner)),
+            Type::Boxed(inner) => format!("boxed({})", Self::asn_attribute_type(&*inner)),
+            Type::SequenceOf(inner, size) => match Self::asn_attribute_size(*size) {
+                Some(size) => format!(
+                    "sequence_of({}, {})",
+                    size,
+                    Self::asn_attribute_type(&*inner)
+                ),
+                None => format!("sequence_of({})", Self::asn_attribute_type(&*inner)),
+            },
             Type::Sequence(_) => String::from("sequence"),
+            Type::SetOf(inner, size) => match Self::asn_attribute_size(*size) {
+                Some(size) => format!("set_of({}, {})", size, Self::asn_attribute_type(&*inner)),
+                None => format!("set_of({})", Self::asn_attribute_type(&*inner)),
+            },
+            Type::Set(_) => String::from("set"),
             Type::Enumerated(_) => String::from("enumerated"),
             Type::Choice(_) => String::from("choice"),
             Type::TypeReference(inner) => format!("complex({})", inner),
+            Type::OpenType(open) => format!("open_type({})", open.key_field),
+        }
+    }
+
+    fn asn_attribute_size(size: Size) -> Option<String> {
+        match size {
+            Size::Any => None,
+            Size::Fix(size, false) => Some(format!("size({})", size)),
+            Size::Fix(size, true) => Some(format!("size({}, ...)", size)),
+            Size::Range(min, max, extensible) => Some(format!(
+                "size({}..{}{})",
+                min,
+                if max == usize::MAX {
+                    String::from("max")
+                } else {
+                    max.to_string()
+                },
+                if extensible { ", ..." } else { "" }
+            )),
         }
     }
 
@@ -313,10 +548,40 @@ impl RustCodeGenerator {
         }
     }
 
+    fn asn_attribute_tagging(tagging: TaggingMode) -> String {
+        match tagging {
+            TaggingMode::Explicit => String::from("explicit"),
+            TaggingMode::Implicit => String::from("implicit"),
+        }
+    }
+
+    fn asn_attribute_default(value: &LiteralValue) -> String {
+        match value {
+            LiteralValue::Boolean(value) => format!("default({})", value),
+            LiteralValue::Integer(value) => format!("default({})", value),
+            LiteralValue::String(value) => format!("default({:?})", value),
+            LiteralValue::EnumeratedVariant(variant) => format!("default({})", variant),
+        }
+    }
+
     fn asn_attribute_extensible_after(variant: String) -> String {
         format!("extensible_after({})", variant)
     }
 
+    fn asn_attribute_name(name: String) -> String {
+        format!("name({:?})", name)
+    }
+
+    /// The name of the field or variant in the ASN.1 definition, if it cannot be derived from
+    /// the identifier in the generated code
+    fn asn_name_if_renamed(asn_name: &str, identifier: &str) -> Option<String> {
+        if asn_name != identifier {
+            Some(asn_name.to_string())
+        } else {
+            None
+        }
+    }
+
     fn impl_definition(
         scope: &mut Scope,
         Definition(name, rust): &Definition<Rust>,
@@ -324,11 +589,12 @@ impl RustCodeGenerator {
         getter_and_setter: bool,
     ) {
         match rust {
-            Rust::Struct(fields) => {
+            Rust::Struct { fields, .. } => {
                 let implementation = Self::impl_struct(scope, name, fields, getter_and_setter);
                 for g in generators {
                     g.extend_impl_of_struct(name, implementation, fields);
                 }
+                Self::impl_struct_default(scope, name, fields);
             }
             Rust::Enum(r_enum) => {
                 let implementation = Self::impl_enum(scope, name, r_enum);
@@ -337,7 +603,7 @@ impl RustCodeGenerator {
                 }
                 Self::impl_enum_default(scope, name, r_enum);
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 let implementation = Self::impl_data_enum(scope, name, enumeration);
                 for g in generators {
                     g.extend_impl_of_data_enum(name, implementation, enumeration);
@@ -379,6 +645,7 @@ impl RustCodeGenerator {
     fn impl_tuple_struct<'a>(scope: &'a mut Scope, name: &str, rust: &RustType) -> &'a mut Impl {
         let implementation = scope.new_impl(name);
         Self::add_min_max_fn_if_applicable(implementation, None, rust);
+        Self::add_named_bit_fns_if_applicable(implementation, None, rust);
         implementation
     }
 
@@ -398,6 +665,11 @@ impl RustCodeGenerator {
             }
 
             Self::add_min_max_fn_if_applicable(implementation, Some(field.name()), field.r#type());
+            Self::add_named_bit_fns_if_applicable(
+                implementation,
+                Some(field.name()),
+                field.r#type(),
+            );
         }
         implementation
     }
@@ -439,6 +711,91 @@ impl RustCodeGenerator {
             ));
     }
 
+    /// Adds an associated constant for each `DEFAULT` value and implements `Default`
+    /// with these values instead of deriving it
+    fn impl_struct_default(scope: &mut Scope, name: &str, fields: &[Field]) {
+        if fields.iter().all(|f| f.default_value().is_none()) {
+            return;
+        }
+
+        let mut constants = vec![format!("impl {} {{", name)];
+        let mut block = Block::new("Self");
+        for field in fields {
+            let value = if let Some(default) = field.default_value() {
+                let constant = Self::default_value_const_name(field.name());
+                let (r#type, value) = Self::literal_value_const(field.r#type(), default);
+                constants.push(format!("pub const {}: {} = {};", constant, r#type, value));
+                Self::default_value_from_const(
+                    field.r#type(),
+                    default,
+                    &format!("Self::{}", constant),
+                )
+            } else {
+                String::from("Default::default()")
+            };
+            block.line(format!(
+                "{}: {},",
+                Self::rust_field_name(field.name(), true),
+                value
+            ));
+        }
+        constants.push(String::from("}"));
+        scope.raw(&constants.join("\n"));
+
+        scope
+            .new_impl(name)
+            .impl_trait("Default")
+            .new_fn("default")
+            .ret("Self")
+            .push_block(block);
+    }
+
+    pub fn default_value_const_name(field_name: &str) -> String {
+        format!(
+            "DEFAULT_{}",
+            Self::rust_field_name(field_name, false).to_uppercase()
+        )
+    }
+
+    /// The type and the value of a constant for the given value of the given type. Strings
+    /// are constants of `&'static str`, because a `String` cannot be created in a const context
+    pub fn literal_value_const(r#type: &RustType, value: &LiteralValue) -> (String, String) {
+        let literal = match value {
+            LiteralValue::Boolean(value) => value.to_string(),
+            LiteralValue::Integer(value) => value.to_string(),
+            LiteralValue::String(value) => {
+                return (String::from("&'static str"), format!("{:?}", value))
+            }
+            LiteralValue::EnumeratedVariant(variant) => {
+                return (
+                    r#type.to_string(),
+                    format!("{}::{}", r#type.to_string(), variant),
+                )
+            }
+        };
+        if let RustType::Complex(name) = r#type {
+            (name.clone(), format!("{}({})", name, literal))
+        } else {
+            (r#type.to_string(), literal)
+        }
+    }
+
+    /// The expression that turns the constant of [`Self::literal_value_const`] into a value
+    /// of the field type
+    pub fn default_value_from_const(
+        r#type: &RustType,
+        value: &LiteralValue,
+        constant: &str,
+    ) -> String {
+        match (value, r#type) {
+            (LiteralValue::String(_), RustType::Complex(name)) => {
+                format!("{}({}.to_string())", name, constant)
+            }
+            (LiteralValue::String(_), _) => format!("{}.to_string()", constant),
+            _ => constant.to_string(),
+        }
+    }
+
     fn impl_enum_default(scope: &mut Scope, name: &str, r_enum: &PlainEnum) {
         scope
             .new_impl(name)
@@ -448,7 +805,7 @@ impl RustCodeGenerator {
             .line(format!(
                 "{}::{}",
                 name,
-                Self::rust_variant_name(r_enum.variants().next().unwrap())
+                Self::rust_variant_name(r_enum.variants().next().unwrap().name())
             ));
     }
 
@@ -475,7 +832,7 @@ impl RustCodeGenerator {
                 "{} => Some({}::{}),",
                 index,
                 name,
-                Self::rust_variant_name(variant)
+                Self::rust_variant_name(variant.name())
             ));
         }
         block_match.line("_ => None,");
@@ -490,7 +847,11 @@ impl RustCodeGenerator {
             .line("[");
 
         for variant in r_enum.variants() {
-            values_fn.line(format!("{}::{},", name, Self::rust_variant_name(variant)));
+            values_fn.line(format!(
+                "{}::{},",
+                name,
+                Self::rust_variant_name(variant.name())
+            ));
         }
         values_fn.line("]");
     }
@@ -510,7 +871,7 @@ impl RustCodeGenerator {
                 block.line(format!(
                     "{}::{} => {},",
                     name,
-                    Self::rust_variant_name(variant),
+                    Self::rust_variant_name(variant.name()),
                     ordinal
                 ));
             });
@@ -603,7 +964,7 @@ impl RustCodeGenerator {
         } else {
             "value_".to_string()
         };
-        if let Some(Range(min, max)) = field_type.integer_range_str() {
+        if let Some(Range(min, max, _)) = field_type.integer_range_str() {
             implementation
                 .new_fn(&format!("{}min", prefix))
                 .vis("pub const")
@@ -617,6 +978,44 @@ impl RustCodeGenerator {
         }
     }
 
+    /// Adds a getter and a setter for each named bit of a `BIT STRING`
+    fn add_named_bit_fns_if_applicable(
+        implementation: &mut Impl,
+        field_name: Option<&str>,
+        field_type: &RustType,
+    ) {
+        if let RustType::BitVec(bit_string) = field_type {
+            let (prefix, field) = if let Some(field_name) = field_name {
+                (
+                    format!("{}_", field_name),
+                    Self::rust_field_name(field_name, true),
+                )
+            } else {
+                (String::new(), "0".to_string())
+            };
+            for (name, bit) in &bit_string.constants {
+                implementation
+                    .new_fn(&format!("is_{}{}_set", prefix, name))
+                    .vis("pub")
+                    .arg_ref_self()
+                    .ret("bool")
+                    .line(format!("self.{}.is_bit_set({})", field, bit));
+
+                let mut block_if = Block::new("if value");
+                block_if.line(format!("self.{}.set_bit({});", field, bit));
+                let mut block_else = Block::new("else");
+                block_else.line(format!("self.{}.reset_bit({});", field, bit));
+                implementation
+                    .new_fn(&format!("set_{}{}", prefix, name))
+                    .vis("pub")
+                    .arg_mut_self()
+                    .arg("value", "bool")
+                    .push_block(block_if)
+                    .push_block(block_else);
+            }
+        }
+    }
+
     fn format_number_nicely(string: &str) -> String {
         let mut out = String::with_capacity(string.len() * 2);
         let mut pos = (3 - string.len() % 3) % 3;
@@ -689,29 +1088,76 @@ impl RustCodeGenerator {
         out
     }
 
-    fn new_struct<'a>(&self, scope: &'a mut Scope, name: &str) -> &'a mut Struct {
-        let str_ct = scope
-            .new_struct(name)
-            .vis("pub")
-            .derive("Default")
-            .derive("Debug")
-            .derive("Clone")
-            .derive("PartialEq")
-            .derive("Hash");
+    /// Whether `Hash` can be derived, which is not the case for types that
+    /// contain a `f64` - directly or through a referenced definition
+    fn is_hashable(&self, rust: &Rust, visited: &mut Vec<String>) -> bool {
+        match rust {
+            Rust::Struct { fields, .. } => fields
+                .iter()
+                .all(|field| self.is_hashable_type(field.r#type(), visited)),
+            Rust::Enum(_) => true,
+            Rust::DataEnum(data) | Rust::OpenType(data) => data
+                .variants()
+                .all(|variant| self.is_hashable_type(variant.r#type(), visited)),
+            Rust::TupleStruct(inner) => self.is_hashable_type(inner, visited),
+        }
+    }
+
+    fn is_hashable_type(&self, r#type: &RustType, visited: &mut Vec<String>) -> bool {
+        match r#type {
+            RustType::F64 => false,
+            RustType::Vec(inner, _, _) | RustType::Option(inner) | RustType::Box(inner) => {
+                self.is_hashable_type(inner, visited)
+            }
+            RustType::Complex(name) if !visited.contains(name) => {
+                visited.push(name.clone());
+                self.models
+                    .iter()
+                    .flat_map(|model| model.definitions.iter())
+                    .find(|Definition(n, _)| n == name)
+                    .is_none_or(|Definition(_, rust)| self.is_hashable(rust, visited))
+            }
+            _ => true,
+        }
+    }
+
+    fn new_struct<'a>(
+        &self,
+        scope: &'a mut Scope,
+        name: &str,
+        hashable: bool,
+        derive_default: bool,
+    ) -> &'a mut Struct {
+        let str_ct = scope.new_struct(name).vis("pub");
+        if derive_default {
+            str_ct.derive("Default");
+        }
+        str_ct.derive("Debug").derive("Clone").derive("PartialEq");
+        if hashable {
+            str_ct.derive("Hash");
+        }
         self.global_derives.iter().for_each(|derive| {
             str_ct.derive(derive);
         });
         str_ct
     }
 
-    fn new_enum<'a>(&self, scope: &'a mut Scope, name: &str, c_enum: bool) -> &'a mut Enum {
+    fn new_enum<'a>(
+        &self,
+        scope: &'a mut Scope,
+        name: &str,
+        c_enum: bool,
+        hashable: bool,
+    ) -> &'a mut Enum {
         let en_m = scope
             .new_enum(name)
             .vis("pub")
             .derive("Debug")
             .derive("Clone")
-            .derive("PartialEq")
-            .derive("Hash");
+            .derive("PartialEq");
+        if hashable {
+            en_m.derive("Hash");
+        }
         if c_enum {
             en_m.derive("Copy").derive("PartialOrd").derive("Eq");
         }
@@ -7,6 +7,7 @@ use crate::model::Definition;
 use crate::model::ProtobufType;
 use crate::model::Rust;
 use crate::model::RustType;
+use crate::model::Size;
 use codegen::Block;
 use codegen::Function;
 use codegen::Impl;
@@ -72,13 +73,13 @@ impl ProtobufSerializer {
             Rust::TupleStruct(aliased) => {
                 Self::impl_read_fn_for_tuple_struct(function, aliased);
             }
-            Rust::Struct(fields) => {
+            Rust::Struct { fields, .. } => {
                 Self::impl_read_fn_for_struct(function, name, &fields[..]);
             }
             Rust::Enum(r_enum) => {
                 Self::impl_read_fn_for_enum(function, name, r_enum);
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::impl_read_fn_for_data_enum(function, name, enumeration);
             }
         };
@@ -102,7 +103,11 @@ impl ProtobufSerializer {
 
         match aliased.clone().into_inner_type() {
             RustType::Complex(custom) => {
-                block_reader.line(format!("me.0.push({}::read_protobuf(reader)?);", custom))
+                if aliased.as_no_option().is_vec() {
+                    block_reader.line(format!("me.0.push({}::read_protobuf(reader)?);", custom))
+                } else {
+                    block_reader.line(format!("me.0 = {}::read_protobuf(reader)?;", custom))
+                }
             }
             r => {
                 if aliased.as_no_option().is_vec() {
@@ -140,11 +145,17 @@ impl ProtobufSerializer {
         for (prev_tag, field) in fields.iter().enumerate() {
             match &field.r#type().clone().into_inner_type() {
                 RustType::Complex(name) => {
+                    let (boxed_prefix, boxed_suffix) =
+                        if let RustType::Box(_) = field.r#type().as_no_option() {
+                            ("Box::new(", ")")
+                        } else {
+                            ("", "")
+                        };
                     let mut block_case = Block::new(&format!(
                         "{} => read_{}{}(",
                         prev_tag + 1,
                         RustCodeGenerator::rust_field_name(field.name(), false),
-                        if let RustType::Vec(_) = field.r#type().clone().no_option() {
+                        if let RustType::Vec(..) = field.r#type().clone().no_option() {
                             ".get_or_insert_with(Vec::default).push"
                         } else {
                             " = Some"
@@ -156,19 +167,24 @@ impl ProtobufSerializer {
                     ));
                     block_case_if.line("let bytes = reader.read_bytes()?;");
                     block_case_if.line(format!(
-                        "{}::read_protobuf(&mut &bytes[..] as &mut dyn {}Reader)?",
+                        "{}{}::read_protobuf(&mut &bytes[..] as &mut dyn {}Reader)?{}",
+                        boxed_prefix,
                         name,
-                        Self::CODEC
+                        Self::CODEC,
+                        boxed_suffix,
                     ));
                     let mut block_case_el = Block::new("else");
-                    block_case_el.line(format!("{}::read_protobuf(reader)?", name));
+                    block_case_el.line(format!(
+                        "{}{}::read_protobuf(reader)?{}",
+                        boxed_prefix, name, boxed_suffix
+                    ));
                     block_case.push_block(block_case_if);
                     block_case.push_block(block_case_el);
                     block_case.after("),");
                     block_match_tag.push_block(block_case);
                 }
                 role => {
-                    if let RustType::Vec(_) = field.r#type().clone().no_option() {
+                    if let RustType::Vec(..) = field.r#type().clone().no_option() {
                         block_match_tag.line(format!(
                             "{} => read_{}.get_or_insert_with(Vec::default).push({}),",
                             prev_tag + 1,
@@ -203,7 +219,7 @@ impl ProtobufSerializer {
                 RustCodeGenerator::rust_field_name(field.name(), false),
                 if as_rust_statement.is_empty() {
                     "".into()
-                } else if let RustType::Vec(_) = field.r#type().clone().no_option() {
+                } else if let RustType::Vec(..) = field.r#type().clone().no_option() {
                     format!(
                         ".map(|v| v.into_iter().map(|v| v{}).collect())",
                         as_rust_statement
@@ -230,7 +246,7 @@ impl ProtobufSerializer {
                 "{} => Ok({}::{}),",
                 field,
                 name,
-                RustCodeGenerator::rust_variant_name(variant),
+                RustCodeGenerator::rust_variant_name(variant.name()),
             ));
         }
         block_match.line(format!(
@@ -270,14 +286,20 @@ impl ProtobufSerializer {
             } else {
                 // primitive
                 block_case.line(format!(
-                    "let value = reader.read_{}()?;",
-                    variant.r#type().to_protobuf().to_string()
+                    "let value = reader.read_{}()?{};",
+                    variant.r#type().to_protobuf().to_string(),
+                    Self::get_as_rust_type_statement(&variant.r#type().clone().into_inner_type()),
                 ));
             }
             block_case.line(format!(
-                "Ok({}::{}(value))",
+                "Ok({}::{}({}))",
                 name,
-                RustCodeGenerator::rust_variant_name(variant.name())
+                RustCodeGenerator::rust_variant_name(variant.name()),
+                if let RustType::Box(_) = variant.r#type() {
+                    "Box::new(value)"
+                } else {
+                    "value"
+                }
             ));
             block_match.push_block(block_case);
         }
@@ -297,13 +319,13 @@ impl ProtobufSerializer {
             Rust::TupleStruct(aliased) => {
                 Self::impl_write_fn_for_tuple_struct(function, aliased);
             }
-            Rust::Struct(fields) => {
+            Rust::Struct { fields, .. } => {
                 Self::impl_write_fn_for_struct(function, &fields[..]);
             }
             Rust::Enum(r_enum) => {
                 Self::impl_write_fn_for_enum(function, name, r_enum);
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::impl_write_fn_for_data_enum(function, name, enumeration);
             }
         };
@@ -392,10 +414,10 @@ impl ProtobufSerializer {
         deny_self: bool,
     ) {
         match &field_type.clone().no_option() {
-            RustType::Vec(_) => {
+            RustType::Vec(..) => {
                 Self::impl_write_for_vec_attribute(&mut block, field_type, &field_name, tag);
             }
-            RustType::Complex(_) => {
+            RustType::Complex(_) | RustType::Box(_) => {
                 let format_line = format!(
                     "{}{}.{}_format()",
                     if deny_self || field_type.is_option() {
@@ -448,7 +470,12 @@ impl ProtobufSerializer {
                         format!(
                             "{}{}",
                             if ProtobufType::String == r.to_protobuf()
-                                || RustType::VecU8 == r.to_protobuf().to_rust()
+                                || ProtobufType::BitsPrefixed == r.to_protobuf()
+                                || ProtobufType::Null == r.to_protobuf()
+                                || ProtobufType::ObjectIdentifier == r.to_protobuf()
+                                || ProtobufType::GeneralizedTime == r.to_protobuf()
+                                || ProtobufType::UtcTime == r.to_protobuf()
+                                || RustType::VecU8(Size::Any) == r.to_protobuf().to_rust()
                             {
                                 if deny_self || field_type.is_option() {
                                     ""
@@ -475,7 +502,7 @@ impl ProtobufSerializer {
             outer_block.line(format!(
                 "{}::{} => writer.write_varint({})?,",
                 name,
-                RustCodeGenerator::rust_variant_name(variant),
+                RustCodeGenerator::rust_variant_name(variant.name()),
                 field,
             ));
         }
@@ -507,9 +534,9 @@ impl ProtobufSerializer {
         #[allow(clippy::match_same_arms)] // to have the same order as the original enum
         let format = match rust {
             Rust::TupleStruct(_) => Some("LengthDelimited"),
-            Rust::Struct(_) => Some("LengthDelimited"),
+            Rust::Struct { .. } => Some("LengthDelimited"),
             Rust::Enum(_) => Some("VarInt"),
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 let mut block_match = Block::new("match self");
                 for variant in enumeration.variants() {
                     block_match.line(format!(
@@ -550,7 +577,7 @@ impl ProtobufSerializer {
                     Self::CODEC.to_lowercase()
                 ));
             }
-            Rust::Struct(fields) => {
+            Rust::Struct { fields, .. } => {
                 for (num, field) in fields.iter().enumerate() {
                     if num > 0 {
                         function.line("&&");
@@ -567,7 +594,7 @@ impl ProtobufSerializer {
             Rust::Enum(_) => {
                 function.line("self == other");
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 let mut block_match = Block::new("match self");
                 for variant in enumeration.variants() {
                     let mut block_case = Block::new(&format!(
@@ -605,8 +632,16 @@ impl ProtobufSerializer {
             ProtobufType::UInt64 => format!("{}Format::VarInt", Self::CODEC),
             ProtobufType::SInt32 => format!("{}Format::VarInt", Self::CODEC),
             ProtobufType::SInt64 => format!("{}Format::VarInt", Self::CODEC),
+            ProtobufType::Double => format!("{}Format::Fixed64", Self::CODEC),
             ProtobufType::String => format!("{}Format::LengthDelimited", Self::CODEC),
             ProtobufType::Bytes => format!("{}Format::LengthDelimited", Self::CODEC),
+            ProtobufType::BitsPrefixed => format!("{}Format::LengthDelimited", Self::CODEC),
+            ProtobufType::Null => format!("{}Format::LengthDelimited", Self::CODEC),
+            ProtobufType::ObjectIdentifier => {
+                format!("{}Format::LengthDelimited", Self::CODEC)
+            }
+            ProtobufType::GeneralizedTime => format!("{}Format::LengthDelimited", Self::CODEC),
+            ProtobufType::UtcTime => format!("{}Format::LengthDelimited", Self::CODEC),
             ProtobufType::OneOf(_) => format!("{}Format::LengthDelimited", Self::CODEC),
             ProtobufType::Repeated(_) => format!("{}Format::LengthDelimited", Self::CODEC),
             ProtobufType::Complex(_complex_type) => {
@@ -628,7 +663,7 @@ impl ProtobufSerializer {
     fn get_as_rust_type_statement(role_rust: &RustType) -> String {
         let proto_rust = role_rust.to_protobuf().to_rust();
 
-        if role_rust.ne(&proto_rust) {
+        if role_rust.is_primitive() && role_rust.ne(&proto_rust) {
             format!(" as {}", role_rust.to_string())
         } else {
             "".into()
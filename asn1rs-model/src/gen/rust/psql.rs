@@ -75,7 +75,7 @@ impl PsqlInserter {
     fn impl_insertable(scope: &mut Scope, Definition(name, rust): &Definition<Rust>) {
         let implementation = Self::new_insertable_impl(scope, name);
         match rust {
-            Rust::Struct(fields) => {
+            Rust::Struct { fields, .. } => {
                 Self::impl_struct_insert_statement(
                     Self::new_insert_statement_fn(implementation),
                     name,
@@ -87,7 +87,7 @@ impl PsqlInserter {
                     fields.iter().map(Field::fallback_representation),
                 );
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::impl_data_enum_insert_statement(
                     Self::new_insert_statement_fn(implementation),
                     name,
@@ -394,7 +394,7 @@ impl PsqlInserter {
     fn impl_queryable(scope: &mut Scope, Definition(name, rust): &Definition<Rust>) {
         let implementation = Self::new_queryable_impl(scope, name);
         match rust {
-            Rust::Struct(fields) => {
+            Rust::Struct { fields, .. } => {
                 Self::impl_query_statement(Self::new_query_statement_fn(implementation), name);
                 Self::impl_struct_query_fn(Self::new_query_fn(implementation, true), name);
                 Self::impl_struct_load_fn(
@@ -408,7 +408,7 @@ impl PsqlInserter {
                     fields.iter().map(Field::fallback_representation),
                 );
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::impl_query_statement(Self::new_query_statement_fn(implementation), name);
                 Self::impl_data_enum_query_fn(Self::new_query_fn(implementation, true), name);
                 Self::impl_data_enum_load_fn(
@@ -542,23 +542,28 @@ impl PsqlInserter {
                 ));
             } else {
                 let inner = rust.clone().into_inner_type();
+                let (boxed_prefix, boxed_suffix) = Self::boxed_prefix_suffix(rust.as_no_option());
                 let load = if let RustType::Option(_) = rust {
                     format!(
                         "if let Some(id) = row.get_opt::<usize, Option<i32>>({}).ok_or_else({}::no_result)?? {{\
-                         Some({}::query_with(transaction, id)?)\
+                         Some({}{}::query_with(transaction, id)?{})\
                          }} else {{\
                          None\
                          }}",
                         index + 1,
                         ERROR_TYPE,
+                        boxed_prefix,
                         inner.to_string(),
+                        boxed_suffix,
                     )
                 } else {
                     format!(
-                        "{}::query_with(transaction, row.get_opt({}).ok_or_else({}::no_result)??)?",
+                        "{}{}::query_with(transaction, row.get_opt({}).ok_or_else({}::no_result)??)?{}",
+                        boxed_prefix,
                         inner.to_string(),
                         index + 1,
                         ERROR_TYPE,
+                        boxed_suffix,
                     )
                 };
                 block.line(&format!(
@@ -631,10 +636,13 @@ impl PsqlInserter {
                     ));
                 }
             } else {
+                let (boxed_prefix, boxed_suffix) = Self::boxed_prefix_suffix(variant.r#type());
                 block_case.line(&format!(
-                    "{}::query_with(transaction, row.get({}))?",
+                    "{}{}::query_with(transaction, row.get({}))?{}",
+                    boxed_prefix,
                     variant.r#type().clone().into_inner_type().to_string(),
-                    index + 1
+                    index + 1,
+                    boxed_suffix,
                 ));
             }
 
@@ -645,10 +653,19 @@ impl PsqlInserter {
         func.push_block(block);
     }
 
+    /// Boxed values of recursive types are queried as their inner type
+    fn boxed_prefix_suffix(rust: &RustType) -> (&'static str, &'static str) {
+        if let RustType::Box(_) = rust {
+            ("Box::new(", ")")
+        } else {
+            ("", "")
+        }
+    }
+
     fn impl_enum_query_fn(func: &mut Function, name: &str, r_enum: &PlainEnum) {
         let mut block = Block::new("match id");
         for (index, variant) in r_enum.variants().enumerate() {
-            block.line(&format!("{} => Ok({}::{}),", index, name, variant));
+            block.line(&format!("{} => Ok({}::{}),", index, name, variant.name()));
         }
         block.line(&format!("_ => Err({}::no_result()),", ERROR_TYPE));
         func.push_block(block);
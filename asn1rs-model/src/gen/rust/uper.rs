@@ -2,14 +2,21 @@ use crate::gen::rust::GeneratorSupplement;
 use crate::gen::rust::RustCodeGenerator;
 use crate::model::rust::{DataEnum, Enumeration};
 use crate::model::rust::{Field, PlainEnum};
+use crate::model::CharacterString;
+use crate::model::Charset;
 use crate::model::Definition;
+use crate::model::Range;
 use crate::model::Rust;
 use crate::model::RustType;
+use crate::model::Size;
 use codegen::Block;
 use codegen::Function;
 use codegen::Impl;
 use codegen::Scope;
 
+/// Lengths with an upper bound of 64K or more are encoded as length determinant
+const UPER_LENGTH_CONSTRAINED_LIMIT: usize = 64 * 1024;
+
 #[allow(clippy::module_name_repetitions)]
 pub struct UperSerializer;
 
@@ -53,7 +60,7 @@ impl UperSerializer {
             Rust::TupleStruct(aliased) => {
                 Self::impl_read_fn_for_tuple_struct(function, name, aliased);
             }
-            Rust::Struct(fields) => {
+            Rust::Struct { fields, .. } => {
                 for field in fields.iter() {
                     Self::impl_read_fn_header_for_type(function, field.name(), field.r#type());
                 }
@@ -62,7 +69,7 @@ impl UperSerializer {
             Rust::Enum(r_enum) => {
                 Self::impl_read_fn_for_enum(function, name, r_enum);
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::impl_read_fn_for_data_enum(function, name, enumeration);
             }
         };
@@ -102,13 +109,20 @@ impl UperSerializer {
             RustType::Bool => {
                 block.line("reader.read_bit()?");
             }
+            RustType::I64(Range(Some(_), Some(_), true)) => {
+                let prefix = Self::min_max_prefix(&field_name);
+                block.line(format!(
+                    "reader.read_int_extensible((Self::{}min(), Self::{}max()))?",
+                    prefix, prefix,
+                ));
+            }
             RustType::U8(_)
             | RustType::I8(_)
             | RustType::U16(_)
             | RustType::I16(_)
             | RustType::U32(_)
             | RustType::I32(_)
-            | RustType::I64(_) => {
+            | RustType::I64(Range(Some(_), Some(_), _)) => {
                 let prefix = Self::min_max_prefix(&field_name);
                 block.line(format!(
                     "reader.read_int((i64::from(Self::{}min()), i64::from(Self::{}max())))? as {}",
@@ -126,17 +140,82 @@ impl UperSerializer {
                     rust.to_string(),
                 ));
             }
+            RustType::I64(Range(Some(_), None, _)) => {
+                block.line(format!(
+                    "reader.read_int_semi_constrained(Self::{}min())?",
+                    Self::min_max_prefix(&field_name),
+                ));
+            }
+            RustType::I64(Range(None, _, _)) => {
+                block.line("reader.read_int_unconstrained()?");
+            }
             RustType::U64(None) => {
                 block.line("reader.read_int_max()?");
             }
-            RustType::String => {
+            RustType::F64 => {
+                block.line("asn1rs::syn::real::from_content(&reader.read_octet_string(None)?).ok_or(UperError::InvalidReal)?");
+            }
+            RustType::String(_) => {
+                // X.691-201508 30.1, the SIZE of an UTF8String is not PER-visible
                 block.line("reader.read_utf8_string()?");
             }
-            RustType::VecU8 => {
-                block.line("reader.read_octet_string(None)?");
+            RustType::CharacterString(string) if string.size.extensible() => {
+                block.line(format!(
+                    "{{ let len = reader.read_length({:?}, true)?; reader.read_known_multiplier_characters(&{}, len)? }}",
+                    Self::bit_length_range(&string.size),
+                    Self::permitted_alphabet(string)
+                ));
+            }
+            RustType::CharacterString(string) => {
+                block.line(format!(
+                    "reader.read_known_multiplier_string(&{}, {:?})?",
+                    Self::permitted_alphabet(string),
+                    Self::bit_length_range(&string.size),
+                ));
+            }
+            RustType::VecU8(size) if size.extensible() => {
+                block.line(format!(
+                    "{{ let len = reader.read_length({:?}, true)?; let mut bytes = vec![0_u8; len]; reader.read_bit_string_till_end(&mut bytes[..], 0)?; bytes }}",
+                    Self::bit_length_range(size),
+                ));
+            }
+            RustType::VecU8(size) => {
+                block.line(format!(
+                    "reader.read_octet_string({:?})?",
+                    Self::bit_length_range(size)
+                ));
+            }
+            RustType::BitVec(bit_string) if bit_string.size.extensible() => {
+                block.line(format!(
+                    "{{ let bit_len = reader.read_length({:?}, true)?; let mut bytes = vec![0_u8; bit_len.div_ceil(8)]; reader.read_bit_string(&mut bytes[..], 0, bit_len)?; asn1rs::syn::BitVec::from_bytes(bytes, bit_len as u64) }}",
+                    Self::bit_length_range(&bit_string.size),
+                ));
+            }
+            RustType::BitVec(bit_string) => {
+                block.line(format!(
+                    "{{ let (bytes, bit_len) = reader.read_bits_with_length({:?})?; asn1rs::syn::BitVec::from_bytes(bytes, bit_len as u64) }}",
+                    Self::bit_length_range(&bit_string.size),
+                ));
             }
-            RustType::Vec(inner) => {
-                block.line("let len = reader.read_length_determinant()?;");
+            RustType::Null => {
+                block.line("asn1rs::syn::Null");
+            }
+            RustType::ObjectIdentifier => {
+                block.line("asn1rs::syn::ObjectIdentifier::from_content(&reader.read_octet_string(None)?).ok_or(UperError::InvalidObjectIdentifier)?");
+            }
+            RustType::GeneralizedTime | RustType::UtcTime => {
+                block.line(format!(
+                    "{{ let string = reader.read_known_multiplier_string(&{}, None)?; <asn1rs::syn::{} as asn1rs::syn::time::Time>::parse(&string).ok_or(UperError::InvalidTime(string))? }}",
+                    Self::permitted_alphabet(&CharacterString::new(Charset::Visible)),
+                    rust.to_string(),
+                ));
+            }
+            RustType::Vec(inner, size, _) => {
+                block.line(format!(
+                    "let len = reader.read_length({:?}, {})?;",
+                    Self::bit_length_range(size),
+                    size.extensible()
+                ));
                 block.line("let mut values = Vec::with_capacity(len);");
                 let mut for_block = Block::new("for _ in 0..len");
                 for_block.push_block({
@@ -178,6 +257,17 @@ impl UperSerializer {
                 block.push_block(if_block);
                 block.push_block(else_block);
             }
+            RustType::Box(inner) => {
+                let mut box_block = Block::new("Box::new(");
+                Self::impl_read_fn_for_type(
+                    &mut box_block,
+                    &inner.to_inner_type_string(),
+                    field_name,
+                    inner,
+                );
+                box_block.after(")");
+                block.push_block(box_block);
+            }
             RustType::Complex(_inner) => {
                 block.line(format!("{}::read_uper(reader)?", type_name));
             }
@@ -196,6 +286,26 @@ impl UperSerializer {
         }
     }
 
+    /// The `PermittedAlphabet` expression for the given character string type
+    fn permitted_alphabet(string: &CharacterString) -> String {
+        format!(
+            "asn1rs::io::uper::PermittedAlphabet::new(asn1rs::syn::charset::Charset::{:?}, {:?}, false)",
+            string.charset,
+            string.permitted_alphabet.as_deref(),
+        )
+    }
+
+    /// The length range to encode, or `None` for a length determinant, which is also used
+    /// for upper bounds of 64K and more (X.691-201508 11.9.4.1)
+    fn bit_length_range(size: &Size) -> Option<(i64, i64)> {
+        match (size.min(), size.max()) {
+            (Some(min), Some(max)) if max < UPER_LENGTH_CONSTRAINED_LIMIT => {
+                Some((min as i64, max as i64))
+            }
+            _ => None,
+        }
+    }
+
     fn impl_read_fn_for_struct(function: &mut Function, fields: &[Field]) {
         function.line("let mut me = Self::default();");
         for field in fields {
@@ -229,7 +339,7 @@ impl UperSerializer {
         }
         let mut block_match = Block::new("match id");
         for (i, variant) in r_enum.variants().enumerate() {
-            block_match.line(format!("{} => Ok({}::{}),", i, name, variant));
+            block_match.line(format!("{} => Ok({}::{}),", i, name, variant.name()));
         }
         block_match.line(format!(
             "_ => Err(UperError::ValueNotInRange(id, 0, {}))",
@@ -307,7 +417,7 @@ impl UperSerializer {
             Rust::TupleStruct(inner) => {
                 Self::impl_write_fn_for_tuple_struct(function, inner);
             }
-            Rust::Struct(fields) => {
+            Rust::Struct { fields, .. } => {
                 for field in fields.iter() {
                     Self::impl_write_fn_header_for_type(function, field.name(), field.r#type());
                 }
@@ -316,7 +426,7 @@ impl UperSerializer {
             Rust::Enum(r_enum) => {
                 Self::impl_write_fn_for_enum(function, name, r_enum);
             }
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::impl_write_fn_for_data_enum(function, name, enumeration);
             }
         }
@@ -355,13 +465,24 @@ impl UperSerializer {
                         .map_or_else(|| "value".into(), |f| f.to_string()),
                 ));
             }
+            RustType::I64(Range(Some(_), Some(_), true)) => {
+                let prefix = Self::min_max_prefix(&field_name);
+                block.line(format!(
+                    "writer.write_int_extensible({}, (Self::{}min(), Self::{}max()))?;",
+                    field_name
+                        .as_ref()
+                        .map_or_else(|| "value".into(), |f| f.to_string()),
+                    prefix,
+                    prefix,
+                ));
+            }
             RustType::U8(_)
             | RustType::I8(_)
             | RustType::U16(_)
             | RustType::I16(_)
             | RustType::U32(_)
             | RustType::I32(_)
-            | RustType::I64(_) => {
+            | RustType::I64(Range(Some(_), Some(_), _)) => {
                 let prefix = Self::min_max_prefix(&field_name);
                 block.line(format!(
                     "writer.write_int(i64::from({}), (i64::from(Self::{}min()), i64::from(Self::{}max())))?;",
@@ -383,30 +504,122 @@ impl UperSerializer {
                     prefix,
                 ));
             }
+            RustType::I64(Range(Some(_), None, _)) => {
+                block.line(format!(
+                    "writer.write_int_semi_constrained({}, Self::{}min())?;",
+                    field_name
+                        .as_ref()
+                        .map_or_else(|| "value".into(), |f| f.to_string()),
+                    Self::min_max_prefix(&field_name),
+                ));
+            }
+            RustType::I64(Range(None, _, _)) => {
+                block.line(&format!(
+                    "writer.write_int_unconstrained({})?;",
+                    field_name.map_or_else(|| "value".into(), |f| f.to_string()),
+                ));
+            }
             RustType::U64(None) => {
                 block.line(&format!(
                     "writer.write_int_max({})?;",
                     field_name.map_or_else(|| "value".into(), |f| f.to_string()),
                 ));
             }
-            RustType::String => {
+            RustType::F64 => {
+                block.line(&format!(
+                    "writer.write_octet_string(&asn1rs::syn::real::to_content({})[..], None)?;",
+                    field_name.map_or_else(|| "value".into(), |f| f.to_string()),
+                ));
+            }
+            RustType::String(_) => {
                 block.line(&format!(
                     "writer.write_utf8_string({})?;",
                     field_name.map_or_else(|| "value".into(), |f| f.to_string()),
                 ));
             }
-            RustType::VecU8 => {
+            RustType::CharacterString(string) if string.size.extensible() => {
+                let name = field_name.map_or_else(|| "value".into(), |f| f.to_string());
+                block.line(&format!(
+                    "writer.write_length({}.chars().count(), {:?}, true)?;",
+                    name,
+                    Self::bit_length_range(&string.size),
+                ));
+                block.line(&format!(
+                    "writer.write_known_multiplier_characters(&{}, {})?;",
+                    Self::permitted_alphabet(string),
+                    name,
+                ));
+            }
+            RustType::CharacterString(string) => {
+                block.line(&format!(
+                    "writer.write_known_multiplier_string(&{}, {}, {:?})?;",
+                    Self::permitted_alphabet(string),
+                    field_name.map_or_else(|| "value".into(), |f| f.to_string()),
+                    Self::bit_length_range(&string.size),
+                ));
+            }
+            RustType::VecU8(size) if size.extensible() => {
+                let name = field_name.map_or_else(|| "value".into(), |f| f.no_ref().to_string());
+                block.line(format!(
+                    "writer.write_length({}.len(), {:?}, true)?;",
+                    name,
+                    Self::bit_length_range(size),
+                ));
+                block.line(format!(
+                    "writer.write_bit_string_till_end(&{}[..], 0)?;",
+                    name
+                ));
+            }
+            RustType::VecU8(size) => {
                 block.line(format!(
-                    "writer.write_octet_string({}[..], None)?;",
+                    "writer.write_octet_string({}[..], {:?})?;",
                     field_name.map_or_else(|| "value".into(), |f| f.with_ref().to_string()),
+                    Self::bit_length_range(size),
+                ));
+            }
+            RustType::BitVec(bit_string) if bit_string.size.extensible() => {
+                let name = field_name.map_or_else(|| "value".into(), |f| f.no_ref().to_string());
+                block.line(format!(
+                    "writer.write_length({}.bit_len() as usize, {:?}, true)?;",
+                    name,
+                    Self::bit_length_range(&bit_string.size),
+                ));
+                block.line(format!(
+                    "writer.write_bit_string({}.as_byte_slice(), 0, {}.bit_len() as usize)?;",
+                    name, name,
+                ));
+            }
+            RustType::BitVec(bit_string) => {
+                let name = field_name.map_or_else(|| "value".into(), |f| f.no_ref().to_string());
+                block.line(format!(
+                    "writer.write_bits_with_length({}.as_byte_slice(), {}.bit_len() as usize, {:?})?;",
+                    name,
+                    name,
+                    Self::bit_length_range(&bit_string.size),
+                ));
+            }
+            RustType::Null => {}
+            RustType::ObjectIdentifier => {
+                block.line(format!(
+                    "writer.write_octet_string(&{}.to_content()[..], None)?;",
+                    field_name.map_or_else(|| "value".into(), |f| f.no_ref().to_string()),
+                ));
+            }
+            RustType::GeneralizedTime | RustType::UtcTime => {
+                block.line(format!(
+                    "writer.write_known_multiplier_string(&{}, &{}.to_string(), None)?;",
+                    Self::permitted_alphabet(&CharacterString::new(Charset::Visible)),
+                    field_name.map_or_else(|| "value".into(), |f| f.no_ref().to_string()),
                 ));
             }
-            RustType::Vec(inner) => {
+            RustType::Vec(inner, size, _) => {
                 block.line(format!(
-                    "writer.write_length_determinant({}.len())?;",
+                    "writer.write_length({}.len(), {:?}, {})?;",
                     field_name
                         .clone()
-                        .map_or_else(|| "value".into(), |f| f.no_ref().to_string())
+                        .map_or_else(|| "value".into(), |f| f.no_ref().to_string()),
+                    Self::bit_length_range(size),
+                    size.extensible()
                 ));
                 let local_name = field_name
                     .as_ref()
@@ -459,6 +672,7 @@ impl UperSerializer {
                 );
                 block.push_block(if_block);
             }
+            RustType::Box(inner) => Self::impl_write_fn_for_type(block, field_name, inner),
             RustType::Complex(_inner) => {
                 block.line(format!(
                     "{}.write_uper(writer)?;",
@@ -497,7 +711,7 @@ impl UperSerializer {
                 block.line(format!(
                     "{}::{} => writer.write_choice_index_extensible({}, {})?,",
                     name,
-                    &variant,
+                    variant.name(),
                     i,
                     last_standard_index + 1
                 ));
@@ -505,7 +719,7 @@ impl UperSerializer {
                 block.line(format!(
                     "{}::{} => writer.write_int({}, (0, {}))?,",
                     name,
-                    &variant,
+                    variant.name(),
                     i,
                     r_enum.len() - 1
                 ));
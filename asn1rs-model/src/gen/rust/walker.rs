@@ -1,8 +1,14 @@
 use crate::gen::RustCodeGenerator;
-use crate::model::rust::{DataEnum, Field, PlainEnum};
-use crate::model::{Definition, Model, Range, Rust, RustType};
+use crate::model::rust::{
+    DataEnum, DataVariant, EncodingOrdering, Field, PlainEnum, PlainVariant,
+    OPEN_TYPE_UNKNOWN_VARIANT,
+};
+use crate::model::{
+    BitString, CharacterString, Charset, Definition, LiteralValue, Model, Range, Rust, RustType,
+    Size, Tag, TagProperty, TaggingMode,
+};
 use codegen::{Block, Impl, Scope};
-use std::fmt::Display;
+use std::fmt::Debug;
 
 pub const CRATE_SYN_PREFIX: &str = "::asn1rs::syn::";
 
@@ -15,13 +21,26 @@ impl AsnDefWriter {
         Definition(name, r#type): &Definition<Rust>,
     ) {
         match r#type {
-            Rust::Struct(fields) => {
+            Rust::Struct {
+                fields, ordering, ..
+            } => {
                 scope.raw(&format!(
-                    "type AsnDef{} = {}Sequence<{}>;",
-                    name, CRATE_SYN_PREFIX, name
+                    "type AsnDef{} = {}{}<{}>;",
+                    name,
+                    CRATE_SYN_PREFIX,
+                    Self::ordering_type(*ordering, "Sequence", "Set"),
+                    name
                 ));
-                for field in fields {
-                    self.write_type_declaration(scope, &name, field.name(), field.r#type());
+                // open types are read and written through the value of their key field
+                for field in fields.iter().filter(|f| f.key_field().is_none()) {
+                    self.write_type_declaration(
+                        scope,
+                        name,
+                        field.name(),
+                        field.r#type(),
+                        field.tag(),
+                        field.default_value(),
+                    );
                 }
             }
             Rust::Enum(_enm) => {
@@ -36,15 +55,32 @@ impl AsnDefWriter {
                     name, CRATE_SYN_PREFIX, name
                 ));
                 for variant in enm.variants() {
-                    self.write_type_declaration(scope, &name, variant.name(), variant.r#type());
+                    self.write_type_declaration(
+                        scope,
+                        name,
+                        variant.name(),
+                        variant.r#type(),
+                        variant.tag(),
+                        None,
+                    );
+                }
+            }
+            Rust::OpenType(open) => {
+                for variant in open.variants().filter(|v| v.key().is_some()) {
+                    self.write_type_declaration(
+                        scope,
+                        name,
+                        variant.name(),
+                        variant.r#type(),
+                        variant.tag(),
+                        None,
+                    );
                 }
             }
             Rust::TupleStruct(field) => {
-                scope.raw(&format!(
-                    "type AsnDef{} = {}Sequence<{}>;",
-                    name, CRATE_SYN_PREFIX, name
-                ));
-                self.write_type_declaration(scope, &name, "0", field);
+                // the tuple struct is transparent and therefore has no type definition
+                // on its own but uses the definition of its only field
+                self.write_type_declaration(scope, &name, "0", field, None, None);
             }
         }
     }
@@ -64,21 +100,108 @@ impl AsnDefWriter {
                 format!("{}Integer<u64, {}Constraint>", CRATE_SYN_PREFIX, name)
             }
             RustType::U64(None) => format!("{}Integer<u64>", CRATE_SYN_PREFIX),
-            RustType::String => format!("{}Utf8String", CRATE_SYN_PREFIX),
-            RustType::VecU8 => format!("{}OctetString", CRATE_SYN_PREFIX),
-            RustType::Vec(inner) => format!(
-                "{}SequenceOf<{}>",
+            RustType::F64 => format!("{}Real", CRATE_SYN_PREFIX),
+            RustType::String(Size::Any) => format!("{}Utf8String", CRATE_SYN_PREFIX),
+            RustType::String(_) => format!("{}Utf8String<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::CharacterString(CharacterString {
+                charset,
+                size: Size::Any,
+                permitted_alphabet: None,
+            }) => format!(
+                "{}{}",
                 CRATE_SYN_PREFIX,
-                Self::type_declaration(&*inner, name)
+                Self::character_string_type(*charset).1
+            ),
+            RustType::CharacterString(string) => format!(
+                "{}{}<{}Constraint>",
+                CRATE_SYN_PREFIX,
+                Self::character_string_type(string.charset).1,
+                name
+            ),
+            RustType::VecU8(Size::Any) => format!("{}OctetString", CRATE_SYN_PREFIX),
+            RustType::VecU8(_) => format!("{}OctetString<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::BitVec(BitString {
+                size: Size::Any, ..
+            }) => format!("{}BitString", CRATE_SYN_PREFIX),
+            RustType::BitVec(_) => format!("{}BitString<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::Null => format!("{}Null", CRATE_SYN_PREFIX),
+            RustType::ObjectIdentifier => format!("{}ObjectIdentifier", CRATE_SYN_PREFIX),
+            RustType::GeneralizedTime => format!("{}GeneralizedTime", CRATE_SYN_PREFIX),
+            RustType::UtcTime => format!("{}UtcTime", CRATE_SYN_PREFIX),
+            RustType::Vec(inner, Size::Any, ordering) => format!(
+                "{}{}<{}>",
+                CRATE_SYN_PREFIX,
+                Self::ordering_type(*ordering, "SequenceOf", "SetOf"),
+                Self::type_declaration(&*inner, &format!("{}Values", name))
+            ),
+            RustType::Vec(inner, _, ordering) => format!(
+                "{}{}<{}, {}Constraint>",
+                CRATE_SYN_PREFIX,
+                Self::ordering_type(*ordering, "SequenceOf", "SetOf"),
+                Self::type_declaration(&*inner, &format!("{}Values", name)),
+                name
             ),
             RustType::Option(inner) => format!("Option<{}>", Self::type_declaration(&*inner, name)),
+            RustType::Box(inner) => format!(
+                "{}Boxed<{}>",
+                CRATE_SYN_PREFIX,
+                Self::type_declaration(&*inner, name)
+            ),
             RustType::Complex(inner) => format!("{}Complex<{}>", CRATE_SYN_PREFIX, inner),
         }
     }
 
-    fn write_type_declaration(&self, scope: &mut Scope, base: &str, name: &str, r#type: &RustType) {
+    /// The module and the name of the `syn` type for the given character set
+    fn character_string_type(charset: Charset) -> (&'static str, &'static str) {
+        match charset {
+            Charset::Numeric => ("numericstring", "NumericString"),
+            Charset::Printable => ("printablestring", "PrintableString"),
+            Charset::Visible => ("visiblestring", "VisibleString"),
+            Charset::Ia5 => ("ia5string", "Ia5String"),
+            Charset::Bmp => ("bmpstring", "BmpString"),
+            Charset::Universal => ("universalstring", "UniversalString"),
+        }
+    }
+
+    /// Wraps the type declaration in `Tagged`. The tag refers to the type of the
+    /// field and not to its optionality, therefore `Option` stays the outermost type.
+    #[must_use]
+    pub fn tagged_type_declaration(r#type: &RustType, name: &str) -> String {
+        match r#type {
+            RustType::Option(inner) => {
+                format!("Option<{}>", Self::tagged_type_declaration(&*inner, name))
+            }
+            r#type => format!(
+                "{}Tagged<{}, {}Tag>",
+                CRATE_SYN_PREFIX,
+                Self::type_declaration(r#type, name),
+                name
+            ),
+        }
+    }
+
+    fn write_type_declaration(
+        &self,
+        scope: &mut Scope,
+        base: &str,
+        name: &str,
+        r#type: &RustType,
+        tag: Option<Tag>,
+        default: Option<&LiteralValue>,
+    ) {
         let combined = Self::combined_field_type_name(base, name);
-        let type_dec = Self::type_declaration(r#type, &Self::constraint_impl_name(&combined));
+        let constraint = Self::constraint_impl_name(&combined);
+        let mut type_dec = if tag.is_some() {
+            Self::tagged_type_declaration(r#type, &constraint)
+        } else {
+            Self::type_declaration(r#type, &constraint)
+        };
+        if default.is_some() {
+            type_dec = format!(
+                "{}DefaultValue<{}, {}Default>",
+                CRATE_SYN_PREFIX, type_dec, constraint
+            );
+        }
         scope.raw(&format!("type AsnDef{} = {};", combined, type_dec));
     }
 
@@ -86,6 +209,17 @@ impl AsnDefWriter {
         format!("___ans1rs_{}", combined)
     }
 
+    fn ordering_type(
+        ordering: EncodingOrdering,
+        keep: &'static str,
+        sort: &'static str,
+    ) -> &'static str {
+        match ordering {
+            EncodingOrdering::Keep => keep,
+            EncodingOrdering::Sort => sort,
+        }
+    }
+
     #[must_use]
     pub fn combined_field_type_name(base: &str, name: &str) -> String {
         format!(
@@ -97,24 +231,70 @@ impl AsnDefWriter {
 
     fn write_constraints(&self, scope: &mut Scope, Definition(name, r#type): &Definition<Rust>) {
         match r#type {
-            Rust::Struct(fields) => {
+            Rust::Struct {
+                fields,
+                extension_after,
+                extension_groups,
+                ..
+            } => {
                 self.write_field_constraints(scope, &name, &fields);
-                self.write_sequence_constraint(scope, &name, &fields);
+                self.write_sequence_constraint(
+                    scope,
+                    name,
+                    fields,
+                    *extension_after,
+                    extension_groups,
+                );
             }
             Rust::Enum(plain) => {
                 self.write_enumerated_constraint(scope, &name, plain);
             }
-            Rust::DataEnum(data) => self.write_choice_constraint(scope, &name, data),
+            Rust::DataEnum(data) => {
+                let fields = data
+                    .variants()
+                    .map(|v| {
+                        Field::from_name_type(v.name(), v.r#type().clone())
+                            .with_tag_opt(v.tag())
+                            .with_tagging(v.tagging())
+                    })
+                    .collect::<Vec<_>>();
+                self.write_field_constraints(scope, name, &fields[..]);
+                self.write_choice_constraint(scope, name, data)
+            }
+            Rust::OpenType(open) => {
+                let fields = open
+                    .variants()
+                    .filter(|v| v.key().is_some())
+                    .map(|v| {
+                        Field::from_name_type(v.name(), v.r#type().clone())
+                            .with_tag_opt(v.tag())
+                            .with_tagging(v.tagging())
+                    })
+                    .collect::<Vec<_>>();
+                self.write_field_constraints(scope, name, &fields[..]);
+                self.write_open_type_constraint(scope, name, open)
+            }
             Rust::TupleStruct(field) => {
                 let fields = [Field::from_name_type("0", field.clone())];
                 self.write_field_constraints(scope, &name, &fields[..]);
-                self.write_sequence_constraint(scope, &name, &fields[..]);
             }
         }
     }
 
     fn write_field_constraints(&self, scope: &mut Scope, name: &str, fields: &[Field]) {
         for field in fields {
+            if let Some(tag) = field.tag() {
+                Self::write_tag_constraint_type(scope, name, field.name(), tag, field.tagging());
+            }
+            if let Some(default) = field.default_value() {
+                Self::write_default_constraint_type(
+                    scope,
+                    name,
+                    field.name(),
+                    field.r#type(),
+                    default,
+                );
+            }
             match field.r#type() {
                 RustType::Bool => {}
                 RustType::I8(range) => Self::write_integer_constraint_type(
@@ -122,66 +302,127 @@ impl AsnDefWriter {
                     name,
                     field.name(),
                     &field.r#type().to_string(),
-                    range,
+                    range.wrap_opt(),
                 ),
                 RustType::U8(range) => Self::write_integer_constraint_type(
                     scope,
                     name,
                     field.name(),
                     &field.r#type().to_string(),
-                    range,
+                    range.wrap_opt(),
                 ),
                 RustType::I16(range) => Self::write_integer_constraint_type(
                     scope,
                     name,
                     field.name(),
                     &field.r#type().to_string(),
-                    range,
+                    range.wrap_opt(),
                 ),
                 RustType::U16(range) => Self::write_integer_constraint_type(
                     scope,
                     name,
                     field.name(),
                     &field.r#type().to_string(),
-                    range,
+                    range.wrap_opt(),
                 ),
                 RustType::I32(range) => Self::write_integer_constraint_type(
                     scope,
                     name,
                     field.name(),
                     &field.r#type().to_string(),
-                    range,
+                    range.wrap_opt(),
                 ),
                 RustType::U32(range) => Self::write_integer_constraint_type(
                     scope,
                     name,
                     field.name(),
                     &field.r#type().to_string(),
-                    range,
+                    range.wrap_opt(),
                 ),
                 RustType::I64(range) => Self::write_integer_constraint_type(
                     scope,
                     name,
                     field.name(),
                     &field.r#type().to_string(),
-                    range,
+                    *range,
                 ),
                 RustType::U64(Some(range)) => Self::write_integer_constraint_type(
                     scope,
                     name,
                     field.name(),
                     &field.r#type().to_string(),
-                    range,
+                    range.wrap_opt(),
                 ),
                 RustType::U64(_) => {}
-                RustType::String => {}
-                RustType::VecU8 => {}
-                RustType::Vec(inner) => self.write_field_constraints(
-                    scope,
-                    name,
-                    &[Field::from_name_type(field.name(), *inner.clone())],
-                ),
-                RustType::Option(inner) => self.write_field_constraints(
+                RustType::F64 => {}
+                RustType::String(size) => {
+                    if *size != Size::Any {
+                        Self::write_size_constraint_type(
+                            scope,
+                            name,
+                            field.name(),
+                            "utf8string",
+                            size,
+                        )
+                    }
+                }
+                RustType::CharacterString(string) => {
+                    if string.size != Size::Any || string.permitted_alphabet.is_some() {
+                        Self::write_character_string_constraint_type(
+                            scope,
+                            name,
+                            field.name(),
+                            Self::character_string_type(string.charset).0,
+                            string,
+                        )
+                    }
+                }
+                RustType::VecU8(size) => {
+                    if *size != Size::Any {
+                        Self::write_size_constraint_type(
+                            scope,
+                            name,
+                            field.name(),
+                            "octetstring",
+                            size,
+                        )
+                    }
+                }
+                RustType::BitVec(bit_string) => {
+                    if bit_string.size != Size::Any {
+                        Self::write_size_constraint_type(
+                            scope,
+                            name,
+                            field.name(),
+                            "bitstring",
+                            &bit_string.size,
+                        )
+                    }
+                }
+                RustType::Null => {}
+                RustType::ObjectIdentifier => {}
+                RustType::GeneralizedTime => {}
+                RustType::UtcTime => {}
+                RustType::Vec(inner, size, _) => {
+                    if *size != Size::Any {
+                        Self::write_size_constraint_type(
+                            scope,
+                            name,
+                            field.name(),
+                            "sequenceof",
+                            size,
+                        )
+                    }
+                    self.write_field_constraints(
+                        scope,
+                        name,
+                        &[Field::from_name_type(
+                            format!("{}Values", field.name()),
+                            *inner.clone(),
+                        )],
+                    )
+                }
+                RustType::Option(inner) | RustType::Box(inner) => self.write_field_constraints(
                     scope,
                     name,
                     &[Field::from_name_type(field.name(), *inner.clone())],
@@ -191,17 +432,52 @@ impl AsnDefWriter {
         }
     }
 
-    fn write_sequence_constraint(&self, scope: &mut Scope, name: &str, fields: &[Field]) {
+    fn write_sequence_constraint(
+        &self,
+        scope: &mut Scope,
+        name: &str,
+        fields: &[Field],
+        extension_after: Option<usize>,
+        extension_groups: &[Range<usize>],
+    ) {
         let mut imp = Impl::new(name);
         imp.impl_trait(format!("{}sequence::Constraint", CRATE_SYN_PREFIX));
 
-        self.write_sequence_constraint_read_fn(&mut imp, name, fields);
-        self.write_sequence_constraint_write_fn(&mut imp, name, fields);
+        let additions = Self::extension_additions(fields, extension_after, extension_groups);
+        self.write_sequence_constraint_read_fn(&mut imp, name, fields, extension_after, &additions);
+        self.write_sequence_constraint_write_fn(
+            &mut imp,
+            name,
+            fields,
+            extension_after,
+            &additions,
+        );
 
-        Self::write_sequence_constraint_insert_consts(scope, name, fields, imp);
+        Self::write_sequence_constraint_insert_consts(scope, name, fields, extension_after, imp);
     }
 
-    fn impl_readable(&self, scope: &mut Scope, name: &str) {
+    /// Splits the fields after the extension marker into the extension additions, each
+    /// being either a single field or an extension addition group
+    fn extension_additions<'a>(
+        fields: &'a [Field],
+        extension_after: Option<usize>,
+        extension_groups: &[Range<usize>],
+    ) -> Vec<(bool, &'a [Field])> {
+        let mut additions = Vec::new();
+        let mut index = extension_after.map_or(fields.len(), |index| index + 1);
+        while index < fields.len() {
+            if let Some(Range(first, last, _)) = extension_groups.iter().find(|g| g.0 == index) {
+                additions.push((true, &fields[*first..=*last]));
+                index = last + 1;
+            } else {
+                additions.push((false, &fields[index..=index]));
+                index += 1;
+            }
+        }
+        additions
+    }
+
+    fn impl_readable(&self, scope: &mut Scope, Definition(name, r#type): &Definition<Rust>) {
         let imp = scope
             .new_impl(name)
             .impl_trait(format!("{}Readable", CRATE_SYN_PREFIX));
@@ -210,10 +486,17 @@ impl AsnDefWriter {
             .generic(&format!("R: {}Reader", CRATE_SYN_PREFIX))
             .arg("reader", "&mut R")
             .ret("Result<Self, R::Error>")
-            .line(format!("AsnDef{}::read_value(reader)", name));
+            .line(if let Rust::TupleStruct(_) = r#type {
+                format!(
+                    "Ok(Self(AsnDef{}::read_value(reader)?))",
+                    Self::combined_field_type_name(name, "0")
+                )
+            } else {
+                format!("AsnDef{}::read_value(reader)", name)
+            });
     }
 
-    fn impl_writable(&self, scope: &mut Scope, name: &str) {
+    fn impl_writable(&self, scope: &mut Scope, Definition(name, r#type): &Definition<Rust>) {
         let imp = scope
             .new_impl(name)
             .impl_trait(format!("{}Writable", CRATE_SYN_PREFIX));
@@ -223,7 +506,14 @@ impl AsnDefWriter {
             .arg_ref_self()
             .arg("writer", "&mut W")
             .ret("Result<(), W::Error>")
-            .line(format!("AsnDef{}::write_value(writer, self)", name));
+            .line(if let Rust::TupleStruct(_) = r#type {
+                format!(
+                    "AsnDef{}::write_value(writer, &self.0)",
+                    Self::combined_field_type_name(name, "0")
+                )
+            } else {
+                format!("AsnDef{}::write_value(writer, self)", name)
+            });
     }
 
     fn write_enumerated_constraint(&self, scope: &mut Scope, name: &str, enumerated: &PlainEnum) {
@@ -236,7 +526,11 @@ impl AsnDefWriter {
             .push_block({
                 let mut match_block = Block::new("match self");
                 for (index, variant) in enumerated.variants().enumerate() {
-                    match_block.line(format!("Self::{} => {},", variant, index));
+                    match_block.line(format!(
+                        "Self::{} => {},",
+                        Self::variant_identifier(variant.name()),
+                        index
+                    ));
                 }
                 match_block
             });
@@ -247,7 +541,43 @@ impl AsnDefWriter {
             .push_block({
                 let mut match_block = Block::new("match index");
                 for (index, variant) in enumerated.variants().enumerate() {
-                    match_block.line(format!("{} => Some(Self::{}),", index, variant));
+                    match_block.line(format!(
+                        "{} => Some(Self::{}),",
+                        index,
+                        Self::variant_identifier(variant.name())
+                    ));
+                }
+                match_block.line("_ => None,");
+                match_block
+            });
+
+        let numbers = enumerated.variant_numbers();
+        imp.new_fn("to_number")
+            .arg_ref_self()
+            .ret("i64")
+            .push_block({
+                let mut match_block = Block::new("match self");
+                for (variant, number) in enumerated.variants().zip(&numbers) {
+                    match_block.line(format!(
+                        "Self::{} => {},",
+                        Self::variant_identifier(variant.name()),
+                        number
+                    ));
+                }
+                match_block
+            });
+
+        imp.new_fn("from_number")
+            .arg("number", "i64")
+            .ret("Option<Self>")
+            .push_block({
+                let mut match_block = Block::new("match number");
+                for (variant, number) in enumerated.variants().zip(&numbers) {
+                    match_block.line(format!(
+                        "{} => Some(Self::{}),",
+                        number,
+                        Self::variant_identifier(variant.name())
+                    ));
                 }
                 match_block.line("_ => None,");
                 match_block
@@ -257,6 +587,10 @@ impl AsnDefWriter {
             scope,
             imp,
             &[
+                format!(
+                    "const VARIANT_NAMES: &'static [&'static str] = &[{}];",
+                    Self::quoted_names(enumerated.variants().map(PlainVariant::asn_name))
+                ),
                 format!("const NAME: &'static str = \"{}\";", name),
                 format!("const VARIANT_COUNT: usize = {};", enumerated.len()),
                 format!(
@@ -281,7 +615,11 @@ impl AsnDefWriter {
             .push_block({
                 let mut match_block = Block::new("match self");
                 for (index, variant) in choice.variants().enumerate() {
-                    match_block.line(format!("Self::{}(_) => {},", variant.name(), index));
+                    match_block.line(format!(
+                        "Self::{}(_) => {},",
+                        Self::variant_identifier(variant.name()),
+                        index
+                    ));
                 }
                 match_block
             });
@@ -297,7 +635,7 @@ impl AsnDefWriter {
                     let combined = Self::combined_field_type_name(name, variant.name());
                     match_block.line(format!(
                         "Self::{}(c) => AsnDef{}::write_value(writer, c),",
-                        variant.name(),
+                        Self::variant_identifier(variant.name()),
                         combined
                     ));
                 }
@@ -316,7 +654,7 @@ impl AsnDefWriter {
                     match_block.line(format!(
                         "{} => Ok(Some(Self::{}(AsnDef{}::read_value(reader)?))),",
                         index,
-                        variant.name(),
+                        Self::variant_identifier(variant.name()),
                         combined
                     ));
                 }
@@ -328,6 +666,10 @@ impl AsnDefWriter {
             scope,
             imp,
             &[
+                format!(
+                    "const VARIANT_NAMES: &'static [&'static str] = &[{}];",
+                    Self::quoted_names(choice.variants().map(DataVariant::asn_name))
+                ),
                 format!("const NAME: &'static str = \"{}\";", name),
                 format!("const VARIANT_COUNT: usize = {};", choice.len()),
                 format!(
@@ -342,12 +684,122 @@ impl AsnDefWriter {
         );
     }
 
-    fn write_integer_constraint_type<T: Display>(
+    fn write_open_type_constraint(&self, scope: &mut Scope, name: &str, open: &DataEnum) {
+        let mut imp = Impl::new(name);
+        imp.impl_trait(format!("{}opentype::Constraint", CRATE_SYN_PREFIX));
+
+        imp.new_fn("key")
+            .arg_ref_self()
+            .ret("Option<i64>")
+            .push_block({
+                let mut match_block = Block::new("match self");
+                for variant in open.variants() {
+                    match variant.key() {
+                        Some(key) => match_block.line(format!(
+                            "Self::{}(_) => Some({}),",
+                            Self::variant_identifier(variant.name()),
+                            key
+                        )),
+                        None => match_block.line(format!(
+                            "Self::{}(_) => None,",
+                            Self::variant_identifier(variant.name())
+                        )),
+                    };
+                }
+                match_block
+            });
+
+        imp.new_fn("write_content")
+            .generic(&format!("W: {}Writer", CRATE_SYN_PREFIX))
+            .arg_ref_self()
+            .arg("writer", "&mut W")
+            .ret("Result<(), W::Error>")
+            .push_block({
+                let mut match_block = Block::new("match self");
+                for variant in open.variants() {
+                    if variant.key().is_some() {
+                        let combined = Self::combined_field_type_name(name, variant.name());
+                        match_block.line(format!(
+                            "Self::{}(c) => AsnDef{}::write_value(writer, c),",
+                            Self::variant_identifier(variant.name()),
+                            combined
+                        ));
+                    } else {
+                        match_block.line(format!(
+                            "Self::{}(_) => Ok(()),",
+                            Self::variant_identifier(variant.name())
+                        ));
+                    }
+                }
+                match_block
+            });
+
+        imp.new_fn("read_content")
+            .generic(&format!("R: {}Reader", CRATE_SYN_PREFIX))
+            .arg("key", "i64")
+            .arg("reader", "&mut R")
+            .ret("Result<Option<Self>, R::Error>")
+            .push_block({
+                let mut match_block = Block::new("match key");
+                for variant in open.variants() {
+                    if let Some(key) = variant.key() {
+                        let combined = Self::combined_field_type_name(name, variant.name());
+                        match_block.line(format!(
+                            "{} => Ok(Some(Self::{}(AsnDef{}::read_value(reader)?))),",
+                            key,
+                            Self::variant_identifier(variant.name()),
+                            combined
+                        ));
+                    }
+                }
+                match_block.line("_ => Ok(None),");
+                match_block
+            });
+
+        let unknown = open
+            .variants()
+            .find(|v| v.key().is_none())
+            .map(DataVariant::name)
+            .unwrap_or(OPEN_TYPE_UNKNOWN_VARIANT);
+
+        imp.new_fn("from_unknown")
+            .arg("content", "Vec<u8>")
+            .ret("Self")
+            .line(format!("Self::{}(content)", unknown));
+
+        imp.new_fn("unknown_content")
+            .arg_ref_self()
+            .ret("Option<&[u8]>")
+            .push_block({
+                let mut match_block = Block::new("match self");
+                match_block.line(format!("Self::{}(content) => Some(content),", unknown));
+                match_block.line("_ => None,");
+                match_block
+            });
+
+        Self::insert_consts(
+            scope,
+            imp,
+            &[
+                format!("const NAME: &'static str = \"{}\";", name),
+                format!(
+                    "const KEYS: &'static [i64] = &[{}];",
+                    open.variants()
+                        .filter_map(DataVariant::key)
+                        .map(|key| key.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            ],
+        );
+    }
+
+    fn write_integer_constraint_type<T: Debug>(
         scope: &mut Scope,
         name: &str,
         field: &str,
         r#type: &str,
-        Range(min, max): &Range<T>,
+        Range(min, max, extensible): Range<Option<T>>,
     ) {
         let combined = Self::combined_field_type_name(name, field) + "Constraint";
         let combined = Self::constraint_impl_name(&combined);
@@ -357,8 +809,119 @@ impl AsnDefWriter {
             "impl {}numbers::Constraint<{}> for {} {{",
             CRATE_SYN_PREFIX, r#type, combined
         ));
-        scope.raw(&format!("const MIN: Option<{}> = Some({});", r#type, min));
-        scope.raw(&format!("const MAX: Option<{}> = Some({});", r#type, max));
+        scope.raw(&format!("const MIN: Option<{}> = {:?};", r#type, min));
+        scope.raw(&format!("const MAX: Option<{}> = {:?};", r#type, max));
+        if extensible {
+            scope.raw("const EXTENSIBLE: bool = true;");
+        }
+        scope.raw("}");
+    }
+
+    fn write_size_constraint_type(
+        scope: &mut Scope,
+        name: &str,
+        field: &str,
+        module: &str,
+        size: &Size,
+    ) {
+        let combined = Self::combined_field_type_name(name, field) + "Constraint";
+        let combined = Self::constraint_impl_name(&combined);
+
+        scope.new_struct(&combined).derive("Default");
+        scope.raw(&format!(
+            "impl {}{}::Constraint for {} {{",
+            CRATE_SYN_PREFIX, module, combined
+        ));
+        Self::write_size_constraint_consts(scope, size);
+        scope.raw("}");
+    }
+
+    fn write_size_constraint_consts(scope: &mut Scope, size: &Size) {
+        scope.raw(&format!("const MIN: Option<usize> = {:?};", size.min()));
+        scope.raw(&format!(
+            "const MAX: Option<usize> = {:?};",
+            size.max().filter(|max| *max != usize::MAX)
+        ));
+        if size.extensible() {
+            scope.raw("const EXTENSIBLE: bool = true;");
+        }
+    }
+
+    fn write_character_string_constraint_type(
+        scope: &mut Scope,
+        name: &str,
+        field: &str,
+        module: &str,
+        string: &CharacterString,
+    ) {
+        let combined = Self::combined_field_type_name(name, field) + "Constraint";
+        let combined = Self::constraint_impl_name(&combined);
+
+        scope.new_struct(&combined).derive("Default");
+        scope.raw(&format!(
+            "impl {}{}::Constraint for {} {{",
+            CRATE_SYN_PREFIX, module, combined
+        ));
+        if string.size != Size::Any {
+            Self::write_size_constraint_consts(scope, &string.size);
+        }
+        if let Some(alphabet) = &string.permitted_alphabet {
+            scope.raw(&format!(
+                "const PERMITTED_ALPHABET: Option<&'static str> = Some({:?});",
+                alphabet
+            ));
+        }
+        scope.raw("}");
+    }
+
+    fn write_tag_constraint_type(
+        scope: &mut Scope,
+        name: &str,
+        field: &str,
+        tag: Tag,
+        tagging: Option<TaggingMode>,
+    ) {
+        let combined = Self::combined_field_type_name(name, field);
+        let combined = Self::constraint_impl_name(&combined) + "Tag";
+
+        scope.new_struct(&combined).derive("Default");
+        scope.raw(&format!(
+            "impl {}tagged::Constraint for {} {{",
+            CRATE_SYN_PREFIX, combined
+        ));
+        scope.raw(&format!(
+            "const TAG: {}Tag = {}Tag::{:?};",
+            CRATE_SYN_PREFIX, CRATE_SYN_PREFIX, tag
+        ));
+        if tagging == Some(TaggingMode::Explicit) {
+            scope.raw("const EXPLICIT: bool = true;");
+        }
+        scope.raw("}");
+    }
+
+    fn write_default_constraint_type(
+        scope: &mut Scope,
+        name: &str,
+        field: &str,
+        r#type: &RustType,
+        default: &LiteralValue,
+    ) {
+        let combined = Self::combined_field_type_name(name, field);
+        let combined = Self::constraint_impl_name(&combined) + "Default";
+        let (_, constant) = RustCodeGenerator::literal_value_const(r#type, default);
+
+        scope.new_struct(&combined).derive("Default");
+        scope.raw(&format!(
+            "impl {}default::Constraint<{}> for {} {{",
+            CRATE_SYN_PREFIX,
+            r#type.to_string(),
+            combined
+        ));
+        scope.raw(&format!(
+            "fn default_value() -> {} {{ {} }}",
+            r#type.to_string(),
+            RustCodeGenerator::default_value_from_const(r#type, default, &constant)
+        ));
         scope.raw("}");
     }
 
@@ -366,21 +929,55 @@ impl AsnDefWriter {
         scope: &mut Scope,
         name: &str,
         fields: &[Field],
+        extension_after: Option<usize>,
         imp: Impl,
     ) {
+        let root_fields = extension_after.map_or(fields.len(), |index| index + 1);
         Self::insert_consts(
             scope,
             imp,
             &[
+                format!(
+                    "const EXTENDED_AFTER_FIELD: Option<usize> = {:?};",
+                    extension_after
+                ),
+                format!(
+                    "const STD_OPTIONAL_FIELDS: usize = {};",
+                    fields[..root_fields]
+                        .iter()
+                        .filter(|f| f.is_optional_or_default())
+                        .count()
+                ),
+                format!(
+                    "const FIELD_NAMES: &'static [&'static str] = &[{}];",
+                    Self::quoted_names(fields.iter().map(Field::asn_name))
+                ),
                 format!(
                     "const OPTIONAL_FIELDS: usize = {};",
-                    fields.iter().filter(|f| f.r#type().is_option()).count()
+                    fields.iter().filter(|f| f.is_optional_or_default()).count()
                 ),
                 format!("const NAME: &'static str = \"{}\";", name),
             ],
         );
     }
 
+    fn tuple<I: IntoIterator<Item = String>>(values: I) -> String {
+        let values = values.into_iter().collect::<Vec<_>>();
+        if values.len() == 1 {
+            format!("({},)", values[0])
+        } else {
+            format!("({})", values.join(", "))
+        }
+    }
+
+    fn quoted_names<S: AsRef<str>, I: IntoIterator<Item = S>>(names: I) -> String {
+        names
+            .into_iter()
+            .map(|name| format!("\"{}\"", name.as_ref()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     fn insert_consts<S: ToString, I: IntoIterator<Item = S>>(
         scope: &mut Scope,
         imp: Impl,
@@ -396,19 +993,78 @@ impl AsnDefWriter {
         scope.raw(&lines.join("\n"));
     }
 
-    fn write_sequence_constraint_read_fn(&self, imp: &mut Impl, name: &str, fields: &[Field]) {
-        imp.new_fn("read_seq")
+    fn write_sequence_constraint_read_fn(
+        &self,
+        imp: &mut Impl,
+        name: &str,
+        fields: &[Field],
+        extension_after: Option<usize>,
+        additions: &[(bool, &[Field])],
+    ) {
+        let function = imp
+            .new_fn("read_seq")
             .generic(&format!("R: {}Reader", CRATE_SYN_PREFIX))
             .arg("reader", "&mut R")
             .ret("Result<Self, R::Error>")
-            .bound("Self", "Sized")
-            .push_block({
+            .bound("Self", "Sized");
+
+        // open types refer to the value of their key field, which therefore needs to be bound
+        if extension_after.is_some() || fields.iter().any(|f| f.key_field().is_some()) {
+            let root_fields = extension_after.map_or(fields.len(), |index| index + 1);
+            for field in &fields[..root_fields] {
+                function.line(format!(
+                    "let {} = {};",
+                    Self::field_identifier(field),
+                    Self::read_field_expression(name, field, fields)
+                ));
+            }
+
+            for (group, group_fields) in additions {
+                if *group {
+                    function.line(format!(
+                        "let {} = reader.read_extension_addition(Some({}), |reader| Ok({}))?;",
+                        Self::tuple(group_fields.iter().map(Self::field_identifier)),
+                        group_fields
+                            .iter()
+                            .filter(|f| f.is_optional_or_default())
+                            .count(),
+                        Self::tuple(
+                            group_fields
+                                .iter()
+                                .map(|f| Self::read_field_expression(name, f, fields))
+                        )
+                    ));
+                } else if group_fields[0].key_field().is_some() {
+                    function.line(format!(
+                        "let {} = reader.read_extension_addition(None, |reader| Ok({}))?;",
+                        Self::field_identifier(&group_fields[0]),
+                        Self::read_field_expression(name, &group_fields[0], fields)
+                    ));
+                } else {
+                    function.line(format!(
+                        "let {} = reader.read_extension_addition(None, AsnDef{}::read_value)?;",
+                        Self::field_identifier(&group_fields[0]),
+                        Self::combined_field_type_name(name, group_fields[0].name())
+                    ));
+                }
+            }
+
+            function.line(format!(
+                "Ok(Self {{ {} }})",
+                fields
+                    .iter()
+                    .map(Self::field_identifier)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        } else {
+            function.push_block({
                 let mut block = Block::new("Ok(Self");
 
                 for field in fields {
                     block.line(format!(
                         "{}: AsnDef{}::read_value(reader)?,",
-                        field.name(),
+                        Self::field_identifier(field),
                         Self::combined_field_type_name(name, field.name())
                     ));
                 }
@@ -416,9 +1072,77 @@ impl AsnDefWriter {
                 block.after(")");
                 block
             });
+        }
+    }
+
+    /// The expression reading the value of the given field, where the actual type of an open
+    /// type is identified by the value of its key field
+    fn read_field_expression(name: &str, field: &Field, fields: &[Field]) -> String {
+        match field.key_field() {
+            Some(key_field) => format!(
+                "reader.read_open_type({}opentype::Key::key({}))?",
+                CRATE_SYN_PREFIX,
+                Self::key_field_reference("", key_field, fields)
+            ),
+            None => format!(
+                "AsnDef{}::read_value(reader)?",
+                Self::combined_field_type_name(name, field.name())
+            ),
+        }
+    }
+
+    /// The expression writing the value of the given field, where the key field must identify
+    /// the actual type of an open type
+    fn write_field_statement(name: &str, field: &Field, fields: &[Field]) -> String {
+        if let Some(key_field) = field.key_field() {
+            format!(
+                "writer.write_open_type({}opentype::Key::key({}), &self.{})?;",
+                CRATE_SYN_PREFIX,
+                Self::key_field_reference("self.", key_field, fields),
+                Self::field_identifier(field)
+            )
+        } else {
+            format!(
+                "AsnDef{}::write_value(writer, &self.{})?;",
+                Self::combined_field_type_name(name, field.name()),
+                Self::field_identifier(field),
+            )
+        }
+    }
+
+    /// The identifier of the variant in the generated enum
+    fn variant_identifier(name: &str) -> String {
+        RustCodeGenerator::rust_variant_name(name)
+    }
+
+    /// The identifier of the field in the generated struct
+    fn field_identifier(field: &Field) -> String {
+        RustCodeGenerator::rust_field_name(field.name(), true)
+    }
+
+    /// The reference to the integer value of the key field, which might be wrapped in a
+    /// tuple struct
+    fn key_field_reference(receiver: &str, key_field: &str, fields: &[Field]) -> String {
+        let wrapped = fields
+            .iter()
+            .find(|f| f.name() == key_field)
+            .is_some_and(|f| matches!(f.r#type(), RustType::Complex(_)));
+        format!(
+            "&{}{}{}",
+            receiver,
+            RustCodeGenerator::rust_field_name(key_field, true),
+            if wrapped { ".0" } else { "" }
+        )
     }
 
-    fn write_sequence_constraint_write_fn(&self, imp: &mut Impl, name: &str, fields: &[Field]) {
+    fn write_sequence_constraint_write_fn(
+        &self,
+        imp: &mut Impl,
+        name: &str,
+        fields: &[Field],
+        extension_after: Option<usize>,
+        additions: &[(bool, &[Field])],
+    ) {
         let body = imp
             .new_fn("write_seq")
             .generic(&format!("W: {}Writer", CRATE_SYN_PREFIX))
@@ -426,11 +1150,42 @@ impl AsnDefWriter {
             .arg("writer", "&mut W")
             .ret("Result<(), W::Error>");
 
-        for field in fields {
+        let root_fields = extension_after.map_or(fields.len(), |index| index + 1);
+        for field in &fields[..root_fields] {
+            body.line(Self::write_field_statement(name, field, fields));
+        }
+
+        for (group, group_fields) in additions {
+            // an extension addition is present, if any of its fields is present
+            let present = if group_fields.iter().all(|f| f.r#type().is_option()) {
+                group_fields
+                    .iter()
+                    .map(|f| format!("self.{}.is_some()", Self::field_identifier(f)))
+                    .collect::<Vec<_>>()
+                    .join(" || ")
+            } else {
+                String::from("true")
+            };
+            let writes = group_fields
+                .iter()
+                .map(|f| Self::write_field_statement(name, f, fields))
+                .collect::<Vec<_>>()
+                .join(" ");
             body.line(format!(
-                "AsnDef{}::write_value(writer, &self.{})?;",
-                Self::combined_field_type_name(name, field.name()),
-                field.name(),
+                "writer.write_extension_addition({}, {}, |writer| {{ {} Ok(()) }})?;",
+                present,
+                if *group {
+                    format!(
+                        "Some({})",
+                        group_fields
+                            .iter()
+                            .filter(|f| f.is_optional_or_default())
+                            .count()
+                    )
+                } else {
+                    String::from("None")
+                },
+                writes
             ));
         }
 
@@ -444,8 +1199,11 @@ impl AsnDefWriter {
         for definition in &model.definitions {
             myself.write_type_definitions(&mut scope, definition);
             myself.write_constraints(&mut scope, definition);
-            myself.impl_readable(&mut scope, &definition.0);
-            myself.impl_writable(&mut scope, &definition.0);
+            // open types are read and written through the sequence containing their key
+            if !matches!(definition.1, Rust::OpenType(_)) {
+                myself.impl_readable(&mut scope, definition);
+                myself.impl_writable(&mut scope, definition);
+            }
         }
 
         scope.to_string()
@@ -455,17 +1213,23 @@ impl AsnDefWriter {
 #[cfg(test)]
 pub mod tests {
     use crate::gen::rust::walker::AsnDefWriter;
-    use crate::model::rust::Field;
-    use crate::model::{Definition, Rust, RustType};
+    use crate::model::rust::{EncodingOrdering, Field};
+    use crate::model::{Definition, Range, Rust, RustType, Size, Tag, TagProperty, TaggingMode};
     use codegen::Scope;
 
     fn simple_whatever_sequence() -> Definition<Rust> {
         Definition(
             String::from("Whatever"),
-            Rust::Struct(vec![
-                Field::from_name_type("name", RustType::String),
-                Field::from_name_type("opt", RustType::Option(Box::new(RustType::String))),
-                Field::from_name_type("some", RustType::Option(Box::new(RustType::String))),
+            Rust::struct_from_fields(vec![
+                Field::from_name_type("name", RustType::String(Size::Any)),
+                Field::from_name_type(
+                    "opt",
+                    RustType::Option(Box::new(RustType::String(Size::Any))),
+                ),
+                Field::from_name_type(
+                    "some",
+                    RustType::Option(Box::new(RustType::String(Size::Any))),
+                ),
             ]),
         )
     }
@@ -496,13 +1260,48 @@ pub mod tests {
         );
     }
 
+    #[test]
+    pub fn test_tagged_struct_type_declaration() {
+        let def = Definition(
+            String::from("Whatever"),
+            Rust::struct_from_fields(vec![
+                Field::from_name_type("name", RustType::String(Size::Any))
+                    .with_tag(Tag::ContextSpecific(1))
+                    .with_tagging(Some(TaggingMode::Implicit)),
+                Field::from_name_type(
+                    "opt",
+                    RustType::Option(Box::new(RustType::String(Size::Any))),
+                )
+                .with_tag(Tag::Application(2))
+                .with_tagging(Some(TaggingMode::Explicit)),
+            ]),
+        );
+        let mut scope = Scope::new();
+        AsnDefWriter.write_type_definitions(&mut scope, &def);
+        AsnDefWriter.write_constraints(&mut scope, &def);
+        let string = scope.to_string();
+        println!("{}", string);
+        assert!(string.contains(
+            "type AsnDefWhateverFieldName = ::asn1rs::syn::Tagged<::asn1rs::syn::Utf8String, ___ans1rs_WhateverFieldNameTag>;"
+        ));
+        assert!(string.contains(
+            "type AsnDefWhateverFieldOpt = Option<::asn1rs::syn::Tagged<::asn1rs::syn::Utf8String, ___ans1rs_WhateverFieldOptTag>>;"
+        ));
+        assert!(string
+            .contains("const TAG: ::asn1rs::syn::Tag = ::asn1rs::syn::Tag::ContextSpecific(1);"));
+        assert!(
+            string.contains("const TAG: ::asn1rs::syn::Tag = ::asn1rs::syn::Tag::Application(2);")
+        );
+        assert_eq!(1, string.matches("const EXPLICIT: bool = true;").count());
+    }
+
     #[test]
     pub fn test_whatever_struct_constraint_and_read_write_impl() {
         let def = simple_whatever_sequence();
         let mut scope = Scope::new();
         AsnDefWriter.write_constraints(&mut scope, &def);
-        AsnDefWriter.impl_readable(&mut scope, &def.0);
-        AsnDefWriter.impl_writable(&mut scope, &def.0);
+        AsnDefWriter.impl_readable(&mut scope, &def);
+        AsnDefWriter.impl_writable(&mut scope, &def);
         let string = scope.to_string();
         println!("{}", string);
 
@@ -525,6 +1324,9 @@ pub mod tests {
             impl ::asn1rs::syn::sequence::Constraint for Whatever {
                 const NAME: &'static str = "Whatever";
                 const OPTIONAL_FIELDS: usize = 2;
+                const FIELD_NAMES: &'static [&'static str] = &["name", "opt", "some"];
+                const STD_OPTIONAL_FIELDS: usize = 2;
+                const EXTENDED_AFTER_FIELD: Option<usize> = None;
                 
                 fn read_seq<R: ::asn1rs::syn::Reader>(reader: &mut R) -> Result<Self, R::Error>
                 where Self: Sized,
@@ -560,4 +1362,46 @@ pub mod tests {
             &string,
         );
     }
+
+    #[test]
+    pub fn test_extensible_struct_constraint_with_addition_group() {
+        let def = Definition(
+            String::from("Extensible"),
+            Rust::Struct {
+                fields: vec![
+                    Field::from_name_type("name", RustType::String(Size::Any)),
+                    Field::from_name_type(
+                        "opt",
+                        RustType::Option(Box::new(RustType::String(Size::Any))),
+                    ),
+                    Field::from_name_type("grp_a", RustType::Bool),
+                    Field::from_name_type("grp_b", RustType::Option(Box::new(RustType::Bool))),
+                ],
+                extension_after: Some(0),
+                extension_groups: vec![Range::inclusive(2, 3)],
+                ordering: EncodingOrdering::Keep,
+            },
+        );
+        let mut scope = Scope::new();
+        AsnDefWriter.write_constraints(&mut scope, &def);
+        let string = scope.to_string();
+        println!("{}", string);
+        assert!(string.contains("const OPTIONAL_FIELDS: usize = 2;"));
+        assert!(string.contains("const STD_OPTIONAL_FIELDS: usize = 0;"));
+        assert!(string.contains("const EXTENDED_AFTER_FIELD: Option<usize> = Some(0);"));
+        assert!(string.contains("let name = AsnDefExtensibleFieldName::read_value(reader)?;"));
+        assert!(string.contains(
+            "let opt = reader.read_extension_addition(None, AsnDefExtensibleFieldOpt::read_value)?;"
+        ));
+        assert!(string.contains(
+            "let (grp_a, grp_b) = reader.read_extension_addition(Some(1), |reader| Ok((AsnDefExtensibleFieldGrpA::read_value(reader)?, AsnDefExtensibleFieldGrpB::read_value(reader)?)))?;"
+        ));
+        assert!(string.contains("Ok(Self { name, opt, grp_a, grp_b })"));
+        assert!(string.contains(
+            "writer.write_extension_addition(self.opt.is_some(), None, |writer| { AsnDefExtensibleFieldOpt::write_value(writer, &self.opt)?; Ok(()) })?;"
+        ));
+        assert!(string.contains(
+            "writer.write_extension_addition(true, Some(1), |writer| { AsnDefExtensibleFieldGrpA::write_value(writer, &self.grp_a)?; AsnDefExtensibleFieldGrpB::write_value(writer, &self.grp_b)?; Ok(()) })?;"
+        ));
+    }
 }
@@ -2,3 +2,5 @@ pub mod ast;
 pub mod gen;
 pub mod model;
 pub mod parser;
+pub mod report;
+pub mod workspace;
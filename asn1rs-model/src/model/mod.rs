@@ -1,6 +1,7 @@
 pub mod protobuf;
 pub mod rust;
 pub mod sql;
+pub mod validation;
 
 pub use self::rust::Rust;
 pub use self::rust::RustType;
@@ -8,8 +9,10 @@ pub use self::rust::RustType;
 pub use self::protobuf::Protobuf;
 pub use self::protobuf::ProtobufType;
 
-use crate::parser::Token;
+use crate::parser::{Span, Symbol, Token};
+use crate::report::Diagnostic;
 use backtrace::Backtrace;
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::error::Error as StdError;
 use std::fmt::{Debug, Display, Formatter};
@@ -35,25 +38,36 @@ pub enum ErrorKind {
     ExpectedTextGot(String, Token),
     ExpectedSeparator(Token),
     ExpectedSeparatorGot(char, Token),
+    ExpectedSymbolGot(Symbol, Token),
     UnexpectedToken(Token),
     MissingModuleName,
     UnexpectedEndOfStream,
     InvalidRangeValue(Token),
     InvalidNumberForEnumVariant(Token),
+    InvalidNumberForNamedBit(Token),
+    InvalidPermittedAlphabet(Token),
     InvalidTag(Token),
     InvalidPositionForExtensionMarker(Token),
+    InvalidDefaultValue(Token),
+    UnresolvedReference(String),
 }
 
 pub struct Error {
-    kind: ErrorKind,
-    backtrace: Backtrace,
+    /// Boxed, so that results of the parser are not larger than needed on success
+    kind: Box<ErrorKind>,
+    /// Only captured if `RUST_BACKTRACE` is set, because it is expensive and rarely helpful
+    /// to users of the parser
+    backtrace: Option<Backtrace>,
 }
 
 impl From<ErrorKind> for Error {
     fn from(kind: ErrorKind) -> Self {
+        let backtrace = std::env::var_os("RUST_BACKTRACE")
+            .filter(|value| value != "0")
+            .map(|_| Backtrace::new());
         Error {
-            kind,
-            backtrace: Backtrace::new(),
+            kind: Box::new(kind),
+            backtrace,
         }
     }
 }
@@ -69,6 +83,10 @@ impl Error {
         ErrorKind::InvalidPositionForExtensionMarker(token).into()
     }
 
+    pub fn invalid_default_value(token: Token) -> Self {
+        ErrorKind::InvalidDefaultValue(token).into()
+    }
+
     pub fn invalid_tag(token: Token) -> Self {
         ErrorKind::InvalidTag(token).into()
     }
@@ -77,6 +95,14 @@ impl Error {
         ErrorKind::InvalidNumberForEnumVariant(token).into()
     }
 
+    pub fn invalid_number_for_named_bit(token: Token) -> Self {
+        ErrorKind::InvalidNumberForNamedBit(token).into()
+    }
+
+    pub fn invalid_permitted_alphabet(token: Token) -> Self {
+        ErrorKind::InvalidPermittedAlphabet(token).into()
+    }
+
     pub fn invalid_range_value(token: Token) -> Self {
         ErrorKind::InvalidRangeValue(token).into()
     }
@@ -97,6 +123,10 @@ impl Error {
         ErrorKind::ExpectedSeparatorGot(separator, token).into()
     }
 
+    pub fn expected_symbol(symbol: Symbol, token: Token) -> Self {
+        ErrorKind::ExpectedSymbolGot(symbol, token).into()
+    }
+
     pub fn missing_module_name() -> Self {
         ErrorKind::MissingModuleName.into()
     }
@@ -109,23 +139,41 @@ impl Error {
         ErrorKind::UnexpectedEndOfStream.into()
     }
 
-    fn backtrace(&self) -> &Backtrace {
-        &self.backtrace
+    pub fn unresolved_reference(reference: String) -> Self {
+        ErrorKind::UnresolvedReference(reference).into()
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace.as_ref()
+    }
+
+    /// The span of the token the error refers to, if any
+    pub fn span(&self) -> Option<Span> {
+        self.token().map(Token::span)
     }
 
     pub fn token(&self) -> Option<&Token> {
-        match &self.kind {
+        match self.kind.as_ref() {
             ErrorKind::ExpectedText(t) => Some(t),
             ErrorKind::ExpectedTextGot(_, t) => Some(t),
             ErrorKind::ExpectedSeparator(t) => Some(t),
             ErrorKind::ExpectedSeparatorGot(_, t) => Some(t),
+            ErrorKind::ExpectedSymbolGot(_, t) => Some(t),
             ErrorKind::UnexpectedToken(t) => Some(t),
             ErrorKind::MissingModuleName => None,
             ErrorKind::UnexpectedEndOfStream => None,
             ErrorKind::InvalidRangeValue(t) => Some(t),
             ErrorKind::InvalidNumberForEnumVariant(t) => Some(t),
+            ErrorKind::InvalidNumberForNamedBit(t) => Some(t),
+            ErrorKind::InvalidPermittedAlphabet(t) => Some(t),
             ErrorKind::InvalidTag(t) => Some(t),
             ErrorKind::InvalidPositionForExtensionMarker(t) => Some(t),
+            ErrorKind::InvalidDefaultValue(t) => Some(t),
+            ErrorKind::UnresolvedReference(_) => None,
         }
     }
 }
@@ -135,1588 +183,5072 @@ impl StdError for Error {}
 impl Debug for Error {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         writeln!(f, "{}", self)?;
-        writeln!(f, "{:?}", self.backtrace())?;
+        if let Some(backtrace) = self.backtrace() {
+            writeln!(f, "{:?}", backtrace)?;
+        }
         Ok(())
     }
 }
 
-impl Display for Error {
+impl Display for ErrorKind {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        match &self.kind {
-            ErrorKind::ExpectedText( token) => write!(
+        match self {
+            ErrorKind::ExpectedText(token) => write!(
                 f,
-                "At line {}, column {} expected text, but instead got: {}",
-                token.location().line(),
-                token.location().column(),
+                "expected text, but instead got: {}",
                 token,
             ),
-            ErrorKind::ExpectedTextGot( text, token) => write!(
+            ErrorKind::ExpectedTextGot(text, token) => write!(
                 f,
-                "At line {}, column {} expected a text like \"{}\", but instead got: {}",
-                token.location().line(),
-                token.location().column(),
+                "expected a text like \"{}\", but instead got: {}",
                 text,
                 token,
             ),
-            ErrorKind::ExpectedSeparator( token) => write!(
+            ErrorKind::ExpectedSeparator(token) => write!(
                 f,
-                "At line {}, column {} expected separator, but instead got: {}",
-                token.location().line(),
-                token.location().column(),
+                "expected separator, but instead got: {}",
                 token,
             ),
-            ErrorKind::ExpectedSeparatorGot( separator, token) => write!(
+            ErrorKind::ExpectedSeparatorGot(separator, token) => write!(
                 f,
-                "At line {}, column {} expected a separator like '{}', but instead got: {}",
-                token.location().line(),
-                token.location().column(),
+                "expected a separator like '{}', but instead got: {}",
                 separator,
                 token,
             ),
-            ErrorKind::UnexpectedToken( token) => write!(
+            ErrorKind::ExpectedSymbolGot(symbol, token) => write!(
                 f,
-                "At line {}, column {} an unexpected token was encountered: {}",
-                token.location().line(),
-                token.location().column(),
+                "expected '{}', but instead got: {}",
+                symbol,
+                token,
+            ),
+            ErrorKind::UnexpectedToken(token) => write!(
+                f,
+                "an unexpected token was encountered: {}",
                 token,
             ),
             ErrorKind::MissingModuleName => {
-                writeln!(f, "The ASN definition is missing the module name")
+                write!(f, "The ASN definition is missing the module name")
             }
             ErrorKind::UnexpectedEndOfStream => write!(f, "Unexpected end of stream or file"),
-            ErrorKind::InvalidRangeValue( token) => write!(
+            ErrorKind::InvalidRangeValue(token) => write!(
+                f,
+                "an unexpected range value was encountered: {}",
+                token,
+            ),
+            ErrorKind::InvalidNumberForEnumVariant(token) => write!(
+                f,
+                "an invalid value for an enum variant was encountered: {}",
+                token,
+            ),
+            ErrorKind::InvalidNumberForNamedBit(token) => write!(
+                f,
+                "an invalid value for a named bit was encountered: {}",
+                token,
+            ),
+            ErrorKind::InvalidPermittedAlphabet(token) => write!(
                 f,
-                "At line {}, column {} an unexpected range value was encountered: {}",
-                token.location().line(),
-                token.location().column(),
+                "an invalid permitted alphabet was encountered: {}",
                 token,
             ),
-            ErrorKind::InvalidNumberForEnumVariant( token) => write!(
+            ErrorKind::InvalidTag(token) => write!(
                 f,
-                "At line {}, column {} an invalid value for an enum variant was encountered: {}",
-                token.location().line(),
-                token.location().column(),
+                "an invalid value for a tag was encountered: {}",
                 token,
             ),
-            ErrorKind::InvalidTag( token) => write!(
+            ErrorKind::InvalidPositionForExtensionMarker(_) => write!(
                 f,
-                "At line {}, column {} an invalid value for a tag was encountered: {}",
-                token.location().line(),
-                token.location().column(),
+                "an extension marker is present, which this is not allowed at that position",
+            ),
+            ErrorKind::InvalidDefaultValue(token) => write!(
+                f,
+                "an invalid DEFAULT value was encountered: {}",
                 token,
             ),
-            ErrorKind::InvalidPositionForExtensionMarker( token) => write!(
+            ErrorKind::UnresolvedReference(reference) => write!(
                 f,
-                "At line {}, column {} an extension marker is present, which this is not allowed at that position",
-                token.location().line(),
-                token.location().column(),
+                "The reference {} to an information object class, object set or value cannot be resolved",
+                reference
             ),
         }
     }
 }
 
+impl Diagnostic for Error {
+    fn message(&self) -> String {
+        self.kind.to_string()
+    }
+
+    fn span(&self) -> Option<Span> {
+        Error::span(self)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(location) = self.token().map(Token::location) {
+            write!(
+                f,
+                "At line {}, column {}: ",
+                location.line(),
+                location.column()
+            )?;
+        }
+        Display::fmt(&self.kind, f)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Model<T> {
     pub name: String,
+    /// The object identifier of the module, like `{ iso(1) standard(0) 8824 }`
+    pub oid: Option<ObjectIdentifierValue>,
     pub imports: Vec<Import>,
+    pub exports: Exports,
     pub definitions: Vec<Definition<T>>,
+    pub value_references: Vec<ValueReference>,
+    pub tag_default: TagDefault,
+    /// Whether the module is declared with `EXTENSIBILITY IMPLIED`, which makes every
+    /// `SEQUENCE`, `SET`, `CHOICE` and `ENUMERATED` extensible (X.680-201508 13.4)
+    pub extensibility_implied: bool,
+    /// The spans of the names of the definitions, in the order of the definitions. This is
+    /// empty, unless the model is parsed from ASN.1.
+    pub definition_spans: Vec<Span>,
+    /// The spans of the names of the components and variants, together with their paths
+    /// like `Message.header`, in the order of their declaration. This is empty, unless the
+    /// model is parsed from ASN.1.
+    pub component_spans: Vec<(String, Span)>,
+    /// The span of the `IMPORTS` clause up to and including the closing `;`. This is `None`,
+    /// unless the model is parsed from ASN.1.
+    pub imports_span: Option<Span>,
 }
 
 impl<T> Default for Model<T> {
     fn default() -> Self {
         Model {
             name: Default::default(),
+            oid: Default::default(),
             imports: Default::default(),
+            exports: Default::default(),
             definitions: Default::default(),
+            value_references: Default::default(),
+            tag_default: Default::default(),
+            extensibility_implied: Default::default(),
+            definition_spans: Default::default(),
+            component_spans: Default::default(),
+            imports_span: Default::default(),
+        }
+    }
+}
+
+/// A parameterized type assignment, whose body is instantiated for each distinct list of
+/// actual parameters (X.683-201508 8)
+struct ParameterizedType {
+    parameters: Vec<String>,
+    body: Vec<Token>,
+}
+
+impl ParameterizedType {
+    fn instantiate(&self, arguments: &[Vec<Token>]) -> Vec<Token> {
+        let mut body = Vec::with_capacity(self.body.len());
+        for token in &self.body {
+            let parameter = token
+                .text()
+                .and_then(|text| self.parameters.iter().position(|p| p == text));
+            match parameter.and_then(|index| arguments.get(index)) {
+                Some(argument) => body.extend(argument.iter().cloned()),
+                None => body.push(token.clone()),
+            }
+        }
+        body
+    }
+}
+
+/// An information object class like `MESSAGE-ID-AND-TYPE ::= CLASS { &id INTEGER UNIQUE, &Type }`
+/// with its optional defined syntax (X.681-201508 9)
+#[derive(Default)]
+struct InformationObjectClass {
+    fields: Vec<ClassField>,
+    syntax: Option<Vec<Token>>,
+}
+
+struct ClassField {
+    /// The name including the leading ampersand, like `&id`
+    name: String,
+    /// The type of a fixed-type value field, `None` for a type field
+    r#type: Option<Type>,
+    unique: bool,
+}
+
+impl InformationObjectClass {
+    /// The value field that identifies the objects of this class, which is the `UNIQUE` field
+    /// or - if there is none - the first value field
+    fn key_field(&self) -> Option<&ClassField> {
+        self.fields
+            .iter()
+            .find(|field| field.unique)
+            .or_else(|| self.fields.iter().find(|field| field.r#type.is_some()))
+    }
+
+    /// Assigns the tokens of the given object to the fields of this class, either by the default
+    /// syntax like `{&id 1, &Type Foo}` or by the defined syntax like `{Foo IDENTIFIED BY 1}`
+    fn settings(&self, object: &[Token]) -> HashMap<String, Vec<Token>> {
+        let mut settings = HashMap::new();
+        let syntax = match &self.syntax {
+            Some(syntax) => syntax,
+            None => {
+                for setting in Model::<Asn>::split_arguments(object) {
+                    if let Some((field, value)) = setting.split_first() {
+                        if let Some(field) = field.text() {
+                            settings.insert(field.to_string(), value.to_vec());
+                        }
+                    }
+                }
+                return settings;
+            }
+        };
+
+        let is_literal = |token: &Token| !token.eq_separator('[') && !token.eq_separator(']');
+        let is_field = |token: &Token| token.text().is_some_and(|t| t.starts_with('&'));
+
+        let mut position = 0;
+        let mut groups = Vec::new();
+        let mut index = 0;
+        while index < syntax.len() {
+            let word = &syntax[index];
+            if word.eq_separator('[') {
+                groups.push(index);
+            } else if word.eq_separator(']') {
+                groups.pop();
+            } else if let Some(field) = word.text().filter(|_| is_field(word)) {
+                let end = match syntax[index + 1..].iter().find(|t| is_literal(t)) {
+                    Some(next) if is_field(next) => (position + 1).min(object.len()),
+                    Some(next) => object[position..]
+                        .iter()
+                        .position(|t| t.eq_ignore_span(next))
+                        .map_or(object.len(), |end| position + end),
+                    None => object.len(),
+                };
+                if end > position {
+                    settings.insert(field.to_string(), object[position..end].to_vec());
+                    position = end;
+                }
+            } else if object.get(position).is_some_and(|t| t.eq_ignore_span(word)) {
+                position += 1;
+            } else if let Some(group) = groups.pop() {
+                // the optional group is not present in this object
+                index = Model::<Asn>::matching_close_index(syntax, group);
+            }
+            index += 1;
+        }
+        settings
+    }
+}
+
+/// An object set like `MessageTypes MESSAGE-ID-AND-TYPE ::= { {Foo IDENTIFIED BY 1}, ... }`,
+/// whose objects are kept as tokens until the syntax of their class is known
+struct ObjectSet {
+    class: String,
+    objects: Vec<Vec<Token>>,
+}
+
+#[derive(Default)]
+struct InformationObjects {
+    classes: HashMap<String, InformationObjectClass>,
+    object_sets: HashMap<String, ObjectSet>,
+}
+
+impl InformationObjects {
+    /// Replaces references to value fields of classes by the type of the field and determines
+    /// the variants of open types from their object sets
+    fn resolve(&self, r#type: &mut Type, values: &[ValueReference]) -> Result<(), Error> {
+        match r#type {
+            Type::Optional(inner) | Type::SequenceOf(inner, _) | Type::SetOf(inner, _) => {
+                self.resolve(inner, values)
+            }
+            Type::Sequence(list) | Type::Set(list) => list
+                .fields
+                .iter_mut()
+                .try_for_each(|field| self.resolve(&mut field.role.r#type, values)),
+            Type::Choice(choice) => choice
+                .variants
+                .iter_mut()
+                .try_for_each(|variant| self.resolve(&mut variant.r#type, values)),
+            Type::TypeReference(name) if name.contains(".&") => {
+                let resolved = name
+                    .split_once('.')
+                    .and_then(|(class, field)| {
+                        self.classes
+                            .get(class)?
+                            .fields
+                            .iter()
+                            .find(|f| f.name == field)?
+                            .r#type
+                            .clone()
+                    })
+                    .ok_or_else(|| Error::unresolved_reference(name.clone()))?;
+                *r#type = resolved;
+                self.resolve(r#type, values)
+            }
+            Type::OpenType(open) => {
+                open.variants = self.open_type_variants(open, values)?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn open_type_variants(
+        &self,
+        open: &OpenType,
+        values: &[ValueReference],
+    ) -> Result<Vec<OpenTypeVariant>, Error> {
+        let class = self
+            .classes
+            .get(&open.class)
+            .ok_or_else(|| Error::unresolved_reference(open.class.clone()))?;
+        let set = self
+            .object_sets
+            .get(&open.object_set)
+            .filter(|set| set.class == open.class)
+            .ok_or_else(|| Error::unresolved_reference(open.object_set.clone()))?;
+        let key_field = class
+            .key_field()
+            .ok_or_else(|| Error::unresolved_reference(open.class.clone()))?;
+
+        let mut variants = Vec::with_capacity(set.objects.len());
+        for object in &set.objects {
+            let settings = class.settings(object);
+            let setting = |field: &str| {
+                settings
+                    .get(field)
+                    .filter(|tokens| !tokens.is_empty())
+                    .cloned()
+                    .ok_or_else(|| {
+                        Error::unresolved_reference(format!("{}.{}", open.object_set, field))
+                    })
+            };
+
+            let key_tokens = setting(&key_field.name)?;
+            let key_text = key_tokens[0].text();
+            let number = Model::<Asn>::integer_value(
+                &mut key_tokens[1..].iter().cloned().peekable(),
+                &key_tokens[0],
+            );
+            let key = match (number, key_text) {
+                (Some(key), _) => key,
+                (None, Some(key_text)) => values
+                    .iter()
+                    .find(|value| value.name == key_text)
+                    .and_then(|value| match value.value {
+                        LiteralValue::Integer(key) => Some(key),
+                        _ => None,
+                    })
+                    .ok_or_else(|| Error::unresolved_reference(key_text.to_string()))?,
+                (None, None) => return Err(Error::unexpected_token(key_tokens[0].clone())),
+            };
+
+            let mut r#type =
+                Model::<Asn>::read_role(&mut setting(&open.field)?.into_iter().peekable())?;
+            self.resolve(&mut r#type, values)?;
+
+            let name = match (&r#type, key_text) {
+                (Type::TypeReference(name), _) => name.clone(),
+                (_, Some(key_text)) if Model::<Asn>::is_value_reference(key_text) => {
+                    key_text.to_string()
+                }
+                _ => format!("{}{}", open.field.trim_start_matches('&'), key),
+            };
+            variants.push(OpenTypeVariant { name, key, r#type });
         }
+        Ok(variants)
     }
 }
 
 impl Model<Asn> {
     pub fn try_from(value: Vec<Token>) -> Result<Self, Error> {
-        let mut model = Model::default();
-        let mut iter = value.into_iter().peekable();
+        let mut model = Self::default();
+        let mut objects = InformationObjects::default();
+        let value = Self::instantiate_parameterized_types(value);
+        let mut iter = Self::resolve_value_references(value).into_iter().peekable();
 
         model.name = Self::read_name(&mut iter)?;
-        Self::skip_until_after_text_ignore_ascii_case(&mut iter, "BEGIN")?;
+        model.read_header(&mut iter)?;
 
         while let Some(token) = iter.next() {
             if token.eq_text_ignore_ascii_case("END") {
+                for Definition(_name, asn) in &mut model.definitions {
+                    objects.resolve(&mut asn.r#type, &model.value_references)?;
+                    if model.extensibility_implied {
+                        Self::imply_extensibility(&mut asn.r#type);
+                    }
+                }
                 model.make_names_nice();
                 return Ok(model);
+            } else if token.eq_text_ignore_ascii_case("EXPORTS") {
+                model.exports = Self::read_exports(&mut iter)?;
             } else if token.eq_text_ignore_ascii_case("IMPORTS") {
+                let remaining = iter.clone();
                 Self::read_imports(&mut iter)?
                     .into_iter()
                     .for_each(|i| model.imports.push(i));
-            } else {
-                model.definitions.push(Self::read_definition(
+                let read = remaining.len() - iter.len();
+                model.imports_span = remaining
+                    .take(read)
+                    .next_back()
+                    .map(|last| Span::new(token.span().start(), last.span().end()));
+            } else if token.text().is_some_and(Self::is_value_reference) {
+                model.value_references.push(Self::read_value_reference(
                     &mut iter,
                     token.into_text_or_else(Error::unexpected_token)?,
                 )?);
+            } else {
+                let span = token.span();
+                let name = token.into_text_or_else(Error::unexpected_token)?;
+                if iter.peek().and_then(Token::text).is_some() {
+                    let set = Self::read_object_set(&mut iter)?;
+                    objects.object_sets.insert(name, set);
+                    continue;
+                }
+                Self::read_assignment_operator(&mut iter)?;
+                if iter.peek().is_some_and(|t| t.eq_text("CLASS")) {
+                    let _ = Self::next(&mut iter)?;
+                    let class = Self::read_class(&mut iter)?;
+                    objects.classes.insert(name, class);
+                } else {
+                    let remaining = iter.clone();
+                    let definition = Self::read_definition(&mut iter, name)?;
+                    let read = remaining.len() - iter.len();
+                    Self::find_component_spans(
+                        &definition.0,
+                        &definition.1.r#type,
+                        &mut remaining.take(read),
+                        &mut model.component_spans,
+                    );
+                    model.definitions.push(definition);
+                    model.definition_spans.push(span);
+                }
             }
         }
         Err(Error::unexpected_end_of_stream())
     }
 
-    fn read_name(iter: &mut Peekable<IntoIter<Token>>) -> Result<String, Error> {
-        iter.next()
-            .and_then(|token| token.into_text())
-            .ok_or_else(Error::missing_module_name)
-    }
-
-    fn skip_until_after_text_ignore_ascii_case(
-        iter: &mut Peekable<IntoIter<Token>>,
-        text: &str,
-    ) -> Result<(), Error> {
-        for t in iter {
-            if t.eq_text_ignore_ascii_case(text) {
-                return Ok(());
+    /// Finds the names of the components and variants of the given type within the tokens
+    /// it was read from. Because the names are declared in the order in which the type is
+    /// traversed here, each name is searched after the name before.
+    fn find_component_spans(
+        path: &str,
+        r#type: &Type,
+        tokens: &mut impl Iterator<Item = Token>,
+        spans: &mut Vec<(String, Span)>,
+    ) {
+        fn find(
+            path: String,
+            name: &str,
+            tokens: &mut impl Iterator<Item = Token>,
+            spans: &mut Vec<(String, Span)>,
+        ) {
+            if let Some(token) = tokens.find(|t| t.text() == Some(name)) {
+                spans.push((path, token.span()));
             }
         }
-        Err(Error::unexpected_end_of_stream())
-    }
-
-    fn read_imports(iter: &mut Peekable<IntoIter<Token>>) -> Result<Vec<Import>, Error> {
-        let mut imports = Vec::new();
-        let mut import = Import::default();
-        while let Some(token) = iter.next() {
-            if token.eq_separator(';') {
-                return Ok(imports);
-            } else {
-                let text = token.into_text_or_else(Error::unexpected_token)?;
-                import.what.push(text);
-                let token = Self::next(iter)?;
-                if token.eq_separator(',') {
-                    // ignore separator
-                } else if token.eq_text_ignore_ascii_case("FROM") {
-                    import.from = Self::next(iter)?.into_text_or_else(Error::unexpected_token)?;
-                    imports.push(import);
-                    import = Import::default();
+        match r#type {
+            Type::Optional(inner)
+            | Type::Boxed(inner)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _) => Self::find_component_spans(path, inner, tokens, spans),
+            Type::Sequence(list) | Type::Set(list) => {
+                for field in &list.fields {
+                    let path = format!("{}.{}", path, field.name);
+                    find(path.clone(), &field.name, tokens, spans);
+                    Self::find_component_spans(&path, &field.role.r#type, tokens, spans);
+                }
+            }
+            Type::Choice(choice) => {
+                for variant in choice.variants() {
+                    let path = format!("{}.{}", path, variant.name());
+                    find(path.clone(), variant.name(), tokens, spans);
+                    Self::find_component_spans(&path, variant.r#type(), tokens, spans);
+                }
+            }
+            Type::Enumerated(enumerated) => {
+                for variant in enumerated.variants() {
+                    find(
+                        format!("{}.{}", path, variant.name()),
+                        variant.name(),
+                        tokens,
+                        spans,
+                    );
                 }
             }
+            _ => {}
         }
-        Err(Error::unexpected_end_of_stream())
     }
-    fn read_definition(
-        iter: &mut Peekable<IntoIter<Token>>,
-        name: String,
-    ) -> Result<Definition<Asn>, Error> {
-        Self::next_separator_ignore_case(iter, ':')?;
-        Self::next_separator_ignore_case(iter, ':')?;
-        Self::next_separator_ignore_case(iter, '=')?;
 
-        let (token, tag) = Self::next_with_opt_tag(iter)?;
+    /// Value references start with a lowercase letter, type references with an
+    /// uppercase letter (X.680-201508 12.2, 12.4)
+    fn is_value_reference(name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_lowercase())
+    }
 
-        if token.eq_text_ignore_ascii_case("SEQUENCE") {
-            Ok(Definition(
-                name,
-                Self::read_sequence_or_sequence_of(iter)?.opt_tagged(tag),
-            ))
-        } else if token.eq_text_ignore_ascii_case("ENUMERATED") {
-            Ok(Definition(
-                name,
-                Type::Enumerated(Enumerated::try_from(iter)?).opt_tagged(tag),
-            ))
-        } else if token.eq_text_ignore_ascii_case("CHOICE") {
-            Ok(Definition(
-                name,
-                Type::Choice(Choice::try_from(iter)?).opt_tagged(tag),
-            ))
-        } else if let Some(text) = token.text() {
-            Ok(Definition(
-                name,
-                Self::read_role_given_text(iter, text.to_string())?.opt_tagged(tag),
-            ))
+    /// The value of the given number or - if the given token is a minus sign - of the
+    /// negative number that is read from the following tokens (X.680-201508 19.1)
+    fn integer_value(
+        tokens: &mut Peekable<impl Iterator<Item = Token>>,
+        token: &Token,
+    ) -> Option<i64> {
+        if token.eq_separator('-') {
+            let number = tokens.next_if(Token::is_number)?;
+            format!("-{}", number.number()?).parse().ok()
         } else {
-            Err(Error::unexpected_token(token))
+            token.number()?.parse().ok()
         }
     }
 
-    fn next_with_opt_tag(
-        iter: &mut Peekable<IntoIter<Token>>,
-    ) -> Result<(Token, Option<Tag>), Error> {
-        let token = Self::next(iter)?;
-        if token.eq_separator('[') {
-            let tag = Tag::try_from(&mut *iter)?;
-            Self::next_separator_ignore_case(iter, ']')?;
-            let token = Self::next(iter)?;
-            Ok((token, Some(tag)))
+    /// The tokens of the given integer value, where a negative value is preceded by a
+    /// minus sign
+    fn integer_tokens(span: Span, value: i64) -> Vec<Token> {
+        let number = Token::Number(span, value.unsigned_abs().to_string());
+        if value < 0 {
+            vec![Token::Separator(span, '-'), number]
         } else {
-            Ok((token, None))
+            vec![number]
         }
     }
 
-    fn read_role(iter: &mut Peekable<IntoIter<Token>>) -> Result<Type, Error> {
-        let text = Self::next_text(iter)?;
-        Self::read_role_given_text(iter, text)
-    }
-
-    fn read_role_given_text(
-        iter: &mut Peekable<IntoIter<Token>>,
-        text: String,
-    ) -> Result<Type, Error> {
-        if text.eq_ignore_ascii_case("INTEGER") {
-            match iter.peek() {
-                Some(peeked) if peeked.eq_separator('(') => {
-                    Self::next_separator_ignore_case(iter, '(')?;
-                    let start = Self::next(iter)?;
-                    Self::next_separator_ignore_case(iter, '.')?;
-                    Self::next_separator_ignore_case(iter, '.')?;
-                    let end = Self::next(iter)?;
-                    Self::next_separator_ignore_case(iter, ')')?;
-                    if start.eq_text("0") && end.eq_text_ignore_ascii_case("MAX") {
-                        Ok(Type::Integer(None))
-                    } else {
-                        Ok(Type::Integer(Some(Range(
-                            start
-                                .text()
-                                .and_then(|t| t.parse::<i64>().ok())
-                                .ok_or_else(|| Error::invalid_range_value(start))?,
-                            end.text()
-                                .and_then(|t| t.parse::<i64>().ok())
-                                .ok_or_else(|| Error::invalid_range_value(end))?,
-                        ))))
+    /// Replaces references to integer values within constraints by the referenced value,
+    /// so that ranges and sizes can refer to values assigned anywhere in the module
+    fn resolve_value_references(tokens: Vec<Token>) -> Vec<Token> {
+        let values = Self::find_integer_value_assignments(&tokens);
+        let mut depth = 0_usize;
+        tokens
+            .into_iter()
+            .flat_map(|token| {
+                if token.eq_separator('(') {
+                    depth += 1;
+                } else if token.eq_separator(')') {
+                    depth = depth.saturating_sub(1);
+                } else if let Some(value) = token.text().and_then(|t| values.get(t)) {
+                    if depth > 0 {
+                        return Self::integer_tokens(token.span(), *value);
                     }
                 }
-                _ => Ok(Type::Integer(None)),
-            }
-        } else if text.eq_ignore_ascii_case("BOOLEAN") {
-            Ok(Type::Boolean)
-        } else if text.eq_ignore_ascii_case("UTF8String") {
-            Ok(Type::UTF8String)
-        } else if text.eq_ignore_ascii_case("IA5String") {
-            Ok(Type::IA5String)
-        } else if text.eq_ignore_ascii_case("OCTET") {
-            let token = Self::next(iter)?;
-            if token.text().map_or(false, |t| t.eq("STRING")) {
-                Ok(Type::OctetString)
-            } else {
-                Err(Error::unexpected_token(token))
+                vec![token]
+            })
+            .collect()
+    }
+
+    /// Finds assignments like `maxNumberOfPoints INTEGER ::= 32`, where the type might
+    /// also be constrained, like `INTEGER (0..255)`
+    fn find_integer_value_assignments(tokens: &[Token]) -> HashMap<String, i64> {
+        let mut values = HashMap::new();
+        for index in Self::assignment_indices(tokens) {
+            let start = Self::assignment_start(tokens, index);
+            let name = tokens[start]
+                .text()
+                .filter(|name| Self::is_value_reference(name));
+            let integer = tokens[start + 1].eq_text_ignore_ascii_case("INTEGER");
+            let value = tokens.get(index + 1).and_then(|token| {
+                let mut following = tokens[index + 2..].iter().cloned().peekable();
+                Self::integer_value(&mut following, token)
+            });
+            if let (Some(name), true, Some(value)) = (name, integer, value) {
+                values.insert(name.to_string(), value);
             }
-        } else if text.eq_ignore_ascii_case("CHOICE") {
-            Ok(Type::Choice(Choice::try_from(iter)?))
-        } else if text.eq_ignore_ascii_case("ENUMERATED") {
-            Ok(Type::Enumerated(Enumerated::try_from(iter)?))
-        } else if text.eq_ignore_ascii_case("SEQUENCE") {
-            Ok(Self::read_sequence_or_sequence_of(iter)?)
-        } else {
-            Ok(Type::TypeReference(text))
         }
+        values
     }
 
-    fn read_sequence_or_sequence_of(iter: &mut Peekable<IntoIter<Token>>) -> Result<Type, Error> {
-        let token = Self::next(iter)?;
-
-        if token.eq_text_ignore_ascii_case("OF") {
-            Ok(Type::SequenceOf(Box::new(Self::read_role(iter)?)))
-        } else if token.eq_separator('{') {
-            let mut fields = Vec::new();
-
-            loop {
-                let (field, continues) = Self::read_field(iter)?;
-                fields.push(field);
-                if !continues {
-                    break;
-                }
-            }
+    /// The indices of all `::=` in the given tokens
+    fn assignment_indices(tokens: &[Token]) -> Vec<usize> {
+        (1..tokens.len())
+            .filter(|index| tokens[*index].eq_symbol(Symbol::Assignment))
+            .collect()
+    }
 
-            Ok(Type::Sequence(fields))
+    /// The index of the first token of the assignment, whose `::=` is at the given index.
+    /// This is either the name of a (parameterized) type assignment or the name of a
+    /// value assignment, which is followed by its (possibly constrained) type
+    fn assignment_start(tokens: &[Token], index: usize) -> usize {
+        let start = index - 1;
+        if tokens[start].eq_separator('}') {
+            return Self::matching_open_index(tokens, start).saturating_sub(1);
+        }
+        let start = if tokens[start].eq_separator(')') {
+            Self::matching_open_index(tokens, start).saturating_sub(1)
         } else {
-            Err(Error::unexpected_token(token))
+            start
+        };
+        // the value of the previous assignment must not be mistaken as name
+        let value_assignment = start >= 2
+            && tokens[start - 1]
+                .text()
+                .is_some_and(Self::is_value_reference)
+            && !tokens[start - 2].eq_symbol(Symbol::Assignment);
+        // an object set like `MessageTypes MESSAGE-ID-AND-TYPE ::= { ... }`
+        let object_set_assignment = start >= 1
+            && tokens[start - 1].text().is_some()
+            && tokens.get(index + 1).is_some_and(|t| t.eq_separator('{'));
+        if value_assignment || object_set_assignment {
+            start - 1
+        } else {
+            start
         }
     }
 
-    fn read_field(iter: &mut Peekable<IntoIter<Token>>) -> Result<(Field<Asn>, bool), Error> {
-        let name = Self::next_text(iter)?;
-        let (token, tag) = Self::next_with_opt_tag(iter)?;
-        let mut field = Field {
-            name,
-            role: Self::read_role_given_text(iter, token.into_text_or_else(Error::no_text)?)?
-                .opt_tagged(tag),
+    /// The index of the opening bracket for the closing bracket at the given index
+    fn matching_open_index(tokens: &[Token], index: usize) -> usize {
+        let (open, close) = match tokens[index].separator() {
+            Some('}') => ('{', '}'),
+            Some(']') => ('[', ']'),
+            _ => ('(', ')'),
         };
-        let mut token = Self::next(iter)?;
-        if let Some(_optional_flag) = token.text().map(|s| s.eq_ignore_ascii_case("OPTIONAL")) {
-            field.role.optional();
-            token = Self::next(iter)?;
+        let mut depth = 0_usize;
+        for index in (0..=index).rev() {
+            if tokens[index].eq_separator(close) {
+                depth += 1;
+            } else if tokens[index].eq_separator(open) {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return index;
+                }
+            }
         }
+        0
+    }
 
-        let (continues, ends) = token
-            .separator()
-            .map_or((false, false), |s| (s == ',', s == '}'));
+    /// Removes all parameterized type assignments like
+    /// `Container {ElementType, INTEGER : maxSize} ::= SEQUENCE (SIZE(1..maxSize)) OF ElementType`
+    /// and replaces each use, like `Container {Point, 4}`, by a reference to a new type
+    /// assignment for this instantiation, like `Container-Point-4`
+    fn instantiate_parameterized_types(tokens: Vec<Token>) -> Vec<Token> {
+        let begin = tokens
+            .iter()
+            .position(|t| t.eq_text_ignore_ascii_case("BEGIN"))
+            .unwrap_or_default();
+        let end = tokens
+            .iter()
+            .rposition(|t| t.eq_text_ignore_ascii_case("END"))
+            .unwrap_or(tokens.len());
+        let assignments = Self::assignment_indices(&tokens)
+            .into_iter()
+            .filter(|index| *index > begin && *index < end)
+            .map(|index| (Self::assignment_start(&tokens, index), index))
+            .collect::<Vec<_>>();
+
+        let mut templates = HashMap::new();
+        let mut removed = Vec::new();
+        for (n, (start, index)) in assignments.iter().enumerate() {
+            if !tokens[start + 1].eq_separator('{') {
+                continue;
+            }
+            let body_end = assignments.get(n + 1).map_or(end, |(start, _)| *start);
+            if let Some(name) = tokens[*start].text() {
+                let parameters = Self::split_arguments(&tokens[start + 2..index - 1])
+                    .into_iter()
+                    // `INTEGER : maxSize` is referenced by `maxSize` only
+                    .filter_map(|p| p.iter().rev().find_map(Token::text).map(str::to_string))
+                    .collect();
+                let body = tokens[index + 1..body_end].to_vec();
+                templates.insert(name.to_string(), ParameterizedType { parameters, body });
+                removed.push(*start..body_end);
+            }
+        }
 
-        if continues || ends {
-            Ok((field, continues))
-        } else {
-            Err(Error::unexpected_token(token))
+        if templates.is_empty() {
+            return tokens;
         }
-    }
 
-    fn next(iter: &mut Peekable<IntoIter<Token>>) -> Result<Token, Error> {
-        iter.next().ok_or_else(Error::unexpected_end_of_stream)
-    }
+        let mut tokens = tokens
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| !removed.iter().any(|range| range.contains(index)))
+            .map(|(_, token)| token)
+            .collect::<Vec<_>>();
+        let tail = tokens.split_off(end - removed.iter().map(|r| r.len()).sum::<usize>());
+
+        let values = Self::find_integer_value_assignments(&tokens);
+        let mut instances = Vec::new();
+        let mut result = Self::replace_instantiations(tokens, &templates, &values, &mut instances);
+
+        // instantiations might instantiate further parameterized types themselves
+        let mut index = 0;
+        while index < instances.len() {
+            let (name, body) = {
+                let (name, body) = &instances[index];
+                (name.clone(), body.clone())
+            };
+            let span = body.first().map(Token::span).unwrap_or_default();
+            let body = Self::replace_instantiations(body, &templates, &values, &mut instances);
+            result.push(Token::Text(span, name));
+            result.push(Token::Symbol(span, Symbol::Assignment));
+            result.extend(body);
+            index += 1;
+        }
 
-    fn next_text(iter: &mut Peekable<IntoIter<Token>>) -> Result<String, Error> {
-        Self::next(iter)?.into_text_or_else(Error::no_text)
+        result.extend(tail);
+        result
     }
 
-    fn next_separator_ignore_case(
-        iter: &mut Peekable<IntoIter<Token>>,
-        separator: char,
-    ) -> Result<(), Error> {
-        let token = Self::next(iter)?;
-        if let Some(token) = token.separator() {
-            if token.eq_ignore_ascii_case(&separator) {
-                return Ok(());
+    /// Replaces each instantiation of a parameterized type by a reference to the
+    /// instantiated type and adds yet unknown instantiations to the given instances.
+    /// Arguments referring to integer values are replaced by these values beforehand, so
+    /// that `Container {Point, maxNumberOfPoints}` and `Container {Point, 4}` are the same
+    /// instantiation if `maxNumberOfPoints` is 4.
+    fn replace_instantiations(
+        tokens: Vec<Token>,
+        templates: &HashMap<String, ParameterizedType>,
+        values: &HashMap<String, i64>,
+        instances: &mut Vec<(String, Vec<Token>)>,
+    ) -> Vec<Token> {
+        let mut result = Vec::with_capacity(tokens.len());
+        let mut index = 0;
+        while index < tokens.len() {
+            let template = tokens[index].text().and_then(|t| templates.get(t));
+            let open = index + 1;
+            match template {
+                Some(template) if open < tokens.len() && tokens[open].eq_separator('{') => {
+                    let close = Self::matching_close_index(&tokens, open);
+                    let arguments = Self::split_arguments(&tokens[open + 1..close])
+                        .into_iter()
+                        .map(|argument| match argument.as_slice() {
+                            [token] => match token.text().and_then(|t| values.get(t)) {
+                                Some(value) => Self::integer_tokens(token.span(), *value),
+                                None => argument,
+                            },
+                            _ => argument,
+                        })
+                        .collect::<Vec<_>>();
+                    // a minus sign is spelled out, as the name must not contain `--`
+                    let name = tokens[index]
+                        .text()
+                        .into_iter()
+                        .chain(arguments.iter().flatten().filter_map(|token| {
+                            token
+                                .text()
+                                .or_else(|| token.number())
+                                .or_else(|| Some("Minus").filter(|_| token.eq_separator('-')))
+                        }))
+                        .collect::<Vec<_>>()
+                        .join("-");
+                    if !instances.iter().any(|(known, _)| known == &name) {
+                        instances.push((name.clone(), template.instantiate(&arguments)));
+                    }
+                    result.push(Token::Text(tokens[index].span(), name));
+                    index = close + 1;
+                }
+                _ => {
+                    result.push(tokens[index].clone());
+                    index += 1;
+                }
             }
         }
-        Err(Error::expected_separator(separator, token))
+        result
     }
 
-    pub fn make_names_nice(&mut self) {
-        Self::make_name_nice(&mut self.name);
-        for import in &mut self.imports {
-            Self::make_name_nice(&mut import.from);
+    /// The index of the closing bracket for the opening bracket at the given index
+    fn matching_close_index(tokens: &[Token], index: usize) -> usize {
+        let (open, close) = match tokens[index].separator() {
+            Some('{') => ('{', '}'),
+            Some('[') => ('[', ']'),
+            _ => ('(', ')'),
+        };
+        let mut depth = 0_usize;
+        for (index, token) in tokens.iter().enumerate().skip(index) {
+            if token.eq_separator(open) {
+                depth += 1;
+            } else if token.eq_separator(close) {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return index;
+                }
+            }
         }
+        tokens.len().saturating_sub(1)
     }
 
-    fn make_name_nice(name: &mut String) {
-        const TO_REMOVE_AT_END: &[&str] = &["_Module", "Module"];
-        for to_remove in TO_REMOVE_AT_END.iter() {
-            if name.ends_with(to_remove) {
-                let new_len = name.len() - to_remove.len();
-                name.truncate(new_len);
+    /// Splits the comma separated (actual) parameters of a parameter list
+    fn split_arguments(tokens: &[Token]) -> Vec<Vec<Token>> {
+        let mut arguments = vec![Vec::new()];
+        let mut depth = 0_usize;
+        for token in tokens {
+            if token.eq_separator('{') || token.eq_separator('(') {
+                depth += 1;
+            } else if token.eq_separator('}') || token.eq_separator(')') {
+                depth = depth.saturating_sub(1);
+            } else if depth == 0 && token.eq_separator(',') {
+                arguments.push(Vec::new());
+                continue;
+            }
+            if let Some(argument) = arguments.last_mut() {
+                argument.push(token.clone());
             }
         }
+        arguments.retain(|argument| !argument.is_empty());
+        arguments
     }
 
-    pub fn to_rust(&self) -> Model<rust::Rust> {
-        Model::convert_asn_to_rust(self)
+    /// Reads a value assignment like `maxNumberOfPoints INTEGER ::= 32`
+    fn read_value_reference(
+        iter: &mut Peekable<IntoIter<Token>>,
+        name: String,
+    ) -> Result<ValueReference, Error> {
+        let role = Self::read_role(iter)?;
+        Self::read_assignment_operator(iter)?;
+        let value = Self::read_literal_value(iter)?;
+        Ok(ValueReference { name, role, value })
     }
-}
-#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
-pub struct Import {
-    pub what: Vec<String>,
-    pub from: String,
-}
 
-#[derive(Debug, Clone, Copy, PartialOrd, PartialEq)]
-pub struct Range<T>(pub T, pub T);
+    fn read_name(iter: &mut Peekable<IntoIter<Token>>) -> Result<String, Error> {
+        iter.next()
+            .and_then(|token| token.into_text())
+            .ok_or_else(Error::missing_module_name)
+    }
 
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
-pub struct Definition<T>(pub String, pub T);
+    /// Reads the module header after the module name up to and including `BEGIN`, like
+    /// `{ iso(1) 0 } DEFINITIONS AUTOMATIC TAGS EXTENSIBILITY IMPLIED ::= BEGIN`
+    /// (X.680-201508 13.1)
+    fn read_header(&mut self, iter: &mut Peekable<IntoIter<Token>>) -> Result<(), Error> {
+        if iter.peek().is_some_and(|t| t.eq_separator('{')) {
+            self.oid = Some(ObjectIdentifierValue::try_from(&mut *iter)?);
+            // the optional IRI value, like "/ITU-T/Recommendation/X/680"
+            if iter.peek().and_then(Token::cstring).is_some() {
+                let _ = Self::next(iter)?;
+            }
+        }
 
-impl<T> Definition<T> {
-    #[cfg(test)]
-    pub fn new<I: ToString>(name: I, value: T) -> Self {
-        Definition(name.to_string(), value)
-    }
-}
+        let token = Self::next(iter)?;
+        if !token.eq_text_ignore_ascii_case("DEFINITIONS") {
+            return Err(Error::expected_text("DEFINITIONS".into(), token));
+        }
 
-impl TagProperty for Definition<Asn> {
-    fn tag(&self) -> Option<Tag> {
-        self.1.tag()
-    }
+        loop {
+            let token = Self::next(iter)?;
+            if token.eq_symbol(Symbol::Assignment) {
+                break;
+            }
+            let second = Self::next(iter)?;
+            if second.eq_text_ignore_ascii_case("TAGS") {
+                self.tag_default = if token.eq_text_ignore_ascii_case("EXPLICIT") {
+                    TagDefault::Explicit
+                } else if token.eq_text_ignore_ascii_case("IMPLICIT") {
+                    TagDefault::Implicit
+                } else if token.eq_text_ignore_ascii_case("AUTOMATIC") {
+                    TagDefault::Automatic
+                } else {
+                    return Err(Error::unexpected_token(token));
+                };
+            } else if second.eq_text_ignore_ascii_case("IMPLIED")
+                && token.eq_text_ignore_ascii_case("EXTENSIBILITY")
+            {
+                self.extensibility_implied = true;
+            } else if !second.eq_text_ignore_ascii_case("INSTRUCTIONS") || !token.is_text() {
+                // anything but the encoding reference default, like `XER INSTRUCTIONS`
+                return Err(Error::unexpected_token(second));
+            }
+        }
 
-    fn set_tag(&mut self, tag: Tag) {
-        self.1.set_tag(tag)
+        let token = Self::next(iter)?;
+        if token.eq_text_ignore_ascii_case("BEGIN") {
+            Ok(())
+        } else {
+            Err(Error::expected_text("BEGIN".into(), token))
+        }
     }
 
-    fn reset_tag(&mut self) {
-        self.1.reset_tag()
+    /// Adds the extension marker to the end of every `SEQUENCE`, `SET`, `CHOICE` and
+    /// `ENUMERATED` without one, as implied by `EXTENSIBILITY IMPLIED` (X.680-201508 13.4)
+    fn imply_extensibility(r#type: &mut Type) {
+        match r#type {
+            Type::Optional(inner)
+            | Type::Boxed(inner)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _) => Self::imply_extensibility(inner),
+            Type::Sequence(list) | Type::Set(list) => {
+                if list.extension_after.is_none() && !list.fields.is_empty() {
+                    list.extension_after = Some(list.fields.len() - 1);
+                }
+                for field in &mut list.fields {
+                    Self::imply_extensibility(&mut field.role.r#type);
+                }
+            }
+            Type::Choice(choice) => {
+                if choice.extension_after.is_none() && !choice.variants.is_empty() {
+                    choice.extension_after = Some(choice.variants.len() - 1);
+                }
+                for variant in &mut choice.variants {
+                    Self::imply_extensibility(&mut variant.r#type);
+                }
+            }
+            Type::Enumerated(enumerated)
+                if enumerated.extension_after.is_none() && !enumerated.variants.is_empty() =>
+            {
+                enumerated.extension_after = Some(enumerated.variants.len() - 1);
+            }
+            _ => {}
+        }
     }
-}
-
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
-pub struct Field<T> {
-    pub name: String,
-    pub role: T,
-}
 
-impl<T: TagProperty> TagProperty for Field<T> {
-    fn tag(&self) -> Option<Tag> {
-        self.role.tag()
+    /// Sorts the components of the extension root of each `SET` by their tags, which is the
+    /// order in which they are encoded (X.691-201508 21.1). If the components are tagged
+    /// automatically, they are tagged in the order of their definition, which therefore
+    /// already is the canonical order (X.680-201508 25.3). The model itself keeps the order
+    /// of the definition, this is only applied to the types that are encoded. The tags of
+    /// imported types are looked up with the given function.
+    pub(crate) fn sort_set_components(
+        &self,
+        r#type: &mut Type,
+        imported_tag: &dyn Fn(&str) -> Option<Tag>,
+    ) {
+        match r#type {
+            Type::Optional(inner)
+            | Type::Boxed(inner)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _) => self.sort_set_components(inner, imported_tag),
+            Type::Sequence(list) => list
+                .fields
+                .iter_mut()
+                .for_each(|field| self.sort_set_components(&mut field.role.r#type, imported_tag)),
+            Type::Set(list) => {
+                list.fields.iter_mut().for_each(|field| {
+                    self.sort_set_components(&mut field.role.r#type, imported_tag)
+                });
+                let root = list
+                    .extension_after
+                    .map_or(list.fields.len(), |index| index + 1);
+                if !self.is_automatically_tagged(list.fields[..root].iter().map(|f| f.role.tag)) {
+                    list.fields[..root].sort_by_key(|field| {
+                        self.canonical_tag(&field.role, imported_tag, &mut Vec::new())
+                            .map_or((u8::MAX, 0), Tag::canonical_order)
+                    });
+                }
+            }
+            Type::Choice(choice) => choice
+                .variants
+                .iter_mut()
+                .for_each(|variant| self.sort_set_components(&mut variant.r#type, imported_tag)),
+            _ => {}
+        }
     }
 
-    fn set_tag(&mut self, tag: Tag) {
-        self.role.set_tag(tag)
+    /// Replaces the tags of the components of each `SEQUENCE`, `SET` and `CHOICE` with their
+    /// effective tags, so that each tagged component carries its resolved tagging mode and
+    /// automatically tagged components carry their context-specific tag. Components with the
+    /// universal tag of their type stay untagged.
+    pub(crate) fn resolve_component_tags(&self, r#type: &mut Type) {
+        match r#type {
+            Type::Optional(inner)
+            | Type::Boxed(inner)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _) => self.resolve_component_tags(inner),
+            Type::Sequence(list) | Type::Set(list) => {
+                let tags = self.effective_field_tags(list);
+                for (field, tag) in list.fields.iter_mut().zip(tags) {
+                    if let Some(EffectiveTag {
+                        tag,
+                        mode: Some(mode),
+                    }) = tag
+                    {
+                        field.role.tag = Some(tag);
+                        field.role.tagging = Some(mode);
+                    }
+                    self.resolve_component_tags(&mut field.role.r#type);
+                }
+            }
+            Type::Choice(choice) => {
+                let tags = self.effective_variant_tags(choice);
+                for (variant, tag) in choice.variants.iter_mut().zip(tags) {
+                    if let Some(EffectiveTag {
+                        tag,
+                        mode: Some(mode),
+                    }) = tag
+                    {
+                        variant.tag = Some(tag);
+                        variant.tagging = Some(mode);
+                    }
+                    self.resolve_component_tags(&mut variant.r#type);
+                }
+            }
+            _ => {}
+        }
     }
 
-    fn reset_tag(&mut self) {
-        self.role.reset_tag()
+    /// The tag of the given type, which is either its own tag or the universal tag of its
+    /// type. An untagged `CHOICE` has the smallest tag of its variants (X.680-201508 8.6).
+    /// `None` for types whose tag cannot be determined, like references to imported types
+    /// that the given function does not know.
+    pub(crate) fn canonical_tag(
+        &self,
+        asn: &Asn,
+        imported_tag: &dyn Fn(&str) -> Option<Tag>,
+        visited: &mut Vec<String>,
+    ) -> Option<Tag> {
+        asn.tag
+            .or_else(|| self.canonical_type_tag(&asn.r#type, imported_tag, visited))
     }
-}
 
-#[derive(Debug, Clone, Copy, PartialOrd, PartialEq)]
-pub enum Tag {
-    Universal(usize),
-    Application(usize),
-    Private(usize),
-    ContextSpecific(usize),
-}
+    fn canonical_type_tag(
+        &self,
+        r#type: &Type,
+        imported_tag: &dyn Fn(&str) -> Option<Tag>,
+        visited: &mut Vec<String>,
+    ) -> Option<Tag> {
+        Some(Tag::Universal(match r#type {
+            Type::Boolean => 1,
+            Type::Integer(_) => 2,
+            Type::BitString(_) => 3,
+            Type::OctetString(_) => 4,
+            Type::Null => 5,
+            Type::ObjectIdentifier => 6,
+            Type::Real => 9,
+            Type::Enumerated(_) => 10,
+            Type::UTF8String(_) => 12,
+            Type::UTCTime => 23,
+            Type::GeneralizedTime => 24,
+            Type::Sequence(_) | Type::SequenceOf(..) => 16,
+            Type::Set(_) | Type::SetOf(..) => 17,
+            Type::CharacterString(string) => match string.charset {
+                Charset::Numeric => 18,
+                Charset::Printable => 19,
+                Charset::Ia5 => 22,
+                Charset::Visible => 26,
+                Charset::Universal => 28,
+                Charset::Bmp => 30,
+            },
+            Type::Optional(inner) | Type::Boxed(inner) => {
+                return self.canonical_type_tag(inner, imported_tag, visited)
+            }
+            Type::Choice(choice) => {
+                return choice
+                    .variants()
+                    .filter_map(|variant| {
+                        variant.tag.or_else(|| {
+                            self.canonical_type_tag(&variant.r#type, imported_tag, visited)
+                        })
+                    })
+                    .min_by_key(|tag| tag.canonical_order())
+            }
+            Type::TypeReference(name) => {
+                if visited.contains(name) {
+                    return None;
+                }
+                visited.push(name.clone());
+                return match self.definitions.iter().find(|Definition(n, _)| n == name) {
+                    Some(Definition(_, asn)) => self.canonical_tag(asn, imported_tag, visited),
+                    None => imported_tag(name),
+                };
+            }
+            Type::OpenType(_) => return None,
+        }))
+    }
 
-impl TryFrom<&mut Peekable<IntoIter<Token>>> for Tag {
-    type Error = Error;
+    /// The effective tag of the definition, which is its own tag or the tag of its type
+    pub fn effective_tag(&self, asn: &Asn) -> Option<EffectiveTag> {
+        self.effective_tag_of(asn.tag, asn.tagging, &asn.r#type)
+    }
 
-    fn try_from(iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Self::Error> {
-        macro_rules! parse_tag_number {
-            () => {
-                parse_tag_number!(Model::<Asn>::next(iter)?)
-            };
-            ($tag:expr) => {{
-                let tag = $tag;
-                tag.text()
-                    .and_then(|t| t.parse().ok())
-                    .ok_or_else(|| Error::invalid_tag(tag))?
-            }};
+    /// The effective tags of the fields of a `SEQUENCE` or `SET`, in the order of the fields.
+    /// If the fields are tagged automatically, each field is tagged with the context-specific
+    /// tag of its index (X.680-201508 25.3).
+    pub fn effective_field_tags(&self, list: &ComponentTypeList) -> Vec<Option<EffectiveTag>> {
+        let root = list
+            .extension_after
+            .map_or(list.fields.len(), |index| index + 1);
+        if self.is_automatically_tagged(list.fields[..root].iter().map(|f| f.role.tag)) {
+            list.fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| Some(self.automatic_tag(index, &field.role.r#type)))
+                .collect()
+        } else {
+            list.fields
+                .iter()
+                .map(|field| self.effective_tag(&field.role))
+                .collect()
         }
+    }
 
-        let number_or_class = Model::<Asn>::next(iter)?;
-
-        if let Some(text) = number_or_class.text() {
-            Ok(match text {
-                "UNIVERSAL" => Tag::Universal(parse_tag_number!()),
-                "APPLICATION" => Tag::Application(parse_tag_number!()),
-                "PRIVATE" => Tag::Private(parse_tag_number!()),
-                _context_specific => Tag::ContextSpecific(parse_tag_number!(number_or_class)),
-            })
+    /// The effective tags of the variants of a `CHOICE`, in the order of the variants. If the
+    /// variants are tagged automatically, each variant is tagged with the context-specific
+    /// tag of its index (X.680-201508 29.3).
+    pub fn effective_variant_tags(&self, choice: &Choice) -> Vec<Option<EffectiveTag>> {
+        let root = choice
+            .extension_after
+            .map_or(choice.variants.len(), |index| index + 1);
+        if self.is_automatically_tagged(choice.variants[..root].iter().map(|v| v.tag)) {
+            choice
+                .variants()
+                .enumerate()
+                .map(|(index, variant)| Some(self.automatic_tag(index, &variant.r#type)))
+                .collect()
         } else {
-            Err(Error::no_text(number_or_class))
+            choice
+                .variants()
+                .map(|variant| self.effective_tag_of(variant.tag, variant.tagging, &variant.r#type))
+                .collect()
         }
     }
-}
-
-pub trait TagProperty {
-    fn tag(&self) -> Option<Tag>;
 
-    fn set_tag(&mut self, tag: Tag);
+    /// The components are tagged automatically in a module with `AUTOMATIC TAGS`, if none
+    /// of the components of the extension root is tagged (X.680-201508 25.3 and 29.3)
+    fn is_automatically_tagged(&self, mut root_tags: impl Iterator<Item = Option<Tag>>) -> bool {
+        self.tag_default == TagDefault::Automatic && root_tags.all(|tag| tag.is_none())
+    }
 
-    fn reset_tag(&mut self);
+    fn automatic_tag(&self, index: usize, r#type: &Type) -> EffectiveTag {
+        EffectiveTag {
+            tag: Tag::ContextSpecific(index),
+            mode: Some(self.tagging_mode(None, r#type)),
+        }
+    }
 
-    fn with_tag_opt(self, tag: Option<Tag>) -> Self
-    where
-        Self: Sized,
-    {
+    fn effective_tag_of(
+        &self,
+        tag: Option<Tag>,
+        tagging: Option<TaggingMode>,
+        r#type: &Type,
+    ) -> Option<EffectiveTag> {
         if let Some(tag) = tag {
-            self.with_tag(tag)
+            Some(EffectiveTag {
+                tag,
+                mode: Some(self.tagging_mode(tagging, r#type)),
+            })
+        } else if self.is_untagged_choice_or_open_type(r#type, &mut Vec::new()) {
+            // the tag is the tag of the chosen variant or of the actual type
+            None
         } else {
-            self.without_tag()
+            self.canonical_type_tag(r#type, &|_| None, &mut Vec::new())
+                .map(|tag| EffectiveTag { tag, mode: None })
         }
     }
 
-    fn with_tag(mut self, tag: Tag) -> Self
-    where
-        Self: Sized,
-    {
-        self.set_tag(tag);
-        self
+    /// The tagging mode of a tag of the given type, which is the given mode or the default
+    /// of the module. A tag of an untagged `CHOICE` or of an open type is always explicit,
+    /// because their own tags cannot be omitted (X.680-201508 31.2.7).
+    fn tagging_mode(&self, tagging: Option<TaggingMode>, r#type: &Type) -> TaggingMode {
+        if self.is_untagged_choice_or_open_type(r#type, &mut Vec::new()) {
+            TaggingMode::Explicit
+        } else {
+            tagging.unwrap_or(match self.tag_default {
+                TagDefault::Explicit => TaggingMode::Explicit,
+                TagDefault::Implicit | TagDefault::Automatic => TaggingMode::Implicit,
+            })
+        }
     }
 
-    fn without_tag(mut self) -> Self
-    where
-        Self: Sized,
-    {
-        self.reset_tag();
-        self
+    fn is_untagged_choice_or_open_type(&self, r#type: &Type, visited: &mut Vec<String>) -> bool {
+        match r#type {
+            Type::Optional(inner) | Type::Boxed(inner) => {
+                self.is_untagged_choice_or_open_type(inner, visited)
+            }
+            Type::Choice(_) | Type::OpenType(_) => true,
+            Type::TypeReference(name) if !visited.contains(name) => {
+                visited.push(name.clone());
+                self.definitions
+                    .iter()
+                    .find(|Definition(n, _)| n == name)
+                    .is_some_and(|Definition(_, asn)| {
+                        asn.tag.is_none()
+                            && self.is_untagged_choice_or_open_type(&asn.r#type, visited)
+                    })
+            }
+            _ => false,
+        }
     }
-}
-
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
-pub struct Asn {
-    pub tag: Option<Tag>,
-    pub r#type: Type,
-}
 
-impl Asn {
-    pub fn optional(&mut self) {
-        let optional = self.r#type.clone().optional();
-        self.r#type = optional;
+    /// Reads the symbols after `EXPORTS` up to and including the closing `;`, where
+    /// `EXPORTS ALL;` exports all symbols and `EXPORTS;` exports none (X.680-201508 13.13)
+    fn read_exports(iter: &mut Peekable<IntoIter<Token>>) -> Result<Exports, Error> {
+        if iter.peek().is_some_and(|t| t.eq_text("ALL")) {
+            let _ = Self::next(iter)?;
+            Self::next_separator_ignore_case(iter, ';')?;
+            return Ok(Exports::All);
+        }
+        let mut symbols = Vec::new();
+        loop {
+            let token = Self::next(iter)?;
+            if token.eq_separator(';') && symbols.is_empty() {
+                return Ok(Exports::Symbols(symbols));
+            }
+            symbols.push(Self::read_symbol(iter, token)?);
+            let token = Self::next(iter)?;
+            if token.eq_separator(';') {
+                return Ok(Exports::Symbols(symbols));
+            } else if !token.eq_separator(',') {
+                return Err(Error::expected_separator(',', token));
+            }
+        }
     }
 
-    pub const fn opt_tagged(tag: Option<Tag>, r#type: Type) -> Self {
-        Self { tag, r#type }
-    }
+    /// Reads the symbols imported from other modules after `IMPORTS` up to and including
+    /// the closing `;`, like `Foo, Bar{} FROM Module { iso(1) 0 } WITH SUCCESSORS`
+    /// (X.680-201508 13.16)
+    fn read_imports(iter: &mut Peekable<IntoIter<Token>>) -> Result<Vec<Import>, Error> {
+        let mut imports = Vec::new();
+        let mut import = Import::default();
+        let mut next_symbol = None;
+        loop {
+            let symbol = match next_symbol.take() {
+                Some(symbol) => symbol,
+                None => {
+                    let token = Self::next(iter)?;
+                    if token.eq_separator(';') && import.what.is_empty() {
+                        return Ok(imports);
+                    }
+                    Self::read_symbol(iter, token)?
+                }
+            };
+            import.what.push(symbol);
 
-    pub const fn untagged(r#type: Type) -> Self {
-        Self::opt_tagged(None, r#type)
-    }
+            let token = Self::next(iter)?;
+            if token.eq_separator(',') {
+                continue;
+            } else if !token.eq_text_ignore_ascii_case("FROM") {
+                return Err(Error::expected_text("FROM".into(), token));
+            }
 
-    pub const fn tagged(tag: Tag, r#type: Type) -> Self {
-        Self::opt_tagged(Some(tag), r#type)
-    }
+            import.from = Self::next_text(iter)?;
+            if iter.peek().is_some_and(|t| t.eq_separator('{')) {
+                import.oid = Some(ObjectIdentifierValue::try_from(&mut *iter)?);
+            } else if iter
+                .peek()
+                .and_then(Token::text)
+                .is_some_and(Self::is_value_reference)
+            {
+                // either the value of the object identifier of the module or the first symbol
+                // imported from the next module, which is followed by `,` or `FROM`
+                let value = Self::next_text(iter)?;
+                if iter
+                    .peek()
+                    .is_some_and(|t| t.eq_separator(',') || t.eq_text_ignore_ascii_case("FROM"))
+                {
+                    next_symbol = Some(value);
+                } else {
+                    import.oid = Some(ObjectIdentifierValue(vec![ObjIdComponent::name(value)]));
+                }
+            }
 
-    pub fn extensible_after_index(&self) -> Option<usize> {
-        match &self.r#type {
-            Type::Choice(c) => c.extension_after_index(),
-            Type::Enumerated(e) => e.extension_after_index(),
-            _ => None,
+            if next_symbol.is_none() && iter.peek().is_some_and(|t| t.eq_text("WITH")) {
+                let _ = Self::next(iter)?;
+                let token = Self::next(iter)?;
+                import.selection = Some(if token.eq_text("SUCCESSORS") {
+                    SelectionOption::Successors
+                } else if token.eq_text("DESCENDANTS") {
+                    SelectionOption::Descendants
+                } else {
+                    return Err(Error::expected_text("SUCCESSORS".into(), token));
+                });
+            }
+
+            imports.push(std::mem::take(&mut import));
         }
     }
 
-    pub fn extensible_after_variant(&self) -> Option<&str> {
-        match &self.r#type {
-            Type::Choice(c) => c
-                .extension_after_index()
-                .and_then(|index| c.variants().nth(index).map(ChoiceVariant::name)),
-            Type::Enumerated(e) => e
-                .extension_after_index()
-                .and_then(|index| e.variants().nth(index).map(EnumeratedVariant::name)),
-            _ => None,
+    /// Reads the name of an exported or imported symbol, which is followed by `{}` if it is
+    /// a parameterized type
+    fn read_symbol(iter: &mut Peekable<IntoIter<Token>>, token: Token) -> Result<String, Error> {
+        let symbol = token.into_text_or_else(Error::no_text)?;
+        if iter.peek().is_some_and(|t| t.eq_separator('{')) {
+            let _ = Self::read_braced_tokens(iter)?;
         }
+        Ok(symbol)
     }
-}
-
-impl From<Type> for Asn {
-    fn from(r#type: Type) -> Self {
-        Self::untagged(r#type)
+    fn read_assignment_operator(iter: &mut Peekable<IntoIter<Token>>) -> Result<(), Error> {
+        Self::next_symbol(iter, Symbol::Assignment)
     }
-}
 
-impl TagProperty for Asn {
-    fn tag(&self) -> Option<Tag> {
-        self.tag
-    }
+    /// Reads the fields and the optional defined syntax of an information object class after
+    /// the `CLASS` keyword, like `{ &id INTEGER UNIQUE, &Type } WITH SYNTAX { &Type IDENTIFIED BY &id }`
+    fn read_class(iter: &mut Peekable<IntoIter<Token>>) -> Result<InformationObjectClass, Error> {
+        Self::next_separator_ignore_case(iter, '{')?;
+        let mut class = InformationObjectClass::default();
 
-    fn set_tag(&mut self, tag: Tag) {
-        self.tag = Some(tag)
-    }
+        loop {
+            let name = Self::next_text(iter)?;
+            let is_type_field = name
+                .strip_prefix('&')
+                .is_some_and(|field| field.starts_with(|c: char| c.is_ascii_uppercase()));
+            let r#type = if is_type_field {
+                None
+            } else {
+                Some(Self::read_role(iter)?)
+            };
+            let mut token = Self::next(iter)?;
+            let unique = token.eq_text_ignore_ascii_case("UNIQUE");
+            if unique {
+                token = Self::next(iter)?;
+            }
+            if token.eq_text_ignore_ascii_case("OPTIONAL") {
+                token = Self::next(iter)?;
+            } else if token.eq_text_ignore_ascii_case("DEFAULT") {
+                let _ = Self::next(iter)?;
+                token = Self::next(iter)?;
+            }
+            class.fields.push(ClassField {
+                name,
+                r#type,
+                unique,
+            });
+            loop_ctrl_separator!(token);
+        }
 
-    fn reset_tag(&mut self) {
-        self.tag = None
+        if iter.peek().is_some_and(|t| t.eq_text("WITH")) {
+            let _ = Self::next(iter)?;
+            let token = Self::next(iter)?;
+            if !token.eq_text("SYNTAX") {
+                return Err(Error::expected_text("SYNTAX".into(), token));
+            }
+            let syntax = Self::read_braced_tokens(iter)?;
+            class.syntax = Some(Self::split_version_brackets(syntax));
+        }
+        Ok(class)
     }
-}
-
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
-pub enum Type {
-    Boolean,
-    Integer(Option<Range<i64>>),
-    UTF8String,
-    IA5String,
-    OctetString,
 
-    Optional(Box<Type>),
+    /// Splits `[[` and `]]` into single brackets, as these are nested optional groups within
+    /// a defined syntax and no version brackets
+    fn split_version_brackets(tokens: Vec<Token>) -> Vec<Token> {
+        let mut result = Vec::with_capacity(tokens.len());
+        for token in tokens {
+            let bracket = match token.symbol() {
+                Some(Symbol::LeftVersionBrackets) => '[',
+                Some(Symbol::RightVersionBrackets) => ']',
+                _ => {
+                    result.push(token);
+                    continue;
+                }
+            };
+            let span = token.span();
+            result.push(Token::Separator(span, bracket));
+            result.push(Token::Separator(span, bracket));
+        }
+        result
+    }
 
-    SequenceOf(Box<Type>),
-    Sequence(Vec<Field<Asn>>),
-    Enumerated(Enumerated),
-    Choice(Choice),
-    TypeReference(String),
-}
+    /// Reads the class and the objects of an object set after its name, like
+    /// `MESSAGE-ID-AND-TYPE ::= { {Foo IDENTIFIED BY 1} | {Bar IDENTIFIED BY 2}, ... }`
+    fn read_object_set(iter: &mut Peekable<IntoIter<Token>>) -> Result<ObjectSet, Error> {
+        let class = Self::next_text(iter)?;
+        Self::read_assignment_operator(iter)?;
+        Self::next_separator_ignore_case(iter, '{')?;
+        let mut set = ObjectSet {
+            class,
+            objects: Vec::new(),
+        };
 
-impl Type {
-    pub fn optional(self) -> Self {
-        Self::Optional(Box::new(self))
+        loop {
+            let token = match iter.peek() {
+                Some(token) if token.eq_separator('{') => {
+                    set.objects.push(Self::read_braced_tokens(iter)?);
+                    Self::next(iter)?
+                }
+                Some(token) if token.eq_symbol(Symbol::Ellipsis) => {
+                    // the extension marker, unknown objects are decoded as such anyway
+                    Self::next_symbol(iter, Symbol::Ellipsis)?;
+                    Self::next(iter)?
+                }
+                _ => Self::next(iter)?,
+            };
+            if token.eq_separator('|') || token.eq_separator(',') {
+                continue;
+            } else if token.eq_separator('}') {
+                break;
+            } else {
+                return Err(Error::unexpected_token(token));
+            }
+        }
+        Ok(set)
     }
 
-    pub const fn opt_tagged(self, tag: Option<Tag>) -> Asn {
-        Asn::opt_tagged(tag, self)
+    /// Reads the tokens within the next pair of curly brackets, excluding the brackets
+    fn read_braced_tokens(iter: &mut Peekable<IntoIter<Token>>) -> Result<Vec<Token>, Error> {
+        Self::next_separator_ignore_case(iter, '{')?;
+        let mut tokens = Vec::new();
+        let mut depth = 0_usize;
+        loop {
+            let token = Self::next(iter)?;
+            if token.eq_separator('{') {
+                depth += 1;
+            } else if token.eq_separator('}') {
+                if depth == 0 {
+                    return Ok(tokens);
+                }
+                depth -= 1;
+            }
+            tokens.push(token);
+        }
     }
 
-    pub const fn tagged(self, tag: Tag) -> Asn {
-        Asn::tagged(tag, self)
-    }
+    fn read_definition(
+        iter: &mut Peekable<IntoIter<Token>>,
+        name: String,
+    ) -> Result<Definition<Asn>, Error> {
+        let (token, tag, tagging) = Self::next_with_opt_tag(iter)?;
 
-    pub const fn untagged(self) -> Asn {
-        Asn::untagged(self)
+        let r#type = if token.eq_text_ignore_ascii_case("SEQUENCE") {
+            Self::read_sequence_or_sequence_of(iter)?
+        } else if token.eq_text_ignore_ascii_case("SET") {
+            Self::read_set_or_set_of(iter)?
+        } else if token.eq_text_ignore_ascii_case("ENUMERATED") {
+            Type::Enumerated(Enumerated::try_from(iter)?)
+        } else if token.eq_text_ignore_ascii_case("CHOICE") {
+            Type::Choice(Choice::try_from(iter)?)
+        } else if let Some(text) = token.text() {
+            Self::read_role_given_text(iter, text.to_string())?
+        } else {
+            return Err(Error::unexpected_token(token));
+        };
+        Ok(Definition(
+            name,
+            r#type.opt_tagged(tag).with_tagging(tagging),
+        ))
     }
-}
-
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
-pub struct Choice {
-    variants: Vec<ChoiceVariant>,
-    extension_after: Option<usize>,
-}
 
-impl From<Vec<ChoiceVariant>> for Choice {
-    fn from(variants: Vec<ChoiceVariant>) -> Self {
-        Self {
-            variants,
-            extension_after: None,
+    /// Reads the next token, which is preceded by the optional tag and its tagging mode
+    fn next_with_opt_tag(
+        iter: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<(Token, Option<Tag>, Option<TaggingMode>), Error> {
+        let token = Self::next(iter)?;
+        if token.eq_separator('[') {
+            let tag = Tag::try_from(&mut *iter)?;
+            Self::next_separator_ignore_case(iter, ']')?;
+            let token = Self::next(iter)?;
+            if token.eq_text_ignore_ascii_case("EXPLICIT") {
+                Ok((Self::next(iter)?, Some(tag), Some(TaggingMode::Explicit)))
+            } else if token.eq_text_ignore_ascii_case("IMPLICIT") {
+                Ok((Self::next(iter)?, Some(tag), Some(TaggingMode::Implicit)))
+            } else {
+                Ok((token, Some(tag), None))
+            }
+        } else {
+            Ok((token, None, None))
         }
     }
-}
 
-impl Choice {
-    pub fn from_variants(variants: impl Iterator<Item = ChoiceVariant>) -> Self {
-        Self {
-            variants: variants.collect(),
-            extension_after: None,
-        }
+    fn read_role(iter: &mut Peekable<IntoIter<Token>>) -> Result<Type, Error> {
+        let text = Self::next_text(iter)?;
+        Self::read_role_given_text(iter, text)
     }
 
-    pub const fn with_extension_after(mut self, extension_after: Option<usize>) -> Self {
-        self.extension_after = extension_after;
-        self
+    fn read_role_given_text(
+        iter: &mut Peekable<IntoIter<Token>>,
+        text: String,
+    ) -> Result<Type, Error> {
+        if text.eq_ignore_ascii_case("INTEGER") {
+            Ok(Type::Integer(Integer::try_from(iter)?))
+        } else if text.eq_ignore_ascii_case("BOOLEAN") {
+            Ok(Type::Boolean)
+        } else if text.eq_ignore_ascii_case("UTF8String") {
+            Ok(Type::UTF8String(Size::try_from(iter)?))
+        } else if let Some(charset) = Charset::find(&text) {
+            Ok(Type::CharacterString(CharacterString::try_from((
+                charset, iter,
+            ))?))
+        } else if text.eq_ignore_ascii_case("OCTET") {
+            let token = Self::next(iter)?;
+            if token.text().map_or(false, |t| t.eq("STRING")) {
+                Ok(Type::OctetString(Size::try_from(iter)?))
+            } else {
+                Err(Error::unexpected_token(token))
+            }
+        } else if text.eq_ignore_ascii_case("BIT") {
+            let token = Self::next(iter)?;
+            if token.text().is_some_and(|t| t.eq("STRING")) {
+                Ok(Type::BitString(BitString::try_from(iter)?))
+            } else {
+                Err(Error::unexpected_token(token))
+            }
+        } else if text.eq_ignore_ascii_case("NULL") {
+            Ok(Type::Null)
+        } else if text.eq_ignore_ascii_case("REAL") {
+            Ok(Type::Real)
+        } else if text.eq_ignore_ascii_case("OBJECT") {
+            let token = Self::next(iter)?;
+            if token.text().is_some_and(|t| t.eq("IDENTIFIER")) {
+                Ok(Type::ObjectIdentifier)
+            } else {
+                Err(Error::unexpected_token(token))
+            }
+        } else if text.eq_ignore_ascii_case("GeneralizedTime") {
+            Ok(Type::GeneralizedTime)
+        } else if text.eq_ignore_ascii_case("UTCTime") {
+            Ok(Type::UTCTime)
+        } else if text.eq_ignore_ascii_case("CHOICE") {
+            Ok(Type::Choice(Choice::try_from(iter)?))
+        } else if text.eq_ignore_ascii_case("ENUMERATED") {
+            Ok(Type::Enumerated(Enumerated::try_from(iter)?))
+        } else if text.eq_ignore_ascii_case("SEQUENCE") {
+            Ok(Self::read_sequence_or_sequence_of(iter)?)
+        } else if text.eq_ignore_ascii_case("SET") {
+            Ok(Self::read_set_or_set_of(iter)?)
+        } else if iter.peek().is_some_and(|t| t.eq_separator('.')) {
+            Self::read_class_field_reference(iter, text)
+        } else {
+            Ok(Type::TypeReference(text))
+        }
     }
 
-    pub fn len(&self) -> usize {
-        self.variants.len()
-    }
+    /// Reads the reference to a field of an information object class after the name of the
+    /// class, like `.&id ({MessageTypes})` or `.&Type ({MessageTypes}{@messageId})`. The latter
+    /// is an open type, whose actual type is determined by the value of the referenced field.
+    /// The former is - until resolved - represented by a reference like `MESSAGE-ID-AND-TYPE.&id`.
+    fn read_class_field_reference(
+        iter: &mut Peekable<IntoIter<Token>>,
+        class: String,
+    ) -> Result<Type, Error> {
+        Self::next_separator_ignore_case(iter, '.')?;
+        let token = Self::next(iter)?;
+        let field = match token.text() {
+            Some(field) if field.starts_with('&') => field.to_string(),
+            _ => return Err(Error::unexpected_token(token)),
+        };
 
-    pub fn is_empty(&self) -> bool {
-        self.variants.is_empty()
-    }
+        // the table constraint, like ({MessageTypes}{@messageId})
+        let mut constraint = Vec::new();
+        if iter.peek().is_some_and(|t| t.eq_separator('(')) {
+            Self::next_separator_ignore_case(iter, '(')?;
+            while iter.peek().is_some_and(|t| t.eq_separator('{')) {
+                let reference = Self::read_braced_tokens(iter)?
+                    .iter()
+                    .filter_map(Token::text)
+                    .collect::<String>();
+                constraint.push(reference);
+            }
+            Self::next_separator_ignore_case(iter, ')')?;
+        }
 
-    pub fn variants(&self) -> impl Iterator<Item = &ChoiceVariant> {
-        self.variants.iter()
+        let is_type_field = field[1..].starts_with(|c: char| c.is_ascii_uppercase());
+        match (is_type_field, constraint.first(), constraint.get(1)) {
+            (false, _, _) => Ok(Type::TypeReference(format!("{}.{}", class, field))),
+            (true, Some(object_set), Some(key_field)) => Ok(Type::OpenType(OpenType {
+                class,
+                field,
+                object_set: object_set.clone(),
+                key_field: key_field.trim_start_matches('@').to_string(),
+                variants: Vec::new(),
+            })),
+            (true, _, _) => Err(Error::unexpected_token(token)),
+        }
     }
 
-    pub fn is_extensible(&self) -> bool {
-        self.extension_after.is_some()
+    fn read_sequence_or_sequence_of(iter: &mut Peekable<IntoIter<Token>>) -> Result<Type, Error> {
+        Self::read_component_type_list_or_of(iter, Type::Sequence, Type::SequenceOf)
     }
 
-    pub fn extension_after_index(&self) -> Option<usize> {
-        self.extension_after
+    fn read_set_or_set_of(iter: &mut Peekable<IntoIter<Token>>) -> Result<Type, Error> {
+        Self::read_component_type_list_or_of(iter, Type::Set, Type::SetOf)
     }
-}
-
-impl TryFrom<&mut Peekable<IntoIter<Token>>> for Choice {
-    type Error = Error;
 
-    fn try_from(iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Self::Error> {
-        Model::<Asn>::next_separator_ignore_case(iter, '{')?;
-        let mut choice = Choice {
-            variants: Vec::new(),
-            extension_after: None,
+    /// Reads either the fields of a `SEQUENCE` or `SET` or the (optionally `SIZE` constrained)
+    /// type of the elements of a `SEQUENCE OF` or `SET OF`
+    fn read_component_type_list_or_of(
+        iter: &mut Peekable<IntoIter<Token>>,
+        list: fn(ComponentTypeList) -> Type,
+        of: fn(Box<Type>, Size) -> Type,
+    ) -> Result<Type, Error> {
+        let size = match iter.peek() {
+            Some(token) if token.eq_separator('(') => Some(Size::try_from(&mut *iter)?),
+            Some(token) if token.eq_text_ignore_ascii_case("SIZE") => {
+                let _ = Self::next(iter)?;
+                Some(Size::read_size(iter)?)
+            }
+            _ => None,
         };
+        let token = Self::next(iter)?;
+
+        if token.eq_text_ignore_ascii_case("OF") {
+            Ok(of(
+                Box::new(Self::read_role(iter)?),
+                size.unwrap_or_default(),
+            ))
+        } else if size.is_some() {
+            Err(Error::expected_text("OF".into(), token))
+        } else if token.eq_separator('{') {
+            Ok(list(Self::read_component_type_list(iter)?))
+        } else {
+            Err(Error::unexpected_token(token))
+        }
+    }
+
+    /// Reads the fields of a `SEQUENCE` or `SET` after its opening `{` up to and including its closing `}`
+    fn read_component_type_list(
+        iter: &mut Peekable<IntoIter<Token>>,
+    ) -> Result<ComponentTypeList, Error> {
+        let mut list = ComponentTypeList::default();
 
         loop {
-            let name_or_extension_marker = Model::<Asn>::next(iter)?;
-            if name_or_extension_marker.eq_separator('.') {
-                Model::<Asn>::next_separator_ignore_case(iter, '.')?;
-                Model::<Asn>::next_separator_ignore_case(iter, '.')?;
+            let token = match iter.peek() {
+                Some(token) if token.eq_symbol(Symbol::Ellipsis) => {
+                    let marker = Self::next(iter)?;
 
-                if choice.variants.is_empty() || choice.extension_after.is_some() {
-                    return Err(Error::invalid_position_for_extension_marker(
-                        name_or_extension_marker,
-                    ));
-                } else {
-                    choice.extension_after = Some(choice.variants.len() - 1);
+                    if list.fields.is_empty() || list.extension_after.is_some() {
+                        return Err(Error::invalid_position_for_extension_marker(marker));
+                    } else {
+                        list.extension_after = Some(list.fields.len() - 1);
+                    }
+                    Self::next(iter)?
                 }
-            } else {
-                let name = name_or_extension_marker.into_text_or_else(Error::no_text)?;
-                let (token, tag) = Model::<Asn>::next_with_opt_tag(iter)?;
-                let r#type = Model::<Asn>::read_role_given_text(
-                    iter,
-                    token.into_text_or_else(Error::no_text)?,
-                )?;
-                choice.variants.push(ChoiceVariant { name, tag, r#type });
-            }
+                Some(token) if token.eq_symbol(Symbol::LeftVersionBrackets) => {
+                    let group = Self::next(iter)?;
 
-            let end_or_continuation_marker = Model::<Asn>::next(iter)?;
+                    if list.extension_after.is_none() {
+                        return Err(Error::invalid_position_for_extension_marker(group));
+                    }
 
-            if end_or_continuation_marker.eq_separator(',') {
-                continue;
-            } else if end_or_continuation_marker.eq_separator('}') {
-                break;
-            } else {
-                return Err(Error::unexpected_token(end_or_continuation_marker));
-            }
-        }
+                    // the optional version number of the extension addition group
+                    if iter.peek().is_some_and(Token::is_number) {
+                        Self::next(iter)?;
+                        Self::next_separator_ignore_case(iter, ':')?;
+                    }
 
-        Ok(choice)
-    }
-}
+                    let first = list.fields.len();
+                    loop {
+                        let (field, token) = Self::read_field(iter)?;
+                        list.fields.push(field);
+                        if token.eq_symbol(Symbol::RightVersionBrackets) {
+                            break;
+                        } else if !token.eq_separator(',') {
+                            return Err(Error::unexpected_token(token));
+                        }
+                    }
 
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
-pub struct ChoiceVariant {
-    pub name: String,
-    pub tag: Option<Tag>,
-    pub r#type: Type,
-}
+                    list.extension_groups
+                        .push(Range::inclusive(first, list.fields.len() - 1));
+                    Self::next(iter)?
+                }
+                _ => {
+                    let (field, token) = Self::read_field(iter)?;
+                    list.fields.push(field);
+                    token
+                }
+            };
 
-impl ChoiceVariant {
-    #[cfg(test)]
-    pub fn name_type<I: ToString>(name: I, r#type: Type) -> Self {
-        ChoiceVariant {
-            name: name.to_string(),
-            tag: None,
-            r#type,
+            loop_ctrl_separator!(token);
         }
-    }
 
-    pub fn name(&self) -> &str {
-        &self.name
+        Ok(list)
     }
 
-    pub fn r#type(&self) -> &Type {
-        &self.r#type
+    /// Reads the field and returns it together with the token that follows it
+    fn read_field(iter: &mut Peekable<IntoIter<Token>>) -> Result<(Field<Asn>, Token), Error> {
+        let name = Self::next_text(iter)?;
+        let (token, tag, tagging) = Self::next_with_opt_tag(iter)?;
+        let mut field = Field {
+            name,
+            role: Self::read_role_given_text(iter, token.into_text_or_else(Error::no_text)?)?
+                .opt_tagged(tag)
+                .with_tagging(tagging),
+            default: None,
+        };
+        let mut token = Self::next(iter)?;
+        if token.eq_text_ignore_ascii_case("OPTIONAL") {
+            field.role.optional();
+            token = Self::next(iter)?;
+        } else if token.eq_text_ignore_ascii_case("DEFAULT") {
+            field.default = Some(Self::read_literal_value(iter)?);
+            token = Self::next(iter)?;
+        }
+        Ok((field, token))
     }
-}
 
-impl TagProperty for ChoiceVariant {
-    fn tag(&self) -> Option<Tag> {
-        self.tag
+    /// Reads the value of a value assignment or of a `DEFAULT` clause, which might be a
+    /// negative number
+    fn read_literal_value(iter: &mut Peekable<IntoIter<Token>>) -> Result<LiteralValue, Error> {
+        let token = Self::next(iter)?;
+        match Self::integer_value(iter, &token) {
+            Some(value) => Ok(LiteralValue::Integer(value)),
+            None => LiteralValue::try_from(token),
+        }
     }
 
-    fn set_tag(&mut self, tag: Tag) {
-        self.tag = Some(tag)
+    fn next(iter: &mut Peekable<IntoIter<Token>>) -> Result<Token, Error> {
+        iter.next().ok_or_else(Error::unexpected_end_of_stream)
     }
 
-    fn reset_tag(&mut self) {
-        self.tag = None
+    fn next_text(iter: &mut Peekable<IntoIter<Token>>) -> Result<String, Error> {
+        Self::next(iter)?.into_text_or_else(Error::no_text)
     }
-}
-
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
-pub struct Enumerated {
-    variants: Vec<EnumeratedVariant>,
-    extension_after: Option<usize>,
-}
 
-impl From<Vec<EnumeratedVariant>> for Enumerated {
-    fn from(variants: Vec<EnumeratedVariant>) -> Self {
-        Self {
-            variants,
-            extension_after: None,
+    fn next_separator_ignore_case(
+        iter: &mut Peekable<IntoIter<Token>>,
+        separator: char,
+    ) -> Result<(), Error> {
+        let token = Self::next(iter)?;
+        if let Some(token) = token.separator() {
+            if token.eq_ignore_ascii_case(&separator) {
+                return Ok(());
+            }
         }
+        Err(Error::expected_separator(separator, token))
     }
-}
 
-impl Enumerated {
-    pub fn from_variants(variants: impl Into<Vec<EnumeratedVariant>>) -> Self {
-        Self {
-            variants: variants.into(),
-            extension_after: None,
+    fn next_symbol(iter: &mut Peekable<IntoIter<Token>>, symbol: Symbol) -> Result<(), Error> {
+        let token = Self::next(iter)?;
+        if token.eq_symbol(symbol) {
+            Ok(())
+        } else {
+            Err(Error::expected_symbol(symbol, token))
         }
     }
 
-    pub fn from_names<I: ToString>(variants: impl Iterator<Item = I>) -> Self {
-        Self {
-            variants: variants.map(EnumeratedVariant::from_name).collect(),
-            extension_after: None,
+    /// Reads the optional `, ...` that marks a constraint as extensible
+    fn read_extension_marker(iter: &mut Peekable<IntoIter<Token>>) -> Result<bool, Error> {
+        let extensible = iter.peek().is_some_and(|token| token.eq_separator(','));
+        if extensible {
+            Self::next_separator_ignore_case(iter, ',')?;
+            Self::next_symbol(iter, Symbol::Ellipsis)?;
         }
+        Ok(extensible)
     }
 
-    pub const fn with_extension_after(mut self, extension_after: Option<usize>) -> Self {
-        self.extension_after = extension_after;
-        self
+    pub fn make_names_nice(&mut self) {
+        Self::make_name_nice(&mut self.name);
+        for import in &mut self.imports {
+            Self::make_name_nice(&mut import.from);
+        }
     }
 
-    pub fn len(&self) -> usize {
-        self.variants.len()
+    fn make_name_nice(name: &mut String) {
+        const TO_REMOVE_AT_END: &[&str] = &["_Module", "Module"];
+        for to_remove in TO_REMOVE_AT_END.iter() {
+            if name.ends_with(to_remove) {
+                let new_len = name.len() - to_remove.len();
+                name.truncate(new_len);
+            }
+        }
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.variants.is_empty()
-    }
-
-    pub fn variants(&self) -> impl Iterator<Item = &EnumeratedVariant> {
-        self.variants.iter()
+    pub fn to_rust(&self) -> Model<rust::Rust> {
+        Model::convert_asn_to_rust(self)
     }
+}
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct Import {
+    pub what: Vec<String>,
+    pub from: String,
+    /// The object identifier of the module the symbols are imported from
+    pub oid: Option<ObjectIdentifierValue>,
+    pub selection: Option<SelectionOption>,
+}
 
-    pub fn is_extensible(&self) -> bool {
-        self.extension_after.is_some()
-    }
+/// Whether an import also accepts later versions of the referenced module (X.680-202102 13.16)
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq)]
+pub enum SelectionOption {
+    /// `WITH SUCCESSORS`
+    Successors,
+    /// `WITH DESCENDANTS`
+    Descendants,
+}
 
-    pub fn extension_after_index(&self) -> Option<usize> {
-        self.extension_after
-    }
+/// The symbols a module makes available to other modules (X.680-201508 13.13)
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub enum Exports {
+    /// `EXPORTS ALL;` or no `EXPORTS` at all
+    #[default]
+    All,
+    Symbols(Vec<String>),
 }
 
-impl TryFrom<&mut Peekable<IntoIter<Token>>> for Enumerated {
+/// An object identifier value like `{ iso(1) standard(0) 8824 }` (X.680-201508 32.3)
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct ObjectIdentifierValue(pub Vec<ObjIdComponent>);
+
+impl TryFrom<&mut Peekable<IntoIter<Token>>> for ObjectIdentifierValue {
     type Error = Error;
 
+    /// Reads the components within the curly brackets
     fn try_from(iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Self::Error> {
         Model::<Asn>::next_separator_ignore_case(iter, '{')?;
-        let mut enumerated = Self {
-            variants: Vec::new(),
-            extension_after: None,
-        };
-
+        let mut components = Vec::new();
         loop {
             let token = Model::<Asn>::next(iter)?;
-
-            if token.eq_separator('.') {
-                Model::<Asn>::next_separator_ignore_case(iter, '.')?;
-                Model::<Asn>::next_separator_ignore_case(iter, '.')?;
-                if enumerated.variants.is_empty() || enumerated.extension_after.is_some() {
-                    return Err(Error::invalid_position_for_extension_marker(token));
-                } else {
-                    enumerated.extension_after = Some(enumerated.variants.len() - 1);
-                    loop_ctrl_separator!(Model::<Asn>::next(iter)?);
-                }
+            if token.eq_separator('}') {
+                return Ok(ObjectIdentifierValue(components));
+            } else if token.is_number() {
+                components.push(ObjIdComponent {
+                    name: None,
+                    number: Some(ObjIdComponent::parse_number(token)?),
+                });
             } else {
-                let variant_name = token.into_text_or_else(Error::no_text)?;
-                let token = Model::<Asn>::next(iter)?;
-
-                if token.eq_separator(',') || token.eq_separator('}') {
-                    enumerated
-                        .variants
-                        .push(EnumeratedVariant::from_name(variant_name));
-                    loop_ctrl_separator!(token);
-                } else if token.eq_separator('(') {
-                    let token = Model::<Asn>::next(iter)?;
-                    let number = token
-                        .text()
-                        .and_then(|t| t.parse::<usize>().ok())
-                        .ok_or_else(|| Error::invalid_number_for_enum_variant(token))?;
+                let name = token.into_text_or_else(Error::no_text)?;
+                let number = if iter.peek().is_some_and(|t| t.eq_separator('(')) {
+                    Model::<Asn>::next_separator_ignore_case(iter, '(')?;
+                    let number = ObjIdComponent::parse_number(Model::<Asn>::next(iter)?)?;
                     Model::<Asn>::next_separator_ignore_case(iter, ')')?;
-                    enumerated
-                        .variants
-                        .push(EnumeratedVariant::from_name_number(variant_name, number));
-                    loop_ctrl_separator!(Model::<Asn>::next(iter)?);
+                    Some(number)
                 } else {
-                    loop_ctrl_separator!(token);
-                }
+                    None
+                };
+                components.push(ObjIdComponent {
+                    name: Some(name),
+                    number,
+                });
             }
         }
-
-        Ok(enumerated)
     }
 }
 
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
-pub struct EnumeratedVariant {
-    pub(crate) name: String,
-    pub(crate) number: Option<usize>,
-}
-
-#[cfg(test)]
-impl<S: ToString> From<S> for EnumeratedVariant {
-    fn from(s: S) -> Self {
-        EnumeratedVariant::from_name(s)
-    }
+/// A component of an object identifier value, which is given by its name - which might
+/// also refer to a value assigned elsewhere - its number or both, like `iso(1)`
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct ObjIdComponent {
+    pub name: Option<String>,
+    pub number: Option<u64>,
 }
 
-impl EnumeratedVariant {
-    pub fn from_name<I: ToString>(name: I) -> Self {
+impl ObjIdComponent {
+    pub fn name(name: impl Into<String>) -> Self {
         Self {
-            name: name.to_string(),
+            name: Some(name.into()),
             number: None,
         }
     }
 
-    pub fn from_name_number<I: ToString>(name: I, number: usize) -> Self {
+    pub const fn number(number: u64) -> Self {
         Self {
-            name: name.to_string(),
+            name: None,
             number: Some(number),
         }
     }
 
-    pub const fn with_number(self, number: usize) -> Self {
-        self.with_number_opt(Some(number))
+    fn parse_number(token: Token) -> Result<u64, Error> {
+        token
+            .number()
+            .and_then(|t| t.parse().ok())
+            .ok_or_else(|| Error::unexpected_token(token))
     }
+}
 
-    pub const fn with_number_opt(mut self, number: Option<usize>) -> Self {
-        self.number = number;
-        self
+#[derive(Debug, Default, Clone, Copy, PartialOrd, PartialEq)]
+/// An inclusive range from its minimum to its maximum and whether it is extensible (`...`)
+pub struct Range<T>(pub T, pub T, pub bool);
+
+impl<T> Range<T> {
+    pub const fn inclusive(min: T, max: T) -> Self {
+        Self(min, max, false)
     }
 
-    pub fn name(&self) -> &str {
-        &self.name
+    pub fn with_extensible(self, extensible: bool) -> Self {
+        Self(self.0, self.1, extensible)
     }
 
-    pub fn number(&self) -> Option<usize> {
-        self.number
+    pub const fn extensible(&self) -> bool {
+        self.2
+    }
+
+    /// Turns both bounds into present bounds of an otherwise open range
+    pub fn wrap_opt(self) -> Range<Option<T>> {
+        Range(Some(self.0), Some(self.1), self.2)
     }
 }
 
-#[cfg(test)]
-pub(crate) mod tests {
-    use super::*;
-    use crate::parser::{Location, Tokenizer};
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct Definition<T>(pub String, pub T);
 
-    pub(crate) const SIMPLE_INTEGER_STRUCT_ASN: &str = r"
-        SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
-        BEGIN
+/// A value assignment, like `maxNumberOfPoints INTEGER ::= 32`
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct ValueReference {
+    pub name: String,
+    pub role: Type,
+    pub value: LiteralValue,
+}
 
-        Simple ::= SEQUENCE {
-            small INTEGER(0..255),
-            bigger INTEGER(0..65535),
-            negative INTEGER(-1..255),
-            unlimited INTEGER(0..MAX) OPTIONAL
-        }
-        END
-        ";
+impl<T> Definition<T> {
+    #[cfg(test)]
+    pub fn new<I: ToString>(name: I, value: T) -> Self {
+        Definition(name.to_string(), value)
+    }
+}
 
-    #[test]
-    fn test_simple_asn_sequence_represented_correctly_as_asn_model() {
-        let model = Model::try_from(Tokenizer::default().parse(SIMPLE_INTEGER_STRUCT_ASN)).unwrap();
+impl TagProperty for Definition<Asn> {
+    fn tag(&self) -> Option<Tag> {
+        self.1.tag()
+    }
 
-        assert_eq!("SimpleSchema", model.name);
-        assert_eq!(true, model.imports.is_empty());
-        assert_eq!(1, model.definitions.len());
-        assert_eq!(
-            Definition(
-                "Simple".into(),
-                Type::Sequence(vec![
-                    Field {
-                        name: "small".into(),
-                        role: Type::Integer(Some(Range(0, 255))).untagged(),
-                    },
-                    Field {
-                        name: "bigger".into(),
-                        role: Type::Integer(Some(Range(0, 65535))).untagged(),
-                    },
-                    Field {
-                        name: "negative".into(),
-                        role: Type::Integer(Some(Range(-1, 255))).untagged(),
-                    },
-                    Field {
-                        name: "unlimited".into(),
-                        role: Type::Integer(None).optional().untagged(),
-                    }
-                ])
-                .untagged()
-            ),
-            model.definitions[0]
-        );
+    fn set_tag(&mut self, tag: Tag) {
+        self.1.set_tag(tag)
     }
 
-    pub(crate) const INLINE_ASN_WITH_ENUM: &str = r"
-        SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
-        BEGIN
+    fn reset_tag(&mut self) {
+        self.1.reset_tag()
+    }
+}
 
-        Woah ::= SEQUENCE {
-            decision ENUMERATED {
-                ABORT,
-                RETURN,
-                CONFIRM,
-                MAYDAY,
-                THE_CAKE_IS_A_LIE
-            } OPTIONAL
-        }
-        END
-    ";
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct Field<T> {
+    pub name: String,
+    pub role: T,
+    /// The value of the `DEFAULT` clause, if any
+    pub default: Option<LiteralValue>,
+}
 
-    #[test]
-    fn test_inline_asn_enumerated_represented_correctly_as_asn_model() {
-        let model = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_ENUM)).unwrap();
+impl<T: TagProperty> TagProperty for Field<T> {
+    fn tag(&self) -> Option<Tag> {
+        self.role.tag()
+    }
 
-        assert_eq!("SimpleSchema", model.name);
-        assert_eq!(true, model.imports.is_empty());
-        assert_eq!(1, model.definitions.len());
-        assert_eq!(
-            Definition(
-                "Woah".into(),
-                Type::Sequence(vec![Field {
-                    name: "decision".into(),
-                    role: Type::Enumerated(Enumerated::from_names(
-                        ["ABORT", "RETURN", "CONFIRM", "MAYDAY", "THE_CAKE_IS_A_LIE",].iter()
-                    ))
-                    .optional()
-                    .untagged(),
-                }])
-                .untagged(),
-            ),
-            model.definitions[0]
-        );
+    fn set_tag(&mut self, tag: Tag) {
+        self.role.set_tag(tag)
     }
 
-    pub(crate) const INLINE_ASN_WITH_SEQUENCE_OF: &str = r"
-        SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
-        BEGIN
+    fn reset_tag(&mut self) {
+        self.role.reset_tag()
+    }
+}
 
-        Ones ::= SEQUENCE OF INTEGER(0..1)
+/// The value of a `DEFAULT` clause
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum LiteralValue {
+    Boolean(bool),
+    Integer(i64),
+    String(String),
+    /// The identifier of a variant of an `ENUMERATED` type
+    EnumeratedVariant(String),
+}
 
-        NestedOnes ::= SEQUENCE OF SEQUENCE OF INTEGER(0..1)
+impl TryFrom<Token> for LiteralValue {
+    type Error = Error;
 
-        Woah ::= SEQUENCE {
-            also-ones SEQUENCE OF INTEGER(0..1),
-            nesteds SEQUENCE OF SEQUENCE OF INTEGER(0..1),
-            optionals SEQUENCE OF SEQUENCE OF INTEGER(0..MAX) OPTIONAL
+    fn try_from(token: Token) -> Result<Self, Self::Error> {
+        if token.eq_text_ignore_ascii_case("TRUE") {
+            Ok(LiteralValue::Boolean(true))
+        } else if token.eq_text_ignore_ascii_case("FALSE") {
+            Ok(LiteralValue::Boolean(false))
+        } else if let Some(characters) = CharacterString::characters_of(&token) {
+            Ok(LiteralValue::String(characters.into_iter().collect()))
+        } else if let Some(number) = token.number().and_then(|n| n.parse::<i64>().ok()) {
+            Ok(LiteralValue::Integer(number))
+        } else if let Some(text) = token.text() {
+            if text.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                Ok(LiteralValue::EnumeratedVariant(text.to_string()))
+            } else {
+                Err(Error::invalid_default_value(token))
+            }
+        } else {
+            Err(Error::invalid_default_value(token))
         }
-        END
-    ";
-
-    #[test]
-    fn test_inline_asn_sequence_of_represented_correctly_as_asn_model() {
-        let model =
-            Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_SEQUENCE_OF)).unwrap();
-
-        assert_eq!("SimpleSchema", model.name);
-        assert_eq!(true, model.imports.is_empty());
-        assert_eq!(3, model.definitions.len());
-        assert_eq!(
-            Definition(
-                "Ones".into(),
-                Type::SequenceOf(Box::new(Type::Integer(Some(Range(0, 1))))).untagged()
-            ),
-            model.definitions[0]
-        );
-        assert_eq!(
-            Definition(
-                "NestedOnes".into(),
-                Type::SequenceOf(Box::new(Type::SequenceOf(Box::new(Type::Integer(Some(
-                    Range(0, 1)
-                ))))))
-                .untagged(),
-            ),
-            model.definitions[1]
-        );
-        assert_eq!(
-            Definition(
-                "Woah".into(),
-                Type::Sequence(vec![
-                    Field {
-                        name: "also-ones".into(),
-                        role: Type::SequenceOf(Box::new(Type::Integer(Some(Range(0, 1)))))
-                            .untagged(),
-                    },
-                    Field {
-                        name: "nesteds".into(),
-                        role: Type::SequenceOf(Box::new(Type::SequenceOf(Box::new(
-                            Type::Integer(Some(Range(0, 1)))
-                        ))))
-                        .untagged(),
-                    },
-                    Field {
-                        name: "optionals".into(),
-                        role: Type::SequenceOf(Box::new(Type::SequenceOf(Box::new(
-                            Type::Integer(None)
-                        ))))
-                        .optional()
-                        .untagged(),
-                    },
-                ])
-                .untagged()
-            ),
-            model.definitions[2]
-        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq)]
+pub enum Tag {
+    Universal(usize),
+    Application(usize),
+    Private(usize),
+    ContextSpecific(usize),
+}
+
+impl Tag {
+    /// The key of the canonical order of tags, which orders by class - universal, application,
+    /// context-specific, private - and then by number (X.680-201508 8.6)
+    pub fn canonical_order(self) -> (u8, usize) {
+        match self {
+            Tag::Universal(number) => (0, number),
+            Tag::Application(number) => (1, number),
+            Tag::ContextSpecific(number) => (2, number),
+            Tag::Private(number) => (3, number),
+        }
+    }
+}
+
+impl TryFrom<&mut Peekable<IntoIter<Token>>> for Tag {
+    type Error = Error;
+
+    fn try_from(iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Self::Error> {
+        macro_rules! parse_tag_number {
+            () => {
+                parse_tag_number!(Model::<Asn>::next(iter)?)
+            };
+            ($tag:expr) => {{
+                let tag = $tag;
+                tag.number()
+                    .and_then(|t| t.parse().ok())
+                    .ok_or_else(|| Error::invalid_tag(tag))?
+            }};
+        }
+
+        let number_or_class = Model::<Asn>::next(iter)?;
+
+        Ok(match number_or_class.text() {
+            Some("UNIVERSAL") => Tag::Universal(parse_tag_number!()),
+            Some("APPLICATION") => Tag::Application(parse_tag_number!()),
+            Some("PRIVATE") => Tag::Private(parse_tag_number!()),
+            _context_specific => Tag::ContextSpecific(parse_tag_number!(number_or_class)),
+        })
+    }
+}
+
+/// How a tag is applied to the type it tags (X.680-201508 31.2)
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq)]
+pub enum TaggingMode {
+    /// The tag is encoded in addition to the tag of the type (`EXPLICIT`)
+    Explicit,
+    /// The tag replaces the tag of the type (`IMPLICIT`)
+    Implicit,
+}
+
+/// The tagging mode of a module, which applies to tags that are neither marked `EXPLICIT`
+/// nor `IMPLICIT`. A module without `TagDefault` has explicit tags (X.680-201508 13.3).
+#[derive(Debug, Default, Clone, Copy, PartialOrd, PartialEq, Eq)]
+pub enum TagDefault {
+    #[default]
+    Explicit,
+    Implicit,
+    /// Like `Implicit`, but the components of `SEQUENCE`, `SET` and `CHOICE` types are
+    /// tagged in the order of their definition, if none of them is tagged
+    Automatic,
+}
+
+/// The outermost tag of a type as encoded in tag based encodings like BER
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq)]
+pub struct EffectiveTag {
+    pub tag: Tag,
+    /// How the tag is applied, `None` if the type is not tagged and this is the tag of
+    /// the type itself
+    pub mode: Option<TaggingMode>,
+}
+
+pub trait TagProperty {
+    fn tag(&self) -> Option<Tag>;
+
+    fn set_tag(&mut self, tag: Tag);
+
+    fn reset_tag(&mut self);
+
+    fn with_tag_opt(self, tag: Option<Tag>) -> Self
+    where
+        Self: Sized,
+    {
+        if let Some(tag) = tag {
+            self.with_tag(tag)
+        } else {
+            self.without_tag()
+        }
+    }
+
+    fn with_tag(mut self, tag: Tag) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_tag(tag);
+        self
+    }
+
+    fn without_tag(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        self.reset_tag();
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct Asn {
+    pub tag: Option<Tag>,
+    /// The tagging mode given for the tag, `None` for the default of the module
+    pub tagging: Option<TaggingMode>,
+    pub r#type: Type,
+}
+
+impl Asn {
+    pub fn optional(&mut self) {
+        let optional = self.r#type.clone().optional();
+        self.r#type = optional;
+    }
+
+    pub const fn opt_tagged(tag: Option<Tag>, r#type: Type) -> Self {
+        Self {
+            tag,
+            tagging: None,
+            r#type,
+        }
+    }
+
+    pub const fn with_tagging(mut self, tagging: Option<TaggingMode>) -> Self {
+        self.tagging = tagging;
+        self
+    }
+
+    pub const fn untagged(r#type: Type) -> Self {
+        Self::opt_tagged(None, r#type)
+    }
+
+    pub const fn tagged(tag: Tag, r#type: Type) -> Self {
+        Self::opt_tagged(Some(tag), r#type)
+    }
+
+    pub fn extensible_after_index(&self) -> Option<usize> {
+        match &self.r#type {
+            Type::Sequence(s) | Type::Set(s) => s.extension_after_index(),
+            Type::Choice(c) => c.extension_after_index(),
+            Type::Enumerated(e) => e.extension_after_index(),
+            _ => None,
+        }
+    }
+
+    pub fn extensible_after_variant(&self) -> Option<&str> {
+        match &self.r#type {
+            Type::Sequence(s) | Type::Set(s) => s
+                .extension_after_index()
+                .and_then(|index| s.fields.get(index).map(|f| f.name.as_str())),
+            Type::Choice(c) => c
+                .extension_after_index()
+                .and_then(|index| c.variants().nth(index).map(ChoiceVariant::name)),
+            Type::Enumerated(e) => e
+                .extension_after_index()
+                .and_then(|index| e.variants().nth(index).map(EnumeratedVariant::name)),
+            _ => None,
+        }
+    }
+}
+
+impl From<Type> for Asn {
+    fn from(r#type: Type) -> Self {
+        Self::untagged(r#type)
+    }
+}
+
+impl TagProperty for Asn {
+    fn tag(&self) -> Option<Tag> {
+        self.tag
+    }
+
+    fn set_tag(&mut self, tag: Tag) {
+        self.tag = Some(tag)
+    }
+
+    fn reset_tag(&mut self) {
+        self.tag = None;
+        self.tagging = None;
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum Type {
+    Boolean,
+    Integer(Integer),
+    UTF8String(Size),
+    CharacterString(CharacterString),
+    OctetString(Size),
+    BitString(BitString),
+    Null,
+    Real,
+    ObjectIdentifier,
+    GeneralizedTime,
+    UTCTime,
+
+    Optional(Box<Type>),
+    /// A type that is allocated on the heap, because it contains itself. This is never
+    /// the result of parsing ASN.1 but of the conversion of recursive types to rust.
+    Boxed(Box<Type>),
+
+    SequenceOf(Box<Type>, Size),
+    Sequence(ComponentTypeList),
+    SetOf(Box<Type>, Size),
+    /// The components of the extension root are sorted canonically by their tags
+    Set(ComponentTypeList),
+    Enumerated(Enumerated),
+    Choice(Choice),
+    TypeReference(String),
+    OpenType(OpenType),
+}
+
+impl Type {
+    pub fn optional(self) -> Self {
+        Self::Optional(Box::new(self))
+    }
+
+    pub fn sequence_from_fields(fields: Vec<Field<Asn>>) -> Self {
+        Self::Sequence(ComponentTypeList::from(fields))
+    }
+
+    pub const fn opt_tagged(self, tag: Option<Tag>) -> Asn {
+        Asn::opt_tagged(tag, self)
+    }
+
+    pub const fn tagged(self, tag: Tag) -> Asn {
+        Asn::tagged(tag, self)
+    }
+
+    pub const fn untagged(self) -> Asn {
+        Asn::untagged(self)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct ComponentTypeList {
+    pub fields: Vec<Field<Asn>>,
+    /// The index of the last field of the extension root
+    pub extension_after: Option<usize>,
+    /// The indices of the first and last field of each extension addition group `[[ ... ]]`
+    pub extension_groups: Vec<Range<usize>>,
+}
+
+impl From<Vec<Field<Asn>>> for ComponentTypeList {
+    fn from(fields: Vec<Field<Asn>>) -> Self {
+        Self {
+            fields,
+            extension_after: None,
+            extension_groups: Vec::new(),
+        }
+    }
+}
+
+impl ComponentTypeList {
+    pub fn with_extension_after(mut self, extension_after: Option<usize>) -> Self {
+        self.extension_after = extension_after;
+        self
+    }
+
+    pub fn with_extension_groups(mut self, extension_groups: Vec<Range<usize>>) -> Self {
+        self.extension_groups = extension_groups;
+        self
+    }
+
+    pub fn is_extensible(&self) -> bool {
+        self.extension_after.is_some()
+    }
+
+    pub fn extension_after_index(&self) -> Option<usize> {
+        self.extension_after
+    }
+}
+
+/// A field of a `SEQUENCE` referring to a type field of an information object class, like
+/// `MESSAGE-ID-AND-TYPE.&Type ({MessageTypes}{@messageId})`. Its actual type is determined by
+/// the object of the object set, whose key matches the value of the referenced sibling field
+/// (X.681-201508 14, X.682-201508 10)
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct OpenType {
+    pub class: String,
+    /// The type field of the class including the leading ampersand, like `&Type`
+    pub field: String,
+    pub object_set: String,
+    /// The sibling field, whose value determines the actual type
+    pub key_field: String,
+    pub variants: Vec<OpenTypeVariant>,
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct OpenTypeVariant {
+    pub name: String,
+    pub key: i64,
+    pub r#type: Type,
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct Choice {
+    variants: Vec<ChoiceVariant>,
+    extension_after: Option<usize>,
+}
+
+impl From<Vec<ChoiceVariant>> for Choice {
+    fn from(variants: Vec<ChoiceVariant>) -> Self {
+        Self {
+            variants,
+            extension_after: None,
+        }
+    }
+}
+
+impl Choice {
+    pub fn from_variants(variants: impl Iterator<Item = ChoiceVariant>) -> Self {
+        Self {
+            variants: variants.collect(),
+            extension_after: None,
+        }
+    }
+
+    pub const fn with_extension_after(mut self, extension_after: Option<usize>) -> Self {
+        self.extension_after = extension_after;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.variants.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.variants.is_empty()
+    }
+
+    pub fn variants(&self) -> impl Iterator<Item = &ChoiceVariant> {
+        self.variants.iter()
+    }
+
+    pub fn is_extensible(&self) -> bool {
+        self.extension_after.is_some()
+    }
+
+    pub fn extension_after_index(&self) -> Option<usize> {
+        self.extension_after
+    }
+}
+
+impl TryFrom<&mut Peekable<IntoIter<Token>>> for Choice {
+    type Error = Error;
+
+    fn try_from(iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Self::Error> {
+        Model::<Asn>::next_separator_ignore_case(iter, '{')?;
+        let mut choice = Choice {
+            variants: Vec::new(),
+            extension_after: None,
+        };
+
+        loop {
+            let name_or_extension_marker = Model::<Asn>::next(iter)?;
+            if name_or_extension_marker.eq_symbol(Symbol::Ellipsis) {
+                if choice.variants.is_empty() || choice.extension_after.is_some() {
+                    return Err(Error::invalid_position_for_extension_marker(
+                        name_or_extension_marker,
+                    ));
+                } else {
+                    choice.extension_after = Some(choice.variants.len() - 1);
+                }
+            } else {
+                let name = name_or_extension_marker.into_text_or_else(Error::no_text)?;
+                let (token, tag, tagging) = Model::<Asn>::next_with_opt_tag(iter)?;
+                let r#type = Model::<Asn>::read_role_given_text(
+                    iter,
+                    token.into_text_or_else(Error::no_text)?,
+                )?;
+                choice.variants.push(ChoiceVariant {
+                    name,
+                    tag,
+                    tagging,
+                    r#type,
+                });
+            }
+
+            let end_or_continuation_marker = Model::<Asn>::next(iter)?;
+
+            if end_or_continuation_marker.eq_separator(',') {
+                continue;
+            } else if end_or_continuation_marker.eq_separator('}') {
+                break;
+            } else {
+                return Err(Error::unexpected_token(end_or_continuation_marker));
+            }
+        }
+
+        Ok(choice)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct ChoiceVariant {
+    pub name: String,
+    pub tag: Option<Tag>,
+    /// The tagging mode given for the tag, `None` for the default of the module
+    pub tagging: Option<TaggingMode>,
+    pub r#type: Type,
+}
+
+impl ChoiceVariant {
+    #[cfg(test)]
+    pub fn name_type<I: ToString>(name: I, r#type: Type) -> Self {
+        ChoiceVariant {
+            name: name.to_string(),
+            tag: None,
+            tagging: None,
+            r#type,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn r#type(&self) -> &Type {
+        &self.r#type
+    }
+}
+
+impl TagProperty for ChoiceVariant {
+    fn tag(&self) -> Option<Tag> {
+        self.tag
+    }
+
+    fn set_tag(&mut self, tag: Tag) {
+        self.tag = Some(tag)
+    }
+
+    fn reset_tag(&mut self) {
+        self.tag = None;
+        self.tagging = None;
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct Enumerated {
+    variants: Vec<EnumeratedVariant>,
+    extension_after: Option<usize>,
+}
+
+impl From<Vec<EnumeratedVariant>> for Enumerated {
+    fn from(variants: Vec<EnumeratedVariant>) -> Self {
+        Self {
+            variants,
+            extension_after: None,
+        }
+    }
+}
+
+impl Enumerated {
+    pub fn from_variants(variants: impl Into<Vec<EnumeratedVariant>>) -> Self {
+        Self {
+            variants: variants.into(),
+            extension_after: None,
+        }
+    }
+
+    pub fn from_names<I: ToString>(variants: impl Iterator<Item = I>) -> Self {
+        Self {
+            variants: variants.map(EnumeratedVariant::from_name).collect(),
+            extension_after: None,
+        }
+    }
+
+    pub const fn with_extension_after(mut self, extension_after: Option<usize>) -> Self {
+        self.extension_after = extension_after;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.variants.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.variants.is_empty()
+    }
+
+    pub fn variants(&self) -> impl Iterator<Item = &EnumeratedVariant> {
+        self.variants.iter()
+    }
+
+    pub fn is_extensible(&self) -> bool {
+        self.extension_after.is_some()
+    }
+
+    pub fn extension_after_index(&self) -> Option<usize> {
+        self.extension_after
+    }
+}
+
+impl TryFrom<&mut Peekable<IntoIter<Token>>> for Enumerated {
+    type Error = Error;
+
+    fn try_from(iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Self::Error> {
+        Model::<Asn>::next_separator_ignore_case(iter, '{')?;
+        let mut enumerated = Self {
+            variants: Vec::new(),
+            extension_after: None,
+        };
+
+        loop {
+            let token = Model::<Asn>::next(iter)?;
+
+            if token.eq_symbol(Symbol::Ellipsis) {
+                if enumerated.variants.is_empty() || enumerated.extension_after.is_some() {
+                    return Err(Error::invalid_position_for_extension_marker(token));
+                } else {
+                    enumerated.extension_after = Some(enumerated.variants.len() - 1);
+                    loop_ctrl_separator!(Model::<Asn>::next(iter)?);
+                }
+            } else {
+                let variant_name = token.into_text_or_else(Error::no_text)?;
+                let token = Model::<Asn>::next(iter)?;
+
+                if token.eq_separator(',') || token.eq_separator('}') {
+                    enumerated
+                        .variants
+                        .push(EnumeratedVariant::from_name(variant_name));
+                    loop_ctrl_separator!(token);
+                } else if token.eq_separator('(') {
+                    let token = Model::<Asn>::next(iter)?;
+                    let number = token
+                        .number()
+                        .and_then(|t| t.parse::<usize>().ok())
+                        .ok_or_else(|| Error::invalid_number_for_enum_variant(token))?;
+                    Model::<Asn>::next_separator_ignore_case(iter, ')')?;
+                    enumerated
+                        .variants
+                        .push(EnumeratedVariant::from_name_number(variant_name, number));
+                    loop_ctrl_separator!(Model::<Asn>::next(iter)?);
+                } else {
+                    loop_ctrl_separator!(token);
+                }
+            }
+        }
+
+        Ok(enumerated)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct EnumeratedVariant {
+    pub(crate) name: String,
+    pub(crate) number: Option<usize>,
+}
+
+#[cfg(test)]
+impl<S: ToString> From<S> for EnumeratedVariant {
+    fn from(s: S) -> Self {
+        EnumeratedVariant::from_name(s)
+    }
+}
+
+impl EnumeratedVariant {
+    pub fn from_name<I: ToString>(name: I) -> Self {
+        Self {
+            name: name.to_string(),
+            number: None,
+        }
+    }
+
+    pub fn from_name_number<I: ToString>(name: I, number: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            number: Some(number),
+        }
+    }
+
+    pub const fn with_number(self, number: usize) -> Self {
+        self.with_number_opt(Some(number))
+    }
+
+    pub const fn with_number_opt(mut self, number: Option<usize>) -> Self {
+        self.number = number;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn number(&self) -> Option<usize> {
+        self.number
+    }
+}
+
+/// The SIZE constraint of a string or list type. The flag tells whether the constraint is
+/// extensible (`SIZE(1..8, ...)`), which permits sizes beyond the given ones.
+#[derive(Debug, Default, Clone, Copy, PartialOrd, PartialEq)]
+pub enum Size {
+    #[default]
+    Any,
+    Fix(usize, bool),
+    Range(usize, usize, bool),
+}
+
+impl Size {
+    pub fn min(&self) -> Option<usize> {
+        match self {
+            Size::Any => None,
+            Size::Fix(size, _) => Some(*size),
+            Size::Range(min, _, _) => Some(*min),
+        }
+    }
+
+    pub fn max(&self) -> Option<usize> {
+        match self {
+            Size::Any => None,
+            Size::Fix(size, _) => Some(*size),
+            Size::Range(_, max, _) => Some(*max),
+        }
+    }
+
+    pub fn extensible(&self) -> bool {
+        match self {
+            Size::Any => false,
+            Size::Fix(_, extensible) => *extensible,
+            Size::Range(_, _, extensible) => *extensible,
+        }
+    }
+
+    /// Reads the `(..)` following the `SIZE` keyword
+    fn read_size(iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Error> {
+        Model::<Asn>::next_separator_ignore_case(iter, '(')?;
+        let parse = |token: Token| {
+            token
+                .number()
+                .and_then(|t| t.parse::<usize>().ok())
+                .ok_or_else(|| Error::invalid_range_value(token))
+        };
+        let start = Model::<Asn>::next(iter)?;
+        let size = if iter
+            .peek()
+            .is_some_and(|token| token.eq_symbol(Symbol::Range))
+        {
+            Model::<Asn>::next_symbol(iter, Symbol::Range)?;
+            let end = Model::<Asn>::next(iter)?;
+            let min = if start.eq_text_ignore_ascii_case("MIN") {
+                0
+            } else {
+                parse(start)?
+            };
+            if end.eq_text_ignore_ascii_case("MAX") {
+                Size::Range(min, usize::MAX, false)
+            } else {
+                Size::Range(min, parse(end)?, false)
+            }
+        } else {
+            Size::Fix(parse(start)?, false)
+        };
+        let extensible = Model::<Asn>::read_extension_marker(iter)?;
+        Model::<Asn>::next_separator_ignore_case(iter, ')')?;
+        Ok(match size {
+            Size::Fix(size, _) => Size::Fix(size, extensible),
+            Size::Range(min, max, _) => Size::Range(min, max, extensible),
+            Size::Any => Size::Any,
+        })
+    }
+}
+
+impl TryFrom<&mut Peekable<IntoIter<Token>>> for Size {
+    type Error = Error;
+
+    /// Reads an optional `(SIZE(..))` constraint
+    fn try_from(iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Self::Error> {
+        if !iter.peek().is_some_and(|token| token.eq_separator('(')) {
+            return Ok(Size::Any);
+        }
+        Model::<Asn>::next_separator_ignore_case(iter, '(')?;
+        let token = Model::<Asn>::next(iter)?;
+        if !token.eq_text_ignore_ascii_case("SIZE") {
+            return Err(Error::expected_text("SIZE".into(), token));
+        }
+        let size = Self::read_size(iter)?;
+        Model::<Asn>::next_separator_ignore_case(iter, ')')?;
+        Ok(size)
+    }
+}
+
+/// The value range of an `INTEGER`, where an absent bound stands for `MIN` or `MAX`.
+/// Depending on its bounds, an `INTEGER` is constrained, semi-constrained (`lb..MAX`)
+/// or unconstrained (X.691-201508 10.5 to 10.8).
+#[derive(Debug, Default, Clone, Copy, PartialOrd, PartialEq)]
+pub struct Integer {
+    pub range: Range<Option<i64>>,
+}
+
+impl Integer {
+    pub const fn with_range(min: i64, max: i64) -> Self {
+        Self {
+            range: Range::inclusive(Some(min), Some(max)),
+        }
+    }
+
+    pub const fn with_lower_bound(min: i64) -> Self {
+        Self {
+            range: Range::inclusive(Some(min), None),
+        }
+    }
+}
+
+impl TryFrom<&mut Peekable<IntoIter<Token>>> for Integer {
+    type Error = Error;
+
+    /// Reads an optional `(lb..ub)` or `(lb..ub, ...)` constraint, where `MIN` and `MAX`
+    /// leave the bound open
+    fn try_from(iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Self::Error> {
+        if !iter.peek().is_some_and(|token| token.eq_separator('(')) {
+            return Ok(Integer::default());
+        }
+        let parse = |iter: &mut Peekable<IntoIter<Token>>, open: &str| {
+            let token = Model::<Asn>::next(iter)?;
+            if token.eq_text_ignore_ascii_case(open) {
+                Ok(None)
+            } else {
+                Model::<Asn>::integer_value(iter, &token)
+                    .map(Some)
+                    .ok_or_else(|| Error::invalid_range_value(token))
+            }
+        };
+        Model::<Asn>::next_separator_ignore_case(iter, '(')?;
+        let min = parse(iter, "MIN")?;
+        Model::<Asn>::next_symbol(iter, Symbol::Range)?;
+        let max = parse(iter, "MAX")?;
+        let extensible = Model::<Asn>::read_extension_marker(iter)?;
+        Model::<Asn>::next_separator_ignore_case(iter, ')')?;
+        Ok(Integer {
+            range: Range(min, max, extensible),
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct BitString {
+    pub size: Size,
+    /// The named bits and their position
+    pub constants: Vec<(String, u64)>,
+}
+
+impl TryFrom<&mut Peekable<IntoIter<Token>>> for BitString {
+    type Error = Error;
+
+    fn try_from(iter: &mut Peekable<IntoIter<Token>>) -> Result<Self, Self::Error> {
+        let mut constants = Vec::new();
+        if iter.peek().is_some_and(|token| token.eq_separator('{')) {
+            Model::<Asn>::next_separator_ignore_case(iter, '{')?;
+            loop {
+                let name = Model::<Asn>::next_text(iter)?;
+                Model::<Asn>::next_separator_ignore_case(iter, '(')?;
+                let token = Model::<Asn>::next(iter)?;
+                let position = token
+                    .number()
+                    .and_then(|t| t.parse::<u64>().ok())
+                    .ok_or_else(|| Error::invalid_number_for_named_bit(token))?;
+                Model::<Asn>::next_separator_ignore_case(iter, ')')?;
+                constants.push((name, position));
+                loop_ctrl_separator!(Model::<Asn>::next(iter)?);
+            }
+        }
+        Ok(Self {
+            size: Size::try_from(iter)?,
+            constants,
+        })
+    }
+}
+
+/// The character set of a known-multiplier character string type
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, Hash)]
+pub enum Charset {
+    Numeric,
+    Printable,
+    Visible,
+    Ia5,
+    Bmp,
+    Universal,
+}
+
+impl Charset {
+    /// The character set of the character string type with the given name
+    pub fn find(name: &str) -> Option<Self> {
+        match name {
+            "NumericString" => Some(Charset::Numeric),
+            "PrintableString" => Some(Charset::Printable),
+            "VisibleString" | "ISO646String" => Some(Charset::Visible),
+            "IA5String" => Some(Charset::Ia5),
+            "BMPString" => Some(Charset::Bmp),
+            "UniversalString" => Some(Charset::Universal),
+            _ => None,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Charset::Numeric => "NumericString",
+            Charset::Printable => "PrintableString",
+            Charset::Visible => "VisibleString",
+            Charset::Ia5 => "IA5String",
+            Charset::Bmp => "BMPString",
+            Charset::Universal => "UniversalString",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct CharacterString {
+    pub charset: Charset,
+    pub size: Size,
+    /// The characters of a `FROM` constraint in canonical order (X.680-201508 43.8)
+    pub permitted_alphabet: Option<String>,
+}
+
+impl CharacterString {
+    pub const fn new(charset: Charset) -> Self {
+        Self {
+            charset,
+            size: Size::Any,
+            permitted_alphabet: None,
+        }
+    }
+
+    pub const fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_permitted_alphabet<I: IntoIterator<Item = char>>(mut self, alphabet: I) -> Self {
+        let mut characters = alphabet.into_iter().collect::<Vec<_>>();
+        characters.sort_unstable();
+        characters.dedup();
+        self.permitted_alphabet = Some(characters.into_iter().collect());
+        self
+    }
+
+    /// The characters of a `cstring` token
+    fn characters_of(token: &Token) -> Option<Vec<char>> {
+        token.cstring().map(|string| string.chars().collect())
+    }
+
+    /// Reads `"abc"` or `"a".."z"` alternatives, separated by `|` or `UNION`, up to and
+    /// including the closing `)`
+    fn read_permitted_alphabet(iter: &mut Peekable<IntoIter<Token>>) -> Result<Vec<char>, Error> {
+        let mut alphabet = Vec::new();
+        loop {
+            let token = Model::<Asn>::next(iter)?;
+            let characters = match Self::characters_of(&token) {
+                Some(characters) => characters,
+                None => return Err(Error::invalid_permitted_alphabet(token)),
+            };
+            if iter
+                .peek()
+                .is_some_and(|token| token.eq_symbol(Symbol::Range))
+            {
+                Model::<Asn>::next_symbol(iter, Symbol::Range)?;
+                let end = Model::<Asn>::next(iter)?;
+                match (&characters[..], Self::characters_of(&end).as_deref()) {
+                    ([start], Some([end])) if start <= end => alphabet.extend(*start..=*end),
+                    _ => return Err(Error::invalid_permitted_alphabet(end)),
+                }
+            } else {
+                alphabet.extend(characters);
+            }
+            let token = Model::<Asn>::next(iter)?;
+            if token.eq_separator(')') {
+                return Ok(alphabet);
+            } else if !token.eq_separator('|') && !token.eq_text("UNION") {
+                return Err(Error::unexpected_token(token));
+            }
+        }
+    }
+}
+
+impl TryFrom<(Charset, &mut Peekable<IntoIter<Token>>)> for CharacterString {
+    type Error = Error;
+
+    /// Reads optional `(SIZE(..))` and `(FROM(..))` constraints, which are either given one
+    /// after another or combined by `^` or `INTERSECTION` in the same parentheses
+    fn try_from(
+        (charset, iter): (Charset, &mut Peekable<IntoIter<Token>>),
+    ) -> Result<Self, Self::Error> {
+        let mut string = Self::new(charset);
+        while iter.peek().is_some_and(|token| token.eq_separator('(')) {
+            Model::<Asn>::next_separator_ignore_case(iter, '(')?;
+            loop {
+                let token = Model::<Asn>::next(iter)?;
+                if token.eq_text_ignore_ascii_case("SIZE") {
+                    string.size = Size::read_size(iter)?;
+                } else if token.eq_text_ignore_ascii_case("FROM") {
+                    Model::<Asn>::next_separator_ignore_case(iter, '(')?;
+                    let alphabet = Self::read_permitted_alphabet(iter)?;
+                    string = string.with_permitted_alphabet(alphabet);
+                } else {
+                    return Err(Error::expected_text("FROM".into(), token));
+                }
+                let token = Model::<Asn>::next(iter)?;
+                if token.eq_separator(')') {
+                    break;
+                } else if !token.eq_separator('^') && !token.eq_text("INTERSECTION") {
+                    return Err(Error::unexpected_token(token));
+                }
+            }
+        }
+        Ok(string)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::model::rust::EncodingOrdering;
+    use crate::parser::{Location, Span, Tokenizer};
+
+    pub(crate) const SIMPLE_INTEGER_STRUCT_ASN: &str = r"
+        SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+        BEGIN
+
+        Simple ::= SEQUENCE {
+            small INTEGER(0..255),
+            bigger INTEGER(0..65535),
+            negative INTEGER(-1..255),
+            unlimited INTEGER(0..MAX) OPTIONAL
+        }
+        END
+        ";
+
+    #[test]
+    fn test_simple_asn_sequence_represented_correctly_as_asn_model() {
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(SIMPLE_INTEGER_STRUCT_ASN)
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!("SimpleSchema", model.name);
+        assert_eq!(true, model.imports.is_empty());
+        assert_eq!(1, model.definitions.len());
+        assert_eq!(
+            Definition(
+                "Simple".into(),
+                Type::sequence_from_fields(vec![
+                    Field {
+                        name: "small".into(),
+                        role: Type::Integer(Integer::with_range(0, 255)).untagged(),
+                        default: None,
+                    },
+                    Field {
+                        name: "bigger".into(),
+                        role: Type::Integer(Integer::with_range(0, 65535)).untagged(),
+                        default: None,
+                    },
+                    Field {
+                        name: "negative".into(),
+                        role: Type::Integer(Integer::with_range(-1, 255)).untagged(),
+                        default: None,
+                    },
+                    Field {
+                        name: "unlimited".into(),
+                        role: Type::Integer(Integer::with_lower_bound(0))
+                            .optional()
+                            .untagged(),
+                        default: None,
+                    }
+                ])
+                .untagged()
+            ),
+            model.definitions[0]
+        );
+    }
+
+    pub(crate) const INLINE_ASN_WITH_ENUM: &str = r"
+        SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+        BEGIN
+
+        Woah ::= SEQUENCE {
+            decision ENUMERATED {
+                ABORT,
+                RETURN,
+                CONFIRM,
+                MAYDAY,
+                THE_CAKE_IS_A_LIE
+            } OPTIONAL
+        }
+        END
+    ";
+
+    #[test]
+    fn test_inline_asn_enumerated_represented_correctly_as_asn_model() {
+        let model =
+            Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_ENUM).unwrap()).unwrap();
+
+        assert_eq!("SimpleSchema", model.name);
+        assert_eq!(true, model.imports.is_empty());
+        assert_eq!(1, model.definitions.len());
+        assert_eq!(
+            Definition(
+                "Woah".into(),
+                Type::sequence_from_fields(vec![Field {
+                    name: "decision".into(),
+                    role: Type::Enumerated(Enumerated::from_names(
+                        ["ABORT", "RETURN", "CONFIRM", "MAYDAY", "THE_CAKE_IS_A_LIE",].iter()
+                    ))
+                    .optional()
+                    .untagged(),
+                    default: None,
+                }])
+                .untagged(),
+            ),
+            model.definitions[0]
+        );
+    }
+
+    pub(crate) const INLINE_ASN_WITH_SEQUENCE_OF: &str = r"
+        SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+        BEGIN
+
+        Ones ::= SEQUENCE OF INTEGER(0..1)
+
+        NestedOnes ::= SEQUENCE OF SEQUENCE OF INTEGER(0..1)
+
+        Woah ::= SEQUENCE {
+            also-ones SEQUENCE OF INTEGER(0..1),
+            nesteds SEQUENCE OF SEQUENCE OF INTEGER(0..1),
+            optionals SEQUENCE OF SEQUENCE OF INTEGER(0..MAX) OPTIONAL
+        }
+        END
+    ";
+
+    #[test]
+    fn test_inline_asn_sequence_of_represented_correctly_as_asn_model() {
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(INLINE_ASN_WITH_SEQUENCE_OF)
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!("SimpleSchema", model.name);
+        assert_eq!(true, model.imports.is_empty());
+        assert_eq!(3, model.definitions.len());
+        assert_eq!(
+            Definition(
+                "Ones".into(),
+                Type::SequenceOf(
+                    Box::new(Type::Integer(Integer::with_range(0, 1))),
+                    Size::Any
+                )
+                .untagged()
+            ),
+            model.definitions[0]
+        );
+        assert_eq!(
+            Definition(
+                "NestedOnes".into(),
+                Type::SequenceOf(
+                    Box::new(Type::SequenceOf(
+                        Box::new(Type::Integer(Integer::with_range(0, 1))),
+                        Size::Any
+                    )),
+                    Size::Any
+                )
+                .untagged(),
+            ),
+            model.definitions[1]
+        );
+        assert_eq!(
+            Definition(
+                "Woah".into(),
+                Type::sequence_from_fields(vec![
+                    Field {
+                        name: "also-ones".into(),
+                        role: Type::SequenceOf(
+                            Box::new(Type::Integer(Integer::with_range(0, 1))),
+                            Size::Any
+                        )
+                        .untagged(),
+                        default: None,
+                    },
+                    Field {
+                        name: "nesteds".into(),
+                        role: Type::SequenceOf(
+                            Box::new(Type::SequenceOf(
+                                Box::new(Type::Integer(Integer::with_range(0, 1))),
+                                Size::Any
+                            )),
+                            Size::Any
+                        )
+                        .untagged(),
+                        default: None,
+                    },
+                    Field {
+                        name: "optionals".into(),
+                        role: Type::SequenceOf(
+                            Box::new(Type::SequenceOf(
+                                Box::new(Type::Integer(Integer::with_lower_bound(0))),
+                                Size::Any
+                            )),
+                            Size::Any
+                        )
+                        .optional()
+                        .untagged(),
+                        default: None,
+                    },
+                ])
+                .untagged()
+            ),
+            model.definitions[2]
+        );
     }
 
     pub(crate) const INLINE_ASN_WITH_CHOICE: &str = r"
         SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
         BEGIN
 
-        This ::= SEQUENCE OF INTEGER(0..1)
+        This ::= SEQUENCE OF INTEGER(0..1)
+
+        That ::= SEQUENCE OF SEQUENCE OF INTEGER(0..1)
+
+        Neither ::= ENUMERATED {
+            ABC,
+            DEF
+        }
+
+        Woah ::= SEQUENCE {
+            decision CHOICE {
+                this This,
+                that That,
+                neither Neither
+            }
+        }
+        END
+    ";
+
+    #[test]
+    fn test_inline_asn_choice_represented_correctly_as_asn_model() {
+        let model =
+            Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_CHOICE).unwrap()).unwrap();
+
+        assert_eq!("SimpleSchema", model.name);
+        assert_eq!(true, model.imports.is_empty());
+        assert_eq!(4, model.definitions.len());
+        assert_eq!(
+            Definition(
+                "This".into(),
+                Type::SequenceOf(
+                    Box::new(Type::Integer(Integer::with_range(0, 1))),
+                    Size::Any
+                )
+                .untagged()
+            ),
+            model.definitions[0]
+        );
+        assert_eq!(
+            Definition(
+                "That".into(),
+                Type::SequenceOf(
+                    Box::new(Type::SequenceOf(
+                        Box::new(Type::Integer(Integer::with_range(0, 1))),
+                        Size::Any
+                    )),
+                    Size::Any
+                )
+                .untagged()
+            ),
+            model.definitions[1]
+        );
+        assert_eq!(
+            Definition(
+                "Neither".into(),
+                Type::Enumerated(Enumerated::from_names(["ABC", "DEF"].iter())).untagged()
+            ),
+            model.definitions[2]
+        );
+        assert_eq!(
+            Definition(
+                "Woah".into(),
+                Type::sequence_from_fields(vec![Field {
+                    name: "decision".into(),
+                    role: Type::Choice(Choice::from(vec![
+                        ChoiceVariant::name_type("this", Type::TypeReference("This".into())),
+                        ChoiceVariant::name_type("that", Type::TypeReference("That".into())),
+                        ChoiceVariant::name_type("neither", Type::TypeReference("Neither".into())),
+                    ]))
+                    .untagged(),
+                    default: None,
+                }])
+                .untagged(),
+            ),
+            model.definitions[3]
+        );
+    }
+
+    pub(crate) const INLINE_ASN_WITH_SEQUENCE: &str = r"
+        SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+        BEGIN
+
+        Woah ::= SEQUENCE {
+            complex SEQUENCE {
+                ones INTEGER(0..1),
+                list-ones SEQUENCE OF INTEGER(0..1),
+                optional-ones SEQUENCE OF INTEGER(0..1) OPTIONAL
+            } OPTIONAL
+        }
+        END
+    ";
+
+    #[test]
+    fn test_inline_asn_sequence_represented_correctly_as_asn_model() {
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(INLINE_ASN_WITH_SEQUENCE)
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!("SimpleSchema", model.name);
+        assert_eq!(true, model.imports.is_empty());
+        assert_eq!(1, model.definitions.len());
+        assert_eq!(
+            Definition(
+                "Woah".into(),
+                Type::sequence_from_fields(vec![Field {
+                    name: "complex".into(),
+                    role: Type::sequence_from_fields(vec![
+                        Field {
+                            name: "ones".into(),
+                            role: Type::Integer(Integer::with_range(0, 1)).untagged(),
+                            default: None,
+                        },
+                        Field {
+                            name: "list-ones".into(),
+                            role: Type::SequenceOf(
+                                Box::new(Type::Integer(Integer::with_range(0, 1))),
+                                Size::Any
+                            )
+                            .untagged(),
+                            default: None,
+                        },
+                        Field {
+                            name: "optional-ones".into(),
+                            role: Type::SequenceOf(
+                                Box::new(Type::Integer(Integer::with_range(0, 1))),
+                                Size::Any
+                            )
+                            .optional()
+                            .untagged(),
+                            default: None,
+                        },
+                    ])
+                    .optional()
+                    .untagged(),
+                    default: None,
+                }])
+                .untagged()
+            ),
+            model.definitions[0]
+        );
+    }
+
+    #[test]
+    fn test_nice_names() {
+        let mut model = Model::default();
+
+        model.name = "SimpleTest".into();
+        model.make_names_nice();
+        assert_eq!("simple_test", model.to_rust().name);
+
+        model.name = "SIMPLE_Test".into();
+        model.make_names_nice();
+        assert_eq!("simple_test", model.to_rust().name);
+
+        model.name = "DRY_Module".into();
+        model.make_names_nice();
+        assert_eq!("dry", model.to_rust().name);
+
+        model.name = "DRYModule".into();
+        model.make_names_nice();
+        assert_eq!("dry", model.to_rust().name);
+    }
+
+    #[test]
+    pub fn test_integer_type_with_range() {
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"
+            SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+    
+            SimpleTypeWithRange ::= Integer (0..65535)
+            
+            END
+        ",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
+
+        assert_eq!("SimpleSchema", &model.name);
+        assert_eq!(
+            &[Definition(
+                "SimpleTypeWithRange".to_string(),
+                Type::Integer(Integer::with_range(0, 65_535)).untagged()
+            )][..],
+            &model.definitions[..]
+        )
+    }
+
+    #[test]
+    pub fn test_string_type() {
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"
+            SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+    
+            SimpleUnicodeStringType ::= UTF8String
+            SimpleAsciiStringType ::= IA5String
+            
+            END
+        ",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
+
+        assert_eq!("SimpleSchema", &model.name);
+        assert_eq!(
+            &[
+                Definition(
+                    "SimpleUnicodeStringType".to_string(),
+                    Type::UTF8String(Size::Any).untagged()
+                ),
+                Definition(
+                    "SimpleAsciiStringType".to_string(),
+                    Type::CharacterString(CharacterString::new(Charset::Ia5)).untagged()
+                )
+            ][..],
+            &model.definitions[..]
+        );
+    }
+
+    #[test]
+    pub fn test_character_string_permitted_alphabet() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r#"
+            SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Code ::= PrintableString (FROM("A".."F" | "0123456789"))
+            Digits ::= NumericString
+            Quoted ::= IA5String (FROM("a""b" UNION " "))
+            Wide ::= BMPString
+
+            END
+        "#,
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
+
+        assert_eq!(
+            &[
+                Definition(
+                    "Code".to_string(),
+                    Type::CharacterString(
+                        CharacterString::new(Charset::Printable)
+                            .with_permitted_alphabet("0123456789ABCDEF".chars())
+                    )
+                    .untagged()
+                ),
+                Definition(
+                    "Digits".to_string(),
+                    Type::CharacterString(CharacterString::new(Charset::Numeric)).untagged()
+                ),
+                Definition(
+                    "Quoted".to_string(),
+                    Type::CharacterString(
+                        CharacterString::new(Charset::Ia5).with_permitted_alphabet(" \"ab".chars())
+                    )
+                    .untagged()
+                ),
+                Definition(
+                    "Wide".to_string(),
+                    Type::CharacterString(CharacterString::new(Charset::Bmp)).untagged()
+                ),
+            ][..],
+            &model.definitions[..]
+        );
+    }
+
+    #[test]
+    pub fn test_character_string_invalid_permitted_alphabet() {
+        let result = Model::try_from(
+            Tokenizer
+                .parse(
+                    r#"
+            SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Code ::= PrintableString (FROM("AB".."Z"))
+
+            END
+        "#,
+                )
+                .unwrap(),
+        );
+        assert!(matches!(
+            result.map_err(|e| *e.kind),
+            Err(ErrorKind::InvalidPermittedAlphabet(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_enumerated_advanced() {
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+    
+            Basic ::= ENUMERATED {
+                abc,
+                def
+            }
+    
+            WithExplicitNumber ::= ENUMERATED {
+                abc(1),
+                def(9)
+            }
+            
+            WithExplicitNumberAndDefaultMark ::= ENUMERATED {
+                abc(4),
+                def(7),
+                ...
+            }
+            
+            WithExplicitNumberAndDefaultMarkV2 ::= ENUMERATED {
+                abc(8),
+                def(1),
+                ...,
+                v2(11)
+            }
+            
+            END
+        ",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
+
+        assert_eq!("SimpleSchema", &model.name);
+        assert_eq!(
+            &[
+                Definition(
+                    "Basic".to_string(),
+                    Type::Enumerated(Enumerated::from_names(["abc", "def"].iter())).untagged(),
+                ),
+                Definition(
+                    "WithExplicitNumber".to_string(),
+                    Type::Enumerated(Enumerated {
+                        variants: vec![
+                            EnumeratedVariant::from_name_number("abc", 1),
+                            EnumeratedVariant::from_name_number("def", 9)
+                        ],
+                        extension_after: None,
+                    })
+                    .untagged(),
+                ),
+                Definition(
+                    "WithExplicitNumberAndDefaultMark".to_string(),
+                    Type::Enumerated(Enumerated {
+                        variants: vec![
+                            EnumeratedVariant::from_name_number("abc", 4),
+                            EnumeratedVariant::from_name_number("def", 7),
+                        ],
+                        extension_after: Some(1),
+                    })
+                    .untagged(),
+                ),
+                Definition(
+                    "WithExplicitNumberAndDefaultMarkV2".to_string(),
+                    Type::Enumerated(Enumerated {
+                        variants: vec![
+                            EnumeratedVariant::from_name_number("abc", 8),
+                            EnumeratedVariant::from_name_number("def", 1),
+                            EnumeratedVariant::from_name_number("v2", 11)
+                        ],
+                        extension_after: Some(1),
+                    })
+                    .untagged(),
+                )
+            ][..],
+            &model.definitions[..]
+        )
+    }
+
+    #[test]
+    pub fn test_enumerated_tags() {
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+    
+            Universal ::= [UNIVERSAL 2] ENUMERATED {
+                abc,
+                def
+            }
+    
+            Application ::= [APPLICATION 7] ENUMERATED {
+                abc,
+                def
+            }
+            
+            Private ::= [PRIVATE 11] ENUMERATED {
+                abc,
+                def
+            }
+            
+            ContextSpecific ::= [8] ENUMERATED {
+                abc,
+                def
+            }
+            
+            END
+        ",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
+
+        assert_eq!("SimpleSchema", &model.name);
+        assert_eq!(
+            &[
+                Definition(
+                    "Universal".to_string(),
+                    Type::Enumerated(Enumerated::from_names(["abc", "def"].iter()))
+                        .tagged(Tag::Universal(2))
+                ),
+                Definition(
+                    "Application".to_string(),
+                    Type::Enumerated(Enumerated::from_names(["abc", "def"].iter()))
+                        .tagged(Tag::Application(7))
+                ),
+                Definition(
+                    "Private".to_string(),
+                    Type::Enumerated(Enumerated::from_names(["abc", "def"].iter()))
+                        .tagged(Tag::Private(11))
+                ),
+                Definition(
+                    "ContextSpecific".to_string(),
+                    Type::Enumerated(Enumerated::from_names(["abc", "def"].iter()))
+                        .tagged(Tag::ContextSpecific(8))
+                ),
+            ][..],
+            &model.definitions[..]
+        )
+    }
+
+    #[test]
+    pub fn test_parsing_tags_in_front_of_definitions_does_not_fail() {
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+    
+            Universal ::= [UNIVERSAL 2] SEQUENCE {
+                abc [1] INTEGER(0..MAX),
+                def [2] INTEGER(0..255)
+            }
+    
+            Application ::= [APPLICATION 7] SEQUENCE OF Utf8String
+            
+            Private ::= [PRIVATE 11] ENUMERATED {
+                abc,
+                def
+            }
+            
+            ContextSpecific ::= [8] INTEGER(0..MAX)
+            
+            END
+        ",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
+
+        assert_eq!("SimpleSchema", &model.name);
+        assert_eq!(
+            &[
+                Definition(
+                    "Universal".to_string(),
+                    Type::sequence_from_fields(vec![
+                        Field {
+                            name: "abc".to_string(),
+                            role: Type::Integer(Integer::with_lower_bound(0))
+                                .tagged(Tag::ContextSpecific(1)),
+                            default: None,
+                        },
+                        Field {
+                            name: "def".to_string(),
+                            role: Type::Integer(Integer::with_range(0, 255))
+                                .tagged(Tag::ContextSpecific(2)),
+                            default: None,
+                        }
+                    ])
+                    .tagged(Tag::Universal(2)),
+                ),
+                Definition(
+                    "Application".to_string(),
+                    Type::SequenceOf(Box::new(Type::UTF8String(Size::Any)), Size::Any)
+                        .tagged(Tag::Application(7))
+                ),
+                Definition(
+                    "Private".to_string(),
+                    Type::Enumerated(Enumerated::from_names(["abc", "def"].iter()))
+                        .tagged(Tag::Private(11))
+                ),
+                Definition(
+                    "ContextSpecific".to_string(),
+                    Type::Integer(Integer::with_lower_bound(0)).tagged(Tag::ContextSpecific(8))
+                ),
+            ][..],
+            &model.definitions[..]
+        )
+    }
+
+    #[test]
+    pub fn test_parsing_of_extensible_choices() {
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+    
+            WithoutMarker ::= CHOICE {
+                abc Utf8String,
+                def Utf8String
+            }
+            
+            WithoutExtensionPresent ::= CHOICE {
+                abc Utf8String,
+                def Utf8String,
+                ...
+            }
+    
+            WithExtensionPresent ::= CHOICE {
+                abc Utf8String,
+                def Utf8String,
+                ...,
+                ghi Utf8String
+            }
+            
+            END
+        ",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
+
+        assert_eq!("SimpleSchema", model.name.as_str());
+        assert_eq!(
+            &[
+                Definition::new(
+                    "WithoutMarker",
+                    Type::Choice(Choice {
+                        variants: vec![
+                            ChoiceVariant::name_type("abc", Type::UTF8String(Size::Any)),
+                            ChoiceVariant::name_type("def", Type::UTF8String(Size::Any)),
+                        ],
+                        extension_after: None
+                    })
+                    .untagged()
+                ),
+                Definition::new(
+                    "WithoutExtensionPresent",
+                    Type::Choice(Choice {
+                        variants: vec![
+                            ChoiceVariant::name_type("abc", Type::UTF8String(Size::Any)),
+                            ChoiceVariant::name_type("def", Type::UTF8String(Size::Any)),
+                        ],
+                        extension_after: Some(1)
+                    })
+                    .untagged()
+                ),
+                Definition::new(
+                    "WithExtensionPresent",
+                    Type::Choice(Choice {
+                        variants: vec![
+                            ChoiceVariant::name_type("abc", Type::UTF8String(Size::Any)),
+                            ChoiceVariant::name_type("def", Type::UTF8String(Size::Any)),
+                            ChoiceVariant::name_type("ghi", Type::UTF8String(Size::Any)),
+                        ],
+                        extension_after: Some(1)
+                    })
+                    .untagged()
+                )
+            ][..],
+            &model.definitions[..]
+        )
+    }
+
+    #[test]
+    pub fn test_parsing_of_extensible_sequences() {
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            WithoutExtensionPresent ::= SEQUENCE {
+                abc INTEGER,
+                ...
+            }
+
+            WithExtensionPresent ::= SEQUENCE {
+                abc INTEGER,
+                ...,
+                def BOOLEAN OPTIONAL,
+                [[ 2:
+                    ghi INTEGER,
+                    jkl BOOLEAN OPTIONAL
+                ]],
+                [[ mno BOOLEAN ]]
+            }
+
+            END
+        ",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
+
+        assert_eq!(
+            &[
+                Definition::new(
+                    "WithoutExtensionPresent",
+                    Type::Sequence(
+                        ComponentTypeList::from(vec![Field {
+                            name: "abc".into(),
+                            role: Type::Integer(Integer::default()).untagged(),
+                            default: None,
+                        }])
+                        .with_extension_after(Some(0))
+                    )
+                    .untagged()
+                ),
+                Definition::new(
+                    "WithExtensionPresent",
+                    Type::Sequence(
+                        ComponentTypeList::from(vec![
+                            Field {
+                                name: "abc".into(),
+                                role: Type::Integer(Integer::default()).untagged(),
+                                default: None,
+                            },
+                            Field {
+                                name: "def".into(),
+                                role: Type::Boolean.optional().untagged(),
+                                default: None,
+                            },
+                            Field {
+                                name: "ghi".into(),
+                                role: Type::Integer(Integer::default()).untagged(),
+                                default: None,
+                            },
+                            Field {
+                                name: "jkl".into(),
+                                role: Type::Boolean.optional().untagged(),
+                                default: None,
+                            },
+                            Field {
+                                name: "mno".into(),
+                                role: Type::Boolean.untagged(),
+                                default: None,
+                            },
+                        ])
+                        .with_extension_after(Some(0))
+                        .with_extension_groups(vec![
+                            Range::inclusive(2, 3),
+                            Range::inclusive(4, 4)
+                        ])
+                    )
+                    .untagged()
+                ),
+            ][..],
+            &model.definitions[..]
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_of_extensible_with_markers_at_invalid_locations() {
+        assert_eq!(
+            Error::invalid_position_for_extension_marker(Token::Symbol(
+                Span::new(Location::at(4, 21), Location::at(4, 24)),
+                Symbol::Ellipsis
+            )),
+            Model::try_from(
+                Tokenizer::default()
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Invalid ::= CHOICE {
+                    ...
+                }
+                
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+
+        assert_eq!(
+            Error::invalid_position_for_extension_marker(Token::Symbol(
+                Span::new(Location::at(4, 21), Location::at(4, 24)),
+                Symbol::Ellipsis
+            )),
+            Model::try_from(
+                Tokenizer::default()
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+    
+                Invalid ::= CHOICE {
+                    ...,
+                    abc Utf8String
+                }
+                
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+
+        assert_eq!(
+            Error::invalid_position_for_extension_marker(Token::Symbol(
+                Span::new(Location::at(4, 21), Location::at(4, 24)),
+                Symbol::Ellipsis
+            )),
+            Model::try_from(
+                Tokenizer::default()
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+    
+                Invalid ::= ENUMERATED {
+                    ...
+                }
+                
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
 
-        That ::= SEQUENCE OF SEQUENCE OF INTEGER(0..1)
+        assert_eq!(
+            Error::invalid_position_for_extension_marker(Token::Symbol(
+                Span::new(Location::at(4, 21), Location::at(4, 24)),
+                Symbol::Ellipsis
+            )),
+            Model::try_from(
+                Tokenizer::default()
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
 
-        Neither ::= ENUMERATED {
-            ABC,
-            DEF
-        }
+                Invalid ::= ENUMERATED {
+                    ...,
+                    abc(77)
+                }
+                
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
 
-        Woah ::= SEQUENCE {
-            decision CHOICE {
-                this This,
-                that That,
-                neither Neither
-            }
-        }
-        END
-    ";
+        assert_eq!(
+            Error::invalid_position_for_extension_marker(Token::Symbol(
+                Span::new(Location::at(4, 21), Location::at(4, 24)),
+                Symbol::Ellipsis
+            )),
+            Model::try_from(
+                Tokenizer::default()
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Invalid ::= SEQUENCE {
+                    ...,
+                    abc INTEGER
+                }
+                
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+
+        assert_eq!(
+            Error::invalid_position_for_extension_marker(Token::Symbol(
+                Span::new(Location::at(5, 21), Location::at(5, 23)),
+                Symbol::LeftVersionBrackets
+            )),
+            Model::try_from(
+                Tokenizer::default()
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Invalid ::= SEQUENCE {
+                    abc INTEGER,
+                    [[ def INTEGER ]]
+                }
+                
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+    }
 
     #[test]
-    fn test_inline_asn_choice_represented_correctly_as_asn_model() {
-        let model = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_CHOICE)).unwrap();
+    pub fn test_parsing_of_bit_strings() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Plain ::= BIT STRING
+
+            Fixed ::= BIT STRING (SIZE(8))
+
+            Flags ::= BIT STRING { readable(0), writable(1), executable(7) } (SIZE(1..MAX))
+
+            END",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
 
-        assert_eq!("SimpleSchema", model.name);
-        assert_eq!(true, model.imports.is_empty());
-        assert_eq!(4, model.definitions.len());
         assert_eq!(
-            Definition(
-                "This".into(),
-                Type::SequenceOf(Box::new(Type::Integer(Some(Range(0, 1))))).untagged()
-            ),
-            model.definitions[0]
+            &[
+                Definition::new("Plain", Type::BitString(BitString::default()).untagged()),
+                Definition::new(
+                    "Fixed",
+                    Type::BitString(BitString {
+                        size: Size::Fix(8, false),
+                        constants: Vec::new(),
+                    })
+                    .untagged()
+                ),
+                Definition::new(
+                    "Flags",
+                    Type::BitString(BitString {
+                        size: Size::Range(1, usize::MAX, false),
+                        constants: vec![
+                            ("readable".to_string(), 0),
+                            ("writable".to_string(), 1),
+                            ("executable".to_string(), 7),
+                        ],
+                    })
+                    .untagged()
+                ),
+            ][..],
+            &model.definitions[..]
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_of_null_real_and_object_identifier() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Reading ::= SEQUENCE {
+                sensor OBJECT IDENTIFIER,
+                value REAL
+            }
+
+            MaybeReading ::= CHOICE {
+                none NULL,
+                reading Reading
+            }
+
+            END",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
+
+        assert_eq!(
+            &[
+                Definition::new(
+                    "Reading",
+                    Type::sequence_from_fields(vec![
+                        Field {
+                            name: "sensor".into(),
+                            role: Type::ObjectIdentifier.untagged(),
+                            default: None,
+                        },
+                        Field {
+                            name: "value".into(),
+                            role: Type::Real.untagged(),
+                            default: None,
+                        },
+                    ])
+                    .untagged()
+                ),
+                Definition::new(
+                    "MaybeReading",
+                    Type::Choice(Choice::from(vec![
+                        ChoiceVariant::name_type("none", Type::Null),
+                        ChoiceVariant::name_type("reading", Type::TypeReference("Reading".into())),
+                    ]))
+                    .untagged()
+                ),
+            ][..],
+            &model.definitions[..]
         );
+    }
+
+    #[test]
+    pub fn test_parsing_of_generalized_time_and_utc_time() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            AuditEntry ::= SEQUENCE {
+                created GeneralizedTime,
+                expires UTCTime OPTIONAL
+            }
+
+            END",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
+
         assert_eq!(
-            Definition(
-                "That".into(),
-                Type::SequenceOf(Box::new(Type::SequenceOf(Box::new(Type::Integer(Some(
-                    Range(0, 1)
-                ))))))
+            &[Definition::new(
+                "AuditEntry",
+                Type::sequence_from_fields(vec![
+                    Field {
+                        name: "created".into(),
+                        role: Type::GeneralizedTime.untagged(),
+                        default: None,
+                    },
+                    Field {
+                        name: "expires".into(),
+                        role: Type::UTCTime.optional().untagged(),
+                        default: None,
+                    },
+                ])
                 .untagged()
+            ),][..],
+            &model.definitions[..]
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_of_bit_string_with_invalid_named_bit() {
+        assert_eq!(
+            Error::invalid_number_for_named_bit(Token::Text(
+                Span::new(Location::at(3, 48), Location::at(3, 49)),
+                "a".to_string()
+            )),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Invalid ::= BIT STRING { first(a) }
+
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_of_size_constraints() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Name ::= UTF8String (SIZE(1..16))
+
+            Hash ::= OCTET STRING (SIZE(32))
+
+            Code ::= IA5String (SIZE(2..8, ...))
+
+            Values ::= SEQUENCE (SIZE(0..8)) OF INTEGER
+
+            Labels ::= SEQUENCE SIZE(1..MAX, ...) OF UTF8String
+
+            END",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
+
+        assert_eq!(
+            &[
+                Definition::new(
+                    "Name",
+                    Type::UTF8String(Size::Range(1, 16, false)).untagged()
+                ),
+                Definition::new("Hash", Type::OctetString(Size::Fix(32, false)).untagged()),
+                Definition::new(
+                    "Code",
+                    Type::CharacterString(
+                        CharacterString::new(Charset::Ia5).with_size(Size::Range(2, 8, true))
+                    )
+                    .untagged()
+                ),
+                Definition::new(
+                    "Values",
+                    Type::SequenceOf(
+                        Box::new(Type::Integer(Integer::default())),
+                        Size::Range(0, 8, false)
+                    )
+                    .untagged()
+                ),
+                Definition::new(
+                    "Labels",
+                    Type::SequenceOf(
+                        Box::new(Type::UTF8String(Size::Any)),
+                        Size::Range(1, usize::MAX, true)
+                    )
+                    .untagged()
+                ),
+            ][..],
+            &model.definitions[..]
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_of_integer_bounds_and_extensibility() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Unconstrained ::= INTEGER
+
+            SemiConstrained ::= INTEGER (-5..MAX)
+
+            UpperBounded ::= INTEGER (MIN..7)
+
+            Positive ::= INTEGER (0..MAX)
+
+            Unbounded ::= INTEGER (MIN..MAX)
+
+            Extensible ::= INTEGER (0..255, ...)
+
+            END",
+                )
+                .unwrap(),
+        )
+        .expect("Failed to parse");
+
+        assert_eq!(
+            &[
+                Definition::new(
+                    "Unconstrained",
+                    Type::Integer(Integer::default()).untagged()
+                ),
+                Definition::new(
+                    "SemiConstrained",
+                    Type::Integer(Integer::with_lower_bound(-5)).untagged()
+                ),
+                Definition::new(
+                    "UpperBounded",
+                    Type::Integer(Integer {
+                        range: Range::inclusive(None, Some(7))
+                    })
+                    .untagged()
+                ),
+                Definition::new(
+                    "Positive",
+                    Type::Integer(Integer::with_lower_bound(0)).untagged()
+                ),
+                Definition::new("Unbounded", Type::Integer(Integer::default()).untagged()),
+                Definition::new(
+                    "Extensible",
+                    Type::Integer(Integer {
+                        range: Range(Some(0), Some(255), true)
+                    })
+                    .untagged()
+                ),
+            ][..],
+            &model.definitions[..]
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_of_sequence_with_size_but_without_of() {
+        assert_eq!(
+            Error::expected_text(
+                "OF".into(),
+                Token::Separator(Span::new(Location::at(3, 51), Location::at(3, 52)), '{')
             ),
-            model.definitions[1]
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Invalid ::= SEQUENCE (SIZE(1..2)) { abc INTEGER }
+
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
         );
+    }
+
+    #[test]
+    fn test_set_fields_are_sorted_canonically_for_encoding() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS EXPLICIT TAGS ::= BEGIN
+
+            Unordered ::= SET {
+                name UTF8String,
+                tagged [0] BOOLEAN,
+                choice CHOICE { text IA5String, nothing NULL },
+                flag BOOLEAN,
+                reference Reference OPTIONAL,
+                ...,
+                addition BOOLEAN
+            }
+
+            Reference ::= INTEGER
+
+            Flags ::= SET (SIZE(1..4)) OF BOOLEAN
+
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        match &model.definitions[0].1.r#type {
+            Type::Set(list) => {
+                assert_eq!(
+                    vec!["name", "tagged", "choice", "flag", "reference", "addition"],
+                    list.fields
+                        .iter()
+                        .map(|f| f.name.as_str())
+                        .collect::<Vec<_>>()
+                );
+                assert_eq!(Some(4), list.extension_after_index());
+            }
+            other => panic!("Expected SET but got {:?}", other),
+        }
+        let rust = model.to_rust();
+        match rust
+            .definitions
+            .iter()
+            .find(|d| d.0 == "Unordered")
+            .map(|d| &d.1)
+        {
+            Some(Rust::Struct {
+                fields,
+                extension_after,
+                ordering,
+                ..
+            }) => {
+                assert_eq!(
+                    vec!["flag", "reference", "choice", "name", "tagged", "addition"],
+                    fields.iter().map(|f| f.name()).collect::<Vec<_>>()
+                );
+                assert_eq!(Some(4), *extension_after);
+                assert_eq!(EncodingOrdering::Sort, *ordering);
+            }
+            other => panic!("Expected struct but got {:?}", other),
+        }
         assert_eq!(
-            Definition(
-                "Neither".into(),
-                Type::Enumerated(Enumerated::from_names(["ABC", "DEF"].iter())).untagged()
+            Definition::new(
+                "Flags",
+                Type::SetOf(Box::new(Type::Boolean), Size::Range(1, 4, false)).untagged()
             ),
             model.definitions[2]
         );
-        assert_eq!(
-            Definition(
-                "Woah".into(),
-                Type::Sequence(vec![Field {
-                    name: "decision".into(),
-                    role: Type::Choice(Choice::from(vec![
-                        ChoiceVariant::name_type("this", Type::TypeReference("This".into())),
-                        ChoiceVariant::name_type("that", Type::TypeReference("That".into())),
-                        ChoiceVariant::name_type("neither", Type::TypeReference("Neither".into())),
-                    ]))
-                    .untagged(),
-                }])
-                .untagged(),
+    }
+
+    #[test]
+    fn test_parsing_of_set_with_automatic_tags_keeps_order() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Ordered ::= SET {
+                name UTF8String,
+                flag BOOLEAN
+            }
+
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        match &model.definitions[0].1.r#type {
+            Type::Set(list) => assert_eq!(
+                vec!["name", "flag"],
+                list.fields
+                    .iter()
+                    .map(|f| f.name.as_str())
+                    .collect::<Vec<_>>()
             ),
-            model.definitions[3]
+            other => panic!("Expected SET but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parsing_of_tag_default() {
+        for (header, tag_default) in [
+            ("", TagDefault::Explicit),
+            ("EXPLICIT TAGS", TagDefault::Explicit),
+            ("IMPLICIT TAGS", TagDefault::Implicit),
+            ("AUTOMATIC TAGS", TagDefault::Automatic),
+        ] {
+            let model = Model::try_from(
+                Tokenizer
+                    .parse(&format!(
+                        "SimpleSchema DEFINITIONS {} ::= BEGIN Flag ::= BOOLEAN END",
+                        header
+                    ))
+                    .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(tag_default, model.tag_default);
+        }
+    }
+
+    #[test]
+    fn test_effective_tags_of_implicit_and_explicit_tags() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS IMPLICIT TAGS ::= BEGIN
+
+            Message ::= [APPLICATION 1] IMPLICIT SEQUENCE {
+                id [0] INTEGER,
+                payload [1] EXPLICIT OCTET STRING,
+                content [2] IMPLICIT Content,
+                alternatives CHOICE { flag BOOLEAN, nothing NULL },
+                name UTF8String
+            }
+
+            Content ::= CHOICE {
+                text [0] EXPLICIT UTF8String,
+                number INTEGER
+            }
+
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        let Definition(_, message) = &model.definitions[0];
+        assert_eq!(Some(TaggingMode::Implicit), message.tagging);
+        assert_eq!(
+            Some(EffectiveTag {
+                tag: Tag::Application(1),
+                mode: Some(TaggingMode::Implicit)
+            }),
+            model.effective_tag(message)
         );
+        match &message.r#type {
+            Type::Sequence(list) => assert_eq!(
+                vec![
+                    Some(EffectiveTag {
+                        tag: Tag::ContextSpecific(0),
+                        mode: Some(TaggingMode::Implicit)
+                    }),
+                    Some(EffectiveTag {
+                        tag: Tag::ContextSpecific(1),
+                        mode: Some(TaggingMode::Explicit)
+                    }),
+                    // the tag of an untagged CHOICE is always explicit
+                    Some(EffectiveTag {
+                        tag: Tag::ContextSpecific(2),
+                        mode: Some(TaggingMode::Explicit)
+                    }),
+                    None,
+                    Some(EffectiveTag {
+                        tag: Tag::Universal(12),
+                        mode: None
+                    }),
+                ],
+                model.effective_field_tags(list)
+            ),
+            other => panic!("Expected SEQUENCE but got {:?}", other),
+        }
+        match &model.definitions[1].1.r#type {
+            Type::Choice(choice) => assert_eq!(
+                vec![
+                    Some(EffectiveTag {
+                        tag: Tag::ContextSpecific(0),
+                        mode: Some(TaggingMode::Explicit)
+                    }),
+                    Some(EffectiveTag {
+                        tag: Tag::Universal(2),
+                        mode: None
+                    }),
+                ],
+                model.effective_variant_tags(choice)
+            ),
+            other => panic!("Expected CHOICE but got {:?}", other),
+        }
     }
 
-    pub(crate) const INLINE_ASN_WITH_SEQUENCE: &str = r"
-        SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
-        BEGIN
+    #[test]
+    fn test_effective_tags_of_automatic_tags() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Automatic ::= SEQUENCE {
+                flag BOOLEAN,
+                reference Alternatives,
+                ...,
+                addition INTEGER
+            }
 
-        Woah ::= SEQUENCE {
-            complex SEQUENCE {
-                ones INTEGER(0..1),
-                list-ones SEQUENCE OF INTEGER(0..1),
-                optional-ones SEQUENCE OF INTEGER(0..1) OPTIONAL
-            } OPTIONAL
+            Alternatives ::= CHOICE {
+                tagged [5] BOOLEAN,
+                number INTEGER
+            }
+
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        match &model.definitions[0].1.r#type {
+            Type::Sequence(list) => assert_eq!(
+                vec![
+                    Some(EffectiveTag {
+                        tag: Tag::ContextSpecific(0),
+                        mode: Some(TaggingMode::Implicit)
+                    }),
+                    Some(EffectiveTag {
+                        tag: Tag::ContextSpecific(1),
+                        mode: Some(TaggingMode::Explicit)
+                    }),
+                    Some(EffectiveTag {
+                        tag: Tag::ContextSpecific(2),
+                        mode: Some(TaggingMode::Implicit)
+                    }),
+                ],
+                model.effective_field_tags(list)
+            ),
+            other => panic!("Expected SEQUENCE but got {:?}", other),
         }
-        END
-    ";
+        // a tagged variant disables automatic tagging
+        match &model.definitions[1].1.r#type {
+            Type::Choice(choice) => assert_eq!(
+                vec![
+                    Some(EffectiveTag {
+                        tag: Tag::ContextSpecific(5),
+                        mode: Some(TaggingMode::Implicit)
+                    }),
+                    Some(EffectiveTag {
+                        tag: Tag::Universal(2),
+                        mode: None
+                    }),
+                ],
+                model.effective_variant_tags(choice)
+            ),
+            other => panic!("Expected CHOICE but got {:?}", other),
+        }
+    }
 
     #[test]
-    fn test_inline_asn_sequence_represented_correctly_as_asn_model() {
-        let model = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_SEQUENCE)).unwrap();
+    fn test_parsing_of_module_header_with_imports() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"ITS-Container {
+                itu-t (0) identified-organization (4) etsi (0) itsDomain (5) wg1 (1) ts (102894)
+                cdd (2) version (2)
+            }
+            DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
 
-        assert_eq!("SimpleSchema", model.name);
-        assert_eq!(true, model.imports.is_empty());
-        assert_eq!(1, model.definitions.len());
+            EXPORTS ALL;
+
+            IMPORTS
+                Foo, Bar{} FROM ModuleA { iso(1) 0 16 } WITH SUCCESSORS
+                baz FROM ModuleB id-moduleB
+                Qux FROM ModuleC
+                quux FROM ModuleD;
+
+            Flag ::= BOOLEAN
+
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!("ITS-Container", model.name);
         assert_eq!(
-            Definition(
-                "Woah".into(),
-                Type::Sequence(vec![Field {
-                    name: "complex".into(),
-                    role: Type::Sequence(vec![
-                        Field {
-                            name: "ones".into(),
-                            role: Type::Integer(Some(Range(0, 1))).untagged(),
-                        },
-                        Field {
-                            name: "list-ones".into(),
-                            role: Type::SequenceOf(Box::new(Type::Integer(Some(Range(0, 1)))))
-                                .untagged(),
-                        },
-                        Field {
-                            name: "optional-ones".into(),
-                            role: Type::SequenceOf(Box::new(Type::Integer(Some(Range(0, 1)))))
-                                .optional()
-                                .untagged(),
+            Some(vec![0, 4, 0, 5, 1, 102894, 2, 2]),
+            model
+                .oid
+                .as_ref()
+                .map(|oid| oid.0.iter().filter_map(|c| c.number).collect::<Vec<_>>())
+        );
+        assert_eq!(
+            Some(&ObjIdComponent {
+                name: Some("itu-t".into()),
+                number: Some(0),
+            }),
+            model.oid.as_ref().and_then(|oid| oid.0.first())
+        );
+        assert_eq!(TagDefault::Automatic, model.tag_default);
+        assert_eq!(Exports::All, model.exports);
+        assert!(!model.extensibility_implied);
+        assert_eq!(
+            vec![
+                Import {
+                    what: vec!["Foo".into(), "Bar".into()],
+                    from: "ModuleA".into(),
+                    oid: Some(ObjectIdentifierValue(vec![
+                        ObjIdComponent {
+                            name: Some("iso".into()),
+                            number: Some(1),
                         },
-                    ])
-                    .optional()
-                    .untagged(),
-                }])
-                .untagged()
-            ),
-            model.definitions[0]
+                        ObjIdComponent::number(0),
+                        ObjIdComponent::number(16),
+                    ])),
+                    selection: Some(SelectionOption::Successors),
+                },
+                Import {
+                    what: vec!["baz".into()],
+                    from: "ModuleB".into(),
+                    oid: Some(ObjectIdentifierValue(vec![ObjIdComponent::name(
+                        "id-moduleB"
+                    )])),
+                    selection: None,
+                },
+                Import {
+                    what: vec!["Qux".into()],
+                    from: "ModuleC".into(),
+                    oid: None,
+                    selection: None,
+                },
+                Import {
+                    what: vec!["quux".into()],
+                    from: "ModuleD".into(),
+                    oid: None,
+                    selection: None,
+                },
+            ],
+            model.imports
         );
+        assert_eq!(1, model.definitions.len());
     }
 
     #[test]
-    fn test_nice_names() {
-        let mut model = Model::default();
+    fn test_parsing_of_exports_and_extensibility_implied() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"Extensible DEFINITIONS IMPLICIT TAGS EXTENSIBILITY IMPLIED ::= BEGIN
 
-        model.name = "SimpleTest".into();
-        model.make_names_nice();
-        assert_eq!("simple_test", model.to_rust().name);
+            EXPORTS Flags, Container{};
 
-        model.name = "SIMPLE_Test".into();
-        model.make_names_nice();
-        assert_eq!("simple_test", model.to_rust().name);
+            Flags ::= SEQUENCE {
+                a BOOLEAN,
+                b CHOICE { x NULL, y BOOLEAN, ..., z INTEGER }
+            }
 
-        model.name = "DRY_Module".into();
-        model.make_names_nice();
-        assert_eq!("dry", model.to_rust().name);
+            Color ::= ENUMERATED { red, green }
 
-        model.name = "DRYModule".into();
-        model.make_names_nice();
-        assert_eq!("dry", model.to_rust().name);
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(TagDefault::Implicit, model.tag_default);
+        assert!(model.extensibility_implied);
+        assert_eq!(
+            Exports::Symbols(vec!["Flags".into(), "Container".into()]),
+            model.exports
+        );
+        match &model.definitions[0].1.r#type {
+            Type::Sequence(list) => {
+                assert_eq!(Some(1), list.extension_after);
+                match &list.fields[1].role.r#type {
+                    Type::Choice(choice) => assert_eq!(Some(1), choice.extension_after),
+                    other => panic!("Expected CHOICE but got {:?}", other),
+                }
+            }
+            other => panic!("Expected SEQUENCE but got {:?}", other),
+        }
+        match &model.definitions[1].1.r#type {
+            Type::Enumerated(enumerated) => assert_eq!(Some(1), enumerated.extension_after),
+            other => panic!("Expected ENUMERATED but got {:?}", other),
+        }
     }
 
     #[test]
-    pub fn test_integer_type_with_range() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"
-            SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
-            BEGIN
-    
-            SimpleTypeWithRange ::= Integer (0..65535)
-            
-            END
-        ",
-        ))
-        .expect("Failed to parse");
-
-        assert_eq!("SimpleSchema", &model.name);
-        assert_eq!(
-            &[Definition(
-                "SimpleTypeWithRange".to_string(),
-                Type::Integer(Some(Range(0, 65_535))).untagged()
-            )][..],
-            &model.definitions[..]
+    fn test_parsing_of_exports_nothing() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(r"Nothing DEFINITIONS ::= BEGIN EXPORTS; Flag ::= BOOLEAN END")
+                .unwrap(),
         )
+        .unwrap();
+        assert_eq!(Exports::Symbols(Vec::new()), model.exports);
     }
 
     #[test]
-    pub fn test_string_type() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"
-            SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
-            BEGIN
-    
-            SimpleUnicodeStringType ::= UTF8String
-            SimpleAsciiStringType ::= IA5String
-            
-            END
-        ",
-        ))
-        .expect("Failed to parse");
-
-        assert_eq!("SimpleSchema", &model.name);
+    fn test_parsing_of_invalid_module_header() {
         assert_eq!(
-            &[Definition(
-                "SimpleUnicodeStringType".to_string(),
-                Type::UTF8String.untagged()
-            )][..],
-            &model.definitions[..]
+            Error::expected_text(
+                "DEFINITIONS".into(),
+                Token::Symbol(
+                    Span::new(Location::at(1, 9), Location::at(1, 12)),
+                    Symbol::Assignment
+                )
+            ),
+            Model::try_from(Tokenizer.parse("Invalid ::= BEGIN END").unwrap()).unwrap_err()
+        );
+        assert_eq!(
+            Error::unexpected_token(Token::Text(
+                Span::new(Location::at(1, 31), Location::at(1, 38)),
+                "ALIGNED".into()
+            )),
+            Model::try_from(
+                Tokenizer
+                    .parse("Invalid DEFINITIONS AUTOMATIC ALIGNED ::= BEGIN END")
+                    .unwrap()
+            )
+            .unwrap_err()
         );
+    }
+
+    #[test]
+    fn test_parsing_with_comments() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r#"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            /* a block comment /* with a nested
+               comment */ Ignored ::= BOOLEAN */
+            Message ::= SEQUENCE { -- the fields -- range INTEGER (-5..5),
+                label UTF8String DEFAULT "-- no comment"
+            }
+
+            END"#,
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
         assert_eq!(
             &[Definition(
-                "SimpleAsciiStringType".to_string(),
-                Type::IA5String.untagged()
+                "Message".into(),
+                Type::sequence_from_fields(vec![
+                    Field {
+                        name: "range".into(),
+                        role: Type::Integer(Integer::with_range(-5, 5)).untagged(),
+                        default: None,
+                    },
+                    Field {
+                        name: "label".into(),
+                        role: Type::UTF8String(Size::Any).untagged(),
+                        default: Some(LiteralValue::String("-- no comment".into())),
+                    },
+                ])
+                .untagged(),
             )][..],
             &model.definitions[..]
         );
     }
 
     #[test]
-    pub fn test_enumerated_advanced() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
-            BEGIN
-    
-            Basic ::= ENUMERATED {
-                abc,
-                def
-            }
-    
-            WithExplicitNumber ::= ENUMERATED {
-                abc(1),
-                def(9)
-            }
-            
-            WithExplicitNumberAndDefaultMark ::= ENUMERATED {
-                abc(4),
-                def(7),
-                ...
-            }
-            
-            WithExplicitNumberAndDefaultMarkV2 ::= ENUMERATED {
-                abc(8),
-                def(1),
-                ...,
-                v2(11)
+    pub fn test_parsing_of_default_values() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r#"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Defaults ::= SEQUENCE {
+                number INTEGER (0..255) DEFAULT 42,
+                negative INTEGER DEFAULT -3,
+                flag BOOLEAN DEFAULT FALSE,
+                name UTF8String DEFAULT "some ""name""",
+                color Color DEFAULT dark-blue,
+                optional BOOLEAN OPTIONAL
             }
-            
-            END
-        ",
-        ))
+
+            END"#,
+                )
+                .unwrap(),
+        )
         .expect("Failed to parse");
 
-        assert_eq!("SimpleSchema", &model.name);
         assert_eq!(
-            &[
-                Definition(
-                    "Basic".to_string(),
-                    Type::Enumerated(Enumerated::from_names(["abc", "def"].iter())).untagged(),
-                ),
-                Definition(
-                    "WithExplicitNumber".to_string(),
-                    Type::Enumerated(Enumerated {
-                        variants: vec![
-                            EnumeratedVariant::from_name_number("abc", 1),
-                            EnumeratedVariant::from_name_number("def", 9)
-                        ],
-                        extension_after: None,
-                    })
-                    .untagged(),
-                ),
-                Definition(
-                    "WithExplicitNumberAndDefaultMark".to_string(),
-                    Type::Enumerated(Enumerated {
-                        variants: vec![
-                            EnumeratedVariant::from_name_number("abc", 4),
-                            EnumeratedVariant::from_name_number("def", 7),
-                        ],
-                        extension_after: Some(1),
-                    })
-                    .untagged(),
-                ),
-                Definition(
-                    "WithExplicitNumberAndDefaultMarkV2".to_string(),
-                    Type::Enumerated(Enumerated {
-                        variants: vec![
-                            EnumeratedVariant::from_name_number("abc", 8),
-                            EnumeratedVariant::from_name_number("def", 1),
-                            EnumeratedVariant::from_name_number("v2", 11)
-                        ],
-                        extension_after: Some(1),
-                    })
-                    .untagged(),
-                )
-            ][..],
+            &[Definition::new(
+                "Defaults",
+                Type::sequence_from_fields(vec![
+                    Field {
+                        name: "number".into(),
+                        role: Type::Integer(Integer::with_range(0, 255)).untagged(),
+                        default: Some(LiteralValue::Integer(42)),
+                    },
+                    Field {
+                        name: "negative".into(),
+                        role: Type::Integer(Integer::default()).untagged(),
+                        default: Some(LiteralValue::Integer(-3)),
+                    },
+                    Field {
+                        name: "flag".into(),
+                        role: Type::Boolean.untagged(),
+                        default: Some(LiteralValue::Boolean(false)),
+                    },
+                    Field {
+                        name: "name".into(),
+                        role: Type::UTF8String(Size::Any).untagged(),
+                        default: Some(LiteralValue::String("some \"name\"".into())),
+                    },
+                    Field {
+                        name: "color".into(),
+                        role: Type::TypeReference("Color".into()).untagged(),
+                        default: Some(LiteralValue::EnumeratedVariant("dark-blue".into())),
+                    },
+                    Field {
+                        name: "optional".into(),
+                        role: Type::Boolean.optional().untagged(),
+                        default: None,
+                    },
+                ])
+                .untagged()
+            )][..],
             &model.definitions[..]
-        )
+        );
     }
 
-    #[test]
-    pub fn test_enumerated_tags() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
-            BEGIN
-    
-            Universal ::= [UNIVERSAL 2] ENUMERATED {
-                abc,
-                def
-            }
-    
-            Application ::= [APPLICATION 7] ENUMERATED {
-                abc,
-                def
-            }
-            
-            Private ::= [PRIVATE 11] ENUMERATED {
-                abc,
-                def
-            }
-            
-            ContextSpecific ::= [8] ENUMERATED {
-                abc,
-                def
-            }
-            
-            END
-        ",
-        ))
+    #[test]
+    pub fn test_parsing_of_invalid_default_value() {
+        assert_eq!(
+            Error::invalid_default_value(Token::Separator(
+                Span::new(Location::at(3, 60), Location::at(3, 61)),
+                '('
+            )),
+            Model::try_from(
+                Tokenizer
+                    .parse(
+                        r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+                Invalid ::= SEQUENCE { abc INTEGER DEFAULT ( }
+
+                END",
+                    )
+                    .unwrap()
+            )
+            .expect_err("Parsed invalid definition")
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_of_value_references() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            maxNumberOfPoints INTEGER ::= 32
+
+            Points ::= SEQUENCE (SIZE(1..maxNumberOfPoints)) OF Point
+
+            Point ::= INTEGER (minValue..maxValue)
+
+            minValue INTEGER ::= -5
+            maxValue INTEGER (0..255) ::= 5
+            defaultColor Color ::= green
+
+            END",
+                )
+                .unwrap(),
+        )
         .expect("Failed to parse");
 
-        assert_eq!("SimpleSchema", &model.name);
         assert_eq!(
             &[
-                Definition(
-                    "Universal".to_string(),
-                    Type::Enumerated(Enumerated::from_names(["abc", "def"].iter()))
-                        .tagged(Tag::Universal(2))
-                ),
-                Definition(
-                    "Application".to_string(),
-                    Type::Enumerated(Enumerated::from_names(["abc", "def"].iter()))
-                        .tagged(Tag::Application(7))
-                ),
-                Definition(
-                    "Private".to_string(),
-                    Type::Enumerated(Enumerated::from_names(["abc", "def"].iter()))
-                        .tagged(Tag::Private(11))
+                Definition::new(
+                    "Points",
+                    Type::SequenceOf(
+                        Box::new(Type::TypeReference("Point".into())),
+                        Size::Range(1, 32, false)
+                    )
+                    .untagged()
                 ),
-                Definition(
-                    "ContextSpecific".to_string(),
-                    Type::Enumerated(Enumerated::from_names(["abc", "def"].iter()))
-                        .tagged(Tag::ContextSpecific(8))
+                Definition::new(
+                    "Point",
+                    Type::Integer(Integer::with_range(-5, 5)).untagged()
                 ),
             ][..],
             &model.definitions[..]
+        );
+        assert_eq!(
+            &[
+                ValueReference {
+                    name: "maxNumberOfPoints".into(),
+                    role: Type::Integer(Integer::default()),
+                    value: LiteralValue::Integer(32),
+                },
+                ValueReference {
+                    name: "minValue".into(),
+                    role: Type::Integer(Integer::default()),
+                    value: LiteralValue::Integer(-5),
+                },
+                ValueReference {
+                    name: "maxValue".into(),
+                    role: Type::Integer(Integer::with_range(0, 255)),
+                    value: LiteralValue::Integer(5),
+                },
+                ValueReference {
+                    name: "defaultColor".into(),
+                    role: Type::TypeReference("Color".into()),
+                    value: LiteralValue::EnumeratedVariant("green".into()),
+                },
+            ][..],
+            &model.value_references[..]
+        );
+    }
+
+    #[test]
+    pub fn test_parsing_of_negative_numbers_regardless_of_whitespace() {
+        let model = Model::try_from(
+            Tokenizer
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Adjacent ::= INTEGER (-5..-1)
+            Spaced ::= INTEGER (- 5 .. - 1)
+
+            END",
+                )
+                .unwrap(),
         )
+        .expect("Failed to parse");
+
+        assert_eq!(
+            Type::Integer(Integer::with_range(-5, -1)),
+            model.definitions[0].1.r#type
+        );
+        assert_eq!(model.definitions[0].1, model.definitions[1].1);
     }
 
     #[test]
-    pub fn test_parsing_tags_in_front_of_definitions_does_not_fail() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
-            BEGIN
-    
-            Universal ::= [UNIVERSAL 2] SEQUENCE {
-                abc [1] INTEGER(0..MAX),
-                def [2] INTEGER(0..255)
+    pub fn test_parsing_of_parameterized_types() {
+        let model = Model::try_from(Tokenizer.parse(
+            r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Container {ElementType, INTEGER : maxSize} ::= SEQUENCE (SIZE(1..maxSize)) OF ElementType
+
+            Points ::= Container {Point, maxNumberOfPoints}
+
+            Tagged {Payload} ::= SEQUENCE {
+                id INTEGER (0..255),
+                payload Payload
             }
-    
-            Application ::= [APPLICATION 7] SEQUENCE OF Utf8String
-            
-            Private ::= [PRIVATE 11] ENUMERATED {
-                abc,
-                def
+
+            maxNumberOfPoints INTEGER ::= 4
+
+            Record ::= SEQUENCE {
+                points Container {Point, 2},
+                more Container {Point, 4},
+                tagged Tagged {Container {BOOLEAN, 1}}
             }
-            
-            ContextSpecific ::= [8] INTEGER(0..MAX)
-            
-            END
-        ",
-        ))
+
+            Point ::= INTEGER (0..255)
+
+            END",
+        ).unwrap())
         .expect("Failed to parse");
 
-        assert_eq!("SimpleSchema", &model.name);
         assert_eq!(
             &[
-                Definition(
-                    "Universal".to_string(),
-                    Type::Sequence(vec![
+                Definition::new(
+                    "Points",
+                    Type::TypeReference("Container-Point-4".into()).untagged()
+                ),
+                Definition::new(
+                    "Record",
+                    Type::sequence_from_fields(vec![
                         Field {
-                            name: "abc".to_string(),
-                            role: Type::Integer(None).tagged(Tag::ContextSpecific(1)),
+                            name: "points".into(),
+                            role: Type::TypeReference("Container-Point-2".into()).untagged(),
+                            default: None,
                         },
                         Field {
-                            name: "def".to_string(),
-                            role: Type::Integer(Some(Range(0, 255)))
-                                .tagged(Tag::ContextSpecific(2)),
-                        }
+                            name: "more".into(),
+                            role: Type::TypeReference("Container-Point-4".into()).untagged(),
+                            default: None,
+                        },
+                        Field {
+                            name: "tagged".into(),
+                            role: Type::TypeReference("Tagged-Container-BOOLEAN-1".into())
+                                .untagged(),
+                            default: None,
+                        },
                     ])
-                    .tagged(Tag::Universal(2)),
+                    .untagged()
                 ),
-                Definition(
-                    "Application".to_string(),
-                    Type::SequenceOf(Box::new(Type::UTF8String)).tagged(Tag::Application(7))
+                Definition::new(
+                    "Point",
+                    Type::Integer(Integer::with_range(0, 255)).untagged()
                 ),
-                Definition(
-                    "Private".to_string(),
-                    Type::Enumerated(Enumerated::from_names(["abc", "def"].iter()))
-                        .tagged(Tag::Private(11))
+                Definition::new(
+                    "Container-Point-4",
+                    Type::SequenceOf(
+                        Box::new(Type::TypeReference("Point".into())),
+                        Size::Range(1, 4, false)
+                    )
+                    .untagged()
                 ),
-                Definition(
-                    "ContextSpecific".to_string(),
-                    Type::Integer(None).tagged(Tag::ContextSpecific(8))
+                Definition::new(
+                    "Container-Point-2",
+                    Type::SequenceOf(
+                        Box::new(Type::TypeReference("Point".into())),
+                        Size::Range(1, 2, false)
+                    )
+                    .untagged()
+                ),
+                Definition::new(
+                    "Tagged-Container-BOOLEAN-1",
+                    Type::sequence_from_fields(vec![
+                        Field {
+                            name: "id".into(),
+                            role: Type::Integer(Integer::with_range(0, 255)).untagged(),
+                            default: None,
+                        },
+                        Field {
+                            name: "payload".into(),
+                            role: Type::TypeReference("Container-BOOLEAN-1".into()).untagged(),
+                            default: None,
+                        },
+                    ])
+                    .untagged()
+                ),
+                Definition::new(
+                    "Container-BOOLEAN-1",
+                    Type::SequenceOf(Box::new(Type::Boolean), Size::Range(1, 1, false)).untagged()
                 ),
             ][..],
             &model.definitions[..]
-        )
+        );
     }
 
     #[test]
-    pub fn test_parsing_of_extensible_choices() {
-        let model = Model::try_from(Tokenizer::default().parse(
-            r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
+    fn test_parsing_of_information_object_classes() {
+        let model = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::=
             BEGIN
-    
-            WithoutMarker ::= CHOICE {
-                abc Utf8String,
-                def Utf8String
-            }
-            
-            WithoutExtensionPresent ::= CHOICE {
-                abc Utf8String,
-                def Utf8String,
+
+            MESSAGE-ID-AND-TYPE ::= CLASS {
+                &id     MessageId UNIQUE,
+                &Type
+            } WITH SYNTAX { &Type IDENTIFIED BY &id }
+
+            MessageTypes MESSAGE-ID-AND-TYPE ::= {
+                { Position IDENTIFIED BY position } |
+                { BOOLEAN IDENTIFIED BY 7 },
                 ...
             }
-    
-            WithExtensionPresent ::= CHOICE {
-                abc Utf8String,
-                def Utf8String,
-                ...,
-                ghi Utf8String
+
+            MessageFrame ::= SEQUENCE {
+                messageId MESSAGE-ID-AND-TYPE.&id ({MessageTypes}),
+                value     MESSAGE-ID-AND-TYPE.&Type ({MessageTypes}{@messageId})
             }
-            
-            END
-        ",
-        ))
+
+            RawFrame ::= SEQUENCE {
+                id      RAW-ID-AND-TYPE.&id ({RawTypes}),
+                content RAW-ID-AND-TYPE.&Type ({RawTypes}{@.id})
+            }
+
+            RawTypes RAW-ID-AND-TYPE ::= { {&id 1, &Type OCTET STRING}, ... }
+
+            RAW-ID-AND-TYPE ::= CLASS { &id INTEGER (0..255), &Type }
+
+            MessageId ::= INTEGER (0..255)
+            position MessageId ::= 3
+            Position ::= SEQUENCE { x INTEGER, y INTEGER }
+
+            END",
+                )
+                .unwrap(),
+        )
         .expect("Failed to parse");
 
-        assert_eq!("SimpleSchema", model.name.as_str());
         assert_eq!(
             &[
                 Definition::new(
-                    "WithoutMarker",
-                    Type::Choice(Choice {
-                        variants: vec![
-                            ChoiceVariant::name_type("abc", Type::UTF8String),
-                            ChoiceVariant::name_type("def", Type::UTF8String),
-                        ],
-                        extension_after: None
-                    })
+                    "MessageFrame",
+                    Type::sequence_from_fields(vec![
+                        Field {
+                            name: "messageId".into(),
+                            role: Type::TypeReference("MessageId".into()).untagged(),
+                            default: None,
+                        },
+                        Field {
+                            name: "value".into(),
+                            role: Type::OpenType(OpenType {
+                                class: "MESSAGE-ID-AND-TYPE".into(),
+                                field: "&Type".into(),
+                                object_set: "MessageTypes".into(),
+                                key_field: "messageId".into(),
+                                variants: vec![
+                                    OpenTypeVariant {
+                                        name: "Position".into(),
+                                        key: 3,
+                                        r#type: Type::TypeReference("Position".into()),
+                                    },
+                                    OpenTypeVariant {
+                                        name: "Type7".into(),
+                                        key: 7,
+                                        r#type: Type::Boolean,
+                                    },
+                                ],
+                            })
+                            .untagged(),
+                            default: None,
+                        },
+                    ])
                     .untagged()
                 ),
                 Definition::new(
-                    "WithoutExtensionPresent",
-                    Type::Choice(Choice {
-                        variants: vec![
-                            ChoiceVariant::name_type("abc", Type::UTF8String),
-                            ChoiceVariant::name_type("def", Type::UTF8String),
-                        ],
-                        extension_after: Some(1)
-                    })
+                    "RawFrame",
+                    Type::sequence_from_fields(vec![
+                        Field {
+                            name: "id".into(),
+                            role: Type::Integer(Integer::with_range(0, 255)).untagged(),
+                            default: None,
+                        },
+                        Field {
+                            name: "content".into(),
+                            role: Type::OpenType(OpenType {
+                                class: "RAW-ID-AND-TYPE".into(),
+                                field: "&Type".into(),
+                                object_set: "RawTypes".into(),
+                                key_field: "id".into(),
+                                variants: vec![OpenTypeVariant {
+                                    name: "Type1".into(),
+                                    key: 1,
+                                    r#type: Type::OctetString(Size::Any),
+                                }],
+                            })
+                            .untagged(),
+                            default: None,
+                        },
+                    ])
                     .untagged()
                 ),
                 Definition::new(
-                    "WithExtensionPresent",
-                    Type::Choice(Choice {
-                        variants: vec![
-                            ChoiceVariant::name_type("abc", Type::UTF8String),
-                            ChoiceVariant::name_type("def", Type::UTF8String),
-                            ChoiceVariant::name_type("ghi", Type::UTF8String),
-                        ],
-                        extension_after: Some(1)
-                    })
+                    "MessageId",
+                    Type::Integer(Integer::with_range(0, 255)).untagged()
+                ),
+                Definition::new(
+                    "Position",
+                    Type::sequence_from_fields(vec![
+                        Field {
+                            name: "x".into(),
+                            role: Type::Integer(Integer::default()).untagged(),
+                            default: None,
+                        },
+                        Field {
+                            name: "y".into(),
+                            role: Type::Integer(Integer::default()).untagged(),
+                            default: None,
+                        },
+                    ])
                     .untagged()
-                )
+                ),
             ][..],
             &model.definitions[..]
-        )
-    }
-
-    #[test]
-    pub fn test_parsing_of_extensible_with_markers_at_invalid_locations() {
-        assert_eq!(
-            Error::invalid_position_for_extension_marker(Token::Separator(
-                Location::at(4, 21),
-                '.'
-            )),
-            Model::try_from(Tokenizer::default().parse(
-                r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
-
-                Invalid ::= CHOICE {
-                    ...
-                }
-                
-                END",
-            ))
-            .expect_err("Parsed invalid definition")
-        );
-
-        assert_eq!(
-            Error::invalid_position_for_extension_marker(Token::Separator(
-                Location::at(4, 21),
-                '.'
-            )),
-            Model::try_from(Tokenizer::default().parse(
-                r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
-    
-                Invalid ::= CHOICE {
-                    ...,
-                    abc Utf8String
-                }
-                
-                END",
-            ))
-            .expect_err("Parsed invalid definition")
-        );
-
-        assert_eq!(
-            Error::invalid_position_for_extension_marker(Token::Separator(
-                Location::at(4, 21),
-                '.'
-            )),
-            Model::try_from(Tokenizer::default().parse(
-                r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
-    
-                Invalid ::= ENUMERATED {
-                    ...
-                }
-                
-                END",
-            ))
-            .expect_err("Parsed invalid definition")
-        );
-
-        assert_eq!(
-            Error::invalid_position_for_extension_marker(Token::Separator(
-                Location::at(4, 21),
-                '.'
-            )),
-            Model::try_from(Tokenizer::default().parse(
-                r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
-
-                Invalid ::= ENUMERATED {
-                    ...,
-                    abc(77)
-                }
-                
-                END",
-            ))
-            .expect_err("Parsed invalid definition")
         );
     }
 }
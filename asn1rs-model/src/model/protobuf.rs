@@ -16,8 +16,20 @@ pub enum ProtobufType {
     UInt64,
     SInt32,
     SInt64,
+    Double,
     String,
     Bytes,
+    /// A `bytes` field with the number of unused bits in the last byte
+    /// as first byte, see `asn1rs::syn::BitVec::to_prefixed_bytes`
+    BitsPrefixed,
+    /// An empty `bytes` field, its presence is the value
+    Null,
+    /// A `string` field in dotted notation, see `asn1rs::syn::ObjectIdentifier`
+    ObjectIdentifier,
+    /// A `string` field in the form of `asn1rs::syn::GeneralizedTime`
+    GeneralizedTime,
+    /// A `string` field in the form of `asn1rs::syn::UtcTime`
+    UtcTime,
     Repeated(Box<ProtobufType>),
     OneOf(Vec<(String, ProtobufType)>),
     /// Indicates a complex, custom type that is
@@ -34,15 +46,23 @@ impl ProtobufType {
         #[allow(clippy::match_same_arms)] // to have the same order as the original enum
         match self {
             ProtobufType::Bool => RustType::Bool,
-            ProtobufType::SFixed32 => RustType::I32(Range(0, i32::max_value())),
-            ProtobufType::SFixed64 => RustType::I64(Range(0, i64::max_value())),
-            ProtobufType::UInt32 => RustType::U32(Range(0, u32::max_value())),
+            ProtobufType::SFixed32 => RustType::I32(Range::inclusive(0, i32::MAX)),
+            ProtobufType::SFixed64 => RustType::I64(Range::inclusive(Some(0), Some(i64::MAX))),
+            ProtobufType::UInt32 => RustType::U32(Range::inclusive(0, u32::MAX)),
             ProtobufType::UInt64 => RustType::U64(None),
-            ProtobufType::SInt32 => RustType::I32(Range(0, i32::max_value())),
-            ProtobufType::SInt64 => RustType::I64(Range(0, i64::max_value())),
-            ProtobufType::String => RustType::String,
-            ProtobufType::Bytes => RustType::VecU8,
-            ProtobufType::Repeated(inner) => RustType::Vec(Box::new(inner.to_rust())),
+            ProtobufType::SInt32 => RustType::I32(Range::inclusive(0, i32::MAX)),
+            ProtobufType::SInt64 => RustType::I64(Range::inclusive(Some(0), Some(i64::MAX))),
+            ProtobufType::Double => RustType::F64,
+            ProtobufType::String => RustType::String(Size::Any),
+            ProtobufType::Bytes => RustType::VecU8(Size::Any),
+            ProtobufType::BitsPrefixed => RustType::BitVec(BitString::default()),
+            ProtobufType::Null => RustType::Null,
+            ProtobufType::ObjectIdentifier => RustType::ObjectIdentifier,
+            ProtobufType::GeneralizedTime => RustType::GeneralizedTime,
+            ProtobufType::UtcTime => RustType::UtcTime,
+            ProtobufType::Repeated(inner) => {
+                RustType::Vec(Box::new(inner.to_rust()), Size::Any, EncodingOrdering::Keep)
+            }
             ProtobufType::OneOf(_) => panic!("ProtobufType::OneOf cannot be mapped to a RustType"),
             ProtobufType::Complex(name) => RustType::Complex(name.clone()),
         }
@@ -58,8 +78,14 @@ impl ProtobufType {
             ProtobufType::UInt64 => true,
             ProtobufType::SInt32 => true,
             ProtobufType::SInt64 => true,
+            ProtobufType::Double => true,
             ProtobufType::String => true,
             ProtobufType::Bytes => true,
+            ProtobufType::BitsPrefixed => true,
+            ProtobufType::Null => true,
+            ProtobufType::ObjectIdentifier => true,
+            ProtobufType::GeneralizedTime => true,
+            ProtobufType::UtcTime => true,
             ProtobufType::OneOf(_) => false,
             ProtobufType::Complex(_) => false,
             ProtobufType::Repeated(_) => false,
@@ -77,8 +103,14 @@ impl ToString for ProtobufType {
             ProtobufType::UInt64 => "uint64",
             ProtobufType::SInt32 => "sint32",
             ProtobufType::SInt64 => "sint64",
+            ProtobufType::Double => "double",
             ProtobufType::String => "string",
             ProtobufType::Bytes => "bytes",
+            ProtobufType::BitsPrefixed => "bits",
+            ProtobufType::Null => "null",
+            ProtobufType::ObjectIdentifier => "oid",
+            ProtobufType::GeneralizedTime => "generalized_time",
+            ProtobufType::UtcTime => "utc_time",
             ProtobufType::OneOf(_) => "oneof",
             ProtobufType::Complex(name) => return name.clone(),
             ProtobufType::Repeated(name) => return format!("repeated {}", name.to_string()),
@@ -109,6 +141,14 @@ impl Model<Protobuf> {
             name: rust_model.name.clone(),
             imports: rust_model.imports.clone(),
             definitions: Vec::with_capacity(rust_model.definitions.len()),
+            value_references: Default::default(), // constants are not supported in protobuf
+            tag_default: rust_model.tag_default,
+            oid: rust_model.oid.clone(),
+            exports: rust_model.exports.clone(),
+            extensibility_implied: rust_model.extensibility_implied,
+            definition_spans: Default::default(),
+            component_spans: Default::default(),
+            imports_span: Default::default(),
         };
         for Definition(name, rust) in &rust_model.definitions {
             let proto = Self::definition_to_protobuf(rust);
@@ -121,7 +161,7 @@ impl Model<Protobuf> {
 
     pub fn definition_to_protobuf(rust: &Rust) -> Protobuf {
         match rust {
-            Rust::Struct(fields) => {
+            Rust::Struct { fields, .. } => {
                 let mut proto_fields = Vec::with_capacity(fields.len());
                 for field in fields.iter() {
                     proto_fields.push((
@@ -132,10 +172,13 @@ impl Model<Protobuf> {
 
                 Protobuf::Message(proto_fields)
             }
-            Rust::Enum(r_enum) => {
-                Protobuf::Enum(r_enum.variants().map(|v| proto_variant_name(v)).collect())
-            }
-            Rust::DataEnum(enumeration) => {
+            Rust::Enum(r_enum) => Protobuf::Enum(
+                r_enum
+                    .variants()
+                    .map(|v| proto_variant_name(v.name()))
+                    .collect(),
+            ),
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 let mut proto_enum = Vec::with_capacity(enumeration.len());
                 for variant in enumeration.variants() {
                     proto_enum.push((
@@ -167,8 +210,15 @@ impl Model<Protobuf> {
             RustType::I32(_) => ProtobufType::SInt32,
             RustType::U64(_) => ProtobufType::UInt64,
             RustType::I64(_) => ProtobufType::SInt64,
-            RustType::String => ProtobufType::String,
-            RustType::VecU8 => ProtobufType::Bytes,
+            RustType::F64 => ProtobufType::Double,
+            RustType::String(_) => ProtobufType::String,
+            RustType::CharacterString(_) => ProtobufType::String,
+            RustType::VecU8(_) => ProtobufType::Bytes,
+            RustType::BitVec(_) => ProtobufType::BitsPrefixed,
+            RustType::Null => ProtobufType::Null,
+            RustType::ObjectIdentifier => ProtobufType::ObjectIdentifier,
+            RustType::GeneralizedTime => ProtobufType::GeneralizedTime,
+            RustType::UtcTime => ProtobufType::UtcTime,
 
             RustType::Complex(complex) => ProtobufType::Complex(complex.clone()),
 
@@ -177,7 +227,9 @@ impl Model<Protobuf> {
                 Self::definition_type_to_protobuf_type(inner)
             }
 
-            RustType::Vec(inner) => {
+            RustType::Box(inner) => Self::definition_type_to_protobuf_type(inner),
+
+            RustType::Vec(inner, _, _) => {
                 ProtobufType::Repeated(Box::new(Self::definition_type_to_protobuf_type(inner)))
             }
         }
@@ -217,6 +269,8 @@ mod tests {
         model_rust.imports = vec![Import {
             what: vec!["a".into(), "b".into()],
             from: "some_very_specific_module".into(),
+            oid: None,
+            selection: None,
         }];
         let model_proto = model_rust.to_protobuf();
         assert_eq!(model_rust.name, model_proto.name);
@@ -229,9 +283,9 @@ mod tests {
         test_model_definition_conversion(
             &[Definition(
                 "Mine".into(),
-                Rust::Struct(vec![Field::from_name_type(
+                Rust::struct_from_fields(vec![Field::from_name_type(
                     "field",
-                    RustType::U8(Range(0, 255)),
+                    RustType::U8(Range::inclusive(0, 255)),
                 )]),
             )],
             &[Definition(
@@ -277,9 +331,9 @@ mod tests {
         test_model_definition_conversion(
             &[Definition(
                 "SuchStruct".into(),
-                Rust::Struct(vec![Field::from_name_type(
+                Rust::struct_from_fields(vec![Field::from_name_type(
                     "very_optional",
-                    RustType::Option(Box::new(RustType::String)),
+                    RustType::Option(Box::new(RustType::String(Size::Any))),
                 )]),
             )],
             &[Definition(
@@ -295,7 +349,11 @@ mod tests {
             &[Definition(
                 "SuchDataEnum".into(),
                 Rust::DataEnum(
-                    vec![DataVariant::from_name_type("MuchVariant", RustType::String)].into(),
+                    vec![DataVariant::from_name_type(
+                        "MuchVariant",
+                        RustType::String(Size::Any),
+                    )]
+                    .into(),
                 ),
             )],
             &[Definition(
@@ -316,7 +374,10 @@ mod tests {
                     "First".into(),
                     Rust::Enum(vec!["A".into(), "B".into()].into()),
                 ),
-                Definition("Second".into(), Rust::TupleStruct(RustType::VecU8)),
+                Definition(
+                    "Second".into(),
+                    Rust::TupleStruct(RustType::VecU8(Size::Any)),
+                ),
             ],
             &[
                 Definition("First".into(), Protobuf::Enum(vec!["A".into(), "B".into()])),
@@ -2,24 +2,27 @@ use crate::model::rust::Field as RustField;
 use crate::model::Model;
 use crate::model::Range;
 use crate::model::Type as AsnType;
-use crate::model::{Asn, ChoiceVariant};
-use crate::model::{Definition, Type};
-use crate::model::{Import, Tag, TagProperty};
+use crate::model::{Asn, BitString, CharacterString, ChoiceVariant, Integer, Size};
+use crate::model::{Definition, Exports, Type};
+use crate::model::{Import, LiteralValue, Tag, TagProperty, TaggingMode, ValueReference};
+use std::collections::HashMap;
 
-const I8_MAX: i64 = i8::max_value() as i64;
-const I16_MAX: i64 = i16::max_value() as i64;
-const I32_MAX: i64 = i32::max_value() as i64;
-//const I64_MAX: i64 = i64::max_value() as i64;
+const I8_MAX: i64 = i8::MAX as i64;
+const I16_MAX: i64 = i16::MAX as i64;
+const I32_MAX: i64 = i32::MAX as i64;
+//const I64_MAX: i64 = i64::MAX as i64;
 
-const U8_MAX: u64 = u8::max_value() as u64;
-const U16_MAX: u64 = u16::max_value() as u64;
-const U32_MAX: u64 = u32::max_value() as u64;
-//const U64_MAX: u64 = u64::max_value() as u64;
+const U8_MAX: u64 = u8::MAX as u64;
+const U16_MAX: u64 = u16::MAX as u64;
+const U32_MAX: u64 = u32::MAX as u64;
+//const U64_MAX: u64 = u64::MAX as u64;
 
-pub type PlainVariant = String;
 pub type PlainEnum = Enumeration<PlainVariant>;
 pub type DataEnum = Enumeration<DataVariant>;
 
+/// The name of the variant of an open type, which holds the content of an unknown type
+pub const OPEN_TYPE_UNKNOWN_VARIANT: &str = "Unknown";
+
 /// Integers are ordered where Ixx < Uxx so
 /// that when comparing two instances `RustType`
 /// and a > b, then the integer type of a can
@@ -34,12 +37,24 @@ pub enum RustType {
     U16(Range<u16>),
     I32(Range<i32>),
     U32(Range<u32>),
-    I64(Range<i64>),
+    I64(Range<Option<i64>>),
     U64(Option<Range<u64>>),
-    String,
-    VecU8,
-    Vec(Box<RustType>),
+    F64,
+    String(Size),
+    /// A `String` restricted to the characters of a known-multiplier character string type
+    CharacterString(CharacterString),
+    VecU8(Size),
+    /// A `BitVec`, its constants are named after rust fields
+    BitVec(BitString),
+    Null,
+    ObjectIdentifier,
+    GeneralizedTime,
+    UtcTime,
+    /// A `Vec` of the elements of a `SEQUENCE OF` or `SET OF`
+    Vec(Box<RustType>, Size, EncodingOrdering),
     Option(Box<RustType>),
+    /// A heap allocated type, which breaks the cycle of a type that contains itself
+    Box(Box<RustType>),
 
     /// Indicates a complex, custom type that is
     /// not one of rusts known types. This can be
@@ -48,12 +63,22 @@ pub enum RustType {
     Complex(String),
 }
 
+/// Whether the order of the fields or elements is significant
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq, Hash)]
+pub enum EncodingOrdering {
+    /// The order is kept as given, like for `SEQUENCE` and `SEQUENCE OF`
+    Keep,
+    /// The order is not significant, like for `SET` and `SET OF`. The fields of a `SET`
+    /// are therefore sorted canonically by their tags.
+    Sort,
+}
+
 impl RustType {
     pub fn as_inner_type(&self) -> &RustType {
         if self.is_primitive() {
             return self;
         }
-        if let RustType::Vec(inner) | RustType::Option(inner) = self {
+        if let RustType::Vec(inner, _, _) | RustType::Option(inner) | RustType::Box(inner) = self {
             inner.as_inner_type()
         } else {
             self
@@ -64,7 +89,7 @@ impl RustType {
         if self.is_primitive() {
             return self;
         }
-        if let RustType::Vec(inner) | RustType::Option(inner) = self {
+        if let RustType::Vec(inner, _, _) | RustType::Option(inner) | RustType::Box(inner) = self {
             inner.into_inner_type()
         } else {
             self
@@ -75,7 +100,7 @@ impl RustType {
         if self.is_primitive() {
             return Some(self.to_string());
         }
-        if let RustType::Vec(inner) | RustType::Option(inner) = self {
+        if let RustType::Vec(inner, _, _) | RustType::Option(inner) | RustType::Box(inner) = self {
             inner.to_inner()
         } else {
             None
@@ -102,7 +127,7 @@ impl RustType {
     }
 
     pub fn is_vec(&self) -> bool {
-        if let RustType::Vec(_) = self.as_no_option() {
+        if let RustType::Vec(..) = self.as_no_option() {
             true
         } else {
             false
@@ -117,6 +142,15 @@ impl RustType {
         }
     }
 
+    /// The `Complex` type, that is stored within a value of this type - and not on the heap
+    fn contained_by_value_mut(&mut self) -> Option<&mut RustType> {
+        match self {
+            RustType::Option(inner) => inner.contained_by_value_mut(),
+            RustType::Complex(_) => Some(self),
+            _ => None,
+        }
+    }
+
     pub fn is_primitive(&self) -> bool {
         #[allow(clippy::match_same_arms)] // to have the same order as the original enum
         match self {
@@ -129,6 +163,7 @@ impl RustType {
             RustType::I32(_) => true,
             RustType::U64(_) => true,
             RustType::I64(_) => true,
+            RustType::F64 => true,
             _ => false,
         }
     }
@@ -137,19 +172,44 @@ impl RustType {
         #[allow(clippy::match_same_arms)] // to have the same order as the original enum
         match self {
             RustType::Bool => None,
-            RustType::U8(Range(min, max)) => Some(Range(min.to_string(), max.to_string())),
-            RustType::I8(Range(min, max)) => Some(Range(min.to_string(), max.to_string())),
-            RustType::U16(Range(min, max)) => Some(Range(min.to_string(), max.to_string())),
-            RustType::I16(Range(min, max)) => Some(Range(min.to_string(), max.to_string())),
-            RustType::U32(Range(min, max)) => Some(Range(min.to_string(), max.to_string())),
-            RustType::I32(Range(min, max)) => Some(Range(min.to_string(), max.to_string())),
-            RustType::U64(None) => Some(Range("0".into(), i64::max_value().to_string())), // i64 max!
-            RustType::U64(Some(Range(min, max))) => Some(Range(min.to_string(), max.to_string())),
-            RustType::I64(Range(min, max)) => Some(Range(min.to_string(), max.to_string())),
-            RustType::String => None,
-            RustType::VecU8 => None,
-            RustType::Vec(inner) => inner.integer_range_str(),
+            RustType::U8(Range(min, max, _)) => {
+                Some(Range::inclusive(min.to_string(), max.to_string()))
+            }
+            RustType::I8(Range(min, max, _)) => {
+                Some(Range::inclusive(min.to_string(), max.to_string()))
+            }
+            RustType::U16(Range(min, max, _)) => {
+                Some(Range::inclusive(min.to_string(), max.to_string()))
+            }
+            RustType::I16(Range(min, max, _)) => {
+                Some(Range::inclusive(min.to_string(), max.to_string()))
+            }
+            RustType::U32(Range(min, max, _)) => {
+                Some(Range::inclusive(min.to_string(), max.to_string()))
+            }
+            RustType::I32(Range(min, max, _)) => {
+                Some(Range::inclusive(min.to_string(), max.to_string()))
+            }
+            RustType::U64(None) => Some(Range::inclusive("0".into(), i64::MAX.to_string())), // i64 max!
+            RustType::U64(Some(Range(min, max, _))) => {
+                Some(Range::inclusive(min.to_string(), max.to_string()))
+            }
+            RustType::I64(Range(min, max, _)) => Some(Range::inclusive(
+                min.unwrap_or_else(i64::min_value).to_string(),
+                max.unwrap_or_else(i64::max_value).to_string(),
+            )),
+            RustType::F64 => None,
+            RustType::String(_) => None,
+            RustType::CharacterString(_) => None,
+            RustType::VecU8(_) => None,
+            RustType::BitVec(_) => None,
+            RustType::Null => None,
+            RustType::ObjectIdentifier => None,
+            RustType::GeneralizedTime => None,
+            RustType::UtcTime => None,
+            RustType::Vec(inner, _, _) => inner.integer_range_str(),
             RustType::Option(inner) => inner.integer_range_str(),
+            RustType::Box(inner) => inner.integer_range_str(),
             RustType::Complex(_) => None,
         }
     }
@@ -157,33 +217,46 @@ impl RustType {
     pub fn into_asn(self) -> AsnType {
         match self {
             RustType::Bool => AsnType::Boolean,
-            RustType::I8(Range(min, max)) => {
-                AsnType::Integer(Some(Range(i64::from(min), i64::from(max))))
+            RustType::I8(Range(min, max, extensible)) => AsnType::Integer(Integer {
+                range: Range(Some(i64::from(min)), Some(i64::from(max)), extensible),
+            }),
+            RustType::U8(Range(min, max, extensible)) => AsnType::Integer(Integer {
+                range: Range(Some(i64::from(min)), Some(i64::from(max)), extensible),
+            }),
+            RustType::I16(Range(min, max, extensible)) => AsnType::Integer(Integer {
+                range: Range(Some(i64::from(min)), Some(i64::from(max)), extensible),
+            }),
+            RustType::U16(Range(min, max, extensible)) => AsnType::Integer(Integer {
+                range: Range(Some(i64::from(min)), Some(i64::from(max)), extensible),
+            }),
+            RustType::I32(Range(min, max, extensible)) => AsnType::Integer(Integer {
+                range: Range(Some(i64::from(min)), Some(i64::from(max)), extensible),
+            }),
+            RustType::U32(Range(min, max, extensible)) => AsnType::Integer(Integer {
+                range: Range(Some(i64::from(min)), Some(i64::from(max)), extensible),
+            }),
+            RustType::I64(range) => AsnType::Integer(Integer { range }),
+            RustType::U64(Some(Range(min, max, extensible))) => AsnType::Integer(Integer {
+                range: Range(Some(min as i64), Some(max as i64), extensible),
+            }),
+            RustType::U64(None) => AsnType::Integer(Integer::with_lower_bound(0)),
+            RustType::F64 => AsnType::Real,
+            RustType::String(size) => AsnType::UTF8String(size),
+            RustType::CharacterString(string) => AsnType::CharacterString(string),
+            RustType::VecU8(size) => AsnType::OctetString(size),
+            RustType::BitVec(bit_string) => AsnType::BitString(bit_string),
+            RustType::Null => AsnType::Null,
+            RustType::ObjectIdentifier => AsnType::ObjectIdentifier,
+            RustType::GeneralizedTime => AsnType::GeneralizedTime,
+            RustType::UtcTime => AsnType::UTCTime,
+            RustType::Vec(inner, size, EncodingOrdering::Keep) => {
+                AsnType::SequenceOf(Box::new(inner.into_asn()), size)
             }
-            RustType::U8(Range(min, max)) => {
-                AsnType::Integer(Some(Range(i64::from(min), i64::from(max))))
+            RustType::Vec(inner, size, EncodingOrdering::Sort) => {
+                AsnType::SetOf(Box::new(inner.into_asn()), size)
             }
-            RustType::I16(Range(min, max)) => {
-                AsnType::Integer(Some(Range(i64::from(min), i64::from(max))))
-            }
-            RustType::U16(Range(min, max)) => {
-                AsnType::Integer(Some(Range(i64::from(min), i64::from(max))))
-            }
-            RustType::I32(Range(min, max)) => {
-                AsnType::Integer(Some(Range(i64::from(min), i64::from(max))))
-            }
-            RustType::U32(Range(min, max)) => {
-                AsnType::Integer(Some(Range(i64::from(min), i64::from(max))))
-            }
-            RustType::I64(Range(min, max)) => AsnType::Integer(Some(Range(min, max))),
-            RustType::U64(Some(Range(min, max))) => {
-                AsnType::Integer(Some(Range(min as i64, max as i64)))
-            }
-            RustType::U64(None) => AsnType::Integer(None),
-            RustType::String => AsnType::UTF8String,
-            RustType::VecU8 => AsnType::OctetString,
-            RustType::Vec(inner) => AsnType::SequenceOf(Box::new(inner.into_asn())),
             RustType::Option(value) => AsnType::Optional(Box::new(value.into_asn())),
+            RustType::Box(value) => AsnType::Boxed(Box::new(value.into_asn())),
             RustType::Complex(name) => AsnType::TypeReference(name),
         }
     }
@@ -231,18 +304,28 @@ impl RustType {
                     return true;
                 }
             }
-            RustType::String => {
-                if let RustType::String = other {
+            RustType::F64 => return *other == RustType::F64,
+            RustType::String(_) | RustType::CharacterString(_) => {
+                if let RustType::String(_) | RustType::CharacterString(_) = other {
+                    return true;
+                }
+            }
+            RustType::VecU8(_) => {
+                if let RustType::VecU8(_) = other {
                     return true;
                 }
             }
-            RustType::VecU8 => {
-                if let RustType::VecU8 = other {
+            RustType::BitVec(_) => {
+                if let RustType::BitVec(_) = other {
                     return true;
                 }
             }
-            RustType::Vec(inner_a) => {
-                if let RustType::Vec(inner_b) = other {
+            RustType::Null => return *other == RustType::Null,
+            RustType::ObjectIdentifier => return *other == RustType::ObjectIdentifier,
+            RustType::GeneralizedTime => return *other == RustType::GeneralizedTime,
+            RustType::UtcTime => return *other == RustType::UtcTime,
+            RustType::Vec(inner_a, _, _) => {
+                if let RustType::Vec(inner_b, _, _) = other {
                     return inner_a.similar(inner_b);
                 }
             }
@@ -251,6 +334,11 @@ impl RustType {
                     return inner_a.similar(inner_b);
                 }
             }
+            RustType::Box(inner_a) => {
+                if let RustType::Box(inner_b) = other {
+                    return inner_a.similar(inner_b);
+                }
+            }
             RustType::Complex(inner_a) => {
                 if let RustType::Complex(inner_b) = other {
                     return inner_a.eq(inner_b);
@@ -263,14 +351,36 @@ impl RustType {
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub enum Rust {
-    Struct(Vec<Field>),
+    Struct {
+        fields: Vec<Field>,
+        /// The index of the last field of the extension root
+        extension_after: Option<usize>,
+        /// The indices of the first and last field of each extension addition group
+        extension_groups: Vec<Range<usize>>,
+        /// `Sort` for a `SET`, whose fields are in canonical order
+        ordering: EncodingOrdering,
+    },
     Enum(PlainEnum),
     DataEnum(DataEnum),
+    /// The known types of an open type, each identified by its key, and a variant without
+    /// a key, which holds the encoded content of any other type
+    OpenType(DataEnum),
 
     /// Used to represent a single, unnamed inner type
     TupleStruct(RustType),
 }
 
+impl Rust {
+    pub fn struct_from_fields(fields: Vec<Field>) -> Self {
+        Self::Struct {
+            fields,
+            extension_after: None,
+            extension_groups: Vec::new(),
+            ordering: EncodingOrdering::Keep,
+        }
+    }
+}
+
 impl ToString for RustType {
     fn to_string(&self) -> String {
         match self {
@@ -283,10 +393,18 @@ impl ToString for RustType {
             RustType::I32(_) => "i32",
             RustType::U64(_) => "u64",
             RustType::I64(_) => "i64",
-            RustType::String => "String",
-            RustType::VecU8 => "Vec<u8>",
-            RustType::Vec(inner) => return format!("Vec<{}>", inner.to_string()),
+            RustType::F64 => "f64",
+            RustType::String(_) => "String",
+            RustType::CharacterString(_) => "String",
+            RustType::VecU8(_) => "Vec<u8>",
+            RustType::BitVec(_) => "BitVec",
+            RustType::Null => "Null",
+            RustType::ObjectIdentifier => "ObjectIdentifier",
+            RustType::GeneralizedTime => "GeneralizedTime",
+            RustType::UtcTime => "UtcTime",
+            RustType::Vec(inner, _, _) => return format!("Vec<{}>", inner.to_string()),
             RustType::Option(inner) => return format!("Option<{}>", inner.to_string()),
+            RustType::Box(inner) => return format!("Box<{}>", inner.to_string()),
             RustType::Complex(name) => return name.clone(),
         }
         .into()
@@ -296,17 +414,46 @@ impl ToString for RustType {
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct Field {
     name_type: (String, RustType),
+    asn_name: String,
     tag: Option<Tag>,
+    tagging: Option<TaggingMode>,
+    default: Option<LiteralValue>,
+    key_field: Option<String>,
 }
 
 impl Field {
     pub fn from_name_type<T: ToString>(name: T, r#type: RustType) -> Self {
+        let name = name.to_string();
         Self {
-            name_type: (name.to_string(), r#type),
+            asn_name: name.clone(),
+            name_type: (name, r#type),
             tag: None,
+            tagging: None,
+            default: None,
+            key_field: None,
         }
     }
 
+    pub fn with_asn_name<T: ToString>(mut self, asn_name: T) -> Self {
+        self.asn_name = asn_name.to_string();
+        self
+    }
+
+    pub fn with_tagging(mut self, tagging: Option<TaggingMode>) -> Self {
+        self.tagging = tagging;
+        self
+    }
+
+    pub fn with_default_opt(mut self, default: Option<LiteralValue>) -> Self {
+        self.default = default;
+        self
+    }
+
+    pub fn with_key_field_opt(mut self, key_field: Option<String>) -> Self {
+        self.key_field = key_field;
+        self
+    }
+
     pub fn fallback_representation(&self) -> &(String, RustType) {
         &self.name_type
     }
@@ -315,9 +462,37 @@ impl Field {
         &self.name_type.0
     }
 
+    /// The name of the field in the ASN.1 definition
+    pub fn asn_name(&self) -> &str {
+        &self.asn_name
+    }
+
     pub fn r#type(&self) -> &RustType {
         &self.name_type.1
     }
+
+    /// The tagging mode of the tag, which is resolved for each tagged field
+    pub fn tagging(&self) -> Option<TaggingMode> {
+        self.tagging
+    }
+
+    /// The value of the `DEFAULT` clause, which refers to enumerated variants by their
+    /// rust name
+    pub fn default_value(&self) -> Option<&LiteralValue> {
+        self.default.as_ref()
+    }
+
+    /// Whether the field is encoded with a presence bit, which is the case for
+    /// `OPTIONAL` fields and fields with a `DEFAULT` value
+    pub fn is_optional_or_default(&self) -> bool {
+        self.r#type().is_option() || self.default.is_some()
+    }
+
+    /// The name of the sibling field, whose value determines the actual type of this
+    /// open type field
+    pub fn key_field(&self) -> Option<&str> {
+        self.key_field.as_deref()
+    }
 }
 
 impl TagProperty for Field {
@@ -331,6 +506,7 @@ impl TagProperty for Field {
 
     fn reset_tag(&mut self) {
         self.tag = None;
+        self.tagging = None;
     }
 }
 
@@ -383,24 +559,129 @@ impl<T> Enumeration<T> {
 
 impl PlainEnum {
     pub fn from_names(names: impl Iterator<Item = impl ToString>) -> Self {
-        Self::from(names.map(|n| n.to_string()).collect::<Vec<_>>())
+        Self::from(names.map(PlainVariant::from_name).collect::<Vec<_>>())
+    }
+
+    /// The numbers of the variants, in the order of the variants. Variants without a number
+    /// of the extension root are numbered with the smallest numbers from zero not assigned
+    /// otherwise (X.680-201508 20.3), extension additions without a number with the
+    /// smallest number greater than those of the preceding additions (X.680-201508 20.4).
+    pub fn variant_numbers(&self) -> Vec<usize> {
+        let root = self
+            .extended_after_index
+            .map_or(self.variants.len(), |index| index + 1);
+        let assigned = self.variants[..root]
+            .iter()
+            .filter_map(PlainVariant::number)
+            .collect::<Vec<_>>();
+        let mut numbers = Vec::with_capacity(self.variants.len());
+        for (index, variant) in self.variants.iter().enumerate() {
+            let number = variant.number().unwrap_or_else(|| {
+                let min = if index < root {
+                    0
+                } else {
+                    numbers[root..].iter().max().map_or(0, |max| max + 1)
+                };
+                (min..)
+                    .find(|number| !assigned.contains(number) && !numbers.contains(number))
+                    .unwrap_or(min)
+            });
+            numbers.push(number);
+        }
+        numbers
+    }
+}
+
+/// A variant of an enumerated with the number assigned to it in the definition, if any
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct PlainVariant {
+    name: String,
+    asn_name: String,
+    number: Option<usize>,
+}
+
+impl PlainVariant {
+    pub fn from_name<T: ToString>(name: T) -> Self {
+        let name = name.to_string();
+        Self {
+            asn_name: name.clone(),
+            name,
+            number: None,
+        }
+    }
+
+    pub fn with_asn_name<T: ToString>(mut self, asn_name: T) -> Self {
+        self.asn_name = asn_name.to_string();
+        self
+    }
+
+    pub fn with_number_opt(mut self, number: Option<usize>) -> Self {
+        self.number = number;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The name of the variant in the ASN.1 definition
+    pub fn asn_name(&self) -> &str {
+        &self.asn_name
+    }
+
+    pub fn number(&self) -> Option<usize> {
+        self.number
+    }
+}
+
+impl<T: ToString> From<T> for PlainVariant {
+    fn from(name: T) -> Self {
+        Self::from_name(name)
+    }
+}
+
+impl AsRef<str> for PlainVariant {
+    fn as_ref(&self) -> &str {
+        self.name()
     }
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub struct DataVariant {
     name_type: (String, RustType),
+    asn_name: String,
     tag: Option<Tag>,
+    tagging: Option<TaggingMode>,
+    key: Option<i64>,
 }
 
 impl DataVariant {
     pub fn from_name_type<T: ToString>(name: T, r#type: RustType) -> Self {
+        let name = name.to_string();
         Self {
-            name_type: (name.to_string(), r#type),
+            asn_name: name.clone(),
+            name_type: (name, r#type),
             tag: None,
+            tagging: None,
+            key: None,
         }
     }
 
+    pub fn with_asn_name<T: ToString>(mut self, asn_name: T) -> Self {
+        self.asn_name = asn_name.to_string();
+        self
+    }
+
+    pub fn with_tagging(mut self, tagging: Option<TaggingMode>) -> Self {
+        self.tagging = tagging;
+        self
+    }
+
+    pub fn with_key(mut self, key: i64) -> Self {
+        self.key = Some(key);
+        self
+    }
+
     pub fn fallback_representation(&self) -> &(String, RustType) {
         &self.name_type
     }
@@ -409,9 +690,24 @@ impl DataVariant {
         &self.name_type.0
     }
 
+    /// The name of the variant in the ASN.1 definition
+    pub fn asn_name(&self) -> &str {
+        &self.asn_name
+    }
+
     pub fn r#type(&self) -> &RustType {
         &self.name_type.1
     }
+
+    /// The tagging mode of the tag, which is resolved for each tagged variant
+    pub fn tagging(&self) -> Option<TaggingMode> {
+        self.tagging
+    }
+
+    /// The key identifying the type of this variant of an open type
+    pub fn key(&self) -> Option<i64> {
+        self.key
+    }
 }
 
 impl TagProperty for DataVariant {
@@ -425,11 +721,34 @@ impl TagProperty for DataVariant {
 
     fn reset_tag(&mut self) {
         self.tag = None;
+        self.tagging = None;
     }
 }
 
 impl Model<Rust> {
+    /// Converts the given model, whose `SET`s become structs with the fields in the canonical
+    /// order of their tags, in which they are encoded ([`EncodingOrdering::Sort`])
     pub fn convert_asn_to_rust(asn_model: &Model<Asn>) -> Model<Rust> {
+        Self::convert_asn_to_rust_with_imported_tags(asn_model, &|_| None)
+    }
+
+    /// Like [`Model::convert_asn_to_rust`], but the tags of imported types, which determine
+    /// the order of the fields of `SET`s as well, are looked up with the given function
+    pub(crate) fn convert_asn_to_rust_with_imported_tags(
+        asn_model: &Model<Asn>,
+        imported_tag: &dyn Fn(&str) -> Option<Tag>,
+    ) -> Model<Rust> {
+        let mut encoded = asn_model.clone();
+        for Definition(_, asn) in &mut encoded.definitions {
+            // the tags of referenced types are looked up in the unchanged model
+            asn_model.sort_set_components(&mut asn.r#type, imported_tag);
+        }
+        Self::convert_asn_to_rust_in_given_order(&encoded)
+    }
+
+    /// Converts the given model without sorting the components of `SET`s, which are
+    /// expected in their canonical order already, like those read from `#[asn(set)]`
+    pub(crate) fn convert_asn_to_rust_in_given_order(asn_model: &Model<Asn>) -> Model<Rust> {
         let mut model = Model {
             name: rust_module_name(&asn_model.name),
             imports: asn_model
@@ -438,17 +757,91 @@ impl Model<Rust> {
                 .map(|i| Import {
                     what: i.what.iter().map(|w| rust_struct_or_enum_name(w)).collect(),
                     from: rust_module_name(&i.from),
+                    oid: i.oid.clone(),
+                    selection: i.selection,
                 })
                 .collect(),
             definitions: Vec::with_capacity(asn_model.definitions.len()),
+            value_references: asn_model
+                .value_references
+                .iter()
+                .map(|v| ValueReference {
+                    name: rust_constant_name(&v.name),
+                    role: v.role.clone(),
+                    value: match &v.value {
+                        LiteralValue::EnumeratedVariant(variant) => {
+                            LiteralValue::EnumeratedVariant(rust_variant_name(variant))
+                        }
+                        value => value.clone(),
+                    },
+                })
+                .collect(),
+            tag_default: asn_model.tag_default,
+            oid: asn_model.oid.clone(),
+            exports: match &asn_model.exports {
+                Exports::All => Exports::All,
+                Exports::Symbols(symbols) => Exports::Symbols(
+                    symbols
+                        .iter()
+                        .map(|s| rust_struct_or_enum_name(s))
+                        .collect(),
+                ),
+            },
+            extensibility_implied: asn_model.extensibility_implied,
+            definition_spans: Default::default(),
+            component_spans: Default::default(),
+            imports_span: Default::default(),
         };
         for Definition(name, asn) in &asn_model.definitions {
             let rust_name = rust_struct_or_enum_name(name);
-            Self::definition_to_rust(&rust_name, &asn.r#type, &mut model.definitions);
+            let mut r#type = asn.r#type.clone();
+            asn_model.resolve_component_tags(&mut r#type);
+            Self::definition_to_rust(&rust_name, &r#type, &mut model.definitions);
         }
+        Self::box_recursive_types(&mut model.definitions);
         model
     }
 
+    /// Recursive types would have an infinite size, if they contained themselves by value.
+    /// Therefore, each reference that closes a cycle of definitions containing each other
+    /// by value (not within a `Vec` or `Box`) is wrapped in a `Box`.
+    fn box_recursive_types(definitions: &mut [Definition<Rust>]) {
+        let mut contained: HashMap<String, Vec<String>> = HashMap::default();
+        for Definition(name, rust) in definitions.iter_mut() {
+            let types: Vec<&mut RustType> = match rust {
+                Rust::Struct { fields, .. } => {
+                    fields.iter_mut().map(|f| &mut f.name_type.1).collect()
+                }
+                Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => enumeration
+                    .variants
+                    .iter_mut()
+                    .map(|v| &mut v.name_type.1)
+                    .collect(),
+                Rust::TupleStruct(r#type) => vec![r#type],
+                Rust::Enum(_) => Vec::new(),
+            };
+            for r#type in types {
+                if let Some(complex) = r#type.contained_by_value_mut() {
+                    let reference = complex.to_string();
+                    if Self::contains_by_value(&contained, &reference, name) {
+                        *complex = RustType::Box(Box::new(complex.clone()));
+                    } else {
+                        contained.entry(name.clone()).or_default().push(reference);
+                    }
+                }
+            }
+        }
+    }
+
+    fn contains_by_value(contained: &HashMap<String, Vec<String>>, from: &str, to: &str) -> bool {
+        from == to
+            || contained
+                .get(from)
+                .into_iter()
+                .flatten()
+                .any(|next| Self::contains_by_value(contained, next, to))
+    }
+
     /// Converts the given `Asn` value to `Rust`, adding new `Defintion`s as
     /// necessary (inlined types cannot be represented in rust and thus need to
     /// be extracted to their own types).
@@ -460,9 +853,16 @@ impl Model<Rust> {
         match asn {
             AsnType::Boolean
             | AsnType::Integer(_)
-            | AsnType::UTF8String
-            | AsnType::IA5String
-            | AsnType::OctetString
+            | AsnType::UTF8String(_)
+            | AsnType::CharacterString(_)
+            | AsnType::OctetString(_)
+            | AsnType::BitString(_)
+            | AsnType::Null
+            | AsnType::Real
+            | AsnType::ObjectIdentifier
+            | AsnType::GeneralizedTime
+            | AsnType::UTCTime
+            | AsnType::Boxed(_)
             | AsnType::TypeReference(_) => {
                 let rust_type = Self::definition_type_to_rust_type(name, asn, defs);
                 defs.push(Definition(name.into(), Rust::TupleStruct(rust_type)));
@@ -475,24 +875,52 @@ impl Model<Rust> {
                 defs.push(Definition(name.into(), Rust::TupleStruct(inner)))
             }
 
-            AsnType::Sequence(fields) => {
-                let mut rust_fields = Vec::with_capacity(fields.len());
+            AsnType::Sequence(list) | AsnType::Set(list) => {
+                let ordering = if let AsnType::Set(_) = asn {
+                    EncodingOrdering::Sort
+                } else {
+                    EncodingOrdering::Keep
+                };
+                let mut rust_fields = Vec::with_capacity(list.fields.len());
 
-                for field in fields.iter() {
+                for field in list.fields.iter() {
                     let rust_name = format!("{}{}", name, rust_struct_or_enum_name(&field.name));
                     let rust_role =
                         Self::definition_type_to_rust_type(&rust_name, &field.role.r#type, defs);
+                    let key_field = match &field.role.r#type {
+                        AsnType::OpenType(open) => Some(rust_field_name(&open.key_field)),
+                        _ => None,
+                    };
                     let rust_field_name = rust_field_name(&field.name);
-                    rust_fields.push(RustField::from_name_type(rust_field_name, rust_role));
+                    let default = field.default.clone().map(|value| match value {
+                        LiteralValue::EnumeratedVariant(variant) => {
+                            LiteralValue::EnumeratedVariant(rust_variant_name(&variant))
+                        }
+                        value => value,
+                    });
+                    rust_fields.push(
+                        RustField::from_name_type(rust_field_name, rust_role)
+                            .with_asn_name(&field.name)
+                            .with_tag_opt(field.role.tag)
+                            .with_tagging(field.role.tagging)
+                            .with_default_opt(default)
+                            .with_key_field_opt(key_field),
+                    );
                 }
 
-                defs.push(Definition(name.into(), Rust::Struct(rust_fields)));
+                defs.push(Definition(
+                    name.into(),
+                    Rust::Struct {
+                        fields: rust_fields,
+                        extension_after: list.extension_after_index(),
+                        extension_groups: list.extension_groups.clone(),
+                        ordering,
+                    },
+                ));
             }
 
-            AsnType::SequenceOf(asn) => {
-                let inner = RustType::Vec(Box::new(Self::definition_type_to_rust_type(
-                    name, asn, defs,
-                )));
+            AsnType::SequenceOf(..) | AsnType::SetOf(..) => {
+                let inner = Self::definition_type_to_rust_type(name, asn, defs);
                 defs.push(Definition(name.into(), Rust::TupleStruct(inner)));
             }
 
@@ -502,12 +930,21 @@ impl Model<Rust> {
                     extended_after_index: choice.extension_after_index(),
                 };
 
-                for ChoiceVariant { name, r#type, tag } in choice.variants() {
+                for ChoiceVariant {
+                    name,
+                    r#type,
+                    tag,
+                    tagging,
+                } in choice.variants()
+                {
                     let rust_name = format!("{}{}", name, rust_struct_or_enum_name(&name));
                     let rust_role = Self::definition_type_to_rust_type(&rust_name, &r#type, defs);
                     let rust_field_name = rust_variant_name(&name);
                     enumeration.variants.push(
-                        DataVariant::from_name_type(rust_field_name, rust_role).with_tag_opt(*tag),
+                        DataVariant::from_name_type(rust_field_name, rust_role)
+                            .with_asn_name(name)
+                            .with_tag_opt(*tag)
+                            .with_tagging(*tagging),
                     );
                 }
 
@@ -521,11 +958,36 @@ impl Model<Rust> {
                 };
 
                 for variant in enumerated.variants() {
-                    rust_enum.variants.push(rust_variant_name(variant.name()));
+                    rust_enum.variants.push(
+                        PlainVariant::from_name(rust_variant_name(variant.name()))
+                            .with_asn_name(variant.name())
+                            .with_number_opt(variant.number()),
+                    );
                 }
 
                 defs.push(Definition(name.into(), Rust::Enum(rust_enum)));
             }
+
+            AsnType::OpenType(open) => {
+                let mut enumeration =
+                    Enumeration::from(Vec::with_capacity(open.variants.len() + 1));
+
+                for variant in &open.variants {
+                    let rust_name = format!("{}{}", name, rust_struct_or_enum_name(&variant.name));
+                    let rust_role =
+                        Self::definition_type_to_rust_type(&rust_name, &variant.r#type, defs);
+                    enumeration.variants.push(
+                        DataVariant::from_name_type(rust_variant_name(&variant.name), rust_role)
+                            .with_key(variant.key),
+                    );
+                }
+
+                enumeration.variants.push(DataVariant::from_name_type(
+                    OPEN_TYPE_UNKNOWN_VARIANT,
+                    RustType::VecU8(Size::Any),
+                ));
+                defs.push(Definition(name.into(), Rust::OpenType(enumeration)));
+            }
         }
     }
 
@@ -536,44 +998,77 @@ impl Model<Rust> {
     ) -> RustType {
         match asn {
             AsnType::Boolean => RustType::Bool,
-            AsnType::Integer(Some(Range(min, max))) => {
+            AsnType::Integer(Integer {
+                range: Range(Some(min), Some(max), false),
+            }) => {
                 let min = *min;
                 let max = *max;
                 if min >= 0 {
                     match max as u64 {
-                        m if m <= U8_MAX => RustType::U8(Range(min as u8, max as u8)),
-                        m if m <= U16_MAX => RustType::U16(Range(min as u16, max as u16)),
-                        m if m <= U32_MAX => RustType::U32(Range(min as u32, max as u32)),
-                        _/*m if m <= U64_MAX*/ => RustType::U64(Some(Range(min as u64, max as u64))),
+                        m if m <= U8_MAX => RustType::U8(Range::inclusive(min as u8, max as u8)),
+                        m if m <= U16_MAX => RustType::U16(Range::inclusive(min as u16, max as u16)),
+                        m if m <= U32_MAX => RustType::U32(Range::inclusive(min as u32, max as u32)),
+                        _/*m if m <= U64_MAX*/ => RustType::U64(Some(Range::inclusive(min as u64, max as u64))),
                         //_ => panic!("This should never happen, since max (as u64 frm i64) cannot be greater than U64_MAX")
                     }
                 } else {
                     let max_amplitude = (min - 1).abs().max(max);
                     match max_amplitude {
-                        _ if max_amplitude <= I8_MAX => RustType::I8(Range(min as i8, max as i8)),
-                        _ if max_amplitude <= I16_MAX => RustType::I16(Range(min as i16, max as i16)),
-                        _ if max_amplitude <= I32_MAX => RustType::I32(Range(min as i32, max as i32)),
-                        _/*if max_amplitude <= I64_MAX*/ => RustType::I64(Range(min as i64, max as i64)),
+                        _ if max_amplitude <= I8_MAX => RustType::I8(Range::inclusive(min as i8, max as i8)),
+                        _ if max_amplitude <= I16_MAX => RustType::I16(Range::inclusive(min as i16, max as i16)),
+                        _ if max_amplitude <= I32_MAX => RustType::I32(Range::inclusive(min as i32, max as i32)),
+                        _/*if max_amplitude <= I64_MAX*/ => RustType::I64(Range::inclusive(Some(min), Some(max))),
                         //_ => panic!("This should never happen, since max (being i64) cannot be greater than I64_MAX")
                     }
                 }
             }
-            AsnType::Integer(None) => RustType::U64(None),
-            AsnType::UTF8String => RustType::String,
-            AsnType::IA5String => RustType::String,
-            AsnType::OctetString => RustType::VecU8,
+            AsnType::Integer(Integer {
+                range: Range(Some(0), None, false),
+            }) => RustType::U64(None),
+            // values of an extensible range are not limited to its root
+            AsnType::Integer(Integer { range }) => RustType::I64(*range),
+            AsnType::UTF8String(size) => RustType::String(*size),
+            AsnType::CharacterString(string) => RustType::CharacterString(string.clone()),
+            AsnType::OctetString(size) => RustType::VecU8(*size),
+            AsnType::BitString(bit_string) => RustType::BitVec(BitString {
+                size: bit_string.size,
+                constants: bit_string
+                    .constants
+                    .iter()
+                    .map(|(name, position)| (rust_field_name(name), *position))
+                    .collect(),
+            }),
+            AsnType::Null => RustType::Null,
+            AsnType::Real => RustType::F64,
+            AsnType::ObjectIdentifier => RustType::ObjectIdentifier,
+            AsnType::GeneralizedTime => RustType::GeneralizedTime,
+            AsnType::UTCTime => RustType::UtcTime,
             Type::Optional(inner) => RustType::Option(Box::new(
                 Self::definition_type_to_rust_type(name, inner, defs),
             )),
-            AsnType::SequenceOf(asn) => RustType::Vec(Box::new(
-                Self::definition_type_to_rust_type(name, asn, defs),
-            )),
-            AsnType::Sequence(_) | AsnType::Enumerated(_) | AsnType::Choice(_) => {
+            Type::Boxed(inner) => RustType::Box(Box::new(Self::definition_type_to_rust_type(
+                name, inner, defs,
+            ))),
+            AsnType::SequenceOf(asn, size) => RustType::Vec(
+                Box::new(Self::definition_type_to_rust_type(name, asn, defs)),
+                *size,
+                EncodingOrdering::Keep,
+            ),
+            AsnType::SetOf(asn, size) => RustType::Vec(
+                Box::new(Self::definition_type_to_rust_type(name, asn, defs)),
+                *size,
+                EncodingOrdering::Sort,
+            ),
+            AsnType::Sequence(_)
+            | AsnType::Set(_)
+            | AsnType::Enumerated(_)
+            | AsnType::Choice(_)
+            | AsnType::OpenType(_) => {
                 let name = rust_struct_or_enum_name(name);
                 Self::definition_to_rust(&name, asn, defs);
                 RustType::Complex(name)
             }
-            AsnType::TypeReference(name) => RustType::Complex(name.clone()),
+            AsnType::TypeReference(name) => RustType::Complex(rust_struct_or_enum_name(name)),
         }
     }
 }
@@ -600,6 +1095,11 @@ pub fn rust_variant_name(name: &str) -> String {
     out
 }
 
+#[allow(clippy::module_name_repetitions)]
+pub fn rust_constant_name(name: &str) -> String {
+    rust_module_name(name).to_uppercase()
+}
+
 #[allow(clippy::module_name_repetitions)]
 pub fn rust_struct_or_enum_name(name: &str) -> String {
     rust_variant_name(name)
@@ -641,14 +1141,18 @@ pub fn rust_module_name(name: &str) -> String {
 mod tests {
     use super::*;
     use crate::model::tests::*;
-    use crate::model::{Choice, Enumerated, EnumeratedVariant, Field, Tag, Type};
+    use crate::model::{Charset, Choice, Enumerated, EnumeratedVariant, Field, Tag, Type};
     use crate::parser::Tokenizer;
 
     #[test]
     fn test_simple_asn_sequence_represented_correctly_as_rust_model() {
-        let model_rust = Model::try_from(Tokenizer::default().parse(SIMPLE_INTEGER_STRUCT_ASN))
-            .unwrap()
-            .to_rust();
+        let model_rust = Model::try_from(
+            Tokenizer::default()
+                .parse(SIMPLE_INTEGER_STRUCT_ASN)
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
 
         assert_eq!("simple_schema", model_rust.name);
         assert_eq!(true, model_rust.imports.is_empty());
@@ -656,14 +1160,22 @@ mod tests {
         assert_eq!(
             Definition(
                 "Simple".into(),
-                Rust::Struct(vec![
-                    RustField::from_name_type("small", RustType::U8(Range(0, 255))),
-                    RustField::from_name_type("bigger", RustType::U16(Range(0, 65535))),
-                    RustField::from_name_type("negative", RustType::I16(Range(-1, 255))),
+                Rust::struct_from_fields(vec![
+                    RustField::from_name_type("small", RustType::U8(Range::inclusive(0, 255)))
+                        .with_tag(Tag::ContextSpecific(0))
+                        .with_tagging(Some(TaggingMode::Implicit)),
+                    RustField::from_name_type("bigger", RustType::U16(Range::inclusive(0, 65535)))
+                        .with_tag(Tag::ContextSpecific(1))
+                        .with_tagging(Some(TaggingMode::Implicit)),
+                    RustField::from_name_type("negative", RustType::I16(Range::inclusive(-1, 255)))
+                        .with_tag(Tag::ContextSpecific(2))
+                        .with_tagging(Some(TaggingMode::Implicit)),
                     RustField::from_name_type(
                         "unlimited",
                         RustType::Option(Box::new(RustType::U64(None)))
-                    ),
+                    )
+                    .with_tag(Tag::ContextSpecific(3))
+                    .with_tagging(Some(TaggingMode::Implicit)),
                 ])
             ),
             model_rust.definitions[0]
@@ -672,7 +1184,7 @@ mod tests {
 
     #[test]
     fn test_inline_asn_enumerated_represented_correctly_as_rust_model() {
-        let modle_rust = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_ENUM))
+        let modle_rust = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_ENUM).unwrap())
             .unwrap()
             .to_rust();
 
@@ -698,10 +1210,12 @@ mod tests {
         assert_eq!(
             Definition(
                 "Woah".into(),
-                Rust::Struct(vec![RustField::from_name_type(
+                Rust::struct_from_fields(vec![RustField::from_name_type(
                     "decision",
                     RustType::Option(Box::new(RustType::Complex("WoahDecision".into())))
-                )])
+                )
+                .with_tag(Tag::ContextSpecific(0))
+                .with_tagging(Some(TaggingMode::Implicit))])
             ),
             modle_rust.definitions[1]
         );
@@ -709,9 +1223,13 @@ mod tests {
 
     #[test]
     fn test_inline_asn_sequence_of_represented_correctly_as_rust_model() {
-        let model_rust = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_SEQUENCE_OF))
-            .unwrap()
-            .to_rust();
+        let model_rust = Model::try_from(
+            Tokenizer::default()
+                .parse(INLINE_ASN_WITH_SEQUENCE_OF)
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
 
         assert_eq!("simple_schema", model_rust.name);
         assert_eq!(true, model_rust.imports.is_empty());
@@ -719,37 +1237,72 @@ mod tests {
         assert_eq!(
             Definition(
                 "Ones".into(),
-                Rust::TupleStruct(RustType::Vec(Box::new(RustType::U8(Range(0, 1)))))
+                Rust::TupleStruct(RustType::Vec(
+                    Box::new(RustType::U8(Range::inclusive(0, 1))),
+                    Size::Any,
+                    EncodingOrdering::Keep
+                ))
             ),
             model_rust.definitions[0]
         );
         assert_eq!(
             Definition(
                 "NestedOnes".into(),
-                Rust::TupleStruct(RustType::Vec(Box::new(RustType::Vec(Box::new(
-                    RustType::U8(Range(0, 1))
-                )))))
+                Rust::TupleStruct(RustType::Vec(
+                    Box::new(RustType::Vec(
+                        Box::new(RustType::U8(Range::inclusive(0, 1))),
+                        Size::Any,
+                        EncodingOrdering::Keep
+                    )),
+                    Size::Any,
+                    EncodingOrdering::Keep
+                ))
             ),
             model_rust.definitions[1]
         );
         assert_eq!(
             Definition(
                 "Woah".into(),
-                Rust::Struct(vec![
+                Rust::struct_from_fields(vec![
                     RustField::from_name_type(
                         "also_ones",
-                        RustType::Vec(Box::new(RustType::U8(Range(0, 1))))
-                    ),
+                        RustType::Vec(
+                            Box::new(RustType::U8(Range::inclusive(0, 1))),
+                            Size::Any,
+                            EncodingOrdering::Keep
+                        )
+                    )
+                    .with_asn_name("also-ones")
+                    .with_tag(Tag::ContextSpecific(0))
+                    .with_tagging(Some(TaggingMode::Implicit)),
                     RustField::from_name_type(
                         "nesteds",
-                        RustType::Vec(Box::new(RustType::Vec(Box::new(RustType::U8(Range(0, 1))))))
-                    ),
+                        RustType::Vec(
+                            Box::new(RustType::Vec(
+                                Box::new(RustType::U8(Range::inclusive(0, 1))),
+                                Size::Any,
+                                EncodingOrdering::Keep
+                            )),
+                            Size::Any,
+                            EncodingOrdering::Keep
+                        )
+                    )
+                    .with_tag(Tag::ContextSpecific(1))
+                    .with_tagging(Some(TaggingMode::Implicit)),
                     RustField::from_name_type(
                         "optionals",
-                        RustType::Option(Box::new(RustType::Vec(Box::new(RustType::Vec(
-                            Box::new(RustType::U64(None))
-                        )))))
+                        RustType::Option(Box::new(RustType::Vec(
+                            Box::new(RustType::Vec(
+                                Box::new(RustType::U64(None)),
+                                Size::Any,
+                                EncodingOrdering::Keep
+                            )),
+                            Size::Any,
+                            EncodingOrdering::Keep
+                        )))
                     )
+                    .with_tag(Tag::ContextSpecific(2))
+                    .with_tagging(Some(TaggingMode::Implicit))
                 ])
             ),
             model_rust.definitions[2]
@@ -758,9 +1311,10 @@ mod tests {
 
     #[test]
     fn test_inline_asn_choice_represented_correctly_as_rust_model() {
-        let model_rust = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_CHOICE))
-            .unwrap()
-            .to_rust();
+        let model_rust =
+            Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_CHOICE).unwrap())
+                .unwrap()
+                .to_rust();
 
         assert_eq!("simple_schema", model_rust.name);
         assert_eq!(true, model_rust.imports.is_empty());
@@ -768,16 +1322,26 @@ mod tests {
         assert_eq!(
             Definition(
                 "This".into(),
-                Rust::TupleStruct(RustType::Vec(Box::new(RustType::U8(Range(0, 1)))))
+                Rust::TupleStruct(RustType::Vec(
+                    Box::new(RustType::U8(Range::inclusive(0, 1))),
+                    Size::Any,
+                    EncodingOrdering::Keep
+                ))
             ),
             model_rust.definitions[0]
         );
         assert_eq!(
             Definition(
                 "That".into(),
-                Rust::TupleStruct(RustType::Vec(Box::new(RustType::Vec(Box::new(
-                    RustType::U8(Range(0, 1))
-                )))))
+                Rust::TupleStruct(RustType::Vec(
+                    Box::new(RustType::Vec(
+                        Box::new(RustType::U8(Range::inclusive(0, 1))),
+                        Size::Any,
+                        EncodingOrdering::Keep
+                    )),
+                    Size::Any,
+                    EncodingOrdering::Keep
+                ))
             ),
             model_rust.definitions[1]
         );
@@ -793,9 +1357,18 @@ mod tests {
                 "WoahDecision".into(),
                 Rust::DataEnum(
                     vec![
-                        DataVariant::from_name_type("This", RustType::Complex("This".into())),
-                        DataVariant::from_name_type("That", RustType::Complex("That".into())),
-                        DataVariant::from_name_type("Neither", RustType::Complex("Neither".into())),
+                        DataVariant::from_name_type("This", RustType::Complex("This".into()))
+                            .with_asn_name("this")
+                            .with_tag(Tag::ContextSpecific(0))
+                            .with_tagging(Some(TaggingMode::Implicit)),
+                        DataVariant::from_name_type("That", RustType::Complex("That".into()))
+                            .with_asn_name("that")
+                            .with_tag(Tag::ContextSpecific(1))
+                            .with_tagging(Some(TaggingMode::Implicit)),
+                        DataVariant::from_name_type("Neither", RustType::Complex("Neither".into()))
+                            .with_asn_name("neither")
+                            .with_tag(Tag::ContextSpecific(2))
+                            .with_tagging(Some(TaggingMode::Implicit)),
                     ]
                     .into()
                 )
@@ -805,10 +1378,12 @@ mod tests {
         assert_eq!(
             Definition(
                 "Woah".into(),
-                Rust::Struct(vec![RustField::from_name_type(
+                Rust::struct_from_fields(vec![RustField::from_name_type(
                     "decision",
                     RustType::Complex("WoahDecision".into())
-                )])
+                )
+                .with_tag(Tag::ContextSpecific(0))
+                .with_tagging(Some(TaggingMode::Explicit))])
             ),
             model_rust.definitions[4]
         );
@@ -816,9 +1391,13 @@ mod tests {
 
     #[test]
     fn test_inline_asn_sequence_represented_correctly_as_rust_model() {
-        let model_rust = Model::try_from(Tokenizer::default().parse(INLINE_ASN_WITH_SEQUENCE))
-            .unwrap()
-            .to_rust();
+        let model_rust = Model::try_from(
+            Tokenizer::default()
+                .parse(INLINE_ASN_WITH_SEQUENCE)
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
 
         assert_eq!("simple_schema", model_rust.name);
         assert_eq!(true, model_rust.imports.is_empty());
@@ -826,18 +1405,32 @@ mod tests {
         assert_eq!(
             Definition(
                 "WoahComplex".into(),
-                Rust::Struct(vec![
-                    RustField::from_name_type("ones", RustType::U8(Range(0, 1))),
+                Rust::struct_from_fields(vec![
+                    RustField::from_name_type("ones", RustType::U8(Range::inclusive(0, 1)))
+                        .with_tag(Tag::ContextSpecific(0))
+                        .with_tagging(Some(TaggingMode::Implicit)),
                     RustField::from_name_type(
                         "list_ones",
-                        RustType::Vec(Box::new(RustType::U8(Range(0, 1))))
-                    ),
+                        RustType::Vec(
+                            Box::new(RustType::U8(Range::inclusive(0, 1))),
+                            Size::Any,
+                            EncodingOrdering::Keep
+                        )
+                    )
+                    .with_asn_name("list-ones")
+                    .with_tag(Tag::ContextSpecific(1))
+                    .with_tagging(Some(TaggingMode::Implicit)),
                     RustField::from_name_type(
                         "optional_ones",
-                        RustType::Option(Box::new(RustType::Vec(Box::new(RustType::U8(Range(
-                            0, 1
-                        ))))))
-                    ),
+                        RustType::Option(Box::new(RustType::Vec(
+                            Box::new(RustType::U8(Range::inclusive(0, 1))),
+                            Size::Any,
+                            EncodingOrdering::Keep
+                        )))
+                    )
+                    .with_asn_name("optional-ones")
+                    .with_tag(Tag::ContextSpecific(2))
+                    .with_tagging(Some(TaggingMode::Implicit)),
                 ])
             ),
             model_rust.definitions[0]
@@ -845,10 +1438,12 @@ mod tests {
         assert_eq!(
             Definition(
                 "Woah".into(),
-                Rust::Struct(vec![RustField::from_name_type(
+                Rust::struct_from_fields(vec![RustField::from_name_type(
                     "complex",
                     RustType::Option(Box::new(RustType::Complex("WoahComplex".into())))
-                )])
+                )
+                .with_tag(Tag::ContextSpecific(0))
+                .with_tagging(Some(TaggingMode::Implicit))])
             ),
             model_rust.definitions[1]
         );
@@ -871,7 +1466,14 @@ mod tests {
         assert_eq!(
             Definition(
                 "SimpleEnumTest".into(),
-                Rust::Enum(vec!["Bernd".into(), "DasVerdammte".into(), "Brooot".into(),].into())
+                Rust::Enum(
+                    vec![
+                        "Bernd".into(),
+                        PlainVariant::from_name("DasVerdammte").with_asn_name("Das-Verdammte"),
+                        "Brooot".into(),
+                    ]
+                    .into()
+                )
             ),
             model_rust.definitions[0]
         );
@@ -883,9 +1485,12 @@ mod tests {
         model_asn.definitions.push(Definition(
             "SimpleChoiceTest".into(),
             AsnType::Choice(Choice::from(vec![
-                ChoiceVariant::name_type("bernd-das-brot", AsnType::UTF8String),
-                ChoiceVariant::name_type("ascii-choice", AsnType::IA5String),
-                ChoiceVariant::name_type("nochSoEinBrot", AsnType::OctetString),
+                ChoiceVariant::name_type("bernd-das-brot", AsnType::UTF8String(Size::Any)),
+                ChoiceVariant::name_type(
+                    "ascii-choice",
+                    AsnType::CharacterString(CharacterString::new(Charset::Ia5)),
+                ),
+                ChoiceVariant::name_type("nochSoEinBrot", AsnType::OctetString(Size::Any)),
             ]))
             .untagged(),
         ));
@@ -898,9 +1503,15 @@ mod tests {
                 "SimpleChoiceTest".into(),
                 Rust::DataEnum(
                     vec![
-                        DataVariant::from_name_type("BerndDasBrot", RustType::String),
-                        DataVariant::from_name_type("AsciiChoice", RustType::String),
-                        DataVariant::from_name_type("NochSoEinBrot", RustType::VecU8),
+                        DataVariant::from_name_type("BerndDasBrot", RustType::String(Size::Any))
+                            .with_asn_name("bernd-das-brot"),
+                        DataVariant::from_name_type(
+                            "AsciiChoice",
+                            RustType::CharacterString(CharacterString::new(Charset::Ia5))
+                        )
+                        .with_asn_name("ascii-choice"),
+                        DataVariant::from_name_type("NochSoEinBrot", RustType::VecU8(Size::Any))
+                            .with_asn_name("nochSoEinBrot"),
                     ]
                     .into()
                 )
@@ -917,13 +1528,17 @@ mod tests {
             AsnType::Choice(Choice::from(vec![
                 ChoiceVariant::name_type(
                     "normal-List",
-                    AsnType::SequenceOf(Box::new(AsnType::UTF8String)),
+                    AsnType::SequenceOf(Box::new(AsnType::UTF8String(Size::Any)), Size::Any),
                 ),
                 ChoiceVariant::name_type(
                     "NESTEDList",
-                    AsnType::SequenceOf(Box::new(AsnType::SequenceOf(Box::new(
-                        AsnType::OctetString,
-                    )))),
+                    AsnType::SequenceOf(
+                        Box::new(AsnType::SequenceOf(
+                            Box::new(AsnType::OctetString(Size::Any)),
+                            Size::Any,
+                        )),
+                        Size::Any,
+                    ),
                 ),
             ]))
             .untagged(),
@@ -939,11 +1554,24 @@ mod tests {
                     vec![
                         DataVariant::from_name_type(
                             "NormalList",
-                            RustType::Vec(Box::new(RustType::String))
-                        ),
+                            RustType::Vec(
+                                Box::new(RustType::String(Size::Any)),
+                                Size::Any,
+                                EncodingOrdering::Keep
+                            )
+                        )
+                        .with_asn_name("normal-List"),
                         DataVariant::from_name_type(
                             "NESTEDList",
-                            RustType::Vec(Box::new(RustType::Vec(Box::new(RustType::VecU8))))
+                            RustType::Vec(
+                                Box::new(RustType::Vec(
+                                    Box::new(RustType::VecU8(Size::Any)),
+                                    Size::Any,
+                                    EncodingOrdering::Keep
+                                )),
+                                Size::Any,
+                                EncodingOrdering::Keep
+                            )
                         ),
                     ]
                     .into()
@@ -959,7 +1587,7 @@ mod tests {
         model_asn.name = "TupleTestModel".into();
         model_asn.definitions.push(Definition(
             "TupleTest".into(),
-            AsnType::SequenceOf(Box::new(AsnType::UTF8String)).untagged(),
+            AsnType::SequenceOf(Box::new(AsnType::UTF8String(Size::Any)), Size::Any).untagged(),
         ));
         let model_rust = model_asn.to_rust();
         assert_eq!("tuple_test_model", model_rust.name);
@@ -968,7 +1596,11 @@ mod tests {
         assert_eq!(
             Definition(
                 "TupleTest".into(),
-                Rust::TupleStruct(RustType::Vec(Box::new(RustType::String)))
+                Rust::TupleStruct(RustType::Vec(
+                    Box::new(RustType::String(Size::Any)),
+                    Size::Any,
+                    EncodingOrdering::Keep
+                ))
             ),
             model_rust.definitions[0]
         );
@@ -980,8 +1612,14 @@ mod tests {
         model_asn.name = "TupleTestModel".into();
         model_asn.definitions.push(Definition(
             "NestedTupleTest".into(),
-            AsnType::SequenceOf(Box::new(AsnType::SequenceOf(Box::new(AsnType::UTF8String))))
-                .untagged(),
+            AsnType::SequenceOf(
+                Box::new(AsnType::SequenceOf(
+                    Box::new(AsnType::UTF8String(Size::Any)),
+                    Size::Any,
+                )),
+                Size::Any,
+            )
+            .untagged(),
         ));
         let model_rust = model_asn.to_rust();
         assert_eq!("tuple_test_model", model_rust.name);
@@ -990,9 +1628,15 @@ mod tests {
         assert_eq!(
             Definition(
                 "NestedTupleTest".into(),
-                Rust::TupleStruct(RustType::Vec(Box::new(RustType::Vec(Box::new(
-                    RustType::String
-                )))))
+                Rust::TupleStruct(RustType::Vec(
+                    Box::new(RustType::Vec(
+                        Box::new(RustType::String(Size::Any)),
+                        Size::Any,
+                        EncodingOrdering::Keep
+                    )),
+                    Size::Any,
+                    EncodingOrdering::Keep
+                ))
             ),
             model_rust.definitions[0]
         );
@@ -1004,11 +1648,12 @@ mod tests {
         model_asn.name = "OptionalStructListTestModel".into();
         model_asn.definitions.push(Definition(
             "OptionalStructListTest".into(),
-            AsnType::Sequence(vec![Field {
+            AsnType::sequence_from_fields(vec![Field {
                 name: "strings".into(),
-                role: AsnType::SequenceOf(Box::new(AsnType::UTF8String))
+                role: AsnType::SequenceOf(Box::new(AsnType::UTF8String(Size::Any)), Size::Any)
                     .optional()
                     .untagged(),
+                default: None,
             }])
             .untagged(),
         ));
@@ -1019,9 +1664,13 @@ mod tests {
         assert_eq!(
             Definition(
                 "OptionalStructListTest".into(),
-                Rust::Struct(vec![RustField::from_name_type(
+                Rust::struct_from_fields(vec![RustField::from_name_type(
                     "strings",
-                    RustType::Option(Box::new(RustType::Vec(Box::new(RustType::String))))
+                    RustType::Option(Box::new(RustType::Vec(
+                        Box::new(RustType::String(Size::Any)),
+                        Size::Any,
+                        EncodingOrdering::Keep
+                    )))
                 )])
             ),
             model_rust.definitions[0]
@@ -1034,9 +1683,11 @@ mod tests {
         model_asn.name = "StructListTestModel".into();
         model_asn.definitions.push(Definition(
             "StructListTest".into(),
-            AsnType::Sequence(vec![Field {
+            AsnType::sequence_from_fields(vec![Field {
                 name: "strings".into(),
-                role: AsnType::SequenceOf(Box::new(AsnType::UTF8String)).untagged(),
+                role: AsnType::SequenceOf(Box::new(AsnType::UTF8String(Size::Any)), Size::Any)
+                    .untagged(),
+                default: None,
             }])
             .untagged(),
         ));
@@ -1047,9 +1698,13 @@ mod tests {
         assert_eq!(
             Definition(
                 "StructListTest".into(),
-                Rust::Struct(vec![RustField::from_name_type(
+                Rust::struct_from_fields(vec![RustField::from_name_type(
                     "strings",
-                    RustType::Vec(Box::new(RustType::String))
+                    RustType::Vec(
+                        Box::new(RustType::String(Size::Any)),
+                        Size::Any,
+                        EncodingOrdering::Keep
+                    )
                 )])
             ),
             model_rust.definitions[0]
@@ -1062,12 +1717,17 @@ mod tests {
         model_asn.name = "NestedStructListTestModel".into();
         model_asn.definitions.push(Definition(
             "NestedStructListTest".into(),
-            AsnType::Sequence(vec![Field {
+            AsnType::sequence_from_fields(vec![Field {
                 name: "strings".into(),
-                role: AsnType::SequenceOf(Box::new(AsnType::SequenceOf(Box::new(
-                    AsnType::UTF8String,
-                ))))
+                role: AsnType::SequenceOf(
+                    Box::new(AsnType::SequenceOf(
+                        Box::new(AsnType::UTF8String(Size::Any)),
+                        Size::Any,
+                    )),
+                    Size::Any,
+                )
                 .untagged(),
+                default: None,
             }])
             .untagged(),
         ));
@@ -1078,9 +1738,17 @@ mod tests {
         assert_eq!(
             Definition(
                 "NestedStructListTest".into(),
-                Rust::Struct(vec![RustField::from_name_type(
+                Rust::struct_from_fields(vec![RustField::from_name_type(
                     "strings",
-                    RustType::Vec(Box::new(RustType::Vec(Box::new(RustType::String))))
+                    RustType::Vec(
+                        Box::new(RustType::Vec(
+                            Box::new(RustType::String(Size::Any)),
+                            Size::Any,
+                            EncodingOrdering::Keep
+                        )),
+                        Size::Any,
+                        EncodingOrdering::Keep
+                    )
                 )])
             ),
             model_rust.definitions[0]
@@ -1110,13 +1778,38 @@ mod tests {
             &[Definition(
                 "Extensible".into(),
                 Rust::Enum(
-                    PlainEnum::from_names(["Abc", "Def", "Ghi"].iter())
-                        .with_extension_after(Some(2))
+                    PlainEnum::from(vec![
+                        PlainVariant::from_name("Abc").with_asn_name("abc"),
+                        PlainVariant::from_name("Def").with_asn_name("def"),
+                        PlainVariant::from_name("Ghi")
+                            .with_asn_name("ghi")
+                            .with_number_opt(Some(42)),
+                    ])
+                    .with_extension_after(Some(2))
                 )
             )],
             &model_rust.definitions[..]
         );
     }
+
+    #[test]
+    pub fn test_enumerated_variant_numbers() {
+        let numbered = |name: &str, number| PlainVariant::from_name(name).with_number_opt(number);
+        let enumerated = PlainEnum::from(vec![
+            numbered("A", None),
+            numbered("B", Some(0)),
+            numbered("C", None),
+            numbered("D", None),
+            numbered("E", Some(10)),
+            numbered("F", None),
+        ])
+        .with_extension_after(Some(2));
+        assert_eq!(vec![1, 0, 2, 3, 10, 11], enumerated.variant_numbers());
+        assert_eq!(
+            vec![0, 1, 2],
+            PlainEnum::from_names(["A", "B", "C"].iter()).variant_numbers()
+        );
+    }
     #[test]
     pub fn test_extensible_choice() {
         let mut model_asn = Model::default();
@@ -1125,11 +1818,12 @@ mod tests {
             "Extensible".to_string(),
             AsnType::Choice(Choice {
                 variants: vec![
-                    ChoiceVariant::name_type("abc", Type::OctetString),
-                    ChoiceVariant::name_type("def", Type::Integer(None)),
+                    ChoiceVariant::name_type("abc", Type::OctetString(Size::Any)),
+                    ChoiceVariant::name_type("def", Type::Integer(Integer::default())),
                     ChoiceVariant {
                         name: "ghi".to_string(),
                         tag: Some(Tag::Universal(4)),
+                        tagging: None,
                         r#type: Type::Boolean,
                     },
                 ],
@@ -1145,10 +1839,17 @@ mod tests {
                 "Extensible".into(),
                 Rust::DataEnum(
                     DataEnum::from(vec![
-                        DataVariant::from_name_type("Abc".to_string(), RustType::VecU8),
-                        DataVariant::from_name_type("Def".to_string(), RustType::U64(None)),
+                        DataVariant::from_name_type("Abc".to_string(), RustType::VecU8(Size::Any))
+                            .with_asn_name("abc"),
+                        DataVariant::from_name_type(
+                            "Def".to_string(),
+                            RustType::I64(Range::inclusive(None, None))
+                        )
+                        .with_asn_name("def"),
                         DataVariant::from_name_type("Ghi".to_string(), RustType::Bool)
-                            .with_tag(Tag::Universal(4)),
+                            .with_asn_name("ghi")
+                            .with_tag(Tag::Universal(4))
+                            .with_tagging(Some(TaggingMode::Explicit)),
                     ])
                     .with_extension_after(Some(2))
                 )
@@ -1156,4 +1857,184 @@ mod tests {
             &model_rust.definitions[..]
         );
     }
+
+    #[test]
+    pub fn test_fields_carry_their_effective_tagging_mode() {
+        let model_rust = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"TagSchema DEFINITIONS IMPLICIT TAGS ::=
+            BEGIN
+
+            Tagged ::= SEQUENCE {
+                flag [1] EXPLICIT BOOLEAN,
+                value [2] INTEGER (0..255),
+                untagged BOOLEAN
+            }
+
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+
+        assert_eq!(
+            &[Definition(
+                "Tagged".into(),
+                Rust::struct_from_fields(vec![
+                    RustField::from_name_type("flag", RustType::Bool)
+                        .with_tag(Tag::ContextSpecific(1))
+                        .with_tagging(Some(TaggingMode::Explicit)),
+                    RustField::from_name_type("value", RustType::U8(Range::inclusive(0, 255)))
+                        .with_tag(Tag::ContextSpecific(2))
+                        .with_tagging(Some(TaggingMode::Implicit)),
+                    RustField::from_name_type("untagged", RustType::Bool),
+                ])
+            )][..],
+            &model_rust.definitions[..]
+        );
+    }
+
+    #[test]
+    pub fn test_set_and_set_of() {
+        let model_rust = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"SetSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Unordered ::= SET {
+                flag BOOLEAN,
+                values SET OF INTEGER (0..255)
+            }
+
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+
+        assert_eq!(
+            &[Definition(
+                "Unordered".into(),
+                Rust::Struct {
+                    fields: vec![
+                        RustField::from_name_type("flag", RustType::Bool)
+                            .with_tag(Tag::ContextSpecific(0))
+                            .with_tagging(Some(TaggingMode::Implicit)),
+                        RustField::from_name_type(
+                            "values",
+                            RustType::Vec(
+                                Box::new(RustType::U8(Range::inclusive(0, 255))),
+                                Size::Any,
+                                EncodingOrdering::Sort
+                            )
+                        )
+                        .with_tag(Tag::ContextSpecific(1))
+                        .with_tagging(Some(TaggingMode::Implicit)),
+                    ],
+                    extension_after: None,
+                    extension_groups: Vec::new(),
+                    ordering: EncodingOrdering::Sort,
+                }
+            )][..],
+            &model_rust.definitions[..]
+        );
+    }
+
+    #[test]
+    pub fn test_recursive_types_are_boxed() {
+        let model_rust = Model::try_from(
+            Tokenizer::default()
+                .parse(
+                    r"RecursiveSchema DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Node ::= SEQUENCE {
+                value INTEGER (0..255),
+                next Node OPTIONAL,
+                children SEQUENCE OF Node
+            }
+
+            Expression ::= CHOICE {
+                value INTEGER (0..255),
+                negated Negation
+            }
+
+            Negation ::= SEQUENCE {
+                expression Expression
+            }
+
+            END",
+                )
+                .unwrap(),
+        )
+        .unwrap()
+        .to_rust();
+
+        assert_eq!(
+            &[
+                Definition(
+                    "Node".into(),
+                    Rust::struct_from_fields(vec![
+                        RustField::from_name_type("value", RustType::U8(Range::inclusive(0, 255)))
+                            .with_tag(Tag::ContextSpecific(0))
+                            .with_tagging(Some(TaggingMode::Implicit)),
+                        RustField::from_name_type(
+                            "next",
+                            RustType::Option(Box::new(RustType::Box(Box::new(RustType::Complex(
+                                "Node".into()
+                            )))))
+                        )
+                        .with_tag(Tag::ContextSpecific(1))
+                        .with_tagging(Some(TaggingMode::Implicit)),
+                        RustField::from_name_type(
+                            "children",
+                            RustType::Vec(
+                                Box::new(RustType::Complex("Node".into())),
+                                Size::Any,
+                                EncodingOrdering::Keep
+                            )
+                        )
+                        .with_tag(Tag::ContextSpecific(2))
+                        .with_tagging(Some(TaggingMode::Implicit)),
+                    ])
+                ),
+                Definition(
+                    "Expression".into(),
+                    Rust::DataEnum(
+                        vec![
+                            DataVariant::from_name_type(
+                                "Value",
+                                RustType::U8(Range::inclusive(0, 255))
+                            )
+                            .with_asn_name("value")
+                            .with_tag(Tag::ContextSpecific(0))
+                            .with_tagging(Some(TaggingMode::Implicit)),
+                            DataVariant::from_name_type(
+                                "Negated",
+                                RustType::Complex("Negation".into())
+                            )
+                            .with_asn_name("negated")
+                            .with_tag(Tag::ContextSpecific(1))
+                            .with_tagging(Some(TaggingMode::Implicit)),
+                        ]
+                        .into()
+                    )
+                ),
+                Definition(
+                    "Negation".into(),
+                    Rust::struct_from_fields(vec![RustField::from_name_type(
+                        "expression",
+                        RustType::Box(Box::new(RustType::Complex("Expression".into())))
+                    )
+                    .with_tag(Tag::ContextSpecific(0))
+                    .with_tagging(Some(TaggingMode::Explicit))])
+                ),
+            ],
+            &model_rust.definitions[..]
+        );
+    }
 }
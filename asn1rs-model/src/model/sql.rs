@@ -1,11 +1,13 @@
 use crate::gen::RustCodeGenerator;
-use crate::model::rust::{DataEnum, DataVariant};
+use crate::model::rust::{DataEnum, DataVariant, EncodingOrdering};
 use crate::model::rust::{Field, PlainEnum};
+use crate::model::BitString;
 use crate::model::Definition;
 use crate::model::Model;
 use crate::model::Range;
 use crate::model::Rust;
 use crate::model::RustType;
+use crate::model::Size;
 
 const FOREIGN_KEY_DEFAULT_COLUMN: &str = "id";
 const TUPLE_LIST_ENTRY_PARENT_COLUMN: &str = "list";
@@ -18,11 +20,22 @@ pub enum SqlType {
     Integer,  // 4byte
     BigInt,   // 8byte
     Serial,   // 4byte
+    Double,   // 8byte
     Boolean,
     Text,
     Array(Box<SqlType>),
     NotNull(Box<SqlType>),
     ByteArray,
+    BitVarying,
+    /// A `BOOLEAN` that is always `TRUE`, since the presence of a `NULL` value
+    /// is the only information it carries
+    Null,
+    /// A `TEXT` in dotted notation
+    ObjectIdentifier,
+    /// A `TIMESTAMPTZ`, the time zone of the `GeneralizedTime` is not preserved
+    GeneralizedTime,
+    /// A `TIMESTAMPTZ`, the time zone of the `UTCTime` is not preserved
+    UtcTime,
     References(String, String, Option<Action>, Option<Action>),
 }
 
@@ -57,15 +70,23 @@ impl SqlType {
     pub fn to_rust(&self) -> RustType {
         #[allow(clippy::match_same_arms)] // to have the same order as the original enum
         RustType::Option(Box::new(match self {
-            SqlType::SmallInt => RustType::I16(Range(0, i16::max_value())),
-            SqlType::Integer => RustType::I32(Range(0, i32::max_value())),
-            SqlType::BigInt => RustType::I64(Range(0, i64::max_value())),
-            SqlType::Serial => RustType::I32(Range(0, i32::max_value())),
+            SqlType::SmallInt => RustType::I16(Range::inclusive(0, i16::MAX)),
+            SqlType::Integer => RustType::I32(Range::inclusive(0, i32::MAX)),
+            SqlType::BigInt => RustType::I64(Range::inclusive(Some(0), Some(i64::MAX))),
+            SqlType::Serial => RustType::I32(Range::inclusive(0, i32::MAX)),
+            SqlType::Double => RustType::F64,
             SqlType::Boolean => RustType::Bool,
-            SqlType::Text => RustType::String,
-            SqlType::Array(inner) => RustType::Vec(Box::new(inner.to_rust())),
+            SqlType::Text => RustType::String(Size::Any),
+            SqlType::Array(inner) => {
+                RustType::Vec(Box::new(inner.to_rust()), Size::Any, EncodingOrdering::Keep)
+            }
             SqlType::NotNull(inner) => return inner.to_rust().no_option(),
-            SqlType::ByteArray => RustType::VecU8,
+            SqlType::ByteArray => RustType::VecU8(Size::Any),
+            SqlType::BitVarying => RustType::BitVec(BitString::default()),
+            SqlType::Null => RustType::Null,
+            SqlType::ObjectIdentifier => RustType::ObjectIdentifier,
+            SqlType::GeneralizedTime => RustType::GeneralizedTime,
+            SqlType::UtcTime => RustType::UtcTime,
             SqlType::References(name, _, _, _) => RustType::Complex(name.clone()),
         }))
     }
@@ -78,11 +99,16 @@ impl ToString for SqlType {
             SqlType::Integer => "INTEGER".into(),
             SqlType::BigInt => "BIGINT".into(),
             SqlType::Serial => "SERIAL".into(),
+            SqlType::Double => "DOUBLE PRECISION".into(),
             SqlType::Boolean => "BOOLEAN".into(),
             SqlType::Text => "TEXT".into(),
             SqlType::Array(inner) => format!("{}[]", inner.to_string()),
             SqlType::NotNull(inner) => format!("{} NOT NULL", inner.to_string()),
             SqlType::ByteArray => "BYTEA".into(),
+            SqlType::BitVarying => "BIT VARYING".into(),
+            SqlType::Null => SqlType::Boolean.to_string(),
+            SqlType::ObjectIdentifier => SqlType::Text.to_string(),
+            SqlType::GeneralizedTime | SqlType::UtcTime => "TIMESTAMPTZ".into(),
             SqlType::References(table, column, on_delete, on_update) => format!(
                 "INTEGER REFERENCES {}({}){}{}",
                 table,
@@ -131,6 +157,14 @@ impl Model<Sql> {
             name: rust_model.name.clone(),
             imports: Default::default(), // ignored in SQL
             definitions: Vec::with_capacity(rust_model.definitions.len()),
+            value_references: Default::default(), // ignored in SQL
+            tag_default: rust_model.tag_default,
+            oid: rust_model.oid.clone(),
+            exports: rust_model.exports.clone(),
+            extensibility_implied: rust_model.extensibility_implied,
+            definition_spans: Default::default(),
+            component_spans: Default::default(),
+            imports_span: Default::default(),
         };
         for Definition(name, rust) in &rust_model.definitions {
             Self::definition_to_sql(name, rust, &mut model.definitions);
@@ -140,9 +174,11 @@ impl Model<Sql> {
 
     fn definition_to_sql(name: &str, rust: &Rust, definitions: &mut Vec<Definition<Sql>>) {
         match rust {
-            Rust::Struct(fields) => Self::rust_struct_to_sql_table(name, fields, definitions),
+            Rust::Struct { fields, .. } => {
+                Self::rust_struct_to_sql_table(name, fields, definitions)
+            }
             Rust::Enum(rust_enum) => Self::rust_enum_to_sql_enum(name, rust_enum, definitions),
-            Rust::DataEnum(enumeration) => {
+            Rust::DataEnum(enumeration) | Rust::OpenType(enumeration) => {
                 Self::rust_data_enum_to_sql_table(name, enumeration, definitions)
             }
             Rust::TupleStruct(rust) => {
@@ -255,7 +291,10 @@ impl Model<Sql> {
         enumeration: &PlainEnum,
         definitions: &mut Vec<Definition<Sql>>,
     ) {
-        let variants = enumeration.variants().map(String::clone).collect();
+        let variants = enumeration
+            .variants()
+            .map(|v| v.name().to_string())
+            .collect();
         definitions.push(Definition(name.into(), Sql::Enum(variants)));
         Self::add_silently_prevent_any_delete(name, definitions);
     }
@@ -399,8 +438,14 @@ impl Model<Sql> {
     pub fn is_primitive(rust: &RustType) -> bool {
         #[allow(clippy::match_same_arms)] // to have the same order as the original enum
         match rust.clone().into_inner_type() {
-            RustType::String => true,
-            RustType::VecU8 => true,
+            RustType::String(_) => true,
+            RustType::CharacterString(_) => true,
+            RustType::VecU8(_) => true,
+            RustType::BitVec(_) => true,
+            RustType::Null => true,
+            RustType::ObjectIdentifier => true,
+            RustType::GeneralizedTime => true,
+            RustType::UtcTime => true,
             r => r.is_primitive(),
         }
     }
@@ -434,17 +479,22 @@ impl ToSql for RustType {
         SqlType::NotNull(Box::new(match self {
             RustType::Bool => SqlType::Boolean,
             RustType::U8(_) | RustType::I8(_) => SqlType::SmallInt,
-            RustType::U16(Range(_, upper)) if *upper <= i16::max_value() as u16 => {
-                SqlType::SmallInt
-            }
+            RustType::U16(Range(_, upper, _)) if *upper <= i16::MAX as u16 => SqlType::SmallInt,
             RustType::I16(_) => SqlType::SmallInt,
-            RustType::U32(Range(_, upper)) if *upper <= i32::max_value() as u32 => SqlType::Integer,
+            RustType::U32(Range(_, upper, _)) if *upper <= i32::MAX as u32 => SqlType::Integer,
             RustType::U16(_) | RustType::I32(_) => SqlType::Integer,
             RustType::U32(_) | RustType::U64(_) | RustType::I64(_) => SqlType::BigInt,
-            RustType::String => SqlType::Text,
-            RustType::VecU8 => SqlType::ByteArray,
-            RustType::Vec(inner) => SqlType::Array(inner.to_sql().into()),
+            RustType::F64 => SqlType::Double,
+            RustType::String(_) | RustType::CharacterString(_) => SqlType::Text,
+            RustType::VecU8(_) => SqlType::ByteArray,
+            RustType::BitVec(_) => SqlType::BitVarying,
+            RustType::Null => SqlType::Null,
+            RustType::ObjectIdentifier => SqlType::ObjectIdentifier,
+            RustType::GeneralizedTime => SqlType::GeneralizedTime,
+            RustType::UtcTime => SqlType::UtcTime,
+            RustType::Vec(inner, _, _) => SqlType::Array(inner.to_sql().into()),
             RustType::Option(inner) => return inner.to_sql().nullable(),
+            RustType::Box(inner) => return inner.to_sql(),
             RustType::Complex(name) => SqlType::References(
                 name.clone(),
                 FOREIGN_KEY_DEFAULT_COLUMN.into(),
@@ -461,6 +511,7 @@ mod tests {
     use crate::model::rust::Field;
     use crate::model::Import;
     use crate::model::Model;
+    use crate::model::{Exports, TagDefault};
 
     #[test]
     fn test_conversion_struct() {
@@ -469,14 +520,24 @@ mod tests {
             imports: vec![Import {
                 what: vec!["a".into(), "b".into()],
                 from: "to_be_ignored".into(),
+                oid: None,
+                selection: None,
             }],
             definitions: vec![Definition(
                 "Person".into(),
-                Rust::Struct(vec![
-                    Field::from_name_type("name", RustType::String),
+                Rust::struct_from_fields(vec![
+                    Field::from_name_type("name", RustType::String(Size::Any)),
                     Field::from_name_type("birth", RustType::Complex("City".into())),
                 ]),
             )],
+            value_references: vec![],
+            tag_default: TagDefault::default(),
+            oid: None,
+            exports: Exports::default(),
+            extensibility_implied: false,
+            definition_spans: Default::default(),
+            component_spans: Default::default(),
+            imports_span: Default::default(),
         }
         .to_sql();
         assert_eq!("Manfred", &model.name);
@@ -537,17 +598,27 @@ mod tests {
             imports: vec![Import {
                 what: vec!["a".into(), "b".into()],
                 from: "to_be_ignored".into(),
+                oid: None,
+                selection: None,
             }],
             definitions: vec![Definition(
                 "PersonState".into(),
                 Rust::DataEnum(
                     vec![
-                        DataVariant::from_name_type("DeadSince", RustType::String),
+                        DataVariant::from_name_type("DeadSince", RustType::String(Size::Any)),
                         DataVariant::from_name_type("Alive", RustType::Complex("Person".into())),
                     ]
                     .into(),
                 ),
             )],
+            value_references: vec![],
+            tag_default: TagDefault::default(),
+            oid: None,
+            exports: Exports::default(),
+            extensibility_implied: false,
+            definition_spans: Default::default(),
+            component_spans: Default::default(),
+            imports_span: Default::default(),
         }
         .to_sql();
         assert_eq!("Hurray", &model.name);
@@ -608,11 +679,21 @@ mod tests {
             imports: vec![Import {
                 what: vec!["a".into(), "b".into()],
                 from: "to_be_ignored".into(),
+                oid: None,
+                selection: None,
             }],
             definitions: vec![Definition(
                 "City".into(),
                 Rust::Enum(vec!["Esslingen".into(), "Stuttgart".into()].into()),
             )],
+            value_references: vec![],
+            tag_default: TagDefault::default(),
+            oid: None,
+            exports: Exports::default(),
+            extensibility_implied: false,
+            definition_spans: Default::default(),
+            component_spans: Default::default(),
+            imports_span: Default::default(),
         }
         .to_sql();
         assert_eq!("Alfred", &model.name);
@@ -639,17 +720,33 @@ mod tests {
             imports: vec![],
             definitions: vec![Definition(
                 "SomeStruct".into(),
-                Rust::Struct(vec![
+                Rust::struct_from_fields(vec![
                     Field::from_name_type(
                         "list_of_primitive",
-                        RustType::Vec(Box::new(RustType::String)),
+                        RustType::Vec(
+                            Box::new(RustType::String(Size::Any)),
+                            Size::Any,
+                            EncodingOrdering::Keep,
+                        ),
                     ),
                     Field::from_name_type(
                         "list_of_reference",
-                        RustType::Vec(Box::new(RustType::Complex("ComplexType".into()))),
+                        RustType::Vec(
+                            Box::new(RustType::Complex("ComplexType".into())),
+                            Size::Any,
+                            EncodingOrdering::Keep,
+                        ),
                     ),
                 ]),
             )],
+            value_references: vec![],
+            tag_default: TagDefault::default(),
+            oid: None,
+            exports: Exports::default(),
+            extensibility_implied: false,
+            definition_spans: Default::default(),
+            component_spans: Default::default(),
+            imports_span: Default::default(),
         }
         .to_sql();
         assert_eq!("Bernhard", &model.name);
@@ -762,14 +859,27 @@ mod tests {
             imports: vec![Import {
                 what: vec!["a".into(), "b".into()],
                 from: "to_be_ignored".into(),
+                oid: None,
+                selection: None,
             }],
             definitions: vec![
-                Definition("Whatever".into(), Rust::TupleStruct(RustType::String)),
+                Definition(
+                    "Whatever".into(),
+                    Rust::TupleStruct(RustType::String(Size::Any)),
+                ),
                 Definition(
                     "Whatelse".into(),
                     Rust::TupleStruct(RustType::Complex("Whatever".into())),
                 ),
             ],
+            value_references: vec![],
+            tag_default: TagDefault::default(),
+            oid: None,
+            exports: Exports::default(),
+            extensibility_implied: false,
+            definition_spans: Default::default(),
+            component_spans: Default::default(),
+            imports_span: Default::default(),
         }
         .to_sql();
         assert_eq!("Hurray", &model.name);
@@ -915,11 +1025,24 @@ mod tests {
             imports: vec![Import {
                 what: vec!["a".into(), "b".into()],
                 from: "to_be_ignored".into(),
+                oid: None,
+                selection: None,
             }],
             definitions: vec![Definition(
                 "City".into(),
-                Rust::Struct(vec![Field::from_name_type("id", RustType::String)]),
+                Rust::struct_from_fields(vec![Field::from_name_type(
+                    "id",
+                    RustType::String(Size::Any),
+                )]),
             )],
+            value_references: vec![],
+            tag_default: TagDefault::default(),
+            oid: None,
+            exports: Exports::default(),
+            extensibility_implied: false,
+            definition_spans: Default::default(),
+            component_spans: Default::default(),
+            imports_span: Default::default(),
         }
         .to_sql();
         assert_eq!("Alfred", &model.name);
@@ -951,67 +1074,97 @@ mod tests {
     fn test_rust_to_sql_to_rust() {
         assert_eq!(RustType::Bool.to_sql().to_rust(), RustType::Bool);
         assert_eq!(
-            RustType::I8(Range(0, i8::max_value())).to_sql().to_rust(),
-            RustType::I16(Range(0, i16::max_value()))
+            RustType::I8(Range::inclusive(0, i8::MAX))
+                .to_sql()
+                .to_rust(),
+            RustType::I16(Range::inclusive(0, i16::MAX))
         );
         assert_eq!(
-            RustType::U8(Range(0, u8::max_value())).to_sql().to_rust(),
-            RustType::I16(Range(0, i16::max_value()))
+            RustType::U8(Range::inclusive(0, u8::MAX))
+                .to_sql()
+                .to_rust(),
+            RustType::I16(Range::inclusive(0, i16::MAX))
         );
         assert_eq!(
-            RustType::I16(Range(0, i16::max_value())).to_sql().to_rust(),
-            RustType::I16(Range(0, i16::max_value()))
+            RustType::I16(Range::inclusive(0, i16::MAX))
+                .to_sql()
+                .to_rust(),
+            RustType::I16(Range::inclusive(0, i16::MAX))
         );
         assert_eq!(
-            RustType::U16(Range(0, i16::max_value() as u16))
+            RustType::U16(Range::inclusive(0, i16::MAX as u16))
                 .to_sql()
                 .to_rust(),
-            RustType::I16(Range(0, i16::max_value()))
+            RustType::I16(Range::inclusive(0, i16::MAX))
         );
         assert_eq!(
-            RustType::U16(Range(0, u16::max_value())).to_sql().to_rust(),
-            RustType::I32(Range(0, i32::max_value()))
+            RustType::U16(Range::inclusive(0, u16::MAX))
+                .to_sql()
+                .to_rust(),
+            RustType::I32(Range::inclusive(0, i32::MAX))
         );
         assert_eq!(
-            RustType::I32(Range(0, i32::max_value())).to_sql().to_rust(),
-            RustType::I32(Range(0, i32::max_value()))
+            RustType::I32(Range::inclusive(0, i32::MAX))
+                .to_sql()
+                .to_rust(),
+            RustType::I32(Range::inclusive(0, i32::MAX))
         );
         assert_eq!(
-            RustType::U32(Range(0, i32::max_value() as u32))
+            RustType::U32(Range::inclusive(0, i32::MAX as u32))
                 .to_sql()
                 .to_rust(),
-            RustType::I32(Range(0, i32::max_value()))
+            RustType::I32(Range::inclusive(0, i32::MAX))
         );
         assert_eq!(
-            RustType::U32(Range(0, u32::max_value())).to_sql().to_rust(),
-            RustType::I64(Range(0, i64::max_value()))
+            RustType::U32(Range::inclusive(0, u32::MAX))
+                .to_sql()
+                .to_rust(),
+            RustType::I64(Range::inclusive(Some(0), Some(i64::MAX)))
         );
         assert_eq!(
-            RustType::I64(Range(0, i64::max_value())).to_sql().to_rust(),
-            RustType::I64(Range(0, i64::max_value()))
+            RustType::I64(Range::inclusive(Some(0), Some(i64::MAX)))
+                .to_sql()
+                .to_rust(),
+            RustType::I64(Range::inclusive(Some(0), Some(i64::MAX)))
         );
         assert_eq!(
             RustType::U64(None).to_sql().to_rust(),
-            RustType::I64(Range(0, i64::max_value()))
+            RustType::I64(Range::inclusive(Some(0), Some(i64::MAX)))
         );
         assert_eq!(
-            RustType::U64(Some(Range(0, u64::max_value())))
+            RustType::U64(Some(Range::inclusive(0, u64::MAX)))
                 .to_sql()
                 .to_rust(),
-            RustType::I64(Range(0, i64::max_value()))
+            RustType::I64(Range::inclusive(Some(0), Some(i64::MAX)))
         );
 
-        assert_eq!(RustType::String.to_sql().to_rust(), RustType::String,);
-        assert_eq!(RustType::VecU8.to_sql().to_rust(), RustType::VecU8,);
         assert_eq!(
-            RustType::Vec(Box::new(RustType::String)).to_sql().to_rust(),
-            RustType::Vec(Box::new(RustType::String)),
+            RustType::String(Size::Any).to_sql().to_rust(),
+            RustType::String(Size::Any),
+        );
+        assert_eq!(
+            RustType::VecU8(Size::Any).to_sql().to_rust(),
+            RustType::VecU8(Size::Any),
+        );
+        assert_eq!(
+            RustType::Vec(
+                Box::new(RustType::String(Size::Any)),
+                Size::Any,
+                EncodingOrdering::Keep
+            )
+            .to_sql()
+            .to_rust(),
+            RustType::Vec(
+                Box::new(RustType::String(Size::Any)),
+                Size::Any,
+                EncodingOrdering::Keep
+            ),
         );
         assert_eq!(
-            RustType::Option(Box::new(RustType::VecU8))
+            RustType::Option(Box::new(RustType::VecU8(Size::Any)))
                 .to_sql()
                 .to_rust(),
-            RustType::Option(Box::new(RustType::VecU8)),
+            RustType::Option(Box::new(RustType::VecU8(Size::Any))),
         );
         assert_eq!(
             RustType::Complex("MuchComplex".into()).to_sql().to_rust(),
@@ -1024,7 +1177,7 @@ mod tests {
         // only cases that are not already tested by above
         assert_eq!(
             SqlType::NotNull(SqlType::Serial.into()).to_rust(),
-            RustType::I32(Range(0, i32::max_value()))
+            RustType::I32(Range::inclusive(0, i32::MAX))
         );
     }
 
@@ -1043,8 +1196,13 @@ mod tests {
         assert_eq!("INTEGER", &SqlType::Integer.to_string());
         assert_eq!("BIGINT", &SqlType::BigInt.to_string());
         assert_eq!("SERIAL", &SqlType::Serial.to_string());
+        assert_eq!("DOUBLE PRECISION", &SqlType::Double.to_string());
         assert_eq!("BOOLEAN", &SqlType::Boolean.to_string());
         assert_eq!("TEXT", &SqlType::Text.to_string());
+        assert_eq!("BOOLEAN", &SqlType::Null.to_string());
+        assert_eq!("TEXT", &SqlType::ObjectIdentifier.to_string());
+        assert_eq!("TIMESTAMPTZ", &SqlType::GeneralizedTime.to_string());
+        assert_eq!("TIMESTAMPTZ", &SqlType::UtcTime.to_string());
         assert_eq!(
             "SMALLINT[]",
             &SqlType::Array(SqlType::SmallInt.into()).to_string()
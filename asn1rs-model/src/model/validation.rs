@@ -0,0 +1,423 @@
+//! Checks a parsed module for problems the parser cannot notice while reading one token
+//! after another, like references to types that are never defined
+
+use crate::model::{Asn, ComponentTypeList, Definition, Model, Range, Type};
+use crate::parser::Span;
+use crate::report::Diagnostic;
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum ErrorKind {
+    /// The definition has the same name as a previous definition
+    DuplicateDefinition,
+    /// The name of the type that is referenced but neither defined nor imported
+    UndefinedReference(String),
+    /// The name of the component or variant that is declared more than once
+    DuplicateName(String),
+    /// The variant and the number it shares with a previous variant
+    DuplicateNumber(String, usize),
+    /// The index of the extension marker, which is beyond the components or variants
+    InvalidExtensionIndex(usize),
+    /// The extension addition group, which is not within the extension additions
+    InvalidExtensionGroup(Range<usize>),
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct Error {
+    path: String,
+    span: Option<Span>,
+    kind: ErrorKind,
+}
+
+impl Error {
+    /// The path to the erroneous type, which starts with the name of the definition and
+    /// continues with the names of the components, like `Message.header`
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The span of the name of the affected component, variant or definition, if the model
+    /// was parsed from ASN.1
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+impl StdError for Error {}
+
+impl Diagnostic for Error {
+    fn message(&self) -> String {
+        match &self.kind {
+            ErrorKind::DuplicateDefinition => format!("{} is defined more than once", self.path),
+            ErrorKind::UndefinedReference(name) => format!(
+                "{} references {}, which is neither defined nor imported",
+                self.path, name
+            ),
+            ErrorKind::DuplicateName(name) => {
+                format!("{} declares {} more than once", self.path, name)
+            }
+            ErrorKind::DuplicateNumber(name, number) => format!(
+                "{} assigns the number {} of {} more than once",
+                self.path, number, name
+            ),
+            ErrorKind::InvalidExtensionIndex(index) => format!(
+                "{} has an extension marker after the index {}, which does not exist",
+                self.path, index
+            ),
+            ErrorKind::InvalidExtensionGroup(group) => format!(
+                "{} has the extension addition group {}..={}, which is not within the extension additions",
+                self.path, group.0, group.1
+            ),
+        }
+    }
+
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(span) = self.span {
+            write!(
+                f,
+                "At line {}, column {}: ",
+                span.start().line(),
+                span.start().column()
+            )?;
+        }
+        f.write_str(&self.message())
+    }
+}
+
+impl Model<Asn> {
+    /// Checks all definitions and returns all problems at once: references to types that
+    /// are neither defined nor imported, definitions, components, variants or numbers of
+    /// variants that are declared more than once and misplaced extension markers
+    pub fn validate(&self) -> Result<(), Vec<Error>> {
+        let mut validator = Validator {
+            model: self,
+            occurrences: HashMap::new(),
+            errors: Vec::new(),
+        };
+        for (index, Definition(name, asn)) in self.definitions.iter().enumerate() {
+            let span = self.definition_spans.get(index).copied();
+            if self.definitions[..index]
+                .iter()
+                .any(|Definition(n, _)| n == name)
+            {
+                validator.error(name, span, ErrorKind::DuplicateDefinition);
+            }
+            validator.validate(name, span, &asn.r#type);
+        }
+        if validator.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(validator.errors)
+        }
+    }
+}
+
+struct Validator<'a> {
+    model: &'a Model<Asn>,
+    /// How often the components or variants of each path have been visited
+    occurrences: HashMap<String, usize>,
+    errors: Vec<Error>,
+}
+
+impl Validator<'_> {
+    fn error(&mut self, path: &str, span: Option<Span>, kind: ErrorKind) {
+        self.errors.push(Error {
+            path: path.to_string(),
+            span,
+            kind,
+        });
+    }
+
+    /// The span of the name of the next component or variant with the given path or the
+    /// given span of the enclosing type, if unknown. Names might be declared more than once,
+    /// in which case their spans are returned in the order of their declaration.
+    fn component_span(&mut self, path: String, enclosing: Option<Span>) -> Option<Span> {
+        let span = self
+            .model
+            .component_spans
+            .iter()
+            .filter(|(p, _)| p == &path)
+            .nth(self.occurrences.get(&path).copied().unwrap_or_default())
+            .map(|(_, span)| *span);
+        *self.occurrences.entry(path).or_default() += 1;
+        span.or(enclosing)
+    }
+
+    fn validate(&mut self, path: &str, span: Option<Span>, r#type: &Type) {
+        match r#type {
+            Type::Optional(inner)
+            | Type::Boxed(inner)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _) => self.validate(path, span, inner),
+            Type::Sequence(list) | Type::Set(list) => self.validate_components(path, span, list),
+            Type::Choice(choice) => {
+                let spans = choice
+                    .variants()
+                    .map(|v| self.component_span(format!("{}.{}", path, v.name()), span))
+                    .collect::<Vec<_>>();
+                let names = choice.variants().map(|v| v.name());
+                self.validate_names(path, names.zip(spans.iter().copied()));
+                let index = choice.extension_after_index();
+                self.validate_extension_index(path, span, index, choice.len());
+                for (variant, span) in choice.variants().zip(spans) {
+                    let path = format!("{}.{}", path, variant.name());
+                    self.validate(&path, span, variant.r#type());
+                }
+            }
+            Type::Enumerated(enumerated) => {
+                let spans = enumerated
+                    .variants()
+                    .map(|v| self.component_span(format!("{}.{}", path, v.name()), span))
+                    .collect::<Vec<_>>();
+                let names = enumerated.variants().map(|v| v.name());
+                self.validate_names(path, names.zip(spans.iter().copied()));
+                let mut numbers = Vec::new();
+                for (variant, span) in enumerated.variants().zip(spans) {
+                    if let Some(number) = variant.number() {
+                        if numbers.contains(&number) {
+                            let kind =
+                                ErrorKind::DuplicateNumber(variant.name().to_string(), number);
+                            self.error(path, span, kind);
+                        }
+                        numbers.push(number);
+                    }
+                }
+                let index = enumerated.extension_after_index();
+                self.validate_extension_index(path, span, index, enumerated.len());
+            }
+            Type::TypeReference(name) => {
+                let defined = self
+                    .model
+                    .definitions
+                    .iter()
+                    .any(|Definition(n, _)| n == name);
+                let imported = self
+                    .model
+                    .imports
+                    .iter()
+                    .any(|import| import.what.contains(name));
+                if !defined && !imported {
+                    self.error(path, span, ErrorKind::UndefinedReference(name.clone()));
+                }
+            }
+            Type::OpenType(open) => {
+                for variant in &open.variants {
+                    self.validate(path, span, &variant.r#type);
+                }
+            }
+            Type::Boolean
+            | Type::Integer(_)
+            | Type::UTF8String(_)
+            | Type::CharacterString(_)
+            | Type::OctetString(_)
+            | Type::BitString(_)
+            | Type::Null
+            | Type::Real
+            | Type::ObjectIdentifier
+            | Type::GeneralizedTime
+            | Type::UTCTime => {}
+        }
+    }
+
+    fn validate_components(&mut self, path: &str, span: Option<Span>, list: &ComponentTypeList) {
+        let spans = list
+            .fields
+            .iter()
+            .map(|f| self.component_span(format!("{}.{}", path, f.name), span))
+            .collect::<Vec<_>>();
+        let names = list.fields.iter().map(|f| f.name.as_str());
+        self.validate_names(path, names.zip(spans.iter().copied()));
+        self.validate_extension_index(path, span, list.extension_after, list.fields.len());
+        for group in &list.extension_groups {
+            let within_additions = list.extension_after.is_some_and(|index| group.0 > index);
+            if !within_additions || group.0 > group.1 || group.1 >= list.fields.len() {
+                self.error(path, span, ErrorKind::InvalidExtensionGroup(*group));
+            }
+        }
+        for (field, span) in list.fields.iter().zip(spans) {
+            let path = format!("{}.{}", path, field.name);
+            self.validate(&path, span, &field.role.r#type);
+        }
+    }
+
+    fn validate_names<'n>(
+        &mut self,
+        path: &str,
+        names: impl Iterator<Item = (&'n str, Option<Span>)>,
+    ) {
+        let mut seen = Vec::new();
+        for (name, span) in names {
+            if seen.contains(&name) {
+                self.error(path, span, ErrorKind::DuplicateName(name.to_string()));
+            }
+            seen.push(name);
+        }
+    }
+
+    fn validate_extension_index(
+        &mut self,
+        path: &str,
+        span: Option<Span>,
+        index: Option<usize>,
+        len: usize,
+    ) {
+        if let Some(index) = index.filter(|index| *index >= len) {
+            self.error(path, span, ErrorKind::InvalidExtensionIndex(index));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Enumerated, EnumeratedVariant, Field};
+    use crate::parser::{Location, Tokenizer};
+
+    fn validate(asn: &str) -> Result<(), Vec<Error>> {
+        Model::try_from(Tokenizer.parse(asn).unwrap())
+            .unwrap()
+            .validate()
+    }
+
+    #[test]
+    fn test_valid_model() {
+        assert_eq!(
+            Ok(()),
+            validate(
+                r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                IMPORTS Header FROM Headers;
+
+                Message ::= SEQUENCE {
+                    header Header,
+                    kind ENUMERATED { a(1), b(2), ... },
+                    body CHOICE { text UTF8String, numbers SEQUENCE OF INTEGER },
+                    next Message OPTIONAL
+                }
+
+                END"
+            )
+        );
+    }
+
+    #[test]
+    fn test_all_errors_are_reported_with_their_location() {
+        let errors = validate(
+            r"SimpleSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+            Message ::= SEQUENCE {
+                header Header,
+                inner SEQUENCE { flag BOOLEAN, flag Unknown }
+            }
+
+            Kind ::= ENUMERATED { a(1), b(2), c(1), a }
+
+            Message ::= CHOICE { text UTF8String, text UTF8String }
+
+            END",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            vec![
+                (
+                    4,
+                    "Message.header",
+                    ErrorKind::UndefinedReference("Header".into())
+                ),
+                (5, "Message.inner", ErrorKind::DuplicateName("flag".into())),
+                (
+                    5,
+                    "Message.inner.flag",
+                    ErrorKind::UndefinedReference("Unknown".into())
+                ),
+                (8, "Kind", ErrorKind::DuplicateName("a".into())),
+                (8, "Kind", ErrorKind::DuplicateNumber("c".into(), 1)),
+                (10, "Message", ErrorKind::DuplicateDefinition),
+                (10, "Message", ErrorKind::DuplicateName("text".into())),
+            ],
+            errors
+                .iter()
+                .map(|e| (e.span().unwrap().start().line(), e.path(), e.kind().clone()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(Span::new(Location::at(4, 17), Location::at(4, 23))),
+            errors[0].span()
+        );
+        assert_eq!(
+            Some(Span::new(Location::at(5, 48), Location::at(5, 52))),
+            errors[1].span()
+        );
+        assert_eq!(
+            "At line 4, column 17: Message.header references Header, which is neither defined nor imported",
+            errors[0].to_string()
+        );
+    }
+
+    #[test]
+    fn test_invalid_extension_markers() {
+        let model = Model {
+            name: "SimpleSchema".into(),
+            definitions: vec![
+                Definition::new(
+                    "Sequence",
+                    Type::sequence_from_fields(vec![Field {
+                        name: "flag".into(),
+                        role: Type::Boolean.untagged(),
+                        default: None,
+                    }])
+                    .untagged(),
+                ),
+                Definition::new(
+                    "Kind",
+                    Type::Enumerated(
+                        Enumerated::from_variants(vec![EnumeratedVariant::from_name("a")])
+                            .with_extension_after(Some(1)),
+                    )
+                    .untagged(),
+                ),
+            ],
+            ..Default::default()
+        };
+        let mut sequence = model.clone();
+        if let Type::Sequence(list) = &mut sequence.definitions[0].1.r#type {
+            list.extension_after = Some(0);
+            list.extension_groups = vec![Range::inclusive(0, 0)];
+        }
+
+        assert_eq!(
+            vec![ErrorKind::InvalidExtensionIndex(1)],
+            model
+                .validate()
+                .unwrap_err()
+                .into_iter()
+                .map(|e| e.kind)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                ErrorKind::InvalidExtensionGroup(Range::inclusive(0, 0)),
+                ErrorKind::InvalidExtensionIndex(1)
+            ],
+            sequence
+                .validate()
+                .unwrap_err()
+                .into_iter()
+                .map(|e| e.kind)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(None, model.validate().unwrap_err()[0].span());
+    }
+}
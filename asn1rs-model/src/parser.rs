@@ -1,14 +1,37 @@
+use crate::report::Diagnostic;
+use std::error::Error as StdError;
 use std::fmt::{Display, Formatter};
 
+/// Identifies the source a token is read from, like a file registered in
+/// [`Sources`](crate::report::Sources)
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq, Hash)]
+pub struct SourceId(pub(crate) usize);
+
 #[derive(Debug, Default, Copy, Clone, PartialOrd, PartialEq)]
 pub struct Location {
+    source: Option<SourceId>,
     line: usize,
     column: usize,
 }
 
 impl Location {
     pub const fn at(line: usize, column: usize) -> Location {
-        Self { line, column }
+        Self {
+            source: None,
+            line,
+            column,
+        }
+    }
+
+    pub const fn with_source(mut self, source: SourceId) -> Location {
+        self.source = Some(source);
+        self
+    }
+
+    /// The source the location is in, `None` if the tokens are not read from a registered
+    /// source
+    pub const fn source(&self) -> Option<SourceId> {
+        self.source
     }
 
     pub const fn line(&self) -> usize {
@@ -20,21 +43,92 @@ impl Location {
     }
 }
 
-#[derive(Debug, PartialOrd, PartialEq)]
+/// The position of a token in the source, where `end` is the location right after its
+/// last character
+#[derive(Debug, Default, Copy, Clone, PartialOrd, PartialEq)]
+pub struct Span {
+    start: Location,
+    end: Location,
+}
+
+impl Span {
+    pub const fn new(start: Location, end: Location) -> Span {
+        Self { start, end }
+    }
+
+    pub const fn start(&self) -> Location {
+        self.start
+    }
+
+    pub const fn end(&self) -> Location {
+        self.end
+    }
+}
+
+/// The lexical items consisting of more than one character (X.680-201508 12.16 to 12.21)
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq)]
+pub enum Symbol {
+    /// `::=`
+    Assignment,
+    /// `..`
+    Range,
+    /// `...`
+    Ellipsis,
+    /// `[[`
+    LeftVersionBrackets,
+    /// `]]`
+    RightVersionBrackets,
+}
+
+impl Symbol {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Symbol::Assignment => "::=",
+            Symbol::Range => "..",
+            Symbol::Ellipsis => "...",
+            Symbol::LeftVersionBrackets => "[[",
+            Symbol::RightVersionBrackets => "]]",
+        }
+    }
+}
+
+impl Display for Symbol {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub enum Token {
-    Text(Location, String),
-    Separator(Location, char),
+    /// A reference, an identifier or a keyword, like `Person`, `age`, `&id` or `SEQUENCE`
+    Text(Span, String),
+    /// The digits of an integer number, whose minus sign is a separate token
+    Number(Span, String),
+    /// The characters of a `cstring`, without the enclosing and escaping quotation marks
+    CString(Span, String),
+    /// The binary digits of a `bstring` like `'0101'B`
+    BString(Span, String),
+    /// The hexadecimal digits of an `hstring` like `'0F'H`
+    HString(Span, String),
+    Separator(Span, char),
+    Symbol(Span, Symbol),
 }
 
 impl From<char> for Token {
     fn from(separator: char) -> Self {
-        Token::Separator(Location::default(), separator)
+        Token::Separator(Span::default(), separator)
     }
 }
 
 impl From<String> for Token {
     fn from(text: String) -> Self {
-        Token::Text(Location::default(), text)
+        Token::Text(Span::default(), text)
+    }
+}
+
+impl From<Symbol> for Token {
+    fn from(symbol: Symbol) -> Self {
+        Token::Symbol(Span::default(), symbol)
     }
 }
 
@@ -42,29 +136,44 @@ impl Display for Token {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
             Token::Text(_, text) => write!(f, "\"{}\"", text),
+            Token::Number(_, number) => write!(f, "{}", number),
+            Token::CString(_, string) => write!(f, "\"{}\"", string.replace('"', "\"\"")),
+            Token::BString(_, digits) => write!(f, "'{}'B", digits),
+            Token::HString(_, digits) => write!(f, "'{}'H", digits),
             Token::Separator(_, separator) => write!(f, "\'{}\'", separator),
+            Token::Symbol(_, symbol) => write!(f, "\'{}\'", symbol),
         }
     }
 }
 
 impl Token {
-    fn append(self, other: Token) -> (Token, Option<Token>) {
-        match (self, other) {
-            (Token::Text(location, mut text), Token::Text(_, other)) => (
-                Token::Text(location, {
-                    text.push_str(&other);
-                    text
-                }),
-                None,
-            ),
-            (a, b) => (a, Some(b)),
+    pub fn span(&self) -> Span {
+        match self {
+            Token::Text(span, _) => *span,
+            Token::Number(span, _) => *span,
+            Token::CString(span, _) => *span,
+            Token::BString(span, _) => *span,
+            Token::HString(span, _) => *span,
+            Token::Separator(span, _) => *span,
+            Token::Symbol(span, _) => *span,
         }
     }
 
     pub fn location(&self) -> Location {
-        match self {
-            Token::Text(location, _) => *location,
-            Token::Separator(location, _) => *location,
+        self.span().start()
+    }
+
+    /// Whether both tokens are the same lexical item, regardless of where they are located
+    pub fn eq_ignore_span(&self, other: &Token) -> bool {
+        match (self, other) {
+            (Token::Text(_, a), Token::Text(_, b)) => a == b,
+            (Token::Number(_, a), Token::Number(_, b)) => a == b,
+            (Token::CString(_, a), Token::CString(_, b)) => a == b,
+            (Token::BString(_, a), Token::BString(_, b)) => a == b,
+            (Token::HString(_, a), Token::HString(_, b)) => a == b,
+            (Token::Separator(_, a), Token::Separator(_, b)) => a == b,
+            (Token::Symbol(_, a), Token::Symbol(_, b)) => a == b,
+            _ => false,
         }
     }
 
@@ -82,6 +191,11 @@ impl Token {
         self.separator().map(|s| s == separator).unwrap_or(false)
     }
 
+    pub fn eq_symbol(&self, symbol: Symbol) -> bool {
+        self.symbol().map(|s| s == symbol).unwrap_or(false)
+    }
+
+    /// The text of a reference, identifier or keyword
     pub fn text(&self) -> Option<&str> {
         match self {
             Token::Text(_, text) => Some(text),
@@ -89,6 +203,21 @@ impl Token {
         }
     }
 
+    /// The digits of a number, without its sign
+    pub fn number(&self) -> Option<&str> {
+        match self {
+            Token::Number(_, number) => Some(number),
+            _ => None,
+        }
+    }
+
+    pub fn cstring(&self) -> Option<&str> {
+        match self {
+            Token::CString(_, string) => Some(string),
+            _ => None,
+        }
+    }
+
     pub fn separator(&self) -> Option<char> {
         match self {
             Token::Separator(_, char) => Some(*char),
@@ -96,19 +225,29 @@ impl Token {
         }
     }
 
+    pub fn symbol(&self) -> Option<Symbol> {
+        match self {
+            Token::Symbol(_, symbol) => Some(*symbol),
+            _ => None,
+        }
+    }
+
     pub fn is_text(&self) -> bool {
         self.text().is_some()
     }
 
+    pub fn is_number(&self) -> bool {
+        matches!(self, Token::Number(..))
+    }
+
     pub fn is_separator(&self) -> bool {
         self.separator().is_some()
     }
 
     pub fn into_text(self) -> Option<String> {
-        if let Token::Text(_, text) = self {
-            Some(text)
-        } else {
-            None
+        match self {
+            Token::Text(_, text) => Some(text),
+            _ => None,
         }
     }
 
@@ -127,73 +266,291 @@ impl Token {
     }
 }
 
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum ErrorKind {
+    /// The character, which is no part of any lexical item
+    UnexpectedCharacter(char),
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct Error {
+    span: Span,
+    kind: ErrorKind,
+}
+
+impl Error {
+    /// The span of the characters the error refers to
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+impl StdError for Error {}
+
+impl Diagnostic for Error {
+    fn message(&self) -> String {
+        match self.kind {
+            ErrorKind::UnexpectedCharacter(char) => format!(
+                "the character '{}' (0x{:02x}) is not allowed here",
+                char, char as u32
+            ),
+        }
+    }
+
+    fn span(&self) -> Option<Span> {
+        Some(self.span)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "At line {}, column {}: ",
+            self.span.start().line(),
+            self.span.start().column()
+        )?;
+        f.write_str(&self.message())
+    }
+}
+
+/// The characters of the source together with their location
+struct Cursor {
+    chars: Vec<(Location, char)>,
+    index: usize,
+}
+
+impl Cursor {
+    /// Drops control characters, which are not allowed in ASN.1 definitions
+    fn new(source: Option<SourceId>, asn: &str) -> Self {
+        let location = |line, column| Location {
+            source,
+            line,
+            column,
+        };
+        let mut chars = Vec::with_capacity(asn.len());
+        for (line_0, line) in asn.split('\n').enumerate() {
+            for (column_0, char) in line.chars().enumerate() {
+                if !char.is_control() || char.is_whitespace() {
+                    chars.push((location(line_0 + 1, column_0 + 1), char));
+                }
+            }
+            let end = location(line_0 + 1, line.chars().count() + 1);
+            chars.push((end, '\n'));
+        }
+        Self { chars, index: 0 }
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.index + offset).map(|(_, char)| *char)
+    }
+
+    fn starts_with(&self, text: &str) -> bool {
+        text.chars()
+            .enumerate()
+            .all(|(offset, char)| self.peek(offset) == Some(char))
+    }
+
+    fn location(&self) -> Location {
+        self.chars
+            .get(self.index)
+            .map(|(location, _)| *location)
+            .unwrap_or_default()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let char = self.peek(0)?;
+        self.index += 1;
+        Some(char)
+    }
+
+    fn skip(&mut self, count: usize) {
+        self.index = (self.index + count).min(self.chars.len());
+    }
+
+    /// The span from the given start to the end of the last character that was read
+    fn span_from(&self, start: Location) -> Span {
+        let end = self.index.checked_sub(1).and_then(|i| self.chars.get(i));
+        let end = end.map_or(start, |(l, _)| Location {
+            column: l.column + 1,
+            ..*l
+        });
+        Span::new(start, end)
+    }
+
+    fn next_while(&mut self, mut predicate: impl FnMut(&Self, char) -> bool) -> String {
+        let mut text = String::new();
+        while let Some(char) = self.peek(0).filter(|c| predicate(self, *c)) {
+            text.push(char);
+            self.index += 1;
+        }
+        text
+    }
+}
+
 #[derive(Default)]
 pub struct Tokenizer;
 
 impl Tokenizer {
-    pub fn parse(&self, asn: &str) -> Vec<Token> {
-        let mut previous = None;
+    /// The single-character lexical items (X.680-201508 12.37)
+    const SEPARATORS: &[char] = &[
+        '{', '}', '<', '>', ',', '.', '/', '(', ')', '[', ']', '-', ':', '=', ';', '@', '|', '!',
+        '^', '\'',
+    ];
+
+    /// The symbols in the order in which they are matched, longer ones first
+    const SYMBOLS: [Symbol; 5] = [
+        Symbol::Assignment,
+        Symbol::Ellipsis,
+        Symbol::Range,
+        Symbol::LeftVersionBrackets,
+        Symbol::RightVersionBrackets,
+    ];
+
+    /// Splits the given ASN.1 definition into its lexical items, while skipping whitespace
+    /// and comments (X.680-201508 12)
+    pub fn parse(&self, asn: &str) -> Result<Vec<Token>, Error> {
+        self.parse_with_source(None, asn)
+    }
+
+    /// Like [`Tokenizer::parse`], but the locations of the tokens refer to the given source
+    pub fn parse_source(&self, source: SourceId, asn: &str) -> Result<Vec<Token>, Error> {
+        self.parse_with_source(Some(source), asn)
+    }
+
+    fn parse_with_source(&self, source: Option<SourceId>, asn: &str) -> Result<Vec<Token>, Error> {
+        let mut cursor = Cursor::new(source, asn);
         let mut tokens = Vec::new();
 
-        for (line_0, line) in asn.lines().enumerate() {
-            let mut token = None;
-            let content = line.split("--").next(); // get rid of one-line comments
-
-            for (column_0, char) in content.iter().map(|c| c.chars()).flatten().enumerate() {
-                match char {
-                    // asn syntax
-                    ':' | ';' | '=' | '(' | ')' | '{' | '}' | '.' | ',' | '[' | ']' => {
-                        token = Some(Token::Separator(
-                            Location::at(line_0 + 1, column_0 + 1),
-                            char,
-                        ))
-                    }
-                    // text
-                    c if !c.is_control() && c != ' ' => {
-                        token = Some(Token::Text(
-                            Location::at(line_0 + 1, column_0 + 1),
-                            format!("{}", c),
-                        ));
-                    }
-                    // text separator
-                    ' ' | '\r' | '\n' | '\t' => {
-                        if let Some(token) = previous.take() {
-                            tokens.push(token);
-                        }
-                    }
-                    c => eprintln!(
-                        "Ignoring unexpected character: {}-0x{:02x}-{:03}",
-                        c, c as u8, c as u8
-                    ),
-                }
+        while let Some(char) = cursor.peek(0) {
+            let start = cursor.location();
+            let next = cursor.peek(1);
+
+            if char.is_whitespace() {
+                cursor.skip(1);
+            } else if cursor.starts_with("--") {
+                Self::skip_line_comment(&mut cursor);
+            } else if cursor.starts_with("/*") {
+                Self::skip_block_comment(&mut cursor);
+            } else if char == '"' {
+                let string = Self::read_cstring(&mut cursor);
+                tokens.push(Token::CString(cursor.span_from(start), string));
+            } else if let Some(token) = Self::read_bstring_or_hstring(&mut cursor) {
+                tokens.push(token);
+            } else if char.is_ascii_digit() {
+                let number = cursor.next_while(|_, c| c.is_ascii_digit());
+                tokens.push(Token::Number(cursor.span_from(start), number));
+            } else if char.is_alphabetic() || (char == '&' && next.is_some_and(char::is_alphabetic))
+            {
+                cursor.skip(1);
+                let mut text = char.to_string();
+                text.push_str(&cursor.next_while(|cursor, c| {
+                    // a hyphen shall neither be the last character nor followed by another
+                    c.is_alphanumeric()
+                        || c == '_'
+                        || (c == '-' && cursor.peek(1).is_some_and(char::is_alphanumeric))
+                }));
+                tokens.push(Token::Text(cursor.span_from(start), text));
+            } else if let Some(symbol) = Self::SYMBOLS
+                .iter()
+                .find(|symbol| cursor.starts_with(symbol.as_str()))
+            {
+                cursor.skip(symbol.as_str().len());
+                tokens.push(Token::Symbol(cursor.span_from(start), *symbol));
+            } else if Self::SEPARATORS.contains(&char) {
+                cursor.skip(1);
+                tokens.push(Token::Separator(cursor.span_from(start), char));
+            } else {
+                cursor.skip(1);
+                return Err(Error {
+                    span: cursor.span_from(start),
+                    kind: ErrorKind::UnexpectedCharacter(char),
+                });
+            }
+        }
 
-                if let Some(token) = token.take() {
-                    previous = match previous {
-                        None => Some(token),
-                        Some(current) => {
-                            let (token, second) = current.append(token);
-                            match second {
-                                None => Some(token),
-                                Some(next) => {
-                                    tokens.push(token);
-                                    Some(next)
-                                }
-                            }
-                        }
-                    }
-                }
+        Ok(tokens)
+    }
+
+    /// Skips a comment that starts with `--` and ends with the next `--` or the end of
+    /// the line (X.680-201508 12.6.3)
+    fn skip_line_comment(cursor: &mut Cursor) {
+        cursor.skip(2);
+        while let Some(char) = cursor.peek(0) {
+            if char == '\n' {
+                break;
+            } else if cursor.starts_with("--") {
+                cursor.skip(2);
+                break;
             }
+            cursor.skip(1);
+        }
+    }
 
-            if let Some(token) = previous.take() {
-                tokens.push(token);
+    /// Skips a comment that starts with `/*` and ends with the matching `*/`, as these
+    /// comments can be nested (X.680-201508 12.6.4)
+    fn skip_block_comment(cursor: &mut Cursor) {
+        let mut depth = 0_usize;
+        while cursor.peek(0).is_some() {
+            if cursor.starts_with("/*") {
+                cursor.skip(2);
+                depth += 1;
+            } else if cursor.starts_with("*/") {
+                cursor.skip(2);
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            } else {
+                cursor.skip(1);
             }
         }
+    }
 
-        if let Some(token) = previous {
-            tokens.push(token);
+    /// Reads the characters of a `cstring`, where a quotation mark is escaped by another
+    /// one (X.680-201508 12.14)
+    fn read_cstring(cursor: &mut Cursor) -> String {
+        let mut string = String::new();
+        cursor.skip(1);
+        while let Some(char) = cursor.next() {
+            if char == '"' {
+                if cursor.peek(0) == Some('"') {
+                    cursor.skip(1);
+                } else {
+                    break;
+                }
+            }
+            string.push(char);
         }
+        string
+    }
 
-        tokens
+    /// Reads a `bstring` like `'0101'B` or a `hstring` like `'0F'H`, where whitespace
+    /// between the digits is ignored (X.680-201508 12.10, 12.12)
+    fn read_bstring_or_hstring(cursor: &mut Cursor) -> Option<Token> {
+        if cursor.peek(0) != Some('\'') {
+            return None;
+        }
+        let end = (1..)
+            .map(|offset| (offset, cursor.peek(offset)))
+            .find(|(_, char)| *char == Some('\'') || char.is_none())
+            .and_then(|(offset, char)| char.map(|_| offset))?;
+        let digits = (1..end)
+            .filter_map(|offset| cursor.peek(offset))
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>();
+        let start = cursor.location();
+        let token = match cursor.peek(end + 1) {
+            Some('B') if digits.chars().all(|c| c == '0' || c == '1') => Token::BString,
+            Some('H') if digits.chars().all(|c| c.is_ascii_hexdigit()) => Token::HString,
+            _ => return None,
+        };
+        cursor.skip(end + 2);
+        Some(token(cursor.span_from(start), digits))
     }
 }
 
@@ -203,7 +560,7 @@ mod tests {
 
     #[test]
     pub fn test_separator_tokens_not_merged() {
-        let result = Tokenizer.parse(":;=(){}.,[]");
+        let result = Tokenizer.parse(":;=(){}.,[]").unwrap();
         let mut iter = result.into_iter();
         assert!(iter.next().unwrap().eq_separator(':'));
         assert!(iter.next().unwrap().eq_separator(';'));
@@ -221,11 +578,9 @@ mod tests {
 
     #[test]
     pub fn test_text_between_seapators_is_represented_as_one_text_token() {
-        let result = Tokenizer.parse("::=ASN{");
+        let result = Tokenizer.parse("::=ASN{").unwrap();
         let mut iter = result.into_iter();
-        assert!(iter.next().unwrap().eq_separator(':'));
-        assert!(iter.next().unwrap().eq_separator(':'));
-        assert!(iter.next().unwrap().eq_separator('='));
+        assert!(iter.next().unwrap().eq_symbol(Symbol::Assignment));
         assert!(iter.next().unwrap().eq_text("ASN"));
         assert!(iter.next().unwrap().eq_separator('{'));
         assert!(iter.next().is_none());
@@ -233,7 +588,9 @@ mod tests {
 
     #[test]
     pub fn test_invisible_separator_characters() {
-        let result = Tokenizer.parse("a b\rc\nd\te AB\rCD\nEF\tGH aa  bb\r\rcc\n\ndd\t\tee");
+        let result = Tokenizer
+            .parse("a b\rc\nd\te AB\rCD\nEF\tGH aa  bb\r\rcc\n\ndd\t\tee")
+            .unwrap();
         let mut iter = result.into_iter();
         assert!(iter.next().unwrap().eq_text("a"));
         assert!(iter.next().unwrap().eq_text("b"));
@@ -252,6 +609,19 @@ mod tests {
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    pub fn test_cstring_is_one_token() {
+        let result = Tokenizer.parse(r#"FROM("a b.()"|"x""y")"#).unwrap();
+        let mut iter = result.into_iter();
+        assert!(iter.next().unwrap().eq_text("FROM"));
+        assert!(iter.next().unwrap().eq_separator('('));
+        assert_eq!(Some("a b.()"), iter.next().unwrap().cstring());
+        assert!(iter.next().unwrap().eq_separator('|'));
+        assert_eq!(Some("x\"y"), iter.next().unwrap().cstring());
+        assert!(iter.next().unwrap().eq_separator(')'));
+        assert!(iter.next().is_none());
+    }
+
     #[test]
     pub fn test_token_text() {
         let token = Token::from("some text".to_string());
@@ -261,7 +631,7 @@ mod tests {
 
     #[test]
     pub fn test_token_separator() {
-        let result = Tokenizer.parse("AS\x00N");
+        let result = Tokenizer.parse("AS\x00N").unwrap();
         let mut iter = result.into_iter();
         assert!(iter.next().unwrap().eq_text("ASN"));
         assert!(iter.next().is_none());
@@ -276,18 +646,168 @@ mod tests {
 
     #[test]
     pub fn test_ignores_line_comments() {
-        let result = Tokenizer::default().parse(
-            r"
+        let result = Tokenizer::default()
+            .parse(
+                r"
                 Some ::= None -- very clever
                         -- ignore true ::= false
         ",
-        );
+            )
+            .unwrap();
         let mut iter = result.into_iter();
         assert!(iter.next().unwrap().eq_text("Some"));
-        assert!(iter.next().unwrap().eq_separator(':'));
-        assert!(iter.next().unwrap().eq_separator(':'));
-        assert!(iter.next().unwrap().eq_separator('='));
+        assert!(iter.next().unwrap().eq_symbol(Symbol::Assignment));
         assert!(iter.next().unwrap().eq_text("None"));
         assert!(iter.next().is_none());
     }
+
+    #[test]
+    pub fn test_ignores_comments_closed_on_the_same_line() {
+        let result = Tokenizer
+            .parse("Some -- comment -- ::= -- another one")
+            .unwrap();
+        let mut iter = result.into_iter();
+        assert!(iter.next().unwrap().eq_text("Some"));
+        assert!(iter.next().unwrap().eq_symbol(Symbol::Assignment));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    pub fn test_ignores_nested_block_comments() {
+        let result = Tokenizer
+            .parse(
+                r"
+                Some /* a block comment
+                    /* that is nested */ -- and continued
+                ::= */ ::= None /**/
+            ",
+            )
+            .unwrap();
+        let mut iter = result.into_iter();
+        assert!(iter.next().unwrap().eq_text("Some"));
+        assert!(iter.next().unwrap().eq_symbol(Symbol::Assignment));
+        assert!(iter.next().unwrap().eq_text("None"));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    pub fn test_symbols() {
+        let result = Tokenizer
+            .parse("::= (0..MAX, ...) [[ 2: ]] {a(1)}")
+            .unwrap();
+        let mut iter = result.into_iter();
+        assert!(iter.next().unwrap().eq_symbol(Symbol::Assignment));
+        assert!(iter.next().unwrap().eq_separator('('));
+        assert!(iter.next().unwrap().is_number());
+        assert!(iter.next().unwrap().eq_symbol(Symbol::Range));
+        assert!(iter.next().unwrap().eq_text("MAX"));
+        assert!(iter.next().unwrap().eq_separator(','));
+        assert!(iter.next().unwrap().eq_symbol(Symbol::Ellipsis));
+        assert!(iter.next().unwrap().eq_separator(')'));
+        assert!(iter.next().unwrap().eq_symbol(Symbol::LeftVersionBrackets));
+        assert_eq!(Some("2"), iter.next().unwrap().number());
+        assert!(iter.next().unwrap().eq_separator(':'));
+        assert!(iter.next().unwrap().eq_symbol(Symbol::RightVersionBrackets));
+        assert!(iter.next().unwrap().eq_separator('{'));
+        assert!(iter.next().unwrap().eq_text("a"));
+        assert!(iter.next().unwrap().eq_separator('('));
+        assert_eq!(Some("1"), iter.next().unwrap().number());
+        assert!(iter.next().unwrap().eq_separator(')'));
+        assert!(iter.next().unwrap().eq_separator('}'));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    pub fn test_numbers() {
+        let result = Tokenizer.parse("(-128..-1) 42").unwrap();
+        let tokens = result.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            vec!["'('", "'-'", "128", "'..'", "'-'", "1", "')'", "42"],
+            tokens
+        );
+        assert!(result.iter().filter(|t| t.is_number()).count() == 3);
+        assert_eq!(None, result[2].text());
+    }
+
+    #[test]
+    pub fn test_minus_sign_is_lexed_regardless_of_whitespace() {
+        let lex = |asn| {
+            Tokenizer
+                .parse(asn)
+                .unwrap()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec!["\"a\"", "'-'", "1"], lex("a -1"));
+        assert_eq!(lex("a -1"), lex("a - 1"));
+        assert_eq!(lex("0..-1"), lex("0 .. - 1"));
+    }
+
+    #[test]
+    pub fn test_unexpected_character_is_an_error() {
+        let error = Tokenizer.parse("Some ::=\n  # None").unwrap_err();
+        assert_eq!(&ErrorKind::UnexpectedCharacter('#'), error.kind());
+        assert_eq!(
+            Span::new(Location::at(2, 3), Location::at(2, 4)),
+            error.span()
+        );
+        assert_eq!(
+            "At line 2, column 3: the character '#' (0x23) is not allowed here",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    pub fn test_identifiers_with_hyphens() {
+        let result = Tokenizer
+            .parse("MESSAGE-ID-AND-TYPE.&Type my-field-- comment\nend- 1")
+            .unwrap();
+        let mut iter = result.into_iter();
+        assert!(iter.next().unwrap().eq_text("MESSAGE-ID-AND-TYPE"));
+        assert!(iter.next().unwrap().eq_separator('.'));
+        assert!(iter.next().unwrap().eq_text("&Type"));
+        assert!(iter.next().unwrap().eq_text("my-field"));
+        assert!(iter.next().unwrap().eq_text("end"));
+        assert!(iter.next().unwrap().eq_separator('-'));
+        assert_eq!(Some("1"), iter.next().unwrap().number());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    pub fn test_bstring_and_hstring() {
+        let result = Tokenizer.parse("'0101 1'B '0F'H 'A'").unwrap();
+        let mut iter = result.into_iter();
+        assert_eq!(
+            Token::BString(
+                Span::new(Location::at(1, 1), Location::at(1, 10)),
+                "01011".to_string()
+            ),
+            iter.next().unwrap()
+        );
+        assert_eq!(
+            Token::HString(
+                Span::new(Location::at(1, 11), Location::at(1, 16)),
+                "0F".to_string()
+            ),
+            iter.next().unwrap()
+        );
+        assert!(iter.next().unwrap().eq_separator('\''));
+        assert!(iter.next().unwrap().eq_text("A"));
+        assert!(iter.next().unwrap().eq_separator('\''));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    pub fn test_spans() {
+        let result = Tokenizer.parse("Some ::=\n  \"multi\nline\"").unwrap();
+        assert_eq!(
+            vec![
+                Span::new(Location::at(1, 1), Location::at(1, 5)),
+                Span::new(Location::at(1, 6), Location::at(1, 9)),
+                Span::new(Location::at(2, 3), Location::at(3, 6)),
+            ],
+            result.iter().map(Token::span).collect::<Vec<_>>()
+        );
+    }
 }
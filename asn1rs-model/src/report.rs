@@ -0,0 +1,191 @@
+//! Renders errors together with the line of the source they refer to, similar to rustc:
+//!
+//! ```text
+//! error: an unexpected token was encountered: "Foo"
+//!  --> asn/messages.asn1:3:26
+//!   |
+//! 3 |     Message ::= SEQUENCE Foo
+//!   |                          ^^^
+//! ```
+
+use crate::parser::{SourceId, Span};
+use std::error::Error as StdError;
+use std::fmt::{Debug, Display, Formatter};
+use std::path::{Path, PathBuf};
+
+/// An error that might refer to a part of the source
+pub trait Diagnostic {
+    /// The description of the error without its location
+    fn message(&self) -> String;
+
+    /// The part of the source the error refers to, if known
+    fn span(&self) -> Option<Span>;
+}
+
+/// The sources tokens are read from, so that errors can show the lines they refer to
+#[derive(Debug, Default, Clone)]
+pub struct Sources {
+    sources: Vec<(PathBuf, String)>,
+}
+
+impl Sources {
+    /// Registers the content of the given file, whose tokens can then be read with
+    /// [`Tokenizer::parse_source`](crate::parser::Tokenizer::parse_source)
+    pub fn add<P: Into<PathBuf>>(&mut self, path: P, content: String) -> SourceId {
+        self.sources.push((path.into(), content));
+        SourceId(self.sources.len() - 1)
+    }
+
+    pub fn path(&self, source: SourceId) -> Option<&Path> {
+        self.sources.get(source.0).map(|(path, _)| path.as_path())
+    }
+
+    pub fn content(&self, source: SourceId) -> Option<&str> {
+        self.sources
+            .get(source.0)
+            .map(|(_, content)| content.as_str())
+    }
+
+    /// The line with the given number, starting at 1, without its line break
+    pub fn line(&self, source: SourceId, line: usize) -> Option<&str> {
+        self.content(source)?
+            .split('\n')
+            .nth(line.checked_sub(1)?)
+            .map(|line| line.trim_end_matches('\r'))
+    }
+
+    /// Looks up the file and line the given error refers to
+    pub fn report<E: Diagnostic>(&self, error: E) -> Report<E> {
+        let source = error.span().and_then(|span| span.start().source());
+        let path = source.and_then(|s| self.path(s)).map(Path::to_path_buf);
+        let line = source
+            .zip(error.span())
+            .and_then(|(s, span)| self.line(s, span.start().line()))
+            .map(str::to_string);
+        Report { error, path, line }
+    }
+}
+
+/// An error together with the file and line it refers to, which are shown when displayed
+pub struct Report<E> {
+    error: E,
+    path: Option<PathBuf>,
+    line: Option<String>,
+}
+
+impl<E> Report<E> {
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+}
+
+impl<E: Diagnostic + Debug> StdError for Report<E> {}
+
+impl<E: Diagnostic> Debug for Report<E> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<E: Diagnostic> Display for Report<E> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "error: {}", self.error.message())?;
+        let span = match self.error.span() {
+            Some(span) => span,
+            None => return Ok(()),
+        };
+        let (start, end) = (span.start(), span.end());
+        let width = start.line().to_string().len();
+
+        write!(f, "\n{:width$}--> ", "", width = width)?;
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path.display())?;
+        }
+        write!(f, "{}:{}", start.line(), start.column())?;
+
+        if let Some(line) = &self.line {
+            let length = line.chars().count();
+            // the underline ends at the end of the line, if the span continues on another
+            let until = if end.line() == start.line() {
+                end.column().min(length + 1)
+            } else {
+                length + 1
+            };
+            // keep tabs, so that the underline is aligned with the characters above
+            let indent = line
+                .chars()
+                .take(start.column().saturating_sub(1))
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect::<String>();
+            let carets = until.saturating_sub(start.column()).max(1);
+
+            write!(f, "\n{:width$} |", "", width = width)?;
+            write!(f, "\n{} | {}", start.line(), line)?;
+            write!(
+                f,
+                "\n{:width$} | {}{}",
+                "",
+                indent,
+                "^".repeat(carets),
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Asn, Model};
+    use crate::parser::Tokenizer;
+
+    fn report(asn: &str) -> String {
+        let mut sources = Sources::default();
+        let source = sources.add("asn/schema.asn1", asn.to_string());
+        let error =
+            Model::<Asn>::try_from(Tokenizer.parse_source(source, asn).unwrap()).unwrap_err();
+        sources.report(error).to_string()
+    }
+
+    #[test]
+    fn test_report_with_source_line() {
+        assert_eq!(
+            r#"error: an unexpected token was encountered: "Foo"
+ --> asn/schema.asn1:3:26
+  |
+3 |     Message ::= SEQUENCE Foo
+  |                          ^^^"#,
+            report("SimpleSchema DEFINITIONS ::= BEGIN\n\n    Message ::= SEQUENCE Foo\n\nEND")
+        );
+    }
+
+    #[test]
+    fn test_report_aligns_underline_after_tabs() {
+        assert_eq!(
+            "error: an unexpected token was encountered: ';'\n  \
+             --> asn/schema.asn1:10:2\n   \
+             |\n\
+             10 | \t;\n   \
+             | \t^",
+            report("SimpleSchema DEFINITIONS ::= BEGIN\n\n\n\n\n\n\n\n\n\t;\nEND")
+        );
+    }
+
+    #[test]
+    fn test_report_without_source() {
+        let error = Model::<Asn>::try_from(Tokenizer.parse("SimpleSchema").unwrap()).unwrap_err();
+        assert_eq!(
+            "error: Unexpected end of stream or file",
+            Sources::default().report(error).to_string()
+        );
+    }
+}
@@ -0,0 +1,514 @@
+//! Several modules, whose imports refer to one another. While [`Model::try_from`] parses a
+//! single module in isolation, the [`Workspace`] checks that everything that is imported is
+//! also defined and exported and determines what depends on definitions of other modules.
+
+use crate::model::{Asn, Definition, Error as ModelError, Exports, Model, Rust, Tag};
+use crate::parser::{Error as TokenizerError, Span, Tokenizer};
+use crate::report::{Diagnostic, Sources};
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter};
+use std::io::Error as IoError;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, IoError),
+    Tokenizer(PathBuf, TokenizerError),
+    Model(PathBuf, Box<ModelError>),
+    /// The name of a module that is defined more than once
+    DuplicateModule(String),
+    /// The importing module, the unknown module it imports from and the span of the
+    /// `IMPORTS` clause
+    MissingModule(String, String, Option<Box<Span>>),
+    /// The importing module, the module it imports from, the symbol, which the latter
+    /// either does not define or does not export, and the span of the `IMPORTS` clause
+    MissingSymbol(String, String, String, Option<Box<Span>>),
+    /// The modules that import from one another, where the first module imports from the
+    /// second one and the last module imports from the first one, and the span of the
+    /// `IMPORTS` clause of the first module
+    CyclicImports(Vec<String>, Option<Box<Span>>),
+}
+
+impl StdError for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Error::Io(path, e) => write!(f, "Failed to read {}: {}", path.display(), e),
+            Error::Tokenizer(path, e) => write!(f, "Failed to read {}: {}", path.display(), e),
+            Error::Model(path, e) => write!(f, "Failed to parse {}: {}", path.display(), e),
+            Error::DuplicateModule(module) => {
+                write!(f, "The module {} is defined more than once", module)
+            }
+            Error::MissingModule(module, from, _) => write!(
+                f,
+                "The module {} imports from the unknown module {}",
+                module, from
+            ),
+            Error::MissingSymbol(module, from, symbol, _) => write!(
+                f,
+                "The module {} imports {}, which the module {} does not export",
+                module, symbol, from
+            ),
+            Error::CyclicImports(modules, _) => write!(
+                f,
+                "The modules {} import from one another",
+                modules.join(" -> ")
+            ),
+        }
+    }
+}
+
+impl Diagnostic for Error {
+    fn message(&self) -> String {
+        match self {
+            // the report shows the file and the location already
+            Error::Tokenizer(_, e) => e.message(),
+            Error::Model(_, e) if e.span().is_some() => e.message(),
+            e => e.to_string(),
+        }
+    }
+
+    fn span(&self) -> Option<Span> {
+        match self {
+            Error::Tokenizer(_, e) => Diagnostic::span(e),
+            Error::Model(_, e) => e.span(),
+            Error::MissingModule(_, _, span)
+            | Error::MissingSymbol(_, _, _, span)
+            | Error::CyclicImports(_, span) => span.as_deref().copied(),
+            _ => None,
+        }
+    }
+}
+
+/// The modules of several ASN.1 files, which are ordered so that each module follows the
+/// modules it imports from, once resolved
+#[derive(Debug, Default)]
+pub struct Workspace {
+    models: Vec<Model<Asn>>,
+    sources: Sources,
+}
+
+impl Workspace {
+    /// Loads all given files and resolves their imports
+    pub fn from_files<P: AsRef<Path>>(files: &[P]) -> Result<Self, Error> {
+        let mut workspace = Self::default();
+        for file in files {
+            workspace.load_file(file)?;
+        }
+        workspace.resolve()?;
+        Ok(workspace)
+    }
+
+    /// Parses the module of the given file, without resolving its imports
+    pub fn load_file<P: AsRef<Path>>(&mut self, file: P) -> Result<&Model<Asn>, Error> {
+        let path = file.as_ref();
+        let input = std::fs::read_to_string(path).map_err(|e| Error::Io(path.to_path_buf(), e))?;
+        let source = self.sources.add(path, input);
+        let input = self.sources.content(source).unwrap_or_default();
+        let tokens = Tokenizer
+            .parse_source(source, input)
+            .map_err(|e| Error::Tokenizer(path.to_path_buf(), e))?;
+        let model =
+            Model::try_from(tokens).map_err(|e| Error::Model(path.to_path_buf(), Box::new(e)))?;
+        self.add_model(model)
+    }
+
+    /// Adds the given module, without resolving its imports
+    pub fn add_model(&mut self, model: Model<Asn>) -> Result<&Model<Asn>, Error> {
+        if self.model(&model.name).is_some() {
+            return Err(Error::DuplicateModule(model.name));
+        }
+        self.models.push(model);
+        Ok(&self.models[self.models.len() - 1])
+    }
+
+    pub fn models(&self) -> &[Model<Asn>] {
+        &self.models[..]
+    }
+
+    pub fn model(&self, name: &str) -> Option<&Model<Asn>> {
+        self.models.iter().find(|model| model.name == name)
+    }
+
+    /// The contents of all loaded files, which errors can be reported with
+    pub fn sources(&self) -> &Sources {
+        &self.sources
+    }
+
+    pub fn into_models(self) -> Vec<Model<Asn>> {
+        self.models
+    }
+
+    /// Checks that every imported symbol is defined and exported by the module it is
+    /// imported from and that no modules import from one another. Afterwards, the modules
+    /// are ordered so that each module follows the modules it imports from.
+    pub fn resolve(&mut self) -> Result<(), Error> {
+        for model in &self.models {
+            for import in &model.imports {
+                let from = self.model(&import.from).ok_or_else(|| {
+                    Error::MissingModule(
+                        model.name.clone(),
+                        import.from.clone(),
+                        model.imports_span.map(Box::new),
+                    )
+                })?;
+                if let Some(symbol) = import.what.iter().find(|s| !Self::exports(from, s)) {
+                    return Err(Error::MissingSymbol(
+                        model.name.clone(),
+                        from.name.clone(),
+                        symbol.clone(),
+                        model.imports_span.map(Box::new),
+                    ));
+                }
+            }
+        }
+
+        let mut order = Vec::with_capacity(self.models.len());
+        for index in 0..self.models.len() {
+            self.visit(index, &mut Vec::new(), &mut order)?;
+        }
+        let mut models = std::mem::take(&mut self.models)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+        self.models = order
+            .into_iter()
+            .filter_map(|index| models[index].take())
+            .collect();
+        Ok(())
+    }
+
+    /// Finds the definition of the given name as it is visible in the given module, which
+    /// is either defined by the module itself or imported from another module
+    pub fn definition(&self, module: &str, name: &str) -> Option<&Definition<Asn>> {
+        self.definition_with_module(module, name)
+            .map(|(_, definition)| definition)
+    }
+
+    /// Like [`Workspace::definition`], but also returns the module that defines it
+    fn definition_with_module(
+        &self,
+        module: &str,
+        name: &str,
+    ) -> Option<(&Model<Asn>, &Definition<Asn>)> {
+        let model = self.model(module)?;
+        match model.definitions.iter().find(|Definition(n, _)| n == name) {
+            Some(definition) => Some((model, definition)),
+            None => {
+                let import = model
+                    .imports
+                    .iter()
+                    .find(|import| import.what.iter().any(|what| what == name))?;
+                self.definition_with_module(&import.from, name)
+            }
+        }
+    }
+
+    /// Converts the given module to rust. Other than [`Model::to_rust`], the tags of
+    /// imported types are known, which the fields of a `SET` are sorted by.
+    pub fn to_rust(&self, module: &str) -> Option<Model<Rust>> {
+        let model = self.model(module)?;
+        Some(Model::convert_asn_to_rust_with_imported_tags(
+            model,
+            &|name| self.imported_tag(module, name),
+        ))
+    }
+
+    /// The tag of the type, which is imported by the given module
+    fn imported_tag(&self, module: &str, name: &str) -> Option<Tag> {
+        let (from, Definition(_, asn)) = self.definition_with_module(module, name)?;
+        from.canonical_tag(
+            asn,
+            &|name| self.imported_tag(&from.name, name),
+            &mut Vec::new(),
+        )
+    }
+
+    fn exports(model: &Model<Asn>, symbol: &str) -> bool {
+        let defined = model.definitions.iter().any(|Definition(n, _)| n == symbol)
+            || model.value_references.iter().any(|v| v.name == symbol);
+        defined
+            && match &model.exports {
+                Exports::All => true,
+                Exports::Symbols(symbols) => symbols.iter().any(|s| s == symbol),
+            }
+    }
+
+    /// Appends the module at the given index to the order after the modules it imports
+    /// from, where the path contains the modules that are currently being visited
+    fn visit(
+        &self,
+        index: usize,
+        path: &mut Vec<usize>,
+        order: &mut Vec<usize>,
+    ) -> Result<(), Error> {
+        if order.contains(&index) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|i| *i == index) {
+            let mut cycle = path[start..]
+                .iter()
+                .map(|i| self.models[*i].name.clone())
+                .collect::<Vec<_>>();
+            cycle.push(self.models[index].name.clone());
+            return Err(Error::CyclicImports(
+                cycle,
+                self.models[path[start]].imports_span.map(Box::new),
+            ));
+        }
+        path.push(index);
+        for import in &self.models[index].imports {
+            if let Some(from) = self.models.iter().position(|m| m.name == import.from) {
+                self.visit(from, path, order)?;
+            }
+        }
+        path.pop();
+        order.push(index);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::rust::RustCodeGenerator;
+    use crate::gen::Generator;
+    use crate::model::Type;
+
+    fn parse(asn: &str) -> Model<Asn> {
+        Model::try_from(Tokenizer.parse(asn).unwrap()).unwrap()
+    }
+
+    fn workspace(modules: &[&str]) -> Workspace {
+        let mut workspace = Workspace::default();
+        for asn in modules {
+            workspace.add_model(parse(asn)).unwrap();
+        }
+        workspace
+    }
+
+    const COMMON: &str = r"Common DEFINITIONS IMPLICIT TAGS ::= BEGIN
+        EXPORTS Identifier, Label;
+        Identifier ::= [APPLICATION 3] INTEGER
+        Label ::= [APPLICATION 1] UTF8String
+        Hidden ::= BOOLEAN
+        END";
+
+    #[test]
+    fn test_modules_are_ordered_by_their_imports() {
+        let mut workspace = workspace(&[
+            r"Messages DEFINITIONS ::= BEGIN
+            IMPORTS Header FROM Headers Identifier FROM Common;
+            Message ::= SEQUENCE { header Header, id Identifier }
+            END",
+            r"Headers DEFINITIONS ::= BEGIN
+            IMPORTS Label FROM Common;
+            Header ::= SEQUENCE { label Label }
+            END",
+            COMMON,
+        ]);
+        workspace.resolve().unwrap();
+
+        assert_eq!(
+            vec!["Common", "Headers", "Messages"],
+            workspace
+                .models()
+                .iter()
+                .map(|model| model.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(&Definition(
+                "Identifier".into(),
+                Type::Integer(Default::default()).tagged(Tag::Application(3))
+            )),
+            workspace.definition("Messages", "Identifier")
+        );
+        assert!(workspace.definition("Messages", "Label").is_none());
+    }
+
+    #[test]
+    fn test_set_components_are_encoded_in_the_order_of_imported_tags() {
+        let mut workspace = workspace(&[
+            r"Messages DEFINITIONS ::= BEGIN
+            IMPORTS Identifier, Label FROM Common;
+            Message ::= SET { id Identifier, flag [APPLICATION 2] BOOLEAN, label Label }
+            END",
+            COMMON,
+        ]);
+        workspace.resolve().unwrap();
+
+        match &workspace.model("Messages").unwrap().definitions[0].1.r#type {
+            Type::Set(list) => assert_eq!(
+                vec!["id", "flag", "label"],
+                list.fields
+                    .iter()
+                    .map(|field| field.name.as_str())
+                    .collect::<Vec<_>>()
+            ),
+            other => panic!("Expected SET but got {:?}", other),
+        }
+
+        let rust = workspace.to_rust("Messages").unwrap();
+        match &rust.definitions[0].1 {
+            Rust::Struct { fields, .. } => assert_eq!(
+                vec!["label", "flag", "id"],
+                fields.iter().map(|field| field.name()).collect::<Vec<_>>()
+            ),
+            other => panic!("Expected struct but got {:?}", other),
+        }
+
+        let mut generator = RustCodeGenerator::default();
+        generator.add_model(rust);
+        let (_file, code) = generator.to_string().unwrap().remove(0);
+        assert!(code.contains("use super::common::{Identifier, Label};"));
+        let label = code.find("pub label: Label,").unwrap();
+        let flag = code.find("pub flag: bool,").unwrap();
+        let id = code.find("pub id: Identifier,").unwrap();
+        assert!(label < flag && flag < id);
+    }
+
+    #[test]
+    fn test_missing_module() {
+        let mut workspace = workspace(&[r"Messages DEFINITIONS ::= BEGIN
+            IMPORTS Identifier FROM Unknown;
+            Message ::= SEQUENCE { id Identifier }
+            END"]);
+        match workspace.resolve() {
+            Err(Error::MissingModule(module, from, _)) => {
+                assert_eq!(("Messages", "Unknown"), (module.as_str(), from.as_str()))
+            }
+            other => panic!("Expected a missing module but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_missing_or_not_exported_symbol() {
+        for symbol in ["Hidden", "Unknown"] {
+            let mut workspace = workspace(&[
+                &format!(
+                    r"Messages DEFINITIONS ::= BEGIN
+                    IMPORTS {} FROM Common;
+                    Flag ::= BOOLEAN
+                    END",
+                    symbol
+                ),
+                COMMON,
+            ]);
+            match workspace.resolve() {
+                Err(Error::MissingSymbol(module, from, missing, _)) => assert_eq!(
+                    ("Messages", "Common", symbol),
+                    (module.as_str(), from.as_str(), missing.as_str())
+                ),
+                other => panic!("Expected a missing symbol but got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_cyclic_imports() {
+        let mut workspace = workspace(&[
+            r"First DEFINITIONS ::= BEGIN
+            IMPORTS B FROM Second;
+            A ::= SEQUENCE { b B }
+            END",
+            r"Second DEFINITIONS ::= BEGIN
+            IMPORTS A FROM First;
+            B ::= SEQUENCE OF A
+            END",
+        ]);
+        match workspace.resolve() {
+            Err(Error::CyclicImports(modules, _)) => {
+                assert_eq!(vec!["First", "Second", "First"], modules)
+            }
+            other => panic!("Expected cyclic imports but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_errors_refer_to_the_loaded_file() {
+        let path = std::env::temp_dir().join("asn1rs_workspace_test_errors.asn1");
+        std::fs::write(
+            &path,
+            "Broken DEFINITIONS ::= BEGIN\n  Broken ::= SEQUENCE Foo\nEND",
+        )
+        .unwrap();
+        let mut workspace = Workspace::default();
+        let error = workspace.load_file(&path).unwrap_err();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(
+            format!(
+                "error: an unexpected token was encountered: \"Foo\"\n \
+                 --> {}:2:23\n  \
+                 |\n\
+                 2 |   Broken ::= SEQUENCE Foo\n  \
+                 |                       ^^^",
+                path.display()
+            ),
+            workspace.sources().report(error).to_string()
+        );
+    }
+
+    #[test]
+    fn test_unexpected_characters_refer_to_the_loaded_file() {
+        let path = std::env::temp_dir().join("asn1rs_workspace_test_characters.asn1");
+        std::fs::write(
+            &path,
+            "Broken DEFINITIONS ::= BEGIN\n  Broken ::= # BOOLEAN\nEND",
+        )
+        .unwrap();
+        let mut workspace = Workspace::default();
+        let error = workspace.load_file(&path).unwrap_err();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(
+            format!(
+                "error: the character '#' (0x23) is not allowed here\n \
+                 --> {}:2:14\n  \
+                 |\n\
+                 2 |   Broken ::= # BOOLEAN\n  \
+                 |              ^",
+                path.display()
+            ),
+            workspace.sources().report(error).to_string()
+        );
+    }
+
+    #[test]
+    fn test_import_errors_refer_to_the_imports_clause() {
+        let path = std::env::temp_dir().join("asn1rs_workspace_test_imports.asn1");
+        std::fs::write(
+            &path,
+            "Messages DEFINITIONS ::= BEGIN\n  IMPORTS Identifier FROM Unknown;\n  Id ::= Identifier\nEND",
+        )
+        .unwrap();
+        let mut workspace = Workspace::default();
+        let loaded = workspace.load_file(&path).map(drop);
+        let _ = std::fs::remove_file(&path);
+        loaded.unwrap();
+        let error = workspace.resolve().unwrap_err();
+
+        assert_eq!(
+            format!(
+                "error: The module Messages imports from the unknown module Unknown\n \
+                 --> {}:2:3\n  \
+                 |\n\
+                 2 |   IMPORTS Identifier FROM Unknown;\n  \
+                 |   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^",
+                path.display()
+            ),
+            workspace.sources().report(error).to_string()
+        );
+    }
+
+    #[test]
+    fn test_duplicate_module() {
+        let mut workspace = workspace(&[COMMON]);
+        match workspace.add_model(parse(COMMON)) {
+            Err(Error::DuplicateModule(module)) => assert_eq!("Common", module),
+            other => panic!("Expected a duplicate module but got {:?}", other),
+        }
+    }
+}
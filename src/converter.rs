@@ -6,9 +6,13 @@ use crate::gen::sql::SqlDefGenerator as SqlGenerator;
 use crate::gen::Generator;
 use crate::model::protobuf::ToProtobufModel;
 use crate::model::sql::ToSqlModel;
+use crate::model::validation::Error as ValidationError;
 use crate::model::Error as ModelError;
-use crate::model::Model;
-use crate::parser::Tokenizer;
+use crate::model::{Model, Rust};
+use crate::report::Report;
+use crate::workspace::Error as WorkspaceError;
+use crate::workspace::Workspace;
+use std::fmt::{Display, Formatter};
 use std::io::Error as IoError;
 use std::path::Path;
 
@@ -17,10 +21,39 @@ pub enum Error {
     RustGenerator,
     ProtobufGenerator(ProtobufGeneratorError),
     SqlGenerator(SqlGeneratorError),
-    Model(ModelError),
+    Model(Box<ModelError>),
+    /// The problem with the files, reported with the line it refers to
+    Workspace(Box<Report<WorkspaceError>>),
+    /// All problems found while validating the modules
+    Validation(Vec<Report<ValidationError>>),
     Io(IoError),
 }
 
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Error::RustGenerator => write!(f, "error: failed to generate the rust code"),
+            Error::ProtobufGenerator(e) => write!(f, "error: failed to generate protobuf: {:?}", e),
+            Error::SqlGenerator(e) => write!(f, "error: failed to generate sql: {:?}", e),
+            Error::Model(e) => write!(f, "error: {}", e),
+            Error::Workspace(report) => write!(f, "{}", report),
+            Error::Validation(reports) => {
+                for (index, report) in reports.iter().enumerate() {
+                    if index > 0 {
+                        writeln!(f)?;
+                        writeln!(f)?;
+                    }
+                    write!(f, "{}", report)?;
+                }
+                Ok(())
+            }
+            Error::Io(e) => write!(f, "error: {}", e),
+        }
+    }
+}
+
 impl From<ProtobufGeneratorError> for Error {
     fn from(g: ProtobufGeneratorError) -> Self {
         Error::ProtobufGenerator(g)
@@ -35,7 +68,7 @@ impl From<SqlGeneratorError> for Error {
 
 impl From<ModelError> for Error {
     fn from(m: ModelError) -> Self {
-        Error::Model(m)
+        Error::Model(Box::new(m))
     }
 }
 
@@ -50,41 +83,56 @@ pub fn convert_to_rust<F: AsRef<Path>, D: AsRef<Path>, A: FnOnce(&mut RustGenera
     dir: D,
     custom_adjustments: A,
 ) -> Result<Vec<String>, Error> {
-    let input = ::std::fs::read_to_string(file)?;
-    let tokens = Tokenizer::default().parse(&input);
-    let model = Model::try_from(tokens)?;
+    generate_rust(load(&[file], false)?, dir, custom_adjustments)
+}
+
+/// Converts all given files at once, so that the modules can import from one another
+pub fn convert_all_to_rust<F: AsRef<Path>, D: AsRef<Path>, A: FnOnce(&mut RustGenerator)>(
+    files: &[F],
+    dir: D,
+    custom_adjustments: A,
+) -> Result<Vec<String>, Error> {
+    generate_rust(load(files, true)?, dir, custom_adjustments)
+}
+
+fn generate_rust<D: AsRef<Path>, A: FnOnce(&mut RustGenerator)>(
+    workspace: Workspace,
+    dir: D,
+    custom_adjustments: A,
+) -> Result<Vec<String>, Error> {
     let mut generator = RustGenerator::default();
-    generator.add_model(model.to_rust());
+    for model in to_rust(&workspace) {
+        generator.add_model(model);
+    }
 
     custom_adjustments(&mut generator);
 
     let output = generator.to_string().map_err(|_| Error::RustGenerator)?;
-
-    let mut files = Vec::new();
-    for (file, content) in output {
-        ::std::fs::write(dir.as_ref().join(&file), content)?;
-        files.push(file);
-    }
-    Ok(files)
+    write_all(dir, output)
 }
 
 pub fn convert_to_proto<F: AsRef<Path>, D: AsRef<Path>>(
     file: F,
     dir: D,
 ) -> Result<Vec<String>, Error> {
-    let input = ::std::fs::read_to_string(file)?;
-    let tokens = Tokenizer::default().parse(&input);
-    let model = Model::try_from(tokens)?;
-    let mut generator = ProtobufGenerator::default();
-    generator.add_model(model.to_rust().to_protobuf());
-    let output = generator.to_string()?;
+    generate_proto(load(&[file], false)?, dir)
+}
 
-    let mut files = Vec::new();
-    for (file, content) in output {
-        ::std::fs::write(dir.as_ref().join(&file), content)?;
-        files.push(file);
+/// Converts all given files at once, so that the modules can import from one another
+pub fn convert_all_to_proto<F: AsRef<Path>, D: AsRef<Path>>(
+    files: &[F],
+    dir: D,
+) -> Result<Vec<String>, Error> {
+    generate_proto(load(files, true)?, dir)
+}
+
+fn generate_proto<D: AsRef<Path>>(workspace: Workspace, dir: D) -> Result<Vec<String>, Error> {
+    let mut generator = ProtobufGenerator::default();
+    for model in to_rust(&workspace) {
+        generator.add_model(model.to_protobuf());
     }
-    Ok(files)
+    let output = generator.to_string()?;
+    write_all(dir, output)
 }
 
 pub fn convert_to_sql<F: AsRef<Path>, D: AsRef<Path>>(
@@ -97,15 +145,76 @@ pub fn convert_to_sql<F: AsRef<Path>, D: AsRef<Path>>(
 pub fn convert_to_sql_with<F: AsRef<Path>, D: AsRef<Path>>(
     file: F,
     dir: D,
-    mut generator: SqlGenerator,
+    generator: SqlGenerator,
 ) -> Result<Vec<String>, Error> {
-    let input = ::std::fs::read_to_string(file)?;
-    let tokens = Tokenizer::default().parse(&input);
-    let model = Model::try_from(tokens)?;
+    generate_sql(load(&[file], false)?, dir, generator)
+}
 
-    generator.add_model(model.to_rust().to_sql());
+/// Converts all given files at once, so that the modules can import from one another
+pub fn convert_all_to_sql<F: AsRef<Path>, D: AsRef<Path>>(
+    files: &[F],
+    dir: D,
+) -> Result<Vec<String>, Error> {
+    convert_all_to_sql_with(files, dir, SqlGenerator::default())
+}
+
+pub fn convert_all_to_sql_with<F: AsRef<Path>, D: AsRef<Path>>(
+    files: &[F],
+    dir: D,
+    generator: SqlGenerator,
+) -> Result<Vec<String>, Error> {
+    generate_sql(load(files, true)?, dir, generator)
+}
+
+fn generate_sql<D: AsRef<Path>>(
+    workspace: Workspace,
+    dir: D,
+    mut generator: SqlGenerator,
+) -> Result<Vec<String>, Error> {
+    for model in to_rust(&workspace) {
+        generator.add_model(model.to_sql());
+    }
     let output = generator.to_string()?;
+    write_all(dir, output)
+}
+
+/// Loads and validates all given files. Errors are reported with the lines they refer to.
+/// Only if resolved, all imported modules must be among the given files.
+fn load<F: AsRef<Path>>(files: &[F], resolve: bool) -> Result<Workspace, Error> {
+    let mut workspace = Workspace::default();
+    let loaded = files
+        .iter()
+        .try_for_each(|file| workspace.load_file(file).map(drop))
+        .and_then(|_| if resolve { workspace.resolve() } else { Ok(()) });
+    if let Err(e) = loaded {
+        return Err(Error::Workspace(Box::new(workspace.sources().report(e))));
+    }
+
+    let errors = workspace
+        .models()
+        .iter()
+        .filter_map(|model| model.validate().err())
+        .flatten()
+        .map(|e| workspace.sources().report(e))
+        .collect::<Vec<_>>();
+    if errors.is_empty() {
+        Ok(workspace)
+    } else {
+        Err(Error::Validation(errors))
+    }
+}
+
+/// Converts every module, where the types imported from other modules are looked up in
+/// the workspace, if loaded
+fn to_rust(workspace: &Workspace) -> Vec<Model<Rust>> {
+    workspace
+        .models()
+        .iter()
+        .filter_map(|model| workspace.to_rust(&model.name))
+        .collect()
+}
 
+fn write_all<D: AsRef<Path>>(dir: D, output: Vec<(String, String)>) -> Result<Vec<String>, Error> {
     let mut files = Vec::new();
     for (file, content) in output {
         ::std::fs::write(dir.as_ref().join(&file), content)?;
@@ -0,0 +1,319 @@
+use crate::syn::numbers::IntegerError;
+use crate::syn::opentype::KeyError;
+use crate::syn::Tag;
+
+const CLASS_UNIVERSAL: u8 = 0x00;
+const CLASS_APPLICATION: u8 = 0x40;
+const CLASS_CONTEXT_SPECIFIC: u8 = 0x80;
+const CLASS_PRIVATE: u8 = 0xC0;
+const CLASS_MASK: u8 = 0xC0;
+const CONSTRUCTED: u8 = 0x20;
+const TAG_NUMBER_MASK: u8 = 0x1F;
+const LENGTH_LONG_FORM: u8 = 0x80;
+
+#[derive(Debug, PartialOrd, PartialEq)]
+pub enum Error {
+    InvalidUtf8String,
+    UnsupportedOperation(String),
+    UnexpectedTag(Tag, Tag),
+    UnexpectedEncodingForm(Tag, bool),
+    InvalidLength(usize),
+    InvalidBitString,
+    InvalidReal,
+    InvalidObjectIdentifier,
+    InvalidTime(String),
+    InvalidCharacter(u32),
+    InvalidChoiceIndex(usize, usize),
+    /// The number, which is not assigned to a variant of the named enumerated
+    UnknownEnumeratedNumber(i64, &'static str),
+    ValueNotInRange(i64, i64, i64),
+    /// The value, which exceeds the values that can be encoded
+    ValueTooLarge(u64),
+    /// The key, which does not identify the type of the value of the named open type
+    OpenTypeKeyMismatch(i64, &'static str),
+    SizeNotInRange(usize, usize, usize),
+    EndOfStream,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidUtf8String => {
+                write!(f, "The underlying dataset is not a valid UTF8-String")
+            }
+            Error::UnsupportedOperation(o) => write!(f, "The operation is not supported: {}", o),
+            Error::UnexpectedTag(expected, found) => write!(
+                f,
+                "Expected the tag {:?} but found the tag {:?}",
+                expected, found
+            ),
+            Error::UnexpectedEncodingForm(tag, constructed) => write!(
+                f,
+                "The value with the tag {:?} is unexpectedly encoded in the {} form",
+                tag,
+                if *constructed {
+                    "constructed"
+                } else {
+                    "primitive"
+                }
+            ),
+            Error::InvalidLength(length) => {
+                write!(f, "The length {} is not valid for this value", length)
+            }
+            Error::InvalidBitString => write!(f, "The content is not a valid bit string"),
+            Error::InvalidReal => write!(f, "The content is not a valid real"),
+            Error::InvalidObjectIdentifier => {
+                write!(f, "The content is not a valid object identifier")
+            }
+            Error::InvalidTime(time) => write!(f, "The string {} is not a valid time", time),
+            Error::InvalidCharacter(value) => write!(
+                f,
+                "The character U+{:04X} is not within the permitted alphabet",
+                value
+            ),
+            Error::InvalidChoiceIndex(index, variant_count) => write!(
+                f,
+                "Unexpected choice-index {} with variant count {}",
+                index, variant_count
+            ),
+            Error::UnknownEnumeratedNumber(number, name) => write!(
+                f,
+                "The number {} is not assigned to a variant of the {} enumerated",
+                number, name
+            ),
+            Error::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Error::ValueTooLarge(value) => {
+                write!(f, "The value {} is too large to be encoded", value)
+            }
+            Error::OpenTypeKeyMismatch(key, name) => write!(
+                f,
+                "The key {} does not identify the type of the {} value",
+                key, name
+            ),
+            Error::SizeNotInRange(size, min, max) => write!(
+                f,
+                "The size {} is not within the inclusive range of {} and {}",
+                size, min, max
+            ),
+            Error::EndOfStream => write!(
+                f,
+                "Can no longer read or write any bytes from the underlying dataset"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding BER failed"
+    }
+}
+
+impl IntegerError for Error {
+    fn value_not_in_range(value: i64, min: i64, max: i64) -> Self {
+        Error::ValueNotInRange(value, min, max)
+    }
+
+    fn value_too_large(value: u64) -> Self {
+        Error::ValueTooLarge(value)
+    }
+}
+
+impl KeyError for Error {
+    fn key_mismatch(key: i64, name: &'static str) -> Self {
+        Error::OpenTypeKeyMismatch(key, name)
+    }
+}
+
+/// Writes the identifier octets for the given tag, X.690-201508 8.1.2
+pub fn write_identifier(buffer: &mut Vec<u8>, tag: Tag, constructed: bool) {
+    let class = match tag {
+        Tag::Universal(_) => CLASS_UNIVERSAL,
+        Tag::Application(_) => CLASS_APPLICATION,
+        Tag::ContextSpecific(_) => CLASS_CONTEXT_SPECIFIC,
+        Tag::Private(_) => CLASS_PRIVATE,
+    };
+    let form = if constructed { CONSTRUCTED } else { 0x00 };
+    let number = tag.value();
+    if number < TAG_NUMBER_MASK as usize {
+        buffer.push(class | form | number as u8);
+    } else {
+        buffer.push(class | form | TAG_NUMBER_MASK);
+        let len = base128_len(number as u64);
+        for i in (0..len).rev() {
+            let more = if i > 0 { 0x80 } else { 0x00 };
+            buffer.push(more | ((number >> (7 * i)) & 0x7F) as u8);
+        }
+    }
+}
+
+/// Writes the length octets in the definite form, X.690-201508 8.1.3
+pub fn write_length(buffer: &mut Vec<u8>, length: usize) {
+    if length < LENGTH_LONG_FORM as usize {
+        buffer.push(length as u8);
+    } else {
+        let bytes = (length as u64).to_be_bytes();
+        let skip = bytes.iter().take_while(|b| **b == 0).count();
+        buffer.push(LENGTH_LONG_FORM | (bytes.len() - skip) as u8);
+        buffer.extend_from_slice(&bytes[skip..]);
+    }
+}
+
+/// Reads the identifier octets, returning the tag, whether the value is constructed and
+/// the amount of octets read, X.690-201508 8.1.2
+pub fn read_identifier(bytes: &[u8]) -> Result<(Tag, bool, usize), Error> {
+    let first = *bytes.first().ok_or(Error::EndOfStream)?;
+    let constructed = first & CONSTRUCTED != 0;
+    let (number, len) = if first & TAG_NUMBER_MASK == TAG_NUMBER_MASK {
+        let mut number = 0_usize;
+        let mut len = 1;
+        loop {
+            let byte = *bytes.get(len).ok_or(Error::EndOfStream)?;
+            len += 1;
+            if number > (usize::MAX >> 7) {
+                return Err(Error::UnsupportedOperation(
+                    "Tag numbers exceeding the platform word size are not supported".into(),
+                ));
+            }
+            number = (number << 7) | usize::from(byte & 0x7F);
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        (number, len)
+    } else {
+        (usize::from(first & TAG_NUMBER_MASK), 1)
+    };
+    let tag = match first & CLASS_MASK {
+        CLASS_UNIVERSAL => Tag::Universal(number),
+        CLASS_APPLICATION => Tag::Application(number),
+        CLASS_CONTEXT_SPECIFIC => Tag::ContextSpecific(number),
+        _ => Tag::Private(number),
+    };
+    Ok((tag, constructed, len))
+}
+
+/// Reads the length octets, returning `None` as length for the indefinite form
+/// and the amount of octets read, X.690-201508 8.1.3
+pub fn read_length(bytes: &[u8]) -> Result<(Option<usize>, usize), Error> {
+    let first = *bytes.first().ok_or(Error::EndOfStream)?;
+    if first & LENGTH_LONG_FORM == 0 {
+        Ok((Some(usize::from(first)), 1))
+    } else if first == LENGTH_LONG_FORM {
+        Ok((None, 1))
+    } else {
+        let octets = usize::from(first & !LENGTH_LONG_FORM);
+        if octets > core::mem::size_of::<usize>() {
+            return Err(Error::UnsupportedOperation(format!(
+                "Lengths encoded in {} octets are not supported",
+                octets
+            )));
+        }
+        let length = bytes
+            .get(1..=octets)
+            .ok_or(Error::EndOfStream)?
+            .iter()
+            .fold(0_usize, |length, byte| (length << 8) | usize::from(*byte));
+        Ok((Some(length), 1 + octets))
+    }
+}
+
+/// The minimal two's complement representation of the given value, X.690-201508 8.3
+pub fn integer_content(value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let mut skip = 0;
+    while skip < bytes.len() - 1
+        && ((bytes[skip] == 0x00 && bytes[skip + 1] & 0x80 == 0)
+            || (bytes[skip] == 0xFF && bytes[skip + 1] & 0x80 != 0))
+    {
+        skip += 1;
+    }
+    bytes[skip..].to_vec()
+}
+
+/// Interprets the given content as two's complement integer, X.690-201508 8.3
+pub fn integer_from_content(content: &[u8]) -> Result<i64, Error> {
+    if content.is_empty() {
+        Err(Error::InvalidLength(0))
+    } else if content.len() > 8 {
+        Err(Error::UnsupportedOperation(
+            "Reading bigger data types than 64bit is not supported".into(),
+        ))
+    } else {
+        let fill = if content[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+        let mut bytes = [fill; 8];
+        bytes[8 - content.len()..].copy_from_slice(content);
+        Ok(i64::from_be_bytes(bytes))
+    }
+}
+
+fn base128_len(mut value: u64) -> usize {
+    let mut len = 1;
+    while value > 0x7F {
+        value >>= 7;
+        len += 1;
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identifier_low_tag_number() {
+        let mut buffer = Vec::new();
+        write_identifier(&mut buffer, Tag::DEFAULT_SEQUENCE, true);
+        write_identifier(&mut buffer, Tag::ContextSpecific(3), false);
+        write_identifier(&mut buffer, Tag::Application(4), true);
+        assert_eq!(&[0x30, 0x83, 0x64], &buffer[..]);
+        assert_eq!(
+            (Tag::Application(4), true, 1),
+            read_identifier(&buffer[2..]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_identifier_high_tag_number() {
+        let mut buffer = Vec::new();
+        write_identifier(&mut buffer, Tag::Private(201), false);
+        assert_eq!(&[0xDF, 0x81, 0x49], &buffer[..]);
+        assert_eq!(
+            (Tag::Private(201), false, 3),
+            read_identifier(&buffer[..]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_length_short_and_long_form() {
+        let mut buffer = Vec::new();
+        write_length(&mut buffer, 127);
+        write_length(&mut buffer, 128);
+        write_length(&mut buffer, 1337);
+        assert_eq!(&[0x7F, 0x81, 0x80, 0x82, 0x05, 0x39], &buffer[..]);
+        assert_eq!((Some(127), 1), read_length(&buffer[0..]).unwrap());
+        assert_eq!((Some(128), 2), read_length(&buffer[1..]).unwrap());
+        assert_eq!((Some(1337), 3), read_length(&buffer[3..]).unwrap());
+        assert_eq!((None, 1), read_length(&[0x80]).unwrap());
+    }
+
+    #[test]
+    fn test_integer_content_is_minimal() {
+        for (value, content) in &[
+            (0_i64, &[0x00_u8][..]),
+            (127, &[0x7F]),
+            (128, &[0x00, 0x80]),
+            (256, &[0x01, 0x00]),
+            (-1, &[0xFF]),
+            (-128, &[0x80]),
+            (-129, &[0xFF, 0x7F]),
+        ] {
+            assert_eq!(*content, &integer_content(*value)[..]);
+            assert_eq!(*value, integer_from_content(content).unwrap());
+        }
+    }
+}
@@ -965,6 +965,66 @@ mod tests {
         Ok(())
     }
 
+    fn check_int_unconstrained(buffer: &mut BitBuffer, int: i64) -> Result<(), UperError> {
+        {
+            let mut buffer2 = BitBuffer::from_bits(buffer.content().into(), buffer.bit_len());
+            assert_eq!(int, buffer2.read_int_unconstrained()?);
+        }
+
+        assert_eq!(int, buffer.read_int_unconstrained()?);
+        Ok(())
+    }
+
+    #[test]
+    fn bit_buffer_int_unconstrained_shortest_twos_complement() -> Result<(), UperError> {
+        for (int, content) in &[
+            (0_i64, &[0x01, 0x00][..]),
+            (127, &[0x01, 0x7F][..]),
+            (128, &[0x02, 0x00, 0x80][..]),
+            (-1, &[0x01, 0xFF][..]),
+            (-128, &[0x01, 0x80][..]),
+            (-129, &[0x02, 0xFF, 0x7F][..]),
+            (
+                i64::MIN,
+                &[0x08, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..],
+            ),
+        ] {
+            let mut buffer = BitBuffer::default();
+            buffer.write_int_unconstrained(*int)?;
+            assert_eq!(buffer.content(), *content);
+            check_int_unconstrained(&mut buffer, *int)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn bit_buffer_int_semi_constrained_offset_from_lower_bound() -> Result<(), UperError> {
+        let mut buffer = BitBuffer::default();
+        buffer.write_int_semi_constrained(-3, -5)?;
+        // only the offset of 2 from the lower bound is written
+        assert_eq!(buffer.content(), &[0x00 | 1, 0x02]);
+        assert_eq!(-3, buffer.read_int_semi_constrained(-5)?);
+
+        let mut buffer = BitBuffer::default();
+        buffer.write_int_semi_constrained(i64::MAX, -1)?;
+        // the offset exceeds i64::MAX
+        assert_eq!(
+            buffer.content(),
+            &[0x00 | 8, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(i64::MAX, buffer.read_int_semi_constrained(-1)?);
+        Ok(())
+    }
+
+    #[test]
+    fn bit_buffer_write_int_semi_constrained_detects_not_in_range() {
+        let mut buffer = BitBuffer::default();
+        assert_eq!(
+            buffer.write_int_semi_constrained(-6, -5),
+            Err(UperError::ValueNotInRange(-6, -5, i64::MAX))
+        );
+    }
+
     #[test]
     fn bit_buffer_write_int_detects_not_in_range_positive_only() {
         let mut buffer = BitBuffer::default();
@@ -0,0 +1,358 @@
+use crate::syn::numbers::IntegerError;
+use crate::syn::opentype::KeyError;
+use std::fmt::Write;
+
+#[derive(Debug, PartialOrd, PartialEq)]
+pub enum Error {
+    InvalidJson(usize),
+    UnexpectedType(&'static str),
+    MissingField(&'static str),
+    UnknownVariant(String),
+    InvalidHexString,
+    InvalidObjectIdentifier(String),
+    InvalidTime(String),
+    InvalidCharacter(u32),
+    InvalidNumber(String),
+    ValueNotInRange(i64, i64, i64),
+    /// The value, which exceeds the values that can be encoded
+    ValueTooLarge(u64),
+    /// The key, which does not identify the type of the value of the named open type
+    OpenTypeKeyMismatch(i64, &'static str),
+    SizeNotInRange(usize, usize, usize),
+    EndOfStream,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidJson(position) => {
+                write!(f, "The input is not valid JSON at position {}", position)
+            }
+            Error::UnexpectedType(expected) => {
+                write!(f, "Expected a JSON {} but found something else", expected)
+            }
+            Error::MissingField(name) => {
+                write!(f, "The mandatory field {} is not present", name)
+            }
+            Error::UnknownVariant(name) => write!(f, "There is no variant named {}", name),
+            Error::InvalidHexString => write!(f, "The string is not a valid hex-string"),
+            Error::InvalidObjectIdentifier(oid) => {
+                write!(f, "The string {} is not a valid object identifier", oid)
+            }
+            Error::InvalidTime(time) => write!(f, "The string {} is not a valid time", time),
+            Error::InvalidCharacter(value) => write!(
+                f,
+                "The character U+{:04X} is not within the permitted alphabet",
+                value
+            ),
+            Error::InvalidNumber(number) => {
+                write!(f, "The number {} cannot be represented", number)
+            }
+            Error::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Error::ValueTooLarge(value) => {
+                write!(f, "The value {} is too large to be encoded", value)
+            }
+            Error::OpenTypeKeyMismatch(key, name) => write!(
+                f,
+                "The key {} does not identify the type of the {} value",
+                key, name
+            ),
+            Error::SizeNotInRange(size, min, max) => write!(
+                f,
+                "The size {} is not within the inclusive range of {} and {}",
+                size, min, max
+            ),
+            Error::EndOfStream => write!(f, "There are no more values to read"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding JER failed"
+    }
+}
+
+impl IntegerError for Error {
+    fn value_not_in_range(value: i64, min: i64, max: i64) -> Self {
+        Error::ValueNotInRange(value, min, max)
+    }
+
+    fn value_too_large(value: u64) -> Self {
+        Error::ValueTooLarge(value)
+    }
+}
+
+impl KeyError for Error {
+    fn key_mismatch(key: i64, name: &'static str) -> Self {
+        Error::OpenTypeKeyMismatch(key, name)
+    }
+}
+
+/// A minimal JSON document model. Numbers keep their textual representation,
+/// so that they can be interpreted as required by the reading type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn parse(json: &str) -> Result<Value, Error> {
+        let mut parser = Parser {
+            chars: json.char_indices().peekable(),
+            len: json.len(),
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if let Some((position, _)) = parser.chars.next() {
+            Err(Error::InvalidJson(position))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Number(value) => f.write_str(value),
+            Value::String(value) => write_escaped(f, value),
+            Value::Array(values) => {
+                f.write_char('[')?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                f.write_char(']')
+            }
+            Value::Object(entries) => {
+                f.write_char('{')?;
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    if index > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_escaped(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_escaped(f: &mut std::fmt::Formatter<'_>, value: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    len: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn position(&mut self) -> usize {
+        let len = self.len;
+        self.chars.peek().map(|(p, _)| *p).unwrap_or(len)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some((_, ' ')) | Some((_, '\t')) | Some((_, '\n')) | Some((_, '\r')) =
+            self.chars.peek()
+        {
+            self.chars.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Error> {
+        match self.chars.next() {
+            Some((_, c)) if c == expected => Ok(()),
+            Some((position, _)) => Err(Error::InvalidJson(position)),
+            None => Err(Error::InvalidJson(self.len)),
+        }
+    }
+
+    fn expect_word(&mut self, word: &str, value: Value) -> Result<Value, Error> {
+        for c in word.chars() {
+            self.expect(c)?;
+        }
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Value, Error> {
+        self.skip_whitespace();
+        match self.chars.peek().map(|(_, c)| *c) {
+            Some('n') => self.expect_word("null", Value::Null),
+            Some('t') => self.expect_word("true", Value::Bool(true)),
+            Some('f') => self.expect_word("false", Value::Bool(false)),
+            Some('"') => self.string().map(Value::String),
+            Some('[') => self.array(),
+            Some('{') => self.object(),
+            Some(c) if c == '-' || c.is_ascii_digit() => Ok(self.number()),
+            _ => Err(Error::InvalidJson(self.position())),
+        }
+    }
+
+    fn number(&mut self) -> Value {
+        let mut number = String::new();
+        while let Some((_, c)) = self.chars.peek() {
+            if c.is_ascii_digit() || ['-', '+', '.', 'e', 'E'].contains(c) {
+                number.push(*c);
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        Value::Number(number)
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        self.expect('"')?;
+        let mut string = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(string),
+                Some((position, '\\')) => match self.chars.next() {
+                    Some((_, '"')) => string.push('"'),
+                    Some((_, '\\')) => string.push('\\'),
+                    Some((_, '/')) => string.push('/'),
+                    Some((_, 'b')) => string.push('\u{08}'),
+                    Some((_, 'f')) => string.push('\u{0C}'),
+                    Some((_, 'n')) => string.push('\n'),
+                    Some((_, 'r')) => string.push('\r'),
+                    Some((_, 't')) => string.push('\t'),
+                    Some((_, 'u')) => {
+                        let high = self.hex4()?;
+                        let code = if (0xD800..0xDC00).contains(&high) {
+                            self.expect('\\')?;
+                            self.expect('u')?;
+                            let low = self.hex4()?;
+                            0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF)
+                        } else {
+                            high
+                        };
+                        string.push(std::char::from_u32(code).ok_or(Error::InvalidJson(position))?);
+                    }
+                    _ => return Err(Error::InvalidJson(position)),
+                },
+                Some((_, c)) => string.push(c),
+                None => return Err(Error::InvalidJson(self.len)),
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, Error> {
+        let mut value = 0;
+        for _ in 0..4 {
+            let position = self.position();
+            let digit = self
+                .chars
+                .next()
+                .and_then(|(_, c)| c.to_digit(16))
+                .ok_or(Error::InvalidJson(position))?;
+            value = (value << 4) | digit;
+        }
+        Ok(value)
+    }
+
+    fn array(&mut self) -> Result<Value, Error> {
+        self.expect('[')?;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if let Some((_, ']')) = self.chars.peek() {
+            self.chars.next();
+            return Ok(Value::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, ']')) => return Ok(Value::Array(values)),
+                Some((position, _)) => return Err(Error::InvalidJson(position)),
+                None => return Err(Error::InvalidJson(self.len)),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, Error> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if let Some((_, '}')) = self.chars.peek() {
+            self.chars.next();
+            return Ok(Value::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, '}')) => return Ok(Value::Object(entries)),
+                Some((position, _)) => return Err(Error::InvalidJson(position)),
+                None => return Err(Error::InvalidJson(self.len)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_print_round_trip() {
+        let json = r#"{"a":[1,-2,3.5e3],"b":{"c":null,"d":true},"e":"x\"y\\z\n"}"#;
+        let value = Value::parse(json).unwrap();
+        assert_eq!(json, value.to_string());
+    }
+
+    #[test]
+    fn test_parse_whitespace_and_unicode_escapes() {
+        let value = Value::parse(" { \"k\" : [ \"\\u00e4\\ud83d\\ude00\" , false ] } ").unwrap();
+        assert_eq!(
+            Value::Object(vec![(
+                "k".to_string(),
+                Value::Array(vec![
+                    Value::String("\u{e4}\u{1F600}".to_string()),
+                    Value::Bool(false)
+                ])
+            )]),
+            value
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(Err(Error::InvalidJson(5)), Value::parse("[1,2 3]"));
+        assert_eq!(Err(Error::InvalidJson(4)), Value::parse("{\"a\""));
+    }
+}
@@ -1,6 +1,10 @@
+pub mod ber;
 pub mod buffer;
+pub mod jer;
+pub mod oer;
 pub mod protobuf;
 pub mod uper;
+pub mod xer;
 
 #[cfg(feature = "psql")]
 pub mod psql;
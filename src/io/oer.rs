@@ -0,0 +1,393 @@
+use crate::syn::numbers::IntegerError;
+use crate::syn::opentype::KeyError;
+use crate::syn::Tag;
+
+const CLASS_UNIVERSAL: u8 = 0x00;
+const CLASS_APPLICATION: u8 = 0x40;
+const CLASS_CONTEXT_SPECIFIC: u8 = 0x80;
+const CLASS_PRIVATE: u8 = 0xC0;
+const CLASS_MASK: u8 = 0xC0;
+const TAG_NUMBER_MASK: u8 = 0x3F;
+const LENGTH_LONG_FORM: u8 = 0x80;
+
+#[derive(Debug, PartialOrd, PartialEq)]
+pub enum Error {
+    InvalidUtf8String,
+    UnsupportedOperation(String),
+    UnexpectedTag(Tag, Tag),
+    UnknownChoiceTag(Tag),
+    InvalidChoiceIndex(usize, usize),
+    /// The number, which is not assigned to a variant of the named enumerated
+    UnknownEnumeratedNumber(i64, &'static str),
+    ValueNotInRange(i64, i64, i64),
+    /// The value, which exceeds the values that can be encoded
+    ValueTooLarge(u64),
+    /// The key, which does not identify the type of the value of the named open type
+    OpenTypeKeyMismatch(i64, &'static str),
+    SizeNotInRange(usize, usize, usize),
+    InvalidBitString,
+    InvalidReal,
+    InvalidObjectIdentifier,
+    InvalidTime(String),
+    InvalidCharacter(u32),
+    NotCanonical(&'static str),
+    OptFlagsExhausted,
+    EndOfStream,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidUtf8String => {
+                write!(f, "The underlying dataset is not a valid UTF8-String")
+            }
+            Error::UnsupportedOperation(o) => write!(f, "The operation is not supported: {}", o),
+            Error::UnexpectedTag(expected, found) => write!(
+                f,
+                "Expected the tag {:?} but found the tag {:?}",
+                expected, found
+            ),
+            Error::UnknownChoiceTag(tag) => {
+                write!(f, "There is no choice variant with the tag {:?}", tag)
+            }
+            Error::InvalidChoiceIndex(index, variant_count) => write!(
+                f,
+                "Unexpected choice-index {} with variant count {}",
+                index, variant_count
+            ),
+            Error::UnknownEnumeratedNumber(number, name) => write!(
+                f,
+                "The number {} is not assigned to a variant of the {} enumerated",
+                number, name
+            ),
+            Error::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Error::ValueTooLarge(value) => {
+                write!(f, "The value {} is too large to be encoded", value)
+            }
+            Error::OpenTypeKeyMismatch(key, name) => write!(
+                f,
+                "The key {} does not identify the type of the {} value",
+                key, name
+            ),
+            Error::SizeNotInRange(size, min, max) => write!(
+                f,
+                "The size {} is not within the inclusive range of {} and {}",
+                size, min, max
+            ),
+            Error::InvalidBitString => write!(f, "The content is not a valid bit string"),
+            Error::InvalidReal => write!(f, "The content is not a valid real"),
+            Error::InvalidObjectIdentifier => {
+                write!(f, "The content is not a valid object identifier")
+            }
+            Error::InvalidTime(time) => write!(f, "The string {} is not a valid time", time),
+            Error::InvalidCharacter(value) => write!(
+                f,
+                "The character U+{:04X} is not within the permitted alphabet",
+                value
+            ),
+            Error::NotCanonical(what) => {
+                write!(f, "The encoding of the {} is not canonical", what)
+            }
+            Error::OptFlagsExhausted => write!(f, "All optional flags have already been exhausted"),
+            Error::EndOfStream => write!(
+                f,
+                "Can no longer read or write any bytes from the underlying dataset"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding OER failed"
+    }
+}
+
+impl IntegerError for Error {
+    fn value_not_in_range(value: i64, min: i64, max: i64) -> Self {
+        Error::ValueNotInRange(value, min, max)
+    }
+
+    fn value_too_large(value: u64) -> Self {
+        Error::ValueTooLarge(value)
+    }
+}
+
+impl KeyError for Error {
+    fn key_mismatch(key: i64, name: &'static str) -> Self {
+        Error::OpenTypeKeyMismatch(key, name)
+    }
+}
+
+/// Writes the length determinant in the short form for lengths below 128 and in
+/// the long form with the minimal amount of length octets otherwise, X.696-201508 8.6
+pub fn write_length_determinant(buffer: &mut Vec<u8>, length: usize) {
+    if length < LENGTH_LONG_FORM as usize {
+        buffer.push(length as u8);
+    } else {
+        let bytes = unsigned_content(length as u64);
+        buffer.push(LENGTH_LONG_FORM | bytes.len() as u8);
+        buffer.extend_from_slice(&bytes);
+    }
+}
+
+/// Reads the length determinant, returning the length and the amount of octets read.
+/// If `canonical` is set, only the shortest possible form is accepted, X.696-201508 8.6
+pub fn read_length_determinant(bytes: &[u8], canonical: bool) -> Result<(usize, usize), Error> {
+    let first = *bytes.first().ok_or(Error::EndOfStream)?;
+    if first & LENGTH_LONG_FORM == 0 {
+        Ok((usize::from(first), 1))
+    } else {
+        let octets = usize::from(first & !LENGTH_LONG_FORM);
+        if octets > core::mem::size_of::<usize>() {
+            return Err(Error::UnsupportedOperation(format!(
+                "Lengths encoded in {} octets are not supported",
+                octets
+            )));
+        }
+        let content = bytes.get(1..=octets).ok_or(Error::EndOfStream)?;
+        let length = content
+            .iter()
+            .fold(0_usize, |length, byte| (length << 8) | usize::from(*byte));
+        if canonical && (length < LENGTH_LONG_FORM as usize || content.first() == Some(&0)) {
+            return Err(Error::NotCanonical("length determinant"));
+        }
+        Ok((length, 1 + octets))
+    }
+}
+
+/// Writes the tag of a choice alternative: the class in the two most significant bits
+/// followed by the tag number, X.696-201508 8.7
+pub fn write_tag(buffer: &mut Vec<u8>, tag: Tag) {
+    let class = match tag {
+        Tag::Universal(_) => CLASS_UNIVERSAL,
+        Tag::Application(_) => CLASS_APPLICATION,
+        Tag::ContextSpecific(_) => CLASS_CONTEXT_SPECIFIC,
+        Tag::Private(_) => CLASS_PRIVATE,
+    };
+    let number = tag.value();
+    if number < TAG_NUMBER_MASK as usize {
+        buffer.push(class | number as u8);
+    } else {
+        buffer.push(class | TAG_NUMBER_MASK);
+        let mut len = 1;
+        while (number >> (7 * len)) > 0 {
+            len += 1;
+        }
+        for i in (0..len).rev() {
+            let more = if i > 0 { 0x80 } else { 0x00 };
+            buffer.push(more | ((number >> (7 * i)) & 0x7F) as u8);
+        }
+    }
+}
+
+/// Reads the tag of a choice alternative, returning the tag and the amount of octets
+/// read, X.696-201508 8.7
+pub fn read_tag(bytes: &[u8]) -> Result<(Tag, usize), Error> {
+    let first = *bytes.first().ok_or(Error::EndOfStream)?;
+    let (number, len) = if first & TAG_NUMBER_MASK == TAG_NUMBER_MASK {
+        let mut number = 0_usize;
+        let mut len = 1;
+        loop {
+            let byte = *bytes.get(len).ok_or(Error::EndOfStream)?;
+            len += 1;
+            if number > (usize::MAX >> 7) {
+                return Err(Error::UnsupportedOperation(
+                    "Tag numbers exceeding the platform word size are not supported".into(),
+                ));
+            }
+            number = (number << 7) | usize::from(byte & 0x7F);
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        (number, len)
+    } else {
+        (usize::from(first & TAG_NUMBER_MASK), 1)
+    };
+    let tag = match first & CLASS_MASK {
+        CLASS_UNIVERSAL => Tag::Universal(number),
+        CLASS_APPLICATION => Tag::Application(number),
+        CLASS_CONTEXT_SPECIFIC => Tag::ContextSpecific(number),
+        _ => Tag::Private(number),
+    };
+    Ok((tag, len))
+}
+
+/// The amount of octets of the fixed-size encoding of an integer with the given
+/// bounds or `None`, if the value has to be encoded with a length determinant.
+/// An upper bound of `i64::MAX` is understood as unbounded (`MAX`), X.696-201508 10.2
+pub fn fixed_integer_octets((min, max): (i64, i64)) -> Option<usize> {
+    if max == i64::MAX {
+        None
+    } else if min >= 0 {
+        Some(if max <= 0xFF {
+            1
+        } else if max <= 0xFF_FF {
+            2
+        } else if max <= 0xFF_FF_FF_FF {
+            4
+        } else {
+            8
+        })
+    } else if min >= i64::from(i8::MIN) && max <= i64::from(i8::MAX) {
+        Some(1)
+    } else if min >= i64::from(i16::MIN) && max <= i64::from(i16::MAX) {
+        Some(2)
+    } else if min >= i64::from(i32::MIN) && max <= i64::from(i32::MAX) {
+        Some(4)
+    } else {
+        Some(8)
+    }
+}
+
+/// The minimal big-endian representation of the unsigned value, which has at least
+/// one octet, X.696-201508 10.3
+pub fn unsigned_content(value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let skip = bytes.iter().take_while(|b| **b == 0).count().min(7);
+    bytes[skip..].to_vec()
+}
+
+/// The minimal two's complement representation of the given value, X.696-201508 10.4
+pub fn signed_content(value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let mut skip = 0;
+    while skip < bytes.len() - 1
+        && ((bytes[skip] == 0x00 && bytes[skip + 1] & 0x80 == 0)
+            || (bytes[skip] == 0xFF && bytes[skip + 1] & 0x80 != 0))
+    {
+        skip += 1;
+    }
+    bytes[skip..].to_vec()
+}
+
+/// Interprets the content as big-endian unsigned value. If `canonical` is set, leading
+/// zero octets are rejected.
+pub fn unsigned_from_content(content: &[u8], canonical: bool) -> Result<u64, Error> {
+    if content.is_empty() {
+        Err(Error::EndOfStream)
+    } else if content.len() > 8 {
+        Err(Error::UnsupportedOperation(
+            "Reading bigger data types than 64bit is not supported".into(),
+        ))
+    } else if canonical && content.len() > 1 && content[0] == 0x00 {
+        Err(Error::NotCanonical("integer"))
+    } else {
+        Ok(content
+            .iter()
+            .fold(0_u64, |value, byte| (value << 8) | u64::from(*byte)))
+    }
+}
+
+/// Interprets the content as two's complement value. If `canonical` is set, redundant
+/// leading octets are rejected.
+pub fn signed_from_content(content: &[u8], canonical: bool) -> Result<i64, Error> {
+    if content.is_empty() {
+        Err(Error::EndOfStream)
+    } else if content.len() > 8 {
+        Err(Error::UnsupportedOperation(
+            "Reading bigger data types than 64bit is not supported".into(),
+        ))
+    } else if canonical
+        && content.len() > 1
+        && ((content[0] == 0x00 && content[1] & 0x80 == 0)
+            || (content[0] == 0xFF && content[1] & 0x80 != 0))
+    {
+        Err(Error::NotCanonical("integer"))
+    } else {
+        let fill = if content[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+        let mut bytes = [fill; 8];
+        bytes[8 - content.len()..].copy_from_slice(content);
+        Ok(i64::from_be_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length_determinant() {
+        let mut buffer = Vec::new();
+        write_length_determinant(&mut buffer, 127);
+        write_length_determinant(&mut buffer, 128);
+        write_length_determinant(&mut buffer, 1337);
+        assert_eq!(&[0x7F, 0x81, 0x80, 0x82, 0x05, 0x39], &buffer[..]);
+        assert_eq!(
+            (127, 1),
+            read_length_determinant(&buffer[0..], true).unwrap()
+        );
+        assert_eq!(
+            (128, 2),
+            read_length_determinant(&buffer[1..], true).unwrap()
+        );
+        assert_eq!(
+            (1337, 3),
+            read_length_determinant(&buffer[3..], true).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_length_determinant_not_canonical() {
+        for bytes in &[&[0x81, 0x05][..], &[0x82, 0x00, 0x80]] {
+            assert!(read_length_determinant(bytes, false).is_ok());
+            assert_eq!(
+                Err(Error::NotCanonical("length determinant")),
+                read_length_determinant(bytes, true)
+            );
+        }
+    }
+
+    #[test]
+    fn test_tag() {
+        let mut buffer = Vec::new();
+        write_tag(&mut buffer, Tag::ContextSpecific(2));
+        write_tag(&mut buffer, Tag::Application(62));
+        write_tag(&mut buffer, Tag::Private(201));
+        assert_eq!(&[0x82, 0x7E, 0xFF, 0x81, 0x49], &buffer[..]);
+        assert_eq!(
+            (Tag::ContextSpecific(2), 1),
+            read_tag(&buffer[0..]).unwrap()
+        );
+        assert_eq!((Tag::Application(62), 1), read_tag(&buffer[1..]).unwrap());
+        assert_eq!((Tag::Private(201), 3), read_tag(&buffer[2..]).unwrap());
+    }
+
+    #[test]
+    fn test_fixed_integer_octets() {
+        assert_eq!(Some(1), fixed_integer_octets((0, 255)));
+        assert_eq!(Some(2), fixed_integer_octets((0, 256)));
+        assert_eq!(Some(4), fixed_integer_octets((1, 0xFF_FF_FF_FF)));
+        assert_eq!(Some(8), fixed_integer_octets((0, 0x1_00_00_00_00)));
+        assert_eq!(Some(1), fixed_integer_octets((-128, 127)));
+        assert_eq!(Some(2), fixed_integer_octets((-128, 128)));
+        assert_eq!(Some(4), fixed_integer_octets((-40_000, 0)));
+        assert_eq!(Some(8), fixed_integer_octets((i64::MIN, 0)));
+        assert_eq!(None, fixed_integer_octets((0, i64::MAX)));
+        assert_eq!(None, fixed_integer_octets((-1, i64::MAX)));
+    }
+
+    #[test]
+    fn test_integer_content_is_minimal() {
+        assert_eq!(&[0x00], &unsigned_content(0)[..]);
+        assert_eq!(&[0x01, 0x00], &unsigned_content(256)[..]);
+        assert_eq!(&[0xFF], &signed_content(-1)[..]);
+        assert_eq!(&[0x00, 0x80], &signed_content(128)[..]);
+        assert_eq!(Ok(256), unsigned_from_content(&[0x01, 0x00], true));
+        assert_eq!(Ok(-129), signed_from_content(&[0xFF, 0x7F], true));
+        assert_eq!(
+            Err(Error::NotCanonical("integer")),
+            unsigned_from_content(&[0x00, 0x01], true)
+        );
+        assert_eq!(
+            Err(Error::NotCanonical("integer")),
+            signed_from_content(&[0xFF, 0xFF], true)
+        );
+        assert_eq!(Ok(-1), signed_from_content(&[0xFF, 0xFF], false));
+    }
+}
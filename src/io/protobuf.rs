@@ -1,3 +1,8 @@
+use crate::syn::time::Time;
+use crate::syn::BitVec;
+use crate::syn::Null;
+use crate::syn::ObjectIdentifier;
+use crate::syn::{GeneralizedTime, UtcTime};
 use backtrace::Backtrace;
 use byteorder::LittleEndian as E;
 use byteorder::ReadBytesExt;
@@ -12,6 +17,12 @@ pub enum Error {
     #[allow(unused)]
     InvalidUtf8Received,
     #[allow(unused)]
+    InvalidBitStringReceived,
+    #[allow(unused)]
+    InvalidObjectIdentifierReceived,
+    #[allow(unused)]
+    InvalidTimeReceived,
+    #[allow(unused)]
     MissingRequiredField(&'static str),
     InvalidTagReceived(Backtrace, u32),
     InvalidFormat(Backtrace, u32),
@@ -51,6 +62,15 @@ impl std::fmt::Display for Error {
         match self {
             Error::Io(b, ioe) => write!(f, "Internal IO Error: {}\n{:?}", ioe, b),
             Error::InvalidUtf8Received => write!(f, "Received String is not valid UTF8"),
+            Error::InvalidBitStringReceived => {
+                write!(f, "Received BitString has an invalid amount of unused bits")
+            }
+            Error::InvalidObjectIdentifierReceived => {
+                write!(f, "Received ObjectIdentifier is not in dotted notation")
+            }
+            Error::InvalidTimeReceived => {
+                write!(f, "Received time is not in the form of its ASN.1 type")
+            }
             Error::MissingRequiredField(name) => {
                 write!(f, "The required field '{}' is missing", name)
             }
@@ -149,8 +169,35 @@ pub trait Writer {
         self.write_varint(((value << 1) ^ (value >> 63)) as u64)
     }
 
+    fn write_double(&mut self, value: f64) -> Result<(), Error>;
+
     fn write_string(&mut self, value: &str) -> Result<(), Error>;
 
+    /// Writes the bytes prefixed with the number of unused bits in the last byte
+    fn write_bits(&mut self, value: &BitVec) -> Result<(), Error> {
+        self.write_bytes(&value.to_prefixed_bytes()[..])
+    }
+
+    /// Writes empty bytes, the presence of the field is the value
+    fn write_null(&mut self, _value: &Null) -> Result<(), Error> {
+        self.write_bytes(&[])
+    }
+
+    /// Writes the dotted notation as string
+    fn write_oid(&mut self, value: &ObjectIdentifier) -> Result<(), Error> {
+        self.write_string(&value.to_string())
+    }
+
+    /// Writes the string form of the `GeneralizedTime`
+    fn write_generalized_time(&mut self, value: &GeneralizedTime) -> Result<(), Error> {
+        self.write_string(&value.to_string())
+    }
+
+    /// Writes the string form of the `UTCTime`
+    fn write_utc_time(&mut self, value: &UtcTime) -> Result<(), Error> {
+        self.write_string(&value.to_string())
+    }
+
     fn write_tagged_bool(&mut self, field: u32, value: bool) -> Result<(), Error> {
         self.write_tag(field, Format::VarInt)?;
         self.write_bool(value)
@@ -161,6 +208,40 @@ pub trait Writer {
         self.write_bytes(value)
     }
 
+    fn write_tagged_bits(&mut self, field: u32, value: &BitVec) -> Result<(), Error> {
+        self.write_tag(field, Format::LengthDelimited)?;
+        self.write_bits(value)
+    }
+
+    fn write_tagged_null(&mut self, field: u32, value: &Null) -> Result<(), Error> {
+        self.write_tag(field, Format::LengthDelimited)?;
+        self.write_null(value)
+    }
+
+    fn write_tagged_oid(&mut self, field: u32, value: &ObjectIdentifier) -> Result<(), Error> {
+        self.write_tag(field, Format::LengthDelimited)?;
+        self.write_oid(value)
+    }
+
+    fn write_tagged_generalized_time(
+        &mut self,
+        field: u32,
+        value: &GeneralizedTime,
+    ) -> Result<(), Error> {
+        self.write_tag(field, Format::LengthDelimited)?;
+        self.write_generalized_time(value)
+    }
+
+    fn write_tagged_utc_time(&mut self, field: u32, value: &UtcTime) -> Result<(), Error> {
+        self.write_tag(field, Format::LengthDelimited)?;
+        self.write_utc_time(value)
+    }
+
+    fn write_tagged_double(&mut self, field: u32, value: f64) -> Result<(), Error> {
+        self.write_tag(field, Format::Fixed64)?;
+        self.write_double(value)
+    }
+
     fn write_tagged_sfixed32(&mut self, field: u32, value: i32) -> Result<(), Error> {
         self.write_tag(field, Format::Fixed32)?;
         self.write_sfixed32(value)
@@ -222,6 +303,11 @@ impl<W: Write> Writer for W {
         Ok(())
     }
 
+    fn write_double(&mut self, value: f64) -> Result<(), Error> {
+        self.write_f64::<E>(value)?;
+        Ok(())
+    }
+
     fn write_string(&mut self, value: &str) -> Result<(), Error> {
         self.write_bytes(value.as_bytes())?;
         Ok(())
@@ -273,7 +359,30 @@ pub trait Reader {
         Ok(((value >> 1) as i64) ^ (-((value & 0x01) as i64)))
     }
 
+    fn read_double(&mut self) -> Result<f64, Error>;
+
     fn read_string(&mut self) -> Result<String, Error>;
+
+    fn read_bits(&mut self) -> Result<BitVec, Error> {
+        BitVec::from_prefixed_bytes(&self.read_bytes()?[..]).ok_or(Error::InvalidBitStringReceived)
+    }
+
+    fn read_null(&mut self) -> Result<Null, Error> {
+        self.read_bytes()?;
+        Ok(Null)
+    }
+
+    fn read_oid(&mut self) -> Result<ObjectIdentifier, Error> {
+        ObjectIdentifier::parse(&self.read_string()?).ok_or(Error::InvalidObjectIdentifierReceived)
+    }
+
+    fn read_generalized_time(&mut self) -> Result<GeneralizedTime, Error> {
+        GeneralizedTime::parse(&self.read_string()?).ok_or(Error::InvalidTimeReceived)
+    }
+
+    fn read_utc_time(&mut self) -> Result<UtcTime, Error> {
+        UtcTime::parse(&self.read_string()?).ok_or(Error::InvalidTimeReceived)
+    }
 }
 
 impl<R: Read> Reader for R {
@@ -302,6 +411,10 @@ impl<R: Read> Reader for R {
         Ok(self.read_i32::<E>()?)
     }
 
+    fn read_double(&mut self) -> Result<f64, Error> {
+        Ok(self.read_f64::<E>()?)
+    }
+
     fn read_string(&mut self) -> Result<String, Error> {
         let bytes = self.read_bytes()?;
         if let Ok(string) = String::from_utf8(bytes) {
@@ -347,6 +460,12 @@ impl<T: ProtobufEq> ProtobufEq<Vec<T>> for Vec<T> {
     }
 }
 
+impl<T: ProtobufEq> ProtobufEq<Box<T>> for Box<T> {
+    fn protobuf_eq(&self, other: &Box<T>) -> bool {
+        T::protobuf_eq(self, other)
+    }
+}
+
 impl ProtobufEq<bool> for bool {
     fn protobuf_eq(&self, other: &Self) -> bool {
         self == other
@@ -406,3 +525,39 @@ impl ProtobufEq<String> for String {
         self == other
     }
 }
+
+impl ProtobufEq<BitVec> for BitVec {
+    fn protobuf_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl ProtobufEq<f64> for f64 {
+    fn protobuf_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl ProtobufEq<Null> for Null {
+    fn protobuf_eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl ProtobufEq<ObjectIdentifier> for ObjectIdentifier {
+    fn protobuf_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl ProtobufEq<GeneralizedTime> for GeneralizedTime {
+    fn protobuf_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl ProtobufEq<UtcTime> for UtcTime {
+    fn protobuf_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
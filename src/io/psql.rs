@@ -3,7 +3,10 @@ pub use postgres::transaction::Transaction;
 pub use postgres::Error as PostgresError;
 
 use crate::postgres::rows::Rows;
+use crate::postgres::types::{FromSql, IsNull, ToSql, Type, BIT, TIMESTAMPTZ, VARBIT};
+use crate::syn::{BitVec, GeneralizedTime, Null, ObjectIdentifier, UtcTime};
 use backtrace::Backtrace;
+use std::error::Error as StdError;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug)]
@@ -90,6 +93,170 @@ impl From<PostgresError> for Error {
     }
 }
 
+impl ToSql for BitVec {
+    /// The binary format of `BIT VARYING`: the number of bits followed by the bytes
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut Vec<u8>,
+    ) -> Result<IsNull, Box<dyn StdError + Sync + Send>> {
+        if self.bit_len() > i32::MAX as u64 {
+            return Err("BitVec is too long for BIT VARYING".into());
+        }
+        out.extend_from_slice(&(self.bit_len() as i32).to_be_bytes());
+        out.extend_from_slice(self.as_byte_slice());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == BIT || *ty == VARBIT
+    }
+
+    postgres::to_sql_checked!();
+}
+
+impl FromSql for BitVec {
+    fn from_sql(_ty: &Type, raw: &[u8]) -> Result<Self, Box<dyn StdError + Sync + Send>> {
+        if raw.len() < 4 {
+            return Err("BIT VARYING value is missing its length".into());
+        }
+        let (len, bytes) = raw.split_at(4);
+        let bit_len = i32::from_be_bytes([len[0], len[1], len[2], len[3]]);
+        if bit_len < 0 || (bit_len as usize).div_ceil(8) != bytes.len() {
+            return Err("BIT VARYING value has an invalid length".into());
+        }
+        Ok(BitVec::from_bytes(bytes.to_vec(), bit_len as u64))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == BIT || *ty == VARBIT
+    }
+}
+
+impl ToSql for Null {
+    /// A `BOOLEAN` that is always `TRUE`, the presence of the value is its only information
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut Vec<u8>,
+    ) -> Result<IsNull, Box<dyn StdError + Sync + Send>> {
+        true.to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <bool as ToSql>::accepts(ty)
+    }
+
+    postgres::to_sql_checked!();
+}
+
+impl FromSql for Null {
+    fn from_sql(ty: &Type, raw: &[u8]) -> Result<Self, Box<dyn StdError + Sync + Send>> {
+        bool::from_sql(ty, raw).map(|_| Null)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <bool as FromSql>::accepts(ty)
+    }
+}
+
+impl ToSql for ObjectIdentifier {
+    /// The dotted notation as `TEXT`
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut Vec<u8>,
+    ) -> Result<IsNull, Box<dyn StdError + Sync + Send>> {
+        self.to_string().to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <String as ToSql>::accepts(ty)
+    }
+
+    postgres::to_sql_checked!();
+}
+
+impl FromSql for ObjectIdentifier {
+    fn from_sql(ty: &Type, raw: &[u8]) -> Result<Self, Box<dyn StdError + Sync + Send>> {
+        let dotted = String::from_sql(ty, raw)?;
+        ObjectIdentifier::parse(&dotted)
+            .ok_or_else(|| format!("{} is not a valid OBJECT IDENTIFIER", dotted).into())
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <String as FromSql>::accepts(ty)
+    }
+}
+
+/// The microseconds from the unix epoch to the postgres epoch `2000-01-01 00:00:00 UTC`
+const POSTGRES_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+impl ToSql for GeneralizedTime {
+    /// The binary format of `TIMESTAMPTZ`: the microseconds since the postgres epoch
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut Vec<u8>,
+    ) -> Result<IsNull, Box<dyn StdError + Sync + Send>> {
+        let micros = self.unix_timestamp_micros() - POSTGRES_EPOCH_MICROS;
+        out.extend_from_slice(&micros.to_be_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == TIMESTAMPTZ
+    }
+
+    postgres::to_sql_checked!();
+}
+
+impl FromSql for GeneralizedTime {
+    fn from_sql(_ty: &Type, raw: &[u8]) -> Result<Self, Box<dyn StdError + Sync + Send>> {
+        if raw.len() != 8 {
+            return Err("TIMESTAMPTZ value has an invalid length".into());
+        }
+        let mut bytes = [0_u8; 8];
+        bytes.copy_from_slice(raw);
+        let micros = i64::from_be_bytes(bytes).saturating_add(POSTGRES_EPOCH_MICROS);
+        GeneralizedTime::from_unix_timestamp_micros(micros)
+            .ok_or_else(|| "TIMESTAMPTZ value is not within the years 0 to 9999".into())
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == TIMESTAMPTZ
+    }
+}
+
+impl ToSql for UtcTime {
+    /// The binary format of `TIMESTAMPTZ`, see `GeneralizedTime`
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut Vec<u8>,
+    ) -> Result<IsNull, Box<dyn StdError + Sync + Send>> {
+        GeneralizedTime::from(self).to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == TIMESTAMPTZ
+    }
+
+    postgres::to_sql_checked!();
+}
+
+impl FromSql for UtcTime {
+    fn from_sql(ty: &Type, raw: &[u8]) -> Result<Self, Box<dyn StdError + Sync + Send>> {
+        let time = GeneralizedTime::from_sql(ty, raw)?;
+        UtcTime::from_generalized_time(&time)
+            .ok_or_else(|| format!("{} is not within the years 1950 to 2049", time).into())
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == TIMESTAMPTZ
+    }
+}
+
 pub trait Representable {
     fn table_name(&self) -> &'static str;
 }
@@ -1,4 +1,7 @@
 use crate::io::buffer::BitBuffer;
+use crate::syn::charset::Charset;
+use crate::syn::numbers::IntegerError;
+use crate::syn::opentype::KeyError;
 use byteorder::ByteOrder;
 use byteorder::NetworkEndian;
 
@@ -17,7 +20,15 @@ pub enum Error {
     InsufficientDataInSourceBuffer,
     InvalidChoiceIndex(usize, usize),
     ValueNotInRange(i64, i64, i64),
+    /// The value, which exceeds the values that can be encoded
+    ValueTooLarge(u64),
+    /// The key, which does not identify the type of the value of the named open type
+    OpenTypeKeyMismatch(i64, &'static str),
     SizeNotInRange(usize, usize, usize),
+    InvalidReal,
+    InvalidObjectIdentifier,
+    InvalidTime(String),
+    InvalidCharacter(u32),
     OptFlagsExhausted,
     EndOfStream,
 }
@@ -47,11 +58,29 @@ impl std::fmt::Display for Error {
                 "The value {} is not within the inclusive range of {} and {}",
                 value, min, max
             ),
+            Error::ValueTooLarge(value) => {
+                write!(f, "The value {} is too large to be encoded", value)
+            }
+            Error::OpenTypeKeyMismatch(key, name) => write!(
+                f,
+                "The key {} does not identify the type of the {} value",
+                key, name
+            ),
             Error::SizeNotInRange(size, min, max) => write!(
                 f,
                 "The size {} is not within the inclusive range of {} and {}",
                 size, min, max
             ),
+            Error::InvalidReal => write!(f, "The content is not a valid real"),
+            Error::InvalidObjectIdentifier => {
+                write!(f, "The content is not a valid object identifier")
+            }
+            Error::InvalidTime(time) => write!(f, "The string {} is not a valid time", time),
+            Error::InvalidCharacter(value) => write!(
+                f,
+                "The character U+{:04X} is not within the permitted alphabet",
+                value
+            ),
             Error::OptFlagsExhausted => write!(f, "All optional flags have already been exhausted"),
             Error::EndOfStream => write!(
                 f,
@@ -67,6 +96,102 @@ impl std::error::Error for Error {
     }
 }
 
+impl IntegerError for Error {
+    fn value_not_in_range(value: i64, min: i64, max: i64) -> Self {
+        Error::ValueNotInRange(value, min, max)
+    }
+
+    fn value_too_large(value: u64) -> Self {
+        Error::ValueTooLarge(value)
+    }
+}
+
+impl KeyError for Error {
+    fn key_mismatch(key: i64, name: &'static str) -> Self {
+        Error::OpenTypeKeyMismatch(key, name)
+    }
+}
+
+/// The effective permitted alphabet of a known-multiplier character string type, which
+/// determines how each character is encoded in PER (X.691-201508 30.5)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermittedAlphabet {
+    charset: Charset,
+    /// The characters in canonical order if restricted by a `FROM` constraint or if
+    /// the characters are encoded by their index
+    characters: Option<Vec<char>>,
+    bits: usize,
+    indexed: bool,
+}
+
+impl PermittedAlphabet {
+    /// The alphabet of the given character set, restricted to the characters of the
+    /// permitted alphabet if any. For the ALIGNED variant of PER, the amount of bits per
+    /// character is rounded up to the next power of two (30.5.2).
+    pub fn new(charset: Charset, permitted_alphabet: Option<&str>, aligned: bool) -> Self {
+        let characters = permitted_alphabet.map(|alphabet| {
+            let mut characters = alphabet
+                .chars()
+                .filter(|c| charset.is_valid(*c))
+                .collect::<Vec<_>>();
+            characters.sort_unstable();
+            characters.dedup();
+            characters
+        });
+        let (count, max_value) = match &characters {
+            Some(characters) => (
+                characters.len() as u64,
+                characters.last().map(|c| *c as u32).unwrap_or(0),
+            ),
+            None => (charset.character_count(), charset.max_value()),
+        };
+        let mut bits = (64 - count.saturating_sub(1).leading_zeros()) as usize;
+        if aligned && bits > 0 {
+            bits = bits.next_power_of_two();
+        }
+        // 30.5.4: only if the largest value does not fit, the index is encoded instead
+        let indexed = u64::from(max_value) >= 1_u64 << bits;
+        let characters = if indexed {
+            characters.or_else(|| charset.characters())
+        } else {
+            characters
+        };
+        Self {
+            charset,
+            characters,
+            bits,
+            indexed,
+        }
+    }
+
+    /// The amount of bits each character is encoded with
+    pub const fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// The value to encode for the given character or `None` if it is not permitted
+    pub fn encode(&self, c: char) -> Option<u32> {
+        if !self.charset.is_valid(c) {
+            None
+        } else if let Some(characters) = &self.characters {
+            let index = characters.binary_search(&c).ok()?;
+            Some(if self.indexed { index as u32 } else { c as u32 })
+        } else {
+            Some(c as u32)
+        }
+    }
+
+    /// The character for the given encoded value or `None` if it is not permitted
+    pub fn decode(&self, value: u32) -> Option<char> {
+        let c = if self.indexed {
+            *self.characters.as_ref()?.get(value as usize)?
+        } else {
+            core::char::from_u32(value)?
+        };
+        self.encode(c).map(|_| c)
+    }
+}
+
 #[deprecated(note = "Use the UperReader/-Writer with the Read-/Writable interface instead")]
 #[cfg(feature = "legacy-uper-codegen")]
 pub trait Uper {
@@ -98,6 +223,36 @@ pub trait Reader {
         }
     }
 
+    /// X.691-201508 30.5, the length is in characters
+    fn read_known_multiplier_string(
+        &mut self,
+        alphabet: &PermittedAlphabet,
+        length_range: Option<(i64, i64)>,
+    ) -> Result<String, Error> {
+        let len = self.read_length(length_range, false)?;
+        self.read_known_multiplier_characters(alphabet, len)
+    }
+
+    /// Reads the given amount of characters with the bits of the permitted alphabet each
+    fn read_known_multiplier_characters(
+        &mut self,
+        alphabet: &PermittedAlphabet,
+        len: usize,
+    ) -> Result<String, Error> {
+        let mut string = String::with_capacity(len);
+        let mut buffer = [0_u8; 4];
+        for _ in 0..len {
+            self.read_bit_string_till_end(&mut buffer[..], 32 - alphabet.bits())?;
+            let value = u32::from_be_bytes(buffer);
+            string.push(
+                alphabet
+                    .decode(value)
+                    .ok_or(Error::InvalidCharacter(value))?,
+            );
+        }
+        Ok(string)
+    }
+
     fn read_choice_index_extensible(&mut self, no_of_default_variants: u64) -> Result<u64, Error> {
         if self.read_bit()? {
             Ok((self.read_int_normally_small()? + no_of_default_variants) as u64)
@@ -123,6 +278,16 @@ pub trait Reader {
         Ok(value + lower)
     }
 
+    /// Reads an `INTEGER` with an extensible range, where the extension bit tells whether
+    /// the value is within the range (X.691-201508 12.1 and 12.2)
+    fn read_int_extensible(&mut self, range: (i64, i64)) -> Result<i64, Error> {
+        if self.read_bit()? {
+            self.read_int_unconstrained()
+        } else {
+            self.read_int(range)
+        }
+    }
+
     fn read_int_normally_small(&mut self) -> Result<u64, Error> {
         // X.691-201508 11.6
         let is_small = !self.read_bit()?;
@@ -137,6 +302,19 @@ pub trait Reader {
         }
     }
 
+    fn read_normally_small_length(&mut self) -> Result<usize, Error> {
+        // X.691-201508 11.9.3.4
+        let is_small = !self.read_bit()?;
+        if is_small {
+            // 6 bit of the length - 1
+            let mut buffer = [0u8; 8];
+            self.read_bit_string(&mut buffer[7..8], 2, 6)?;
+            Ok(u64::from_be_bytes(buffer) as usize + 1)
+        } else {
+            self.read_length_determinant()
+        }
+    }
+
     fn read_int_max(&mut self) -> Result<u64, Error> {
         let len_in_bytes = self.read_length_determinant()?;
         if len_in_bytes > 8 {
@@ -151,6 +329,42 @@ pub trait Reader {
         }
     }
 
+    /// Reads the offset of a semi-constrained whole number from its lower bound, X.691-201508 10.7
+    fn read_int_semi_constrained(&mut self, lower_bound: i64) -> Result<i64, Error> {
+        let offset = self.read_int_max()?;
+        let value = i128::from(lower_bound) + i128::from(offset);
+        if value > i128::from(i64::MAX) {
+            Err(Error::UnsupportedOperation(
+                "Reading bigger data types than 64bit is not supported".into(),
+            ))
+        } else {
+            Ok(value as i64)
+        }
+    }
+
+    /// Reads an unconstrained whole number in its two's-complement form, X.691-201508 10.8
+    fn read_int_unconstrained(&mut self) -> Result<i64, Error> {
+        let len_in_bytes = self.read_length_determinant()?;
+        if len_in_bytes > 8 {
+            Err(Error::UnsupportedOperation(
+                "Reading bigger data types than 64bit is not supported".into(),
+            ))
+        } else if len_in_bytes == 0 {
+            Ok(0)
+        } else {
+            let mut buffer = [0_u8; 8];
+            let offset = (8 * BYTE_LEN) - (len_in_bytes * BYTE_LEN);
+            self.read_bit_string_till_end(&mut buffer[..], offset)?;
+            if buffer[8 - len_in_bytes] & 0x80 != 0 {
+                // sign extension of the shortened two's-complement
+                buffer[..8 - len_in_bytes]
+                    .iter_mut()
+                    .for_each(|b| *b = 0xFF);
+            }
+            Ok(NetworkEndian::read_i64(&buffer[..]))
+        }
+    }
+
     fn read_bit_string(
         &mut self,
         buffer: &mut [u8],
@@ -178,16 +392,23 @@ pub trait Reader {
     }
 
     fn read_octet_string(&mut self, length_range: Option<(i64, i64)>) -> Result<Vec<u8>, Error> {
-        let len = if let Some((min, max)) = length_range {
-            self.read_int((min, max))? as usize
-        } else {
-            self.read_length_determinant()?
-        };
+        let len = self.read_length(length_range, false)?;
         let mut vec = vec![0_u8; len];
         self.read_bit_string_till_end(&mut vec[..], 0)?;
         Ok(vec)
     }
 
+    /// Reads the length of the bit string and its bits, returning both
+    fn read_bits_with_length(
+        &mut self,
+        length_range: Option<(i64, i64)>,
+    ) -> Result<(Vec<u8>, usize), Error> {
+        let len = self.read_length(length_range, false)?;
+        let mut vec = vec![0_u8; len.div_ceil(BYTE_LEN)];
+        self.read_bit_string(&mut vec[..], 0, len)?;
+        Ok((vec, len))
+    }
+
     fn read_bit_string_till_end(
         &mut self,
         buffer: &mut [u8],
@@ -197,6 +418,22 @@ pub trait Reader {
         self.read_bit_string(buffer, bit_offset, len)
     }
 
+    /// Reads the length of a type with a `SIZE` constraint, which is encoded as constrained
+    /// whole number if there is a length range (X.691-201508 11.9.4.1). For an extensible
+    /// constraint, a preceding bit tells whether the length is outside of the range and
+    /// encoded as length determinant instead.
+    fn read_length(
+        &mut self,
+        length_range: Option<(i64, i64)>,
+        extensible: bool,
+    ) -> Result<usize, Error> {
+        let outside_range = extensible && self.read_bit()?;
+        match length_range {
+            Some(range) if !outside_range => Ok(self.read_int(range)? as usize),
+            _ => self.read_length_determinant(),
+        }
+    }
+
     #[allow(clippy::if_not_else)]
     fn read_length_determinant(&mut self) -> Result<usize, Error> {
         if !self.read_bit()? {
@@ -234,6 +471,32 @@ pub trait Writer {
         Ok(())
     }
 
+    /// X.691-201508 30.5, the length is in characters
+    fn write_known_multiplier_string(
+        &mut self,
+        alphabet: &PermittedAlphabet,
+        value: &str,
+        length_range: Option<(i64, i64)>,
+    ) -> Result<(), Error> {
+        self.write_length(value.chars().count(), length_range, false)?;
+        self.write_known_multiplier_characters(alphabet, value)
+    }
+
+    /// Writes each character with the bits of the permitted alphabet
+    fn write_known_multiplier_characters(
+        &mut self,
+        alphabet: &PermittedAlphabet,
+        value: &str,
+    ) -> Result<(), Error> {
+        for c in value.chars() {
+            let value = alphabet
+                .encode(c)
+                .ok_or(Error::InvalidCharacter(c as u32))?;
+            self.write_bit_string_till_end(&value.to_be_bytes(), 32 - alphabet.bits())?;
+        }
+        Ok(())
+    }
+
     fn write_choice_index_extensible(
         &mut self,
         index: u64,
@@ -273,6 +536,19 @@ pub trait Writer {
         Ok(())
     }
 
+    /// Writes an `INTEGER` with an extensible range, where values outside of the range
+    /// are encoded as unconstrained whole numbers (X.691-201508 12.1 and 12.2)
+    fn write_int_extensible(&mut self, value: i64, range: (i64, i64)) -> Result<(), Error> {
+        let (lower, upper) = range;
+        let within_range = (lower..=upper).contains(&value);
+        self.write_bit(!within_range)?;
+        if within_range {
+            self.write_int(value, range)
+        } else {
+            self.write_int_unconstrained(value)
+        }
+    }
+
     fn write_int_normally_small(&mut self, value: u64) -> Result<(), Error> {
         // X.691-201508 11.6
         if value <= 63 {
@@ -289,10 +565,24 @@ pub trait Writer {
         }
     }
 
+    fn write_normally_small_length(&mut self, length: usize) -> Result<(), Error> {
+        // X.691-201508 11.9.3.4
+        if length > 0 && length <= 64 {
+            // '0'bit + 6 bit of the length - 1
+            self.write_bit(false)?;
+            let buffer = (length as u64 - 1).to_be_bytes();
+            self.write_bit_string(&buffer[7..8], 2, 6)
+        } else {
+            // '1'bit + length-determinant
+            self.write_bit(true)?;
+            self.write_length_determinant(length)
+        }
+    }
+
     /// ??? X.691-201508 11.9
     fn write_int_max(&mut self, value: u64) -> Result<(), Error> {
-        if value > i64::max_value() as u64 {
-            return Err(Error::ValueNotInRange(value as i64, 0, i64::max_value()));
+        if value > i64::MAX as u64 {
+            return Err(Error::ValueTooLarge(value));
         }
         let buffer = value.to_be_bytes();
         let byte_len = {
@@ -309,6 +599,51 @@ pub trait Writer {
         Ok(())
     }
 
+    /// Writes the offset of a semi-constrained whole number from its lower bound, X.691-201508 10.7
+    fn write_int_semi_constrained(&mut self, value: i64, lower_bound: i64) -> Result<(), Error> {
+        if value < lower_bound {
+            return Err(Error::ValueNotInRange(value, lower_bound, i64::MAX));
+        }
+        // the offset always fits into an u64 but not necessarily into an i64
+        let buffer = (value.wrapping_sub(lower_bound) as u64).to_be_bytes();
+        let byte_len = {
+            let mut len = buffer.len();
+            while len > 0 && buffer[buffer.len() - len] == 0x00 {
+                len -= 1;
+            }
+            len
+        }
+        .max(1);
+        self.write_length_determinant(byte_len)?;
+        let bit_offset = (buffer.len() - byte_len) * BYTE_LEN;
+        self.write_bit_string_till_end(&buffer, bit_offset)?;
+        Ok(())
+    }
+
+    /// Writes an unconstrained whole number in its shortest two's-complement form,
+    /// X.691-201508 10.8
+    fn write_int_unconstrained(&mut self, value: i64) -> Result<(), Error> {
+        let buffer = value.to_be_bytes();
+        let byte_len = {
+            let mut len = buffer.len();
+            while len > 1 {
+                let first = buffer[buffer.len() - len];
+                let second = buffer[buffer.len() - len + 1];
+                // the leading octet is redundant if it only repeats the sign bit of the next one
+                if (first == 0x00 && second & 0x80 == 0) || (first == 0xFF && second & 0x80 != 0) {
+                    len -= 1;
+                } else {
+                    break;
+                }
+            }
+            len
+        };
+        self.write_length_determinant(byte_len)?;
+        let bit_offset = (buffer.len() - byte_len) * BYTE_LEN;
+        self.write_bit_string_till_end(&buffer, bit_offset)?;
+        Ok(())
+    }
+
     fn write_bit_string(
         &mut self,
         buffer: &[u8],
@@ -335,20 +670,48 @@ pub trait Writer {
         string: &[u8],
         length_range: Option<(i64, i64)>,
     ) -> Result<(), Error> {
-        if let Some((min, max)) = length_range {
-            self.write_int(string.len() as i64, (min, max))?;
-        } else {
-            self.write_length_determinant(string.len())?;
-        }
+        self.write_length(string.len(), length_range, false)?;
         self.write_bit_string_till_end(string, 0)?;
         Ok(())
     }
 
+    /// Writes the length of the bit string followed by its first `bit_length` bits
+    fn write_bits_with_length(
+        &mut self,
+        buffer: &[u8],
+        bit_length: usize,
+        length_range: Option<(i64, i64)>,
+    ) -> Result<(), Error> {
+        self.write_length(bit_length, length_range, false)?;
+        self.write_bit_string(buffer, 0, bit_length)
+    }
+
     fn write_bit_string_till_end(&mut self, buffer: &[u8], bit_offset: usize) -> Result<(), Error> {
         let len = (buffer.len() * BYTE_LEN) - bit_offset;
         self.write_bit_string(buffer, bit_offset, len)
     }
 
+    /// Writes the length of a type with a `SIZE` constraint, see [`Reader::read_length`]
+    fn write_length(
+        &mut self,
+        length: usize,
+        length_range: Option<(i64, i64)>,
+        extensible: bool,
+    ) -> Result<(), Error> {
+        let within_range =
+            length_range.is_none_or(|(min, max)| (min..=max).contains(&(length as i64)));
+        if extensible {
+            self.write_bit(!within_range)?;
+        }
+        match length_range {
+            Some(range) if within_range => self.write_int(length as i64, range),
+            Some((min, max)) if !extensible => {
+                Err(Error::SizeNotInRange(length, min as usize, max as usize))
+            }
+            _ => self.write_length_determinant(length),
+        }
+    }
+
     fn write_length_determinant(&mut self, length: usize) -> Result<(), Error> {
         if length <= UPER_LENGTH_DET_L1 as usize {
             self.write_bit(false)?;
@@ -367,3 +730,45 @@ pub trait Writer {
 
     fn write_bit(&mut self, bit: bool) -> Result<(), Error>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permitted_alphabet_bits() {
+        let numeric = PermittedAlphabet::new(Charset::Numeric, None, false);
+        assert_eq!(4, numeric.bits());
+        assert_eq!(Some(0), numeric.encode(' '));
+        assert_eq!(Some(10), numeric.encode('9'));
+        assert_eq!(Some('9'), numeric.decode(10));
+
+        let ia5 = PermittedAlphabet::new(Charset::Ia5, None, false);
+        assert_eq!(7, ia5.bits());
+        assert_eq!(Some(0x41), ia5.encode('A'));
+        assert_eq!(8, PermittedAlphabet::new(Charset::Ia5, None, true).bits());
+        assert_eq!(16, PermittedAlphabet::new(Charset::Bmp, None, false).bits());
+        assert_eq!(
+            32,
+            PermittedAlphabet::new(Charset::Universal, None, true).bits()
+        );
+    }
+
+    #[test]
+    fn test_permitted_alphabet_from_constraint() {
+        // the largest value does not fit into one bit, therefore the index is encoded
+        let alphabet = PermittedAlphabet::new(Charset::Ia5, Some("BA"), false);
+        assert_eq!(1, alphabet.bits());
+        assert_eq!(Some(0), alphabet.encode('A'));
+        assert_eq!(Some(1), alphabet.encode('B'));
+        assert_eq!(None, alphabet.encode('C'));
+
+        // X.691-201508 30.5.4: the largest value fits into 7 bits, so the value is encoded
+        let visible = (' '..='~').collect::<String>();
+        let alphabet = PermittedAlphabet::new(Charset::Ia5, Some(&visible), false);
+        assert_eq!(7, alphabet.bits());
+        assert_eq!(Some(0x41), alphabet.encode('A'));
+        assert_eq!(Some('A'), alphabet.decode(0x41));
+        assert_eq!(None, alphabet.encode('\n'));
+    }
+}
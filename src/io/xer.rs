@@ -0,0 +1,344 @@
+use crate::syn::numbers::IntegerError;
+use crate::syn::opentype::KeyError;
+
+#[derive(Debug, PartialOrd, PartialEq)]
+pub enum Error {
+    InvalidXml(usize),
+    UnexpectedElement(String),
+    MissingElement(&'static str),
+    ExpectedText,
+    UnknownVariant(String),
+    InvalidHexString,
+    InvalidObjectIdentifier(String),
+    InvalidTime(String),
+    InvalidCharacter(u32),
+    InvalidBinaryString,
+    InvalidNumber(String),
+    ValueNotInRange(i64, i64, i64),
+    /// The value, which exceeds the values that can be encoded
+    ValueTooLarge(u64),
+    /// The key, which does not identify the type of the value of the named open type
+    OpenTypeKeyMismatch(i64, &'static str),
+    SizeNotInRange(usize, usize, usize),
+    EndOfStream,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidXml(position) => {
+                write!(f, "The input is not valid XML at position {}", position)
+            }
+            Error::UnexpectedElement(name) => write!(f, "Unexpected element <{}>", name),
+            Error::MissingElement(name) => write!(f, "The element <{}> is not present", name),
+            Error::ExpectedText => write!(f, "Expected text but found an element"),
+            Error::UnknownVariant(name) => write!(f, "There is no variant named {}", name),
+            Error::InvalidHexString => write!(f, "The text is not a valid hex-string"),
+            Error::InvalidObjectIdentifier(oid) => {
+                write!(f, "The text {} is not a valid object identifier", oid)
+            }
+            Error::InvalidTime(time) => write!(f, "The text {} is not a valid time", time),
+            Error::InvalidCharacter(value) => write!(
+                f,
+                "The character U+{:04X} is not within the permitted alphabet",
+                value
+            ),
+            Error::InvalidBinaryString => write!(f, "The text is not a valid binary string"),
+            Error::InvalidNumber(number) => {
+                write!(f, "The number {} cannot be represented", number)
+            }
+            Error::ValueNotInRange(value, min, max) => write!(
+                f,
+                "The value {} is not within the inclusive range of {} and {}",
+                value, min, max
+            ),
+            Error::ValueTooLarge(value) => {
+                write!(f, "The value {} is too large to be encoded", value)
+            }
+            Error::OpenTypeKeyMismatch(key, name) => write!(
+                f,
+                "The key {} does not identify the type of the {} value",
+                key, name
+            ),
+            Error::SizeNotInRange(size, min, max) => write!(
+                f,
+                "The size {} is not within the inclusive range of {} and {}",
+                size, min, max
+            ),
+            Error::EndOfStream => write!(f, "There are no more values to read"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        "encoding or decoding XER failed"
+    }
+}
+
+impl IntegerError for Error {
+    fn value_not_in_range(value: i64, min: i64, max: i64) -> Self {
+        Error::ValueNotInRange(value, min, max)
+    }
+
+    fn value_too_large(value: u64) -> Self {
+        Error::ValueTooLarge(value)
+    }
+}
+
+impl KeyError for Error {
+    fn key_mismatch(key: i64, name: &'static str) -> Self {
+        Error::OpenTypeKeyMismatch(key, name)
+    }
+}
+
+/// A minimal XML document model, as required for BASIC-XER: attributes, namespaces and
+/// processing instructions are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Element {
+    pub name: String,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    pub fn parse(xml: &str) -> Result<Element, Error> {
+        let mut parser = Parser { xml, position: 0 };
+        parser.skip_misc()?;
+        let element = parser.element()?;
+        parser.skip_misc()?;
+        if parser.position < xml.len() {
+            Err(Error::InvalidXml(parser.position))
+        } else {
+            Ok(element)
+        }
+    }
+
+    /// The child elements, skipping all text
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+}
+
+/// Escapes the characters that must not appear literally in XML text, X.693-200811 8.2.4
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            c if (c as u32) < 0x20 && c != '\n' && c != '\t' => {
+                escaped.push_str(&format!("&#x{:X};", c as u32))
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+struct Parser<'a> {
+    xml: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.xml[self.position..]
+    }
+
+    fn skip_until(&mut self, pattern: &str) -> Result<&'a str, Error> {
+        let rest = self.rest();
+        let end = rest
+            .find(pattern)
+            .ok_or(Error::InvalidXml(self.xml.len()))?;
+        self.position += end + pattern.len();
+        Ok(&rest[..end])
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skips whitespace, the XML declaration, processing instructions and comments
+    fn skip_misc(&mut self) -> Result<(), Error> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_until("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_until("-->")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<&'a str, Error> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+            .unwrap_or(rest.len());
+        if len == 0 {
+            Err(Error::InvalidXml(self.position))
+        } else {
+            self.position += len;
+            Ok(&rest[..len])
+        }
+    }
+
+    fn element(&mut self) -> Result<Element, Error> {
+        if !self.rest().starts_with('<') {
+            return Err(Error::InvalidXml(self.position));
+        }
+        self.position += 1;
+        let name = self.name()?.to_string();
+
+        // skip the attributes
+        let mut quote = None;
+        loop {
+            let c = self
+                .rest()
+                .chars()
+                .next()
+                .ok_or(Error::InvalidXml(self.xml.len()))?;
+            match (quote, c) {
+                (Some(q), c) if q == c => quote = None,
+                (Some(_), _) => {}
+                (None, '"') | (None, '\'') => quote = Some(c),
+                (None, '>') => {
+                    self.position += 1;
+                    break;
+                }
+                (None, '/') if self.rest().starts_with("/>") => {
+                    self.position += 2;
+                    return Ok(Element {
+                        name,
+                        children: Vec::new(),
+                    });
+                }
+                _ => {}
+            }
+            self.position += c.len_utf8();
+        }
+
+        let mut children = Vec::new();
+        let mut text = String::new();
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.position += 2;
+                let start = self.position;
+                if self.name()? != name {
+                    return Err(Error::InvalidXml(start));
+                }
+                self.skip_whitespace();
+                if !self.rest().starts_with('>') {
+                    return Err(Error::InvalidXml(self.position));
+                }
+                self.position += 1;
+                if !text.is_empty() {
+                    children.push(Node::Text(text));
+                }
+                return Ok(Element { name, children });
+            } else if rest.starts_with("<!--") {
+                self.skip_until("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.position += "<![CDATA[".len();
+                text.push_str(self.skip_until("]]>")?);
+            } else if rest.starts_with('<') {
+                if !text.is_empty() {
+                    children.push(Node::Text(core::mem::take(&mut text)));
+                }
+                children.push(Node::Element(self.element()?));
+            } else if rest.starts_with('&') {
+                let start = self.position;
+                self.position += 1;
+                let entity = self.skip_until(";")?;
+                text.push(Self::entity(entity).ok_or(Error::InvalidXml(start))?);
+            } else if let Some(c) = rest.chars().next() {
+                text.push(c);
+                self.position += c.len_utf8();
+            } else {
+                return Err(Error::InvalidXml(self.xml.len()));
+            }
+        }
+    }
+
+    fn entity(entity: &str) -> Option<char> {
+        match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ if entity.starts_with("#x") => u32::from_str_radix(&entity[2..], 16)
+                .ok()
+                .and_then(std::char::from_u32),
+            _ if entity.starts_with('#') => entity[1..]
+                .parse::<u32>()
+                .ok()
+                .and_then(std::char::from_u32),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, children: Vec<Node>) -> Node {
+        Node::Element(Element {
+            name: name.to_string(),
+            children,
+        })
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        let element = Element::parse(
+            "<?xml version=\"1.0\"?>\n<!-- comment -->\n<A x=\"1>\"><b>te&lt;xt&#x41;</b>\n  <c/></A>\n",
+        )
+        .unwrap();
+        assert_eq!(
+            Element {
+                name: "A".to_string(),
+                children: vec![
+                    node("b", vec![Node::Text("te<xtA".to_string())]),
+                    Node::Text("\n  ".to_string()),
+                    node("c", Vec::new()),
+                ],
+            },
+            element
+        );
+        assert_eq!(
+            vec!["b", "c"],
+            element.elements().map(|e| &e.name[..]).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(Err(Error::InvalidXml(8)), Element::parse("<a><b></a>"));
+        assert_eq!(Err(Error::InvalidXml(4)), Element::parse("<a/>x"));
+        assert_eq!(
+            Err(Error::InvalidXml(3)),
+            Element::parse("<a>&unknown;</a>")
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!("a&lt;b&gt;&amp;c\"", escape("a<b>&c\""));
+    }
+}
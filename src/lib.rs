@@ -21,6 +21,10 @@ pub use asn1rs_model::gen;
 pub use asn1rs_model::model;
 #[cfg(feature = "model")]
 pub use asn1rs_model::parser;
+#[cfg(feature = "model")]
+pub use asn1rs_model::report;
+#[cfg(feature = "model")]
+pub use asn1rs_model::workspace;
 
 pub mod io;
 pub mod syn;
@@ -0,0 +1,183 @@
+use crate::io::uper::BYTE_LEN;
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+pub struct BitString<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for BitString<C> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+pub trait Constraint {
+    const MIN: Option<usize> = None;
+    const MAX: Option<usize> = None;
+    /// Whether the `SIZE` constraint is extensible, permitting sizes beyond `MIN` and `MAX`
+    const EXTENSIBLE: bool = false;
+}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for BitString<C> {
+    type Type = BitVec;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_bit_string::<C>(value.as_byte_slice(), value.bit_len())
+    }
+}
+
+impl<C: Constraint> ReadableType for BitString<C> {
+    type Type = BitVec;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        let (bytes, bit_len) = reader.read_bit_string::<C>()?;
+        Ok(BitVec::from_bytes(bytes, bit_len))
+    }
+}
+
+/// The value of a `BIT STRING`. Bit `0` is the most significant bit of the first byte.
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq, Eq, Hash)]
+pub struct BitVec(Vec<u8>, u64);
+
+impl BitVec {
+    /// Creates a `BitVec` with the given amount of bits, all of them being reset
+    pub fn with_len(bit_len: u64) -> Self {
+        let bytes = vec![0_u8; Self::byte_len_for(bit_len)];
+        Self(bytes, bit_len)
+    }
+
+    /// Bits after `bit_len` are ignored and reset.
+    ///
+    /// # Panics
+    /// If the given bytes contain less than `bit_len` bits
+    pub fn from_bytes(mut bytes: Vec<u8>, bit_len: u64) -> Self {
+        assert!(bit_len <= bytes.len() as u64 * BYTE_LEN as u64);
+        bytes.truncate(Self::byte_len_for(bit_len));
+        let unused = (bytes.len() as u64 * BYTE_LEN as u64 - bit_len) as u32;
+        if let Some(last) = bytes.last_mut() {
+            *last &= 0xFF_u8.checked_shl(unused).unwrap_or(0);
+        }
+        Self(bytes, bit_len)
+    }
+
+    pub fn from_all_bytes(bytes: Vec<u8>) -> Self {
+        let bit_len = bytes.len() as u64 * BYTE_LEN as u64;
+        Self(bytes, bit_len)
+    }
+
+    /// Parses the bytes prefixed with the number of unused bits in the last
+    /// byte (see X.690-201508 8.6.2), as produced by [`BitVec::to_prefixed_bytes`]
+    pub fn from_prefixed_bytes(prefixed: &[u8]) -> Option<Self> {
+        let (unused, bytes) = prefixed.split_first()?;
+        if *unused as usize >= BYTE_LEN || (bytes.is_empty() && *unused > 0) {
+            None
+        } else {
+            let bit_len = bytes.len() as u64 * BYTE_LEN as u64 - u64::from(*unused);
+            Some(Self::from_bytes(bytes.to_vec(), bit_len))
+        }
+    }
+
+    /// The bytes prefixed with the number of unused bits in the last byte, see
+    /// X.690-201508 8.6.2
+    pub fn to_prefixed_bytes(&self) -> Vec<u8> {
+        let mut prefixed = Vec::with_capacity(self.0.len() + 1);
+        prefixed.push(((BYTE_LEN as u64 - self.1 % BYTE_LEN as u64) % BYTE_LEN as u64) as u8);
+        prefixed.extend_from_slice(&self.0);
+        prefixed
+    }
+
+    pub fn split(self) -> (Vec<u8>, u64) {
+        (self.0, self.1)
+    }
+
+    pub fn as_byte_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+
+    pub fn bit_len(&self) -> u64 {
+        self.1
+    }
+
+    pub fn byte_len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_bit_set(&self, bit: u64) -> bool {
+        bit < self.1 && self.0[Self::byte_index(bit)] & Self::bit_mask(bit) != 0
+    }
+
+    /// Sets the given bit, extending the `BitVec` if it is too short
+    pub fn set_bit(&mut self, bit: u64) {
+        self.ensure_len(bit + 1);
+        self.0[Self::byte_index(bit)] |= Self::bit_mask(bit);
+    }
+
+    /// Resets the given bit, extending the `BitVec` if it is too short
+    pub fn reset_bit(&mut self, bit: u64) {
+        self.ensure_len(bit + 1);
+        self.0[Self::byte_index(bit)] &= !Self::bit_mask(bit);
+    }
+
+    fn ensure_len(&mut self, bit_len: u64) {
+        if self.1 < bit_len {
+            self.0.resize(Self::byte_len_for(bit_len), 0x00);
+            self.1 = bit_len;
+        }
+    }
+
+    fn byte_len_for(bit_len: u64) -> usize {
+        bit_len.div_ceil(BYTE_LEN as u64) as usize
+    }
+
+    fn byte_index(bit: u64) -> usize {
+        (bit / BYTE_LEN as u64) as usize
+    }
+
+    fn bit_mask(bit: u64) -> u8 {
+        0x80 >> (bit % BYTE_LEN as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_reset_bits() {
+        let mut bits = BitVec::with_len(3);
+        bits.set_bit(1);
+        assert!(!bits.is_bit_set(0));
+        assert!(bits.is_bit_set(1));
+        assert_eq!(&[0x40], bits.as_byte_slice());
+
+        bits.set_bit(9);
+        assert_eq!(10, bits.bit_len());
+        assert_eq!(&[0x40, 0x40], bits.as_byte_slice());
+
+        bits.reset_bit(1);
+        assert!(!bits.is_bit_set(1));
+        assert!(!bits.is_bit_set(42));
+    }
+
+    #[test]
+    fn test_unused_bits_are_reset() {
+        let bits = BitVec::from_bytes(vec![0xFF, 0xFF, 0xFF], 10);
+        assert_eq!(&[0xFF, 0xC0], bits.as_byte_slice());
+        assert_eq!(BitVec::from_bytes(vec![0xFF, 0xC0], 10), bits);
+    }
+
+    #[test]
+    fn test_prefixed_bytes() {
+        let bits = BitVec::from_bytes(vec![0xCA, 0xF0], 12);
+        assert_eq!(vec![0x04, 0xCA, 0xF0], bits.to_prefixed_bytes());
+        assert_eq!(Some(bits), BitVec::from_prefixed_bytes(&[0x04, 0xCA, 0xF0]));
+        assert_eq!(vec![0x00], BitVec::default().to_prefixed_bytes());
+        assert_eq!(None, BitVec::from_prefixed_bytes(&[0x08, 0xCA]));
+        assert_eq!(None, BitVec::from_prefixed_bytes(&[]));
+    }
+}
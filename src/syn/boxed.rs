@@ -0,0 +1,30 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+/// Stores the value of the inner type `T` on the heap, which is required for types that
+/// contain themselves. The encoding is not affected by this.
+pub struct Boxed<T>(PhantomData<T>);
+
+impl<T> Default for Boxed<T> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<T: WritableType> WritableType for Boxed<T> {
+    type Type = Box<T::Type>;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        T::write_value(writer, value)
+    }
+}
+
+impl<T: ReadableType> ReadableType for Boxed<T> {
+    type Type = Box<T::Type>;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        T::read_value(reader).map(Box::new)
+    }
+}
@@ -0,0 +1,198 @@
+use crate::syn::Tag;
+
+/// The character sets of the known-multiplier character string types (X.680-201508 41.1).
+/// Values of all of them are represented as `String`.
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum Charset {
+    /// `NumericString`: the digits and the space
+    Numeric,
+    /// `PrintableString`: latin letters, digits, the space and `'()+,-./:=?`
+    Printable,
+    /// `VisibleString`: the printable characters of ASCII
+    Visible,
+    /// `IA5String`: all of ASCII, including the control characters
+    Ia5,
+    /// `BMPString`: the basic multilingual plane of ISO 10646
+    Bmp,
+    /// `UniversalString`: all of ISO 10646
+    Universal,
+}
+
+const PRINTABLE_SPECIAL_CHARACTERS: &str = " '()+,-./:=?";
+
+impl Charset {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Charset::Numeric => "NumericString",
+            Charset::Printable => "PrintableString",
+            Charset::Visible => "VisibleString",
+            Charset::Ia5 => "IA5String",
+            Charset::Bmp => "BMPString",
+            Charset::Universal => "UniversalString",
+        }
+    }
+
+    pub const fn tag(self) -> Tag {
+        match self {
+            Charset::Numeric => Tag::DEFAULT_NUMERIC_STRING,
+            Charset::Printable => Tag::DEFAULT_PRINTABLE_STRING,
+            Charset::Visible => Tag::DEFAULT_VISIBLE_STRING,
+            Charset::Ia5 => Tag::DEFAULT_IA5_STRING,
+            Charset::Bmp => Tag::DEFAULT_BMP_STRING,
+            Charset::Universal => Tag::DEFAULT_UNIVERSAL_STRING,
+        }
+    }
+
+    /// The amount of octets each character occupies in the BER and OER encoding
+    pub const fn octets_per_character(self) -> usize {
+        match self {
+            Charset::Numeric | Charset::Printable | Charset::Visible | Charset::Ia5 => 1,
+            Charset::Bmp => 2,
+            Charset::Universal => 4,
+        }
+    }
+
+    /// The amount of characters in the set (X.691-201508 30.5.2)
+    pub const fn character_count(self) -> u64 {
+        match self {
+            Charset::Numeric => 11,
+            Charset::Printable => 74,
+            Charset::Visible => 95,
+            Charset::Ia5 => 128,
+            Charset::Bmp => 1 << 16,
+            Charset::Universal => 1 << 32,
+        }
+    }
+
+    /// The largest value of any character in the set
+    pub const fn max_value(self) -> u32 {
+        match self {
+            Charset::Numeric => '9' as u32,
+            Charset::Printable => 'z' as u32,
+            Charset::Visible => '~' as u32,
+            Charset::Ia5 => 0x7F,
+            Charset::Bmp => 0xFFFF,
+            Charset::Universal => u32::MAX,
+        }
+    }
+
+    pub fn is_valid(self, c: char) -> bool {
+        match self {
+            Charset::Numeric => c == ' ' || c.is_ascii_digit(),
+            Charset::Printable => {
+                c.is_ascii_alphanumeric() || PRINTABLE_SPECIAL_CHARACTERS.contains(c)
+            }
+            Charset::Visible => (' '..='~').contains(&c),
+            Charset::Ia5 => c.is_ascii(),
+            Charset::Bmp => (c as u32) <= 0xFFFF,
+            Charset::Universal => true,
+        }
+    }
+
+    /// Returns the first character of the given value that is not valid for this set or
+    /// not within the permitted alphabet of a `FROM` constraint
+    pub fn find_invalid(self, value: &str, permitted_alphabet: Option<&str>) -> Option<char> {
+        value.chars().find(|c| {
+            !self.is_valid(*c) || permitted_alphabet.is_some_and(|alphabet| !alphabet.contains(*c))
+        })
+    }
+
+    /// The characters in canonical order, which is the order of their values
+    /// (X.680-201508 43.8). `None` for the sets that are too large to be listed.
+    pub fn characters(self) -> Option<Vec<char>> {
+        let range = match self {
+            Charset::Numeric | Charset::Printable | Charset::Visible => ' '..='~',
+            Charset::Ia5 => '\u{0}'..='\u{7F}',
+            Charset::Bmp | Charset::Universal => return None,
+        };
+        Some(range.filter(|c| self.is_valid(*c)).collect())
+    }
+
+    /// The content octets for the BER and OER encoding, each character being encoded
+    /// as big-endian number with [`Charset::octets_per_character`] octets. The caller
+    /// is expected to have checked the value with [`Charset::find_invalid`].
+    pub fn to_content(self, value: &str) -> Vec<u8> {
+        let octets = self.octets_per_character();
+        let mut content = Vec::with_capacity(value.len() * octets);
+        for c in value.chars() {
+            content.extend_from_slice(&(c as u32).to_be_bytes()[4 - octets..]);
+        }
+        content
+    }
+
+    /// Interprets the given content octets, see [`Charset::to_content`]. Returns the
+    /// offending value as error if it is no valid character of this set or incomplete.
+    pub fn from_content(self, content: &[u8]) -> Result<String, u32> {
+        let octets = self.octets_per_character();
+        content
+            .chunks(octets)
+            .map(|chunk| {
+                let value = chunk
+                    .iter()
+                    .fold(0_u32, |value, byte| (value << 8) | u32::from(*byte));
+                core::char::from_u32(value)
+                    .filter(|c| chunk.len() == octets && self.is_valid(*c))
+                    .ok_or(value)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_characters_match_len() {
+        for charset in &[
+            Charset::Numeric,
+            Charset::Printable,
+            Charset::Visible,
+            Charset::Ia5,
+        ] {
+            let characters = charset.characters().unwrap();
+            assert_eq!(
+                charset.character_count(),
+                characters.len() as u64,
+                "{:?}",
+                charset
+            );
+            assert_eq!(
+                charset.max_value(),
+                *characters.last().unwrap() as u32,
+                "{:?}",
+                charset
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_invalid() {
+        assert_eq!(None, Charset::Numeric.find_invalid("12 34", None));
+        assert_eq!(Some('a'), Charset::Numeric.find_invalid("12a", None));
+        assert_eq!(Some('*'), Charset::Printable.find_invalid("Hi*", None));
+        assert_eq!(Some('\n'), Charset::Visible.find_invalid("a\n", None));
+        assert_eq!(None, Charset::Ia5.find_invalid("a\n", None));
+        assert_eq!(Some('ä'), Charset::Ia5.find_invalid("ä", None));
+        assert_eq!(None, Charset::Bmp.find_invalid("ä€", None));
+        assert_eq!(Some('😀'), Charset::Bmp.find_invalid("😀", None));
+        assert_eq!(Some('C'), Charset::Ia5.find_invalid("ABC", Some("AB")));
+    }
+
+    #[test]
+    fn test_content() {
+        assert_eq!(b"ab".to_vec(), Charset::Ia5.to_content("ab"));
+        assert_eq!(vec![0x00, 0x61, 0x20, 0xAC], Charset::Bmp.to_content("a€"));
+        assert_eq!(
+            vec![0x00, 0x01, 0xF6, 0x00],
+            Charset::Universal.to_content("😀")
+        );
+        assert_eq!(
+            Ok("a€".to_string()),
+            Charset::Bmp.from_content(&[0x00, 0x61, 0x20, 0xAC])
+        );
+        assert_eq!(Err(0x80), Charset::Ia5.from_content(&[0x80]));
+        assert_eq!(Err(0xD800), Charset::Bmp.from_content(&[0xD8, 0x00]));
+        assert!(Charset::Bmp.from_content(&[0x00]).is_err());
+    }
+}
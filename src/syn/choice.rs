@@ -14,6 +14,8 @@ pub trait Constraint: Sized {
     const VARIANT_COUNT: usize;
     const STD_VARIANT_COUNT: usize;
     const EXTENSIBLE: bool = false;
+    /// The ASN.1 names of all variants, indexed by the choice-index
+    const VARIANT_NAMES: &'static [&'static str];
 
     fn to_choice_index(&self) -> usize;
 
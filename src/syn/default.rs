@@ -0,0 +1,41 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+/// A field with a `DEFAULT` value given by the [`Constraint`]. It is encoded like an
+/// `OPTIONAL` field, which is absent if the value equals the default value.
+pub struct DefaultValue<T, C>(PhantomData<T>, PhantomData<C>);
+
+impl<T, C> Default for DefaultValue<T, C> {
+    fn default() -> Self {
+        Self(Default::default(), Default::default())
+    }
+}
+
+pub trait Constraint<T> {
+    fn default_value() -> T;
+}
+
+impl<T: WritableType, C: Constraint<T::Type>> WritableType for DefaultValue<T, C>
+where
+    T::Type: PartialEq,
+{
+    type Type = T::Type;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        if C::default_value().eq(value) {
+            writer.write_opt::<T>(None)
+        } else {
+            writer.write_opt::<T>(Some(value))
+        }
+    }
+}
+
+impl<T: ReadableType, C: Constraint<T::Type>> ReadableType for DefaultValue<T, C> {
+    type Type = T::Type;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        Ok(reader.read_opt::<T>()?.unwrap_or_else(C::default_value))
+    }
+}
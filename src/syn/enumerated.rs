@@ -1,4 +1,5 @@
 use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::convert::TryFrom;
 use core::marker::PhantomData;
 
 pub struct Enumerated<C: Constraint>(PhantomData<C>);
@@ -14,10 +15,24 @@ pub trait Constraint: Sized {
     const VARIANT_COUNT: usize;
     const STD_VARIANT_COUNT: usize;
     const EXTENSIBLE: bool = false;
+    /// The ASN.1 names of all variants, indexed by the choice-index
+    const VARIANT_NAMES: &'static [&'static str];
 
     fn to_choice_index(&self) -> usize;
 
     fn from_choice_index(index: usize) -> Option<Self>;
+
+    /// The number assigned to the variant in the definition, which is the choice-index
+    /// unless the definition assigns other numbers (X.680-201508 20)
+    fn to_number(&self) -> i64 {
+        self.to_choice_index() as i64
+    }
+
+    fn from_number(number: i64) -> Option<Self> {
+        usize::try_from(number)
+            .ok()
+            .and_then(Self::from_choice_index)
+    }
 }
 
 impl<C: Constraint> WritableType for Enumerated<C> {
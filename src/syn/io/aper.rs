@@ -0,0 +1,1430 @@
+use crate::io::buffer::BitBuffer;
+use crate::io::uper::Error as AperError;
+use crate::io::uper::PermittedAlphabet;
+use crate::io::uper::Reader as _UperReader;
+use crate::io::uper::Writer as _UperWriter;
+use crate::io::uper::BYTE_LEN;
+use crate::prelude::*;
+use crate::syn::charset::Charset;
+use std::ops::Range;
+
+/// The upper bound (exclusive) for lengths that are encoded as constrained whole number,
+/// larger upper bounds fall back to the unconstrained length determinant (X.691-201508 11.9.4.1)
+const APER_LENGTH_CONSTRAINED_LIMIT: usize = 64 * 1024;
+
+enum WriteScope {
+    /// The position of the next OPTIONAL flag and the end of the preamble
+    OptBitField(Range<usize>),
+    /// Like `OptBitField` but for an extensible SEQUENCE, additionally collecting
+    /// the encoded extension additions (`None` for absent ones)
+    ExtensibleSequence(Range<usize>, Vec<Option<Vec<u8>>>),
+    /// Within an extension addition of a single field, its presence is signalled
+    /// by the extension bitmap instead of an OPTIONAL flag
+    ExtensionAddition,
+}
+
+/// Encoder for the ALIGNED variant of PER (X.691). Compared to the [`UperWriter`], the
+/// [`AperWriter`] inserts padding bits so that certain fields (length determinants, larger
+/// constrained integers, octet- and character-strings) start on an octet boundary.
+///
+/// [`UperWriter`]: crate::syn::io::UperWriter
+#[derive(Default)]
+pub struct AperWriter {
+    buffer: BitBuffer,
+    scope: Option<WriteScope>,
+}
+
+impl AperWriter {
+    pub fn byte_content(&self) -> &[u8] {
+        self.buffer.content()
+    }
+
+    pub const fn bit_len(&self) -> usize {
+        self.buffer.bit_len()
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        self.buffer.into()
+    }
+
+    pub fn into_reader(self) -> AperReader {
+        let bits = self.bit_len();
+        let bytes = self.into_bytes_vec();
+        AperReader::from_bits(bytes, bits)
+    }
+
+    /// Calls the closure within the given scope and returns its result together with the
+    /// scope, which might have been updated by the closure
+    #[inline]
+    fn scope_pushed<R, F: Fn(&mut Self) -> R>(
+        &mut self,
+        scope: WriteScope,
+        f: F,
+    ) -> (R, WriteScope) {
+        let original = self.scope.replace(scope);
+        let result = f(self);
+        let scope = core::mem::replace(&mut self.scope, original);
+        let scope = scope.unwrap(); // save because this is the original from above
+        if let WriteScope::OptBitField(range) | WriteScope::ExtensibleSequence(range, _) = &scope {
+            debug_assert_eq!(range.start, range.end);
+        }
+        (result, scope)
+    }
+
+    #[inline]
+    fn scope_stashed<R, F: Fn(&mut Self) -> R>(&mut self, f: F) -> R {
+        let scope = self.scope.take();
+        let result = f(self);
+        self.scope = scope;
+        result
+    }
+
+    /// Sets the extension bit and writes the extension bitmap followed by the open type
+    /// encoding of each present extension addition, if any extension addition is present
+    /// (X.691-201508 19.7 - 19.9)
+    fn write_extension_additions(
+        &mut self,
+        extension_bit: usize,
+        additions: Vec<Option<Vec<u8>>>,
+    ) -> Result<(), AperError> {
+        if additions.iter().any(Option::is_some) {
+            self.buffer
+                .with_write_position_at(extension_bit, |b| b.write_bit(true))?;
+            // the length of the bitmap and the bitmap itself are not aligned
+            self.buffer.write_normally_small_length(additions.len())?;
+            for addition in &additions {
+                self.buffer.write_bit(addition.is_some())?;
+            }
+            for addition in additions.iter().flatten() {
+                self.write_length_determinant(addition.len())?;
+                self.buffer.write_bit_string_till_end(addition, 0)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes padding bits until the write position is on an octet boundary
+    #[inline]
+    fn align(&mut self) -> Result<(), AperError> {
+        while self.buffer.write_position % BYTE_LEN != 0 {
+            self.buffer.write_bit(false)?;
+        }
+        Ok(())
+    }
+
+    /// X.691-201508 11.5.7
+    fn write_constrained_whole_number(
+        &mut self,
+        value: i64,
+        range: (i64, i64),
+    ) -> Result<(), AperError> {
+        let (lower, upper) = range;
+        if value < lower || value > upper {
+            return Err(AperError::ValueNotInRange(value, lower, upper));
+        }
+        let offset = (value - lower) as u64;
+        let range = (upper - lower) as u64;
+        if range == 0 {
+            // 11.5.4: a range of one needs no bits at all
+            Ok(())
+        } else if range < 255 {
+            // 11.5.7.2: the bit-field case, not aligned
+            self.buffer.write_int(offset as i64, (0, range as i64))
+        } else if range == 255 {
+            // 11.5.7.3: the one-octet case
+            self.align()?;
+            self.buffer
+                .write_bit_string(&offset.to_be_bytes(), 7 * BYTE_LEN, BYTE_LEN)
+        } else if range < 65536 {
+            // 11.5.7.3: the two-octet case
+            self.align()?;
+            self.buffer
+                .write_bit_string(&offset.to_be_bytes(), 6 * BYTE_LEN, 2 * BYTE_LEN)
+        } else {
+            // 11.5.7.4: the indefinite length case
+            let max_octets = octets_needed(range);
+            let octets = octets_needed(offset);
+            self.buffer
+                .write_int(octets as i64 - 1, (0, max_octets as i64 - 1))?;
+            self.align()?;
+            self.buffer
+                .write_bit_string_till_end(&offset.to_be_bytes(), (8 - octets) * BYTE_LEN)
+        }
+    }
+
+    /// X.691-201508 11.9.3.6 - 11.9.3.7
+    fn write_length_determinant(&mut self, length: usize) -> Result<(), AperError> {
+        self.align()?;
+        self.buffer.write_length_determinant(length)
+    }
+
+    /// X.691-201508 11.9.4.1
+    fn write_constrained_length(
+        &mut self,
+        length: usize,
+        min: usize,
+        max: Option<usize>,
+    ) -> Result<(), AperError> {
+        match max {
+            Some(max) if max < APER_LENGTH_CONSTRAINED_LIMIT => {
+                self.write_constrained_whole_number(length as i64, (min as i64, max as i64))
+            }
+            _ => self.write_length_determinant(length),
+        }
+    }
+
+    /// X.691-201508 16.6, 17.3, 20.4 and 30.5.3: for an extensible SIZE constraint, a bit
+    /// tells whether the size is within the range. If not, the size is not constrained.
+    fn write_size_extension_bit(
+        &mut self,
+        len: usize,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+    ) -> Result<(Option<usize>, Option<usize>), AperError> {
+        if !extensible {
+            return Ok((min, max));
+        }
+        let within_range = len >= min.unwrap_or(0) && max.is_none_or(|max| len <= max);
+        self.buffer.write_bit(!within_range)?;
+        Ok(if within_range {
+            (min, max)
+        } else {
+            (None, None)
+        })
+    }
+
+    /// X.691-201508 17
+    fn write_octets(
+        &mut self,
+        value: &[u8],
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+    ) -> Result<(), AperError> {
+        let (min, max) = self.write_size_extension_bit(value.len(), min, max, extensible)?;
+        let min = min.unwrap_or(0);
+        if value.len() < min || max.map(|max| value.len() > max).unwrap_or(false) {
+            return Err(AperError::SizeNotInRange(
+                value.len(),
+                min,
+                max.unwrap_or(usize::MAX),
+            ));
+        }
+        match max {
+            Some(max) if min == max && max <= 2 => {
+                // 17.6: fixed size of at most two octets, not aligned
+            }
+            Some(max) if min == max && max < APER_LENGTH_CONSTRAINED_LIMIT => {
+                // 17.7: fixed size, aligned but without length determinant
+                self.align()?;
+            }
+            _ => {
+                // 17.8
+                self.write_constrained_length(value.len(), min, max)?;
+                self.align()?;
+            }
+        }
+        self.buffer.write_bit_string_till_end(value, 0)
+    }
+
+    /// X.691-201508 16
+    fn write_bits(
+        &mut self,
+        value: &[u8],
+        bit_len: usize,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+    ) -> Result<(), AperError> {
+        let (min, max) = self.write_size_extension_bit(bit_len, min, max, extensible)?;
+        let min = min.unwrap_or(0);
+        if bit_len < min || max.map(|max| bit_len > max).unwrap_or(false) {
+            return Err(AperError::SizeNotInRange(
+                bit_len,
+                min,
+                max.unwrap_or(usize::MAX),
+            ));
+        }
+        match max {
+            Some(max) if min == max && max <= 16 => {
+                // 16.9: fixed size of at most sixteen bits, not aligned
+            }
+            Some(max) if min == max && max <= APER_LENGTH_CONSTRAINED_LIMIT => {
+                // 16.10: fixed size, aligned but without length determinant
+                self.align()?;
+            }
+            _ => {
+                // 16.11
+                self.write_constrained_length(bit_len, min, max)?;
+                self.align()?;
+            }
+        }
+        self.buffer.write_bit_string(value, 0, bit_len)
+    }
+
+    /// X.691-201508 30.5, the length is in characters
+    fn write_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+        value: &str,
+    ) -> Result<(), AperError> {
+        let alphabet = PermittedAlphabet::new(charset, permitted_alphabet, true);
+        let len = value.chars().count();
+        let (min, max) = self.write_size_extension_bit(len, min, max, extensible)?;
+        let min = min.unwrap_or(0);
+        if len < min || max.map(|max| len > max).unwrap_or(false) {
+            return Err(AperError::SizeNotInRange(
+                len,
+                min,
+                max.unwrap_or(usize::MAX),
+            ));
+        }
+        let max_bits = max.map(|max| max.saturating_mul(alphabet.bits()));
+        match max {
+            Some(max) if min == max && max_bits.unwrap_or(usize::MAX) <= 16 => {
+                // 30.5.6: fixed size of at most sixteen bits, not aligned
+            }
+            Some(max) if min == max && max < APER_LENGTH_CONSTRAINED_LIMIT => {
+                // 30.5.7: fixed size, aligned but without length determinant
+                self.align()?;
+            }
+            _ => {
+                // 30.5.7: only aligned if the characters might exceed sixteen bits
+                self.write_constrained_length(len, min, max)?;
+                if max_bits.map(|bits| bits > 16).unwrap_or(true) {
+                    self.align()?;
+                }
+            }
+        }
+        for c in value.chars() {
+            let value = alphabet
+                .encode(c)
+                .ok_or(AperError::InvalidCharacter(c as u32))?;
+            self.buffer
+                .write_bit_string_till_end(&value.to_be_bytes(), 32 - alphabet.bits())?;
+        }
+        Ok(())
+    }
+}
+
+impl Writer for AperWriter {
+    type Error = AperError;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        // As in UPER, the values for all OPTIONAL flags are written before any field
+        // value is written. The preamble is not octet-aligned (X.691-201508 19.2). For
+        // extensible SEQUENCEs, the extension bit precedes the OPTIONAL flags and is set
+        // once a present extension addition has been written.
+        let write_pos = self.buffer.write_position;
+        let extensible = C::EXTENDED_AFTER_FIELD.is_some();
+        let range = write_pos + usize::from(extensible)
+            ..write_pos + usize::from(extensible) + C::STD_OPTIONAL_FIELDS;
+        for _ in write_pos..range.end {
+            if let Err(e) = self.buffer.write_bit(false) {
+                self.buffer.write_position = write_pos; // undo write_bits
+                return Err(e);
+            }
+        }
+
+        let scope = if extensible {
+            WriteScope::ExtensibleSequence(range, Vec::new())
+        } else {
+            WriteScope::OptBitField(range)
+        };
+
+        match self.scope_pushed(scope, f) {
+            (Err(e), _) => Err(e),
+            (Ok(()), WriteScope::ExtensibleSequence(_, additions)) => {
+                self.write_extension_additions(write_pos, additions)
+            }
+            (Ok(()), _) => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        let (min, max) =
+            self.write_size_extension_bit(slice.len(), C::MIN, C::MAX, C::EXTENSIBLE)?;
+        if slice.len() < min.unwrap_or(0) || max.is_some_and(|max| slice.len() > max) {
+            return Err(AperError::SizeNotInRange(
+                slice.len(),
+                min.unwrap_or(0),
+                max.unwrap_or(usize::MAX),
+            ));
+        }
+        self.scope_stashed(|w| {
+            if min.is_none() || min != max {
+                w.write_constrained_length(slice.len(), min.unwrap_or(0), max)?;
+            }
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let index = enumerated.to_choice_index();
+        if C::EXTENSIBLE {
+            if index >= C::STD_VARIANT_COUNT {
+                self.buffer.write_bit(true)?;
+                return self
+                    .buffer
+                    .write_int_normally_small((index - C::STD_VARIANT_COUNT) as u64);
+            }
+            self.buffer.write_bit(false)?;
+        }
+        self.write_constrained_whole_number(index as i64, (0, C::STD_VARIANT_COUNT as i64 - 1))
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        self.scope_stashed(|w| {
+            let index = choice.to_choice_index();
+            if C::EXTENSIBLE {
+                if index >= C::STD_VARIANT_COUNT {
+                    w.buffer.write_bit(true)?;
+                    w.buffer
+                        .write_int_normally_small((index - C::STD_VARIANT_COUNT) as u64)?;
+                    // the extension is encoded as open type, which is a complete encoding
+                    // on its own and therefore starts (and ends) octet-aligned
+                    let mut writer = AperWriter::default();
+                    choice.write_content(&mut writer)?;
+                    writer.align()?;
+                    w.write_length_determinant(writer.byte_content().len())?;
+                    return w.buffer.write_bit_string_till_end(writer.byte_content(), 0);
+                }
+                w.buffer.write_bit(false)?;
+            }
+            w.write_constrained_whole_number(index as i64, (0, C::STD_VARIANT_COUNT as i64 - 1))?;
+            choice.write_content(w)
+        })
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(
+        &mut self,
+        value: Option<&<T as WritableType>::Type>,
+    ) -> Result<(), Self::Error> {
+        match &mut self.scope {
+            Some(WriteScope::ExtensionAddition) => {
+                // the presence is already signalled by the extension bitmap
+            }
+            Some(WriteScope::OptBitField(range))
+            | Some(WriteScope::ExtensibleSequence(range, _)) => {
+                if range.start < range.end {
+                    let result = self
+                        .buffer
+                        .with_write_position_at(range.start, |b| b.write_bit(value.is_some()));
+                    range.start += 1;
+                    result?;
+                } else {
+                    return Err(AperError::OptFlagsExhausted);
+                }
+            }
+            None => self.buffer.write_bit(value.is_some())?,
+        }
+        if let Some(value) = value {
+            self.scope_stashed(|w| T::write_value(w, value))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_extension_addition<F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        present: bool,
+        group_optional_fields: Option<usize>,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        let addition = if present {
+            let mut writer = AperWriter::default();
+            let scope = if let Some(optional_fields) = group_optional_fields {
+                // an extension addition group is encoded like a SEQUENCE of its fields
+                for _ in 0..optional_fields {
+                    writer.buffer.write_bit(false)?;
+                }
+                WriteScope::OptBitField(0..optional_fields)
+            } else {
+                WriteScope::ExtensionAddition
+            };
+            writer.scope_pushed(scope, f).0?;
+            // the open type is a complete encoding on its own and therefore ends octet-aligned
+            writer.align()?;
+            let mut bytes = writer.into_bytes_vec();
+            if bytes.is_empty() {
+                // the open type consists of at least one octet
+                bytes.push(0x00);
+            }
+            Some(bytes)
+        } else {
+            None
+        };
+
+        if let Some(WriteScope::ExtensibleSequence(_, additions)) = &mut self.scope {
+            additions.push(addition);
+            Ok(())
+        } else {
+            Err(AperError::UnsupportedOperation(
+                "Extension additions can only be written within an extensible SEQUENCE".into(),
+            ))
+        }
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        key: i64,
+        value: &C,
+    ) -> Result<(), Self::Error> {
+        if !value.is_identified_by(key) {
+            return Err(AperError::OpenTypeKeyMismatch(key, C::NAME));
+        }
+        self.scope_stashed(|w| {
+            // the open type is a complete encoding on its own and therefore starts (and ends)
+            // octet-aligned, an empty encoding is replaced by a single zero octet
+            let mut bytes = match value.unknown_content() {
+                Some(content) => content.to_vec(),
+                None => {
+                    let mut writer = AperWriter::default();
+                    value.write_content(&mut writer)?;
+                    writer.align()?;
+                    writer.into_bytes_vec()
+                }
+            };
+            if bytes.is_empty() {
+                bytes.push(0);
+            }
+            w.write_length_determinant(bytes.len())?;
+            w.buffer.write_bit_string_till_end(&bytes, 0)
+        })
+    }
+
+    #[inline]
+    fn write_int(&mut self, value: i64, range: (i64, i64)) -> Result<(), Self::Error> {
+        self.write_constrained_whole_number(value, range)
+    }
+
+    #[inline]
+    fn write_int_max(&mut self, value: u64) -> Result<(), Self::Error> {
+        // X.691-201508 11.7: the length determinant is octet-aligned and so is the
+        // number that follows it
+        self.align()?;
+        self.buffer.write_int_max(value)
+    }
+
+    #[inline]
+    fn write_int_semi_constrained(
+        &mut self,
+        value: i64,
+        lower_bound: i64,
+    ) -> Result<(), Self::Error> {
+        // X.691-201508 11.7, like write_int_max
+        self.align()?;
+        self.buffer.write_int_semi_constrained(value, lower_bound)
+    }
+
+    #[inline]
+    fn write_int_unconstrained(&mut self, value: i64) -> Result<(), Self::Error> {
+        // X.691-201508 11.8, like write_int_max
+        self.align()?;
+        self.buffer.write_int_unconstrained(value)
+    }
+
+    #[inline]
+    fn write_int_extensible(
+        &mut self,
+        value: i64,
+        (min, max): (i64, i64),
+    ) -> Result<(), Self::Error> {
+        // X.691-201508 12.1 and 12.2, the extension bit is not aligned
+        let within_range = (min..=max).contains(&value);
+        self.buffer.write_bit(!within_range)?;
+        if within_range {
+            self.write_constrained_whole_number(value, (min, max))
+        } else {
+            self.write_int_unconstrained(value)
+        }
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        // UTF8String is not a known-multiplier character string type, therefore
+        // its size constraint is not PER-visible (X.691-201508 30.1)
+        self.write_length_determinant(value.len())?;
+        self.buffer.write_bit_string_till_end(value.as_bytes(), 0)
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.write_octets(value, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        self.write_bits(value, bit_len as usize, C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.buffer.write_bit(value)
+    }
+
+    #[inline]
+    fn write_null(&mut self) -> Result<(), Self::Error> {
+        // X.691-201508 18
+        Ok(())
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        // X.691-201508 15.2
+        self.write_octets(&real::to_content(value), None, None, false)
+    }
+
+    #[inline]
+    fn write_oid(&mut self, value: &ObjectIdentifier) -> Result<(), Self::Error> {
+        // X.691-201508 24
+        self.write_octets(&value.to_content(), None, None, false)
+    }
+}
+
+enum ReadScope {
+    /// The position of the next OPTIONAL flag and the end of the preamble
+    OptBitField(Range<usize>),
+    /// Like `OptBitField` but for an extensible SEQUENCE, additionally with the value of
+    /// the extension bit and - once the extension additions are reached - the position
+    /// of the next and the end of the extension bitmap
+    ExtensibleSequence {
+        opt_bit_field: Range<usize>,
+        extended: bool,
+        bitmap: Option<Range<usize>>,
+    },
+    /// Within an extension addition of a single field, its presence is given
+    /// by the extension bitmap instead of an OPTIONAL flag
+    ExtensionAddition(bool),
+}
+
+/// Decoder for the ALIGNED variant of PER (X.691), see [`AperWriter`].
+pub struct AperReader {
+    buffer: BitBuffer,
+    scope: Option<ReadScope>,
+}
+
+impl AperReader {
+    pub fn from_bits<I: Into<Vec<u8>>>(bytes: I, bit_len: usize) -> Self {
+        Self {
+            buffer: BitBuffer::from_bits(bytes.into(), bit_len),
+            scope: Default::default(),
+        }
+    }
+
+    #[inline]
+    pub const fn bits_remaining(&self) -> usize {
+        self.buffer.write_position - self.buffer.read_position
+    }
+
+    /// Calls the closure within the given scope and returns its result together with the
+    /// scope, which might have been updated by the closure
+    #[inline]
+    fn scope_pushed<R, F: Fn(&mut Self) -> R>(&mut self, scope: ReadScope, f: F) -> (R, ReadScope) {
+        let original = self.scope.replace(scope);
+        let result = f(self);
+        let scope = core::mem::replace(&mut self.scope, original);
+        let scope = scope.unwrap(); // save because this is the original from above
+        if let ReadScope::OptBitField(range)
+        | ReadScope::ExtensibleSequence {
+            opt_bit_field: range,
+            ..
+        } = &scope
+        {
+            debug_assert_eq!(range.start, range.end);
+        }
+        (result, scope)
+    }
+
+    #[inline]
+    fn scope_stashed<R, F: Fn(&mut Self) -> R>(&mut self, f: F) -> R {
+        let scope = self.scope.take();
+        let result = f(self);
+        self.scope = scope;
+        result
+    }
+
+    /// Skips the OPTIONAL flags and returns their position
+    fn read_opt_bit_field(&mut self, optional_fields: usize) -> Result<Range<usize>, AperError> {
+        let range = self.buffer.read_position..self.buffer.read_position + optional_fields;
+        if self.buffer.bit_len() < range.end {
+            return Err(AperError::EndOfStream);
+        }
+        self.buffer.read_position = range.end;
+        Ok(range)
+    }
+
+    /// Skips the extension bitmap and returns its position (X.691-201508 19.8)
+    fn read_extension_bitmap(&mut self) -> Result<Range<usize>, AperError> {
+        let len = self.buffer.read_normally_small_length()?;
+        self.read_opt_bit_field(len)
+    }
+
+    /// Reads the octets of an open type, which are preceded by an aligned length determinant
+    fn read_open_type_bytes(&mut self) -> Result<Vec<u8>, AperError> {
+        let byte_len = self.read_length_determinant()?;
+        let mut bytes = vec![0u8; byte_len];
+        self.buffer.read_bit_string_till_end(&mut bytes[..], 0)?;
+        Ok(bytes)
+    }
+
+    /// Skips padding bits until the read position is on an octet boundary
+    #[inline]
+    fn align(&mut self) -> Result<(), AperError> {
+        while self.buffer.read_position % BYTE_LEN != 0 {
+            self.buffer.read_bit()?;
+        }
+        Ok(())
+    }
+
+    /// X.691-201508 11.5.7
+    fn read_constrained_whole_number(&mut self, range: (i64, i64)) -> Result<i64, AperError> {
+        let (lower, upper) = range;
+        let range = (upper - lower) as u64;
+        let offset = if range == 0 {
+            0
+        } else if range < 255 {
+            self.buffer.read_int((0, range as i64))? as u64
+        } else if range == 255 {
+            self.align()?;
+            self.read_octets_as_u64(1)?
+        } else if range < 65536 {
+            self.align()?;
+            self.read_octets_as_u64(2)?
+        } else {
+            let max_octets = octets_needed(range);
+            let octets = self.buffer.read_int((0, max_octets as i64 - 1))? as usize + 1;
+            self.align()?;
+            self.read_octets_as_u64(octets)?
+        };
+        let value = offset as i64 + lower;
+        if value > upper {
+            Err(AperError::ValueNotInRange(value, lower, upper))
+        } else {
+            Ok(value)
+        }
+    }
+
+    fn read_octets_as_u64(&mut self, octets: usize) -> Result<u64, AperError> {
+        let mut buffer = [0_u8; 8];
+        self.buffer
+            .read_bit_string_till_end(&mut buffer[..], (8 - octets) * BYTE_LEN)?;
+        Ok(u64::from_be_bytes(buffer))
+    }
+
+    /// X.691-201508 11.9.3.6 - 11.9.3.7
+    fn read_length_determinant(&mut self) -> Result<usize, AperError> {
+        self.align()?;
+        self.buffer.read_length_determinant()
+    }
+
+    /// X.691-201508 11.9.4.1
+    fn read_constrained_length(
+        &mut self,
+        min: usize,
+        max: Option<usize>,
+    ) -> Result<usize, AperError> {
+        match max {
+            Some(max) if max < APER_LENGTH_CONSTRAINED_LIMIT => self
+                .read_constrained_whole_number((min as i64, max as i64))
+                .map(|v| v as usize),
+            _ => self.read_length_determinant(),
+        }
+    }
+
+    /// X.691-201508 16.6, 17.3, 20.4 and 30.5.3, see [`AperWriter::write_size_extension_bit`]
+    fn read_size_extension_bit(
+        &mut self,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+    ) -> Result<(Option<usize>, Option<usize>), AperError> {
+        if extensible && self.buffer.read_bit()? {
+            Ok((None, None))
+        } else {
+            Ok((min, max))
+        }
+    }
+
+    /// X.691-201508 17
+    fn read_octets(
+        &mut self,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+    ) -> Result<Vec<u8>, AperError> {
+        let (min, max) = self.read_size_extension_bit(min, max, extensible)?;
+        let min = min.unwrap_or(0);
+        let len = match max {
+            Some(max) if min == max && max <= 2 => max,
+            Some(max) if min == max && max < APER_LENGTH_CONSTRAINED_LIMIT => {
+                self.align()?;
+                max
+            }
+            _ => {
+                let len = self.read_constrained_length(min, max)?;
+                self.align()?;
+                len
+            }
+        };
+        if len < min || max.map(|max| len > max).unwrap_or(false) {
+            return Err(AperError::SizeNotInRange(
+                len,
+                min,
+                max.unwrap_or(usize::MAX),
+            ));
+        }
+        let mut vec = vec![0_u8; len];
+        self.buffer.read_bit_string_till_end(&mut vec[..], 0)?;
+        Ok(vec)
+    }
+
+    /// X.691-201508 16
+    fn read_bits(
+        &mut self,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+    ) -> Result<(Vec<u8>, usize), AperError> {
+        let (min, max) = self.read_size_extension_bit(min, max, extensible)?;
+        let min = min.unwrap_or(0);
+        let len = match max {
+            Some(max) if min == max && max <= 16 => max,
+            Some(max) if min == max && max <= APER_LENGTH_CONSTRAINED_LIMIT => {
+                self.align()?;
+                max
+            }
+            _ => {
+                let len = self.read_constrained_length(min, max)?;
+                self.align()?;
+                len
+            }
+        };
+        if len < min || max.map(|max| len > max).unwrap_or(false) {
+            return Err(AperError::SizeNotInRange(
+                len,
+                min,
+                max.unwrap_or(usize::MAX),
+            ));
+        }
+        let mut vec = vec![0_u8; len.div_ceil(BYTE_LEN)];
+        self.buffer.read_bit_string(&mut vec[..], 0, len)?;
+        Ok((vec, len))
+    }
+
+    /// X.691-201508 30.5, see [`AperWriter::write_known_multiplier_string`]
+    fn read_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+    ) -> Result<String, AperError> {
+        let alphabet = PermittedAlphabet::new(charset, permitted_alphabet, true);
+        let (min, max) = self.read_size_extension_bit(min, max, extensible)?;
+        let min = min.unwrap_or(0);
+        let max_bits = max.map(|max| max.saturating_mul(alphabet.bits()));
+        let len = match max {
+            Some(max) if min == max && max_bits.unwrap_or(usize::MAX) <= 16 => max,
+            Some(max) if min == max && max < APER_LENGTH_CONSTRAINED_LIMIT => {
+                self.align()?;
+                max
+            }
+            _ => {
+                let len = self.read_constrained_length(min, max)?;
+                if max_bits.map(|bits| bits > 16).unwrap_or(true) {
+                    self.align()?;
+                }
+                len
+            }
+        };
+        if len < min || max.map(|max| len > max).unwrap_or(false) {
+            return Err(AperError::SizeNotInRange(
+                len,
+                min,
+                max.unwrap_or(usize::MAX),
+            ));
+        }
+        let mut string = String::with_capacity(len);
+        let mut buffer = [0_u8; 4];
+        for _ in 0..len {
+            self.buffer
+                .read_bit_string_till_end(&mut buffer[..], 32 - alphabet.bits())?;
+            let value = u32::from_be_bytes(buffer);
+            string.push(
+                alphabet
+                    .decode(value)
+                    .ok_or(AperError::InvalidCharacter(value))?,
+            );
+        }
+        Ok(string)
+    }
+}
+
+impl Reader for AperReader {
+    type Error = AperError;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        // For extensible SEQUENCEs, the extension bit precedes the OPTIONAL flags
+        let scope = if C::EXTENDED_AFTER_FIELD.is_some() {
+            let extended = self.buffer.read_bit()?;
+            ReadScope::ExtensibleSequence {
+                opt_bit_field: self.read_opt_bit_field(C::STD_OPTIONAL_FIELDS)?,
+                extended,
+                bitmap: None,
+            }
+        } else {
+            ReadScope::OptBitField(self.read_opt_bit_field(C::STD_OPTIONAL_FIELDS)?)
+        };
+
+        let (result, scope) = self.scope_pushed(scope, f);
+        let result = result?;
+
+        if let ReadScope::ExtensibleSequence {
+            extended: true,
+            bitmap,
+            ..
+        } = scope
+        {
+            // skip all extension additions unknown to this version of the definition
+            let bitmap = match bitmap {
+                Some(bitmap) => bitmap,
+                None => self.read_extension_bitmap()?,
+            };
+            for position in bitmap {
+                if self
+                    .buffer
+                    .with_read_position_at(position, |b| b.read_bit())?
+                {
+                    self.read_open_type_bytes()?;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        let (min, max) = self.read_size_extension_bit(C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let len = if min.is_some() && min == max {
+            min.unwrap_or(0)
+        } else {
+            self.read_constrained_length(min.unwrap_or(0), max)?
+        };
+        let min = min.unwrap_or(0);
+        let max = max.unwrap_or(usize::MAX);
+        if len < min || len > max {
+            Err(AperError::SizeNotInRange(len, min, max))
+        } else {
+            self.scope_stashed(|w| {
+                let mut vec = Vec::with_capacity(len);
+                for _ in 0..len {
+                    vec.push(T::read_value(w)?);
+                }
+                Ok(vec)
+            })
+        }
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let index = if C::EXTENSIBLE && self.buffer.read_bit()? {
+            self.buffer.read_int_normally_small()? as usize + C::STD_VARIANT_COUNT
+        } else {
+            self.read_constrained_whole_number((0, C::STD_VARIANT_COUNT as i64 - 1))? as usize
+        };
+        C::from_choice_index(index).ok_or(AperError::InvalidChoiceIndex(index, C::VARIANT_COUNT))
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        self.scope_stashed(|r| {
+            let (index, content) = if C::EXTENSIBLE && r.buffer.read_bit()? {
+                let index = r.buffer.read_int_normally_small()? as usize + C::STD_VARIANT_COUNT;
+                let bytes = r.read_open_type_bytes()?;
+                let bits = bytes.len() * BYTE_LEN;
+                let mut reader = AperReader::from_bits(bytes, bits);
+                (index, C::read_content(index, &mut reader)?)
+            } else {
+                let index =
+                    r.read_constrained_whole_number((0, C::STD_VARIANT_COUNT as i64 - 1))? as usize;
+                (index, C::read_content(index, r)?)
+            };
+            content.ok_or(AperError::InvalidChoiceIndex(index, C::VARIANT_COUNT))
+        })
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(
+        &mut self,
+    ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
+        let value = match &mut self.scope {
+            Some(ReadScope::ExtensionAddition(present)) => *present,
+            Some(ReadScope::OptBitField(range))
+            | Some(ReadScope::ExtensibleSequence {
+                opt_bit_field: range,
+                ..
+            }) => {
+                if range.start < range.end {
+                    let result = self
+                        .buffer
+                        .with_read_position_at(range.start, |b| b.read_bit());
+                    range.start += 1;
+                    result?
+                } else {
+                    return Err(AperError::OptFlagsExhausted);
+                }
+            }
+            None => self.buffer.read_bit()?,
+        };
+        if value {
+            self.scope_stashed(T::read_value).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    fn read_extension_addition<T, F: Fn(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        group_optional_fields: Option<usize>,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        let (extended, bitmap) = match &self.scope {
+            Some(ReadScope::ExtensibleSequence {
+                extended, bitmap, ..
+            }) => (*extended, bitmap.clone()),
+            _ => {
+                return Err(AperError::UnsupportedOperation(
+                    "Extension additions can only be read within an extensible SEQUENCE".into(),
+                ))
+            }
+        };
+
+        // the extension bitmap follows the last field of the extension root
+        let mut bitmap = match bitmap {
+            Some(bitmap) => bitmap,
+            None if extended => self.read_extension_bitmap()?,
+            None => 0..0,
+        };
+
+        // extension additions unknown to the sender are absent
+        let present = if bitmap.start < bitmap.end {
+            let present = self
+                .buffer
+                .with_read_position_at(bitmap.start, |b| b.read_bit())?;
+            bitmap.start += 1;
+            present
+        } else {
+            false
+        };
+
+        if let Some(ReadScope::ExtensibleSequence { bitmap: scope, .. }) = &mut self.scope {
+            *scope = Some(bitmap);
+        }
+
+        if present {
+            let bytes = self.read_open_type_bytes()?;
+            let bits = bytes.len() * BYTE_LEN;
+            let mut reader = AperReader::from_bits(bytes, bits);
+            let scope = if let Some(optional_fields) = group_optional_fields {
+                // an extension addition group is encoded like a SEQUENCE of its fields
+                ReadScope::OptBitField(reader.read_opt_bit_field(optional_fields)?)
+            } else {
+                ReadScope::ExtensionAddition(true)
+            };
+            reader.scope_pushed(scope, f).0
+        } else {
+            // OPTIONAL fields are read as absent, while reading any other field fails
+            let mut reader = AperReader::from_bits(Vec::new(), 0);
+            reader.scope = Some(ReadScope::ExtensionAddition(false));
+            f(&mut reader)
+        }
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self, key: i64) -> Result<C, Self::Error> {
+        self.scope_stashed(|r| {
+            let bytes = r.read_open_type_bytes()?;
+            let bits = bytes.len() * BYTE_LEN;
+            let mut reader = AperReader::from_bits(bytes, bits);
+            match C::read_content(key, &mut reader)? {
+                Some(value) => Ok(value),
+                None => Ok(C::from_unknown(reader.buffer.into())),
+            }
+        })
+    }
+
+    #[inline]
+    fn read_int(&mut self, range: (i64, i64)) -> Result<i64, Self::Error> {
+        self.read_constrained_whole_number(range)
+    }
+
+    #[inline]
+    fn read_int_max(&mut self) -> Result<u64, Self::Error> {
+        self.align()?;
+        self.buffer.read_int_max()
+    }
+
+    #[inline]
+    fn read_int_semi_constrained(&mut self, lower_bound: i64) -> Result<i64, Self::Error> {
+        self.align()?;
+        self.buffer.read_int_semi_constrained(lower_bound)
+    }
+
+    #[inline]
+    fn read_int_unconstrained(&mut self) -> Result<i64, Self::Error> {
+        self.align()?;
+        self.buffer.read_int_unconstrained()
+    }
+
+    #[inline]
+    fn read_int_extensible(&mut self, range: (i64, i64)) -> Result<i64, Self::Error> {
+        if self.buffer.read_bit()? {
+            self.read_int_unconstrained()
+        } else {
+            self.read_constrained_whole_number(range)
+        }
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let len = self.read_length_determinant()?;
+        let mut buffer = vec![0_u8; len];
+        self.buffer.read_bit_string_till_end(&mut buffer[..], 0)?;
+        String::from_utf8(buffer).map_err(|_| AperError::InvalidUtf8String)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        self.read_octets(C::MIN, C::MAX, C::EXTENSIBLE)
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let (bytes, bit_len) = self.read_bits(C::MIN, C::MAX, C::EXTENSIBLE)?;
+        Ok((bytes, bit_len as u64))
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        self.buffer.read_bit()
+    }
+
+    #[inline]
+    fn read_null(&mut self) -> Result<Null, Self::Error> {
+        Ok(Null)
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        real::from_content(&self.read_octets(None, None, false)?).ok_or(AperError::InvalidReal)
+    }
+
+    #[inline]
+    fn read_oid(&mut self) -> Result<ObjectIdentifier, Self::Error> {
+        ObjectIdentifier::from_content(&self.read_octets(None, None, false)?)
+            .ok_or(AperError::InvalidObjectIdentifier)
+    }
+
+    #[inline]
+    fn read_time<T: time::Time>(&mut self) -> Result<T, Self::Error> {
+        let string = self.read_visible_string::<visiblestring::NoConstraint>()?;
+        T::parse(&string).ok_or(AperError::InvalidTime(string))
+    }
+}
+
+/// The amount of octets needed to represent the given value, but at least one
+#[inline]
+const fn octets_needed(value: u64) -> usize {
+    let bits = 64 - value.leading_zeros() as usize;
+    if bits == 0 {
+        1
+    } else {
+        bits.div_ceil(BYTE_LEN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_octets_needed() {
+        assert_eq!(1, octets_needed(0));
+        assert_eq!(1, octets_needed(255));
+        assert_eq!(2, octets_needed(256));
+        assert_eq!(3, octets_needed(65536));
+        assert_eq!(8, octets_needed(u64::MAX));
+    }
+
+    #[test]
+    fn test_constrained_whole_number_bit_field_is_not_aligned() {
+        let mut writer = AperWriter::default();
+        writer.write_boolean::<boolean::NoConstraint>(true).unwrap();
+        writer.write_int(3, (0, 7)).unwrap();
+        assert_eq!(4, writer.bit_len());
+        assert_eq!(&[0b1011_0000], writer.byte_content());
+    }
+
+    #[test]
+    fn test_constrained_whole_number_one_octet_is_aligned() {
+        let mut writer = AperWriter::default();
+        writer.write_boolean::<boolean::NoConstraint>(true).unwrap();
+        writer.write_int(0x42, (0, 255)).unwrap();
+        assert_eq!(16, writer.bit_len());
+        assert_eq!(&[0x80, 0x42], writer.byte_content());
+        let mut reader = writer.into_reader();
+        assert!(reader.read_boolean::<boolean::NoConstraint>().unwrap());
+        assert_eq!(0x42, reader.read_int((0, 255)).unwrap());
+        assert_eq!(0, reader.bits_remaining());
+    }
+
+    #[test]
+    fn test_constrained_whole_number_two_octets_is_aligned() {
+        let mut writer = AperWriter::default();
+        writer.write_boolean::<boolean::NoConstraint>(true).unwrap();
+        writer.write_int(1337, (0, 65535)).unwrap();
+        assert_eq!(&[0x80, 0x05, 0x39], writer.byte_content());
+        let mut reader = writer.into_reader();
+        assert!(reader.read_boolean::<boolean::NoConstraint>().unwrap());
+        assert_eq!(1337, reader.read_int((0, 65535)).unwrap());
+        assert_eq!(0, reader.bits_remaining());
+    }
+
+    #[test]
+    fn test_constrained_whole_number_indefinite_length() {
+        let mut writer = AperWriter::default();
+        writer.write_int(66_000, (0, 1_209_600_000)).unwrap();
+        // 2 bits for the octet count (3 - 1), aligned, 3 octets of content
+        assert_eq!(&[0x80, 0x01, 0x01, 0xD0], writer.byte_content());
+        let mut reader = writer.into_reader();
+        assert_eq!(66_000, reader.read_int((0, 1_209_600_000)).unwrap());
+        assert_eq!(0, reader.bits_remaining());
+    }
+
+    #[test]
+    fn test_length_determinant_is_aligned() {
+        let mut writer = AperWriter::default();
+        writer.write_boolean::<boolean::NoConstraint>(true).unwrap();
+        writer
+            .write_octet_string::<octetstring::NoConstraint>(&[0x13, 0x37])
+            .unwrap();
+        assert_eq!(&[0x80, 0x02, 0x13, 0x37], writer.byte_content());
+        let mut reader = writer.into_reader();
+        assert!(reader.read_boolean::<boolean::NoConstraint>().unwrap());
+        assert_eq!(
+            vec![0x13, 0x37],
+            reader
+                .read_octet_string::<octetstring::NoConstraint>()
+                .unwrap()
+        );
+        assert_eq!(0, reader.bits_remaining());
+    }
+}
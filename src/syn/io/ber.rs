@@ -0,0 +1,944 @@
+use crate::io::ber::Error as BerError;
+use crate::io::ber::{
+    integer_content, integer_from_content, read_identifier, read_length, write_identifier,
+    write_length,
+};
+use crate::prelude::*;
+use crate::syn::charset::Charset;
+use std::ops::Range;
+
+/// Encodes values with the Distinguished Encoding Rules (X.690). Each value is written
+/// as tag-length-value triplet, using the default universal tag of the type unless it
+/// is replaced or enclosed by a [`Tagged`] field. Only the definite length form is written.
+#[derive(Default)]
+pub struct DerWriter {
+    buffer: Vec<u8>,
+    tag: Option<Tag>,
+}
+
+impl DerWriter {
+    pub fn byte_content(&self) -> &[u8] {
+        &self.buffer[..]
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    pub fn into_reader(self) -> BerReader {
+        BerReader::from_bytes(self.into_bytes_vec())
+    }
+
+    /// The tag to write for the next value: the tag of a surrounding [`Tagged`] or the
+    /// given default tag of the type
+    #[inline]
+    fn next_tag(&mut self, default: Tag) -> Tag {
+        self.tag.take().unwrap_or(default)
+    }
+
+    fn write_primitive(&mut self, tag: Tag, content: &[u8]) -> Result<(), BerError> {
+        write_identifier(&mut self.buffer, tag, false);
+        write_length(&mut self.buffer, content.len());
+        self.buffer.extend_from_slice(content);
+        Ok(())
+    }
+
+    /// Writes the content of the constructed value into a new buffer, so that its
+    /// length is known before the content is appended to the actual buffer
+    fn write_constructed<F: Fn(&mut Self) -> Result<(), BerError>>(
+        &mut self,
+        tag: Tag,
+        f: F,
+    ) -> Result<(), BerError> {
+        let outer = core::mem::take(&mut self.buffer);
+        let result = f(self);
+        let content = core::mem::replace(&mut self.buffer, outer);
+        result?;
+        write_identifier(&mut self.buffer, tag, true);
+        write_length(&mut self.buffer, content.len());
+        self.buffer.extend_from_slice(&content);
+        Ok(())
+    }
+
+    /// X.690-201508 8.23.5, the size is in characters
+    fn write_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+        value: &str,
+    ) -> Result<(), BerError> {
+        let len = value.chars().count();
+        let min = min.unwrap_or(0);
+        let max = max.unwrap_or(usize::MAX);
+        if !extensible && (len < min || len > max) {
+            return Err(BerError::SizeNotInRange(len, min, max));
+        }
+        if let Some(c) = charset.find_invalid(value, permitted_alphabet) {
+            return Err(BerError::InvalidCharacter(c as u32));
+        }
+        let tag = self.next_tag(charset.tag());
+        self.write_primitive(tag, &charset.to_content(value))
+    }
+}
+
+impl Writer for DerWriter {
+    type Error = BerError;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_SEQUENCE);
+        self.write_constructed(tag, f)
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        check_size::<C>(slice.len())?;
+        let tag = self.next_tag(Tag::DEFAULT_SEQUENCE_OF);
+        self.write_constructed(tag, |w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_set<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_SET);
+        self.write_constructed(tag, f)
+    }
+
+    /// The encodings of the elements are written in ascending order (X.690-201508 11.6)
+    fn write_set_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        check_size::<C>(slice.len())?;
+        let tag = self.next_tag(Tag::DEFAULT_SET_OF);
+        let outer = core::mem::take(&mut self.buffer);
+        let mut encodings = Vec::with_capacity(slice.len());
+        for value in slice {
+            if let Err(e) = T::write_value(self, value) {
+                self.buffer = outer;
+                return Err(e);
+            }
+            encodings.push(core::mem::take(&mut self.buffer));
+        }
+        self.buffer = outer;
+        encodings.sort();
+        self.write_constructed(tag, |w| {
+            encodings
+                .iter()
+                .for_each(|encoding| w.buffer.extend_from_slice(encoding));
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_ENUMERATED);
+        // the number assigned to the variant (X.690-201508 8.4)
+        self.write_primitive(tag, &integer_content(enumerated.to_number()))
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        // CHOICE has no tag on its own, therefore a tag given to a CHOICE
+        // is always explicit (X.680-201508 31.2.7)
+        if let Some(tag) = self.tag.take() {
+            self.write_constructed(tag, |w| choice.write_content(w))
+        } else {
+            choice.write_content(self)
+        }
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(
+        &mut self,
+        value: Option<&<T as WritableType>::Type>,
+    ) -> Result<(), Self::Error> {
+        // an absent value is simply not written
+        if let Some(value) = value {
+            T::write_value(self, value)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_tagged<C: tagged::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        if C::EXPLICIT {
+            // the content of an explicit tag is the complete encoding of the inner value
+            // (X.690-201508 8.14.2)
+            return self.write_constructed(C::TAG, |w| T::write_value(w, value));
+        }
+        self.tag = Some(C::TAG);
+        let result = T::write_value(self, value);
+        self.tag = None;
+        result
+    }
+
+    #[inline]
+    fn write_int(&mut self, value: i64, (min, max): (i64, i64)) -> Result<(), Self::Error> {
+        if value < min || value > max {
+            return Err(BerError::ValueNotInRange(value, min, max));
+        }
+        let tag = self.next_tag(Tag::DEFAULT_INTEGER);
+        self.write_primitive(tag, &integer_content(value))
+    }
+
+    #[inline]
+    fn write_int_max(&mut self, value: u64) -> Result<(), Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_INTEGER);
+        if value > i64::MAX as u64 {
+            // the leading zero octet keeps the value positive
+            let mut content = vec![0x00];
+            content.extend_from_slice(&value.to_be_bytes());
+            self.write_primitive(tag, &content)
+        } else {
+            self.write_primitive(tag, &integer_content(value as i64))
+        }
+    }
+
+    #[inline]
+    fn write_int_semi_constrained(
+        &mut self,
+        value: i64,
+        lower_bound: i64,
+    ) -> Result<(), Self::Error> {
+        self.write_int(value, (lower_bound, i64::MAX))
+    }
+
+    #[inline]
+    fn write_int_unconstrained(&mut self, value: i64) -> Result<(), Self::Error> {
+        self.write_int(value, (i64::MIN, i64::MAX))
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_UTF8_STRING);
+        self.write_primitive(tag, value.as_bytes())
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && (value.len() < min || value.len() > max) {
+            return Err(BerError::SizeNotInRange(value.len(), min, max));
+        }
+        let tag = self.next_tag(Tag::DEFAULT_OCTET_STRING);
+        self.write_primitive(tag, value)
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && ((bit_len as usize) < min || bit_len as usize > max) {
+            return Err(BerError::SizeNotInRange(bit_len as usize, min, max));
+        }
+        let tag = self.next_tag(Tag::DEFAULT_BIT_STRING);
+        // X.690-201508 8.6.2, with the unused bits being zero as required by 11.2.1
+        let content = BitVec::from_bytes(value.to_vec(), bit_len).to_prefixed_bytes();
+        self.write_primitive(tag, &content)
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_BOOLEAN);
+        // X.690-201508 11.1
+        self.write_primitive(tag, &[if value { 0xFF } else { 0x00 }])
+    }
+
+    #[inline]
+    fn write_null(&mut self) -> Result<(), Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_NULL);
+        // X.690-201508 8.8
+        self.write_primitive(tag, &[])
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_REAL);
+        // X.690-201508 8.5 with the restrictions of 11.3.1
+        self.write_primitive(tag, &real::to_content(value))
+    }
+
+    #[inline]
+    fn write_oid(&mut self, value: &ObjectIdentifier) -> Result<(), Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_OBJECT_IDENTIFIER);
+        // X.690-201508 8.19
+        self.write_primitive(tag, &value.to_content())
+    }
+
+    fn write_time<T: time::Time>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let tag = self.next_tag(T::TAG);
+        // X.690-201508 8.23, the string form as VisibleString
+        self.write_primitive(tag, value.to_string().as_bytes())
+    }
+}
+
+/// Decodes values encoded with the Basic Encoding Rules (X.690), which includes values
+/// encoded with the [`DerWriter`]. Constructed values may use the definite or the
+/// indefinite length form.
+pub struct BerReader {
+    bytes: Vec<u8>,
+    position: usize,
+    /// The end of the content of the current constructed value, `None` for the indefinite
+    /// length form, which is terminated by the end-of-contents octets instead
+    end: Option<usize>,
+    tag: Option<Tag>,
+}
+
+impl BerReader {
+    pub fn from_bytes<I: Into<Vec<u8>>>(bytes: I) -> Self {
+        let bytes = bytes.into();
+        Self {
+            end: Some(bytes.len()),
+            bytes,
+            position: 0,
+            tag: None,
+        }
+    }
+
+    #[inline]
+    pub fn bytes_remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    /// The tag expected for the next value: the tag of a surrounding [`Tagged`] or the
+    /// given default tag of the type
+    #[inline]
+    fn next_tag(&mut self, default: Tag) -> Tag {
+        self.tag.take().unwrap_or(default)
+    }
+
+    /// Whether all values of the current constructed value have been read
+    fn is_at_end_of_content(&self) -> bool {
+        match self.end {
+            Some(end) => self.position >= end,
+            None => self.bytes.get(self.position..self.position + 2) == Some(&[0x00, 0x00]),
+        }
+    }
+
+    /// Reads the identifier and length octets of the next value. If the value does not have
+    /// the expected tag, [`BerError::UnexpectedTag`] is returned and nothing is consumed.
+    fn read_header(&mut self, tag: Tag, constructed: bool) -> Result<Option<usize>, BerError> {
+        if self.is_at_end_of_content() {
+            return Err(BerError::EndOfStream);
+        }
+        let (found, found_constructed, identifier_len) =
+            read_identifier(&self.bytes[self.position..])?;
+        if found != tag {
+            return Err(BerError::UnexpectedTag(tag, found));
+        } else if found_constructed != constructed {
+            return Err(BerError::UnexpectedEncodingForm(found, found_constructed));
+        }
+        let (length, length_len) = read_length(&self.bytes[self.position + identifier_len..])?;
+        self.position += identifier_len + length_len;
+        Ok(length)
+    }
+
+    fn read_primitive(&mut self, tag: Tag) -> Result<Range<usize>, BerError> {
+        let length = self.read_header(tag, false)?.ok_or_else(|| {
+            BerError::UnsupportedOperation(
+                "The indefinite length form is only allowed for constructed values".into(),
+            )
+        })?;
+        let range = self.position..self.position + length;
+        if range.end > self.bytes.len() {
+            return Err(BerError::EndOfStream);
+        }
+        self.position = range.end;
+        Ok(range)
+    }
+
+    /// Reads the elements of a `SEQUENCE OF` or `SET OF` with the given tag
+    fn read_elements<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<Vec<T::Type>, BerError> {
+        let values = self.read_constructed(tag, |r| {
+            let mut values = Vec::new();
+            while !r.is_at_end_of_content() {
+                values.push(T::read_value(r)?);
+            }
+            Ok(values)
+        })?;
+        check_size::<C>(values.len())?;
+        Ok(values)
+    }
+
+    fn read_constructed<R, F: Fn(&mut Self) -> Result<R, BerError>>(
+        &mut self,
+        tag: Tag,
+        f: F,
+    ) -> Result<R, BerError> {
+        let end = match self.read_header(tag, true)? {
+            Some(length) if self.position + length > self.bytes.len() => {
+                return Err(BerError::EndOfStream)
+            }
+            Some(length) => Some(self.position + length),
+            None => None,
+        };
+        let outer = core::mem::replace(&mut self.end, end);
+        let result = f(self).and_then(|value| {
+            // values unknown to this version of the type are skipped
+            while !self.is_at_end_of_content() {
+                self.skip_value()?;
+            }
+            if end.is_none() {
+                self.position += 2; // end-of-contents octets
+            }
+            Ok(value)
+        });
+        self.end = outer;
+        result
+    }
+
+    /// Skips the next value, regardless of its tag
+    fn skip_value(&mut self) -> Result<(), BerError> {
+        let (_tag, _constructed, identifier_len) = read_identifier(&self.bytes[self.position..])?;
+        let (length, length_len) = read_length(&self.bytes[self.position + identifier_len..])?;
+        self.position += identifier_len + length_len;
+        if let Some(length) = length {
+            if self.position + length > self.bytes.len() {
+                return Err(BerError::EndOfStream);
+            }
+            self.position += length;
+        } else {
+            while self.bytes.get(self.position..self.position + 2) != Some(&[0x00, 0x00]) {
+                if self.position >= self.bytes.len() {
+                    return Err(BerError::EndOfStream);
+                }
+                self.skip_value()?;
+            }
+            self.position += 2;
+        }
+        Ok(())
+    }
+
+    /// Tries to read a value, returns `None` if the value in the stream has a different tag
+    fn read_if_tag_matches<T, F: Fn(&mut Self) -> Result<T, BerError>>(
+        &mut self,
+        f: F,
+    ) -> Result<Option<T>, BerError> {
+        let position = self.position;
+        match f(self) {
+            Ok(value) => Ok(Some(value)),
+            Err(BerError::UnexpectedTag(..)) if self.position == position => {
+                self.tag = None;
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn read_choice_content<C: choice::Constraint>(&mut self) -> Result<C, BerError> {
+        // the variant is identified by the tag of its value
+        for index in 0..C::VARIANT_COUNT {
+            if let Some(value) = self.read_if_tag_matches(|r| C::read_content(index, r))? {
+                return value.ok_or(BerError::InvalidChoiceIndex(index, C::VARIANT_COUNT));
+            }
+        }
+        let found = read_identifier(&self.bytes[self.position..])?.0;
+        Err(BerError::UnsupportedOperation(format!(
+            "No variant of the choice {} has the tag {:?}",
+            C::NAME,
+            found
+        )))
+    }
+
+    /// See [`DerWriter::write_known_multiplier_string`]
+    fn read_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+    ) -> Result<String, BerError> {
+        let tag = self.next_tag(charset.tag());
+        let range = self.read_primitive(tag)?;
+        let value = charset
+            .from_content(&self.bytes[range])
+            .map_err(BerError::InvalidCharacter)?;
+        if let Some(c) = charset.find_invalid(&value, permitted_alphabet) {
+            return Err(BerError::InvalidCharacter(c as u32));
+        }
+        let len = value.chars().count();
+        let min = min.unwrap_or(0);
+        let max = max.unwrap_or(usize::MAX);
+        if !extensible && (len < min || len > max) {
+            Err(BerError::SizeNotInRange(len, min, max))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl Reader for BerReader {
+    type Error = BerError;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_SEQUENCE);
+        self.read_constructed(tag, f)
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_SEQUENCE_OF);
+        self.read_elements::<C, T>(tag)
+    }
+
+    #[inline]
+    fn read_set<C: sequence::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_SET);
+        self.read_constructed(tag, f)
+    }
+
+    #[inline]
+    fn read_set_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_SET_OF);
+        self.read_elements::<C, T>(tag)
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_ENUMERATED);
+        let range = self.read_primitive(tag)?;
+        let number = integer_from_content(&self.bytes[range])?;
+        C::from_number(number).ok_or(BerError::UnknownEnumeratedNumber(number, C::NAME))
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        if let Some(tag) = self.tag.take() {
+            self.read_constructed(tag, Self::read_choice_content::<C>)
+        } else {
+            self.read_choice_content::<C>()
+        }
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(
+        &mut self,
+    ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
+        if self.is_at_end_of_content() {
+            Ok(None)
+        } else {
+            self.read_if_tag_matches(T::read_value)
+        }
+    }
+
+    #[inline]
+    fn read_tagged<C: tagged::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        if C::EXPLICIT {
+            return self.read_constructed(C::TAG, T::read_value);
+        }
+        self.tag = Some(C::TAG);
+        let result = T::read_value(self);
+        self.tag = None;
+        result
+    }
+
+    #[inline]
+    fn read_int(&mut self, (min, max): (i64, i64)) -> Result<i64, Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_INTEGER);
+        let range = self.read_primitive(tag)?;
+        let value = integer_from_content(&self.bytes[range])?;
+        if value < min || value > max {
+            Err(BerError::ValueNotInRange(value, min, max))
+        } else {
+            Ok(value)
+        }
+    }
+
+    #[inline]
+    fn read_int_max(&mut self) -> Result<u64, Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_INTEGER);
+        let range = self.read_primitive(tag)?;
+        let content = &self.bytes[range];
+        if content.len() == 9 && content[0] == 0x00 {
+            let mut bytes = [0_u8; 8];
+            bytes.copy_from_slice(&content[1..]);
+            Ok(u64::from_be_bytes(bytes))
+        } else {
+            let value = integer_from_content(content)?;
+            if value < 0 {
+                Err(BerError::ValueNotInRange(value, 0, i64::MAX))
+            } else {
+                Ok(value as u64)
+            }
+        }
+    }
+
+    #[inline]
+    fn read_int_semi_constrained(&mut self, lower_bound: i64) -> Result<i64, Self::Error> {
+        self.read_int((lower_bound, i64::MAX))
+    }
+
+    #[inline]
+    fn read_int_unconstrained(&mut self) -> Result<i64, Self::Error> {
+        self.read_int((i64::MIN, i64::MAX))
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_UTF8_STRING);
+        let range = self.read_primitive(tag)?;
+        String::from_utf8(self.bytes[range].to_vec()).map_err(|_| BerError::InvalidUtf8String)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        let tag = self.next_tag(Tag::DEFAULT_OCTET_STRING);
+        let range = self.read_primitive(tag)?;
+        if !C::EXTENSIBLE && (range.len() < min || range.len() > max) {
+            Err(BerError::SizeNotInRange(range.len(), min, max))
+        } else {
+            Ok(self.bytes[range].to_vec())
+        }
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        let tag = self.next_tag(Tag::DEFAULT_BIT_STRING);
+        let range = self.read_primitive(tag)?;
+        let (bytes, bit_len) = BitVec::from_prefixed_bytes(&self.bytes[range])
+            .ok_or(BerError::InvalidBitString)?
+            .split();
+        if !C::EXTENSIBLE && ((bit_len as usize) < min || bit_len as usize > max) {
+            Err(BerError::SizeNotInRange(bit_len as usize, min, max))
+        } else {
+            Ok((bytes, bit_len))
+        }
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_BOOLEAN);
+        let range = self.read_primitive(tag)?;
+        if range.len() != 1 {
+            Err(BerError::InvalidLength(range.len()))
+        } else {
+            // X.690-201508 8.2.2: any non-zero value is true
+            Ok(self.bytes[range.start] != 0x00)
+        }
+    }
+
+    #[inline]
+    fn read_null(&mut self) -> Result<Null, Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_NULL);
+        let range = self.read_primitive(tag)?;
+        if range.is_empty() {
+            Ok(Null)
+        } else {
+            Err(BerError::InvalidLength(range.len()))
+        }
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_REAL);
+        let range = self.read_primitive(tag)?;
+        real::from_content(&self.bytes[range]).ok_or(BerError::InvalidReal)
+    }
+
+    #[inline]
+    fn read_oid(&mut self) -> Result<ObjectIdentifier, Self::Error> {
+        let tag = self.next_tag(Tag::DEFAULT_OBJECT_IDENTIFIER);
+        let range = self.read_primitive(tag)?;
+        ObjectIdentifier::from_content(&self.bytes[range]).ok_or(BerError::InvalidObjectIdentifier)
+    }
+
+    fn read_time<T: time::Time>(&mut self) -> Result<T, Self::Error> {
+        let tag = self.next_tag(T::TAG);
+        let range = self.read_primitive(tag)?;
+        let string = Charset::Visible
+            .from_content(&self.bytes[range])
+            .map_err(BerError::InvalidCharacter)?;
+        T::parse(&string).ok_or(BerError::InvalidTime(string))
+    }
+}
+
+/// Checks the number of elements of a `SEQUENCE OF` or `SET OF` against its `SIZE` constraint
+fn check_size<C: sequenceof::Constraint>(len: usize) -> Result<(), BerError> {
+    let min = C::MIN.unwrap_or(0);
+    let max = C::MAX.unwrap_or(usize::MAX);
+    if !C::EXTENSIBLE && (len < min || len > max) {
+        Err(BerError::SizeNotInRange(len, min, max))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitives() {
+        let mut writer = DerWriter::default();
+        writer.write_boolean::<boolean::NoConstraint>(true).unwrap();
+        writer.write_int(-129, (-200, 200)).unwrap();
+        writer.write_int_max(u64::MAX).unwrap();
+        writer
+            .write_octet_string::<octetstring::NoConstraint>(&[0x13, 0x37])
+            .unwrap();
+        writer
+            .write_utf8string::<utf8string::NoConstraint>("hi")
+            .unwrap();
+        assert_eq!(
+            &[
+                0x01, 0x01, 0xFF, // BOOLEAN
+                0x02, 0x02, 0xFF, 0x7F, // INTEGER
+                0x02, 0x09, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // INTEGER
+                0x04, 0x02, 0x13, 0x37, // OCTET STRING
+                0x0C, 0x02, 0x68, 0x69, // UTF8String
+            ],
+            writer.byte_content()
+        );
+        let mut reader = writer.into_reader();
+        assert!(reader.read_boolean::<boolean::NoConstraint>().unwrap());
+        assert_eq!(-129, reader.read_int((-200, 200)).unwrap());
+        assert_eq!(u64::MAX, reader.read_int_max().unwrap());
+        assert_eq!(
+            vec![0x13, 0x37],
+            reader
+                .read_octet_string::<octetstring::NoConstraint>()
+                .unwrap()
+        );
+        assert_eq!(
+            "hi",
+            reader
+                .read_utf8string::<utf8string::NoConstraint>()
+                .unwrap()
+        );
+        assert_eq!(0, reader.bytes_remaining());
+    }
+
+    #[test]
+    fn test_unexpected_tag_consumes_nothing() {
+        let mut reader = BerReader::from_bytes(vec![0x01, 0x01, 0x00]);
+        assert_eq!(
+            Err(BerError::UnexpectedTag(
+                Tag::DEFAULT_INTEGER,
+                Tag::DEFAULT_BOOLEAN
+            )),
+            reader.read_int_max()
+        );
+        assert_eq!(3, reader.bytes_remaining());
+        assert!(!reader.read_boolean::<boolean::NoConstraint>().unwrap());
+    }
+}
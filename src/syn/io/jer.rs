@@ -0,0 +1,912 @@
+use crate::io::jer::Error as JerError;
+use crate::io::jer::Value;
+use crate::prelude::*;
+use crate::syn::charset::Charset;
+
+/// The JSON value under construction that receives the values written next
+enum WriteScope {
+    Sequence {
+        names: &'static [&'static str],
+        index: usize,
+        entries: Vec<(String, Value)>,
+    },
+    SequenceOf(Vec<Value>),
+    Choice(&'static str, Option<Value>),
+}
+
+/// Encodes values with the JSON Encoding Rules (X.697). A SEQUENCE is written as object
+/// keyed by the field names, a CHOICE as object with the name of the chosen variant as
+/// single key, an ENUMERATED as the name of the variant and an OCTET STRING as hex-string.
+/// Tags are not encoded.
+#[derive(Default)]
+pub struct JerWriter {
+    scopes: Vec<WriteScope>,
+    root: Option<Value>,
+}
+
+impl JerWriter {
+    /// The JSON text of the written value, which is empty if nothing has been written yet
+    pub fn json(&self) -> String {
+        self.root
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default()
+    }
+
+    pub fn into_value(self) -> Option<Value> {
+        self.root
+    }
+
+    pub fn into_reader(self) -> JerReader {
+        JerReader::from_value(self.root.unwrap_or(Value::Null))
+    }
+
+    fn put(&mut self, value: Value) -> Result<(), JerError> {
+        match self.scopes.last_mut() {
+            Some(WriteScope::Sequence {
+                names,
+                index,
+                entries,
+            }) => {
+                let name = names.get(*index).ok_or(JerError::EndOfStream)?;
+                *index += 1;
+                entries.push((name.to_string(), value));
+            }
+            Some(WriteScope::SequenceOf(values)) => values.push(value),
+            Some(WriteScope::Choice(_, content)) => *content = Some(value),
+            None => self.root = Some(value),
+        }
+        Ok(())
+    }
+
+    /// Writes the values of `f` into the given scope and returns the scope afterwards
+    fn write_scoped<F: Fn(&mut Self) -> Result<(), JerError>>(
+        &mut self,
+        scope: WriteScope,
+        f: F,
+    ) -> Result<WriteScope, JerError> {
+        self.scopes.push(scope);
+        let result = f(self);
+        let scope = self.scopes.pop().expect("The scope was pushed before");
+        result.map(|_| scope)
+    }
+
+    /// The size is in characters
+    fn write_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+        value: &str,
+    ) -> Result<(), JerError> {
+        let len = value.chars().count();
+        let min = min.unwrap_or(0);
+        let max = max.unwrap_or(usize::MAX);
+        if !extensible && (len < min || len > max) {
+            return Err(JerError::SizeNotInRange(len, min, max));
+        }
+        if let Some(c) = charset.find_invalid(value, permitted_alphabet) {
+            return Err(JerError::InvalidCharacter(c as u32));
+        }
+        self.put(Value::String(value.to_string()))
+    }
+}
+
+impl Writer for JerWriter {
+    type Error = JerError;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        let scope = WriteScope::Sequence {
+            names: C::FIELD_NAMES,
+            index: 0,
+            entries: Vec::with_capacity(C::FIELD_NAMES.len()),
+        };
+        match self.write_scoped(scope, f)? {
+            WriteScope::Sequence { entries, .. } => self.put(Value::Object(entries)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && (slice.len() < min || slice.len() > max) {
+            return Err(JerError::SizeNotInRange(slice.len(), min, max));
+        }
+        let scope = WriteScope::SequenceOf(Vec::with_capacity(slice.len()));
+        let scope = self.write_scoped(scope, |w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })?;
+        match scope {
+            WriteScope::SequenceOf(values) => self.put(Value::Array(values)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let index = enumerated.to_choice_index();
+        let name = C::VARIANT_NAMES
+            .get(index)
+            .ok_or_else(|| JerError::UnknownVariant(index.to_string()))?;
+        self.put(Value::String(name.to_string()))
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        let index = choice.to_choice_index();
+        let name = C::VARIANT_NAMES
+            .get(index)
+            .copied()
+            .ok_or_else(|| JerError::UnknownVariant(index.to_string()))?;
+        let scope = WriteScope::Choice(name, None);
+        match self.write_scoped(scope, |w| choice.write_content(w))? {
+            WriteScope::Choice(name, Some(value)) => {
+                self.put(Value::Object(vec![(name.to_string(), value)]))
+            }
+            _ => Err(JerError::EndOfStream),
+        }
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(
+        &mut self,
+        value: Option<&<T as WritableType>::Type>,
+    ) -> Result<(), Self::Error> {
+        match (value, self.scopes.last_mut()) {
+            (Some(value), _) => T::write_value(self, value),
+            // an absent field is omitted from the object
+            (None, Some(WriteScope::Sequence { index, .. })) => {
+                *index += 1;
+                Ok(())
+            }
+            (None, _) => self.put(Value::Null),
+        }
+    }
+
+    #[inline]
+    fn write_int(&mut self, value: i64, (min, max): (i64, i64)) -> Result<(), Self::Error> {
+        if value < min || value > max {
+            return Err(JerError::ValueNotInRange(value, min, max));
+        }
+        self.put(Value::Number(value.to_string()))
+    }
+
+    #[inline]
+    fn write_int_max(&mut self, value: u64) -> Result<(), Self::Error> {
+        self.put(Value::Number(value.to_string()))
+    }
+
+    #[inline]
+    fn write_int_semi_constrained(
+        &mut self,
+        value: i64,
+        lower_bound: i64,
+    ) -> Result<(), Self::Error> {
+        self.write_int(value, (lower_bound, i64::MAX))
+    }
+
+    #[inline]
+    fn write_int_unconstrained(&mut self, value: i64) -> Result<(), Self::Error> {
+        self.write_int(value, (i64::MIN, i64::MAX))
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.put(Value::String(value.to_string()))
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && (value.len() < min || value.len() > max) {
+            return Err(JerError::SizeNotInRange(value.len(), min, max));
+        }
+        // X.697-201708 24.2
+        self.put(Value::String(bytes_to_hex(value)))
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && ((bit_len as usize) < min || bit_len as usize > max) {
+            return Err(JerError::SizeNotInRange(bit_len as usize, min, max));
+        }
+        let hex = bytes_to_hex(BitVec::from_bytes(value.to_vec(), bit_len).as_byte_slice());
+        if !C::EXTENSIBLE && C::MIN.is_some() && C::MIN == C::MAX {
+            // X.697-201708 23.2
+            self.put(Value::String(hex))
+        } else {
+            // X.697-201708 23.3
+            self.put(Value::Object(vec![
+                ("value".to_string(), Value::String(hex)),
+                ("length".to_string(), Value::Number(bit_len.to_string())),
+            ]))
+        }
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.put(Value::Bool(value))
+    }
+
+    #[inline]
+    fn write_null(&mut self) -> Result<(), Self::Error> {
+        self.put(Value::Null)
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        // X.697-201708 20: the special values are strings, all others are numbers
+        self.put(match real_special_value_name(value) {
+            Some(name) => Value::String(name.to_string()),
+            None => Value::Number(format!("{:?}", value)),
+        })
+    }
+
+    #[inline]
+    fn write_oid(&mut self, value: &ObjectIdentifier) -> Result<(), Self::Error> {
+        // X.697-201708 30
+        self.put(Value::String(value.to_string()))
+    }
+}
+
+/// The JSON value that provides the values read next
+enum ReadScope {
+    Sequence {
+        names: &'static [&'static str],
+        index: usize,
+        entries: Vec<(String, Value)>,
+    },
+    SequenceOf(std::vec::IntoIter<Value>),
+    Choice(Option<Value>),
+}
+
+/// Decodes values encoded with the JSON Encoding Rules (X.697), as written by the
+/// [`JerWriter`]. Members of an object that are unknown to the read type are ignored.
+pub struct JerReader {
+    scopes: Vec<ReadScope>,
+    root: Option<Value>,
+}
+
+impl JerReader {
+    pub fn from_json(json: &str) -> Result<Self, JerError> {
+        Value::parse(json).map(Self::from_value)
+    }
+
+    pub fn from_value(value: Value) -> Self {
+        Self {
+            scopes: Vec::default(),
+            root: Some(value),
+        }
+    }
+
+    fn take(&mut self) -> Result<Value, JerError> {
+        match self.scopes.last_mut() {
+            Some(ReadScope::Sequence {
+                names,
+                index,
+                entries,
+            }) => {
+                let name = *names.get(*index).ok_or(JerError::EndOfStream)?;
+                *index += 1;
+                entries
+                    .iter()
+                    .position(|(key, _)| key == name)
+                    .map(|position| entries.remove(position).1)
+                    .ok_or(JerError::MissingField(name))
+            }
+            Some(ReadScope::SequenceOf(values)) => values.next().ok_or(JerError::EndOfStream),
+            Some(ReadScope::Choice(content)) => content.take().ok_or(JerError::EndOfStream),
+            None => self.root.take().ok_or(JerError::EndOfStream),
+        }
+    }
+
+    /// Whether the next value is present, without consuming it. Within a sequence an
+    /// absent field is skipped.
+    fn next_is_present(&mut self) -> bool {
+        match self.scopes.last_mut() {
+            Some(ReadScope::Sequence {
+                names,
+                index,
+                entries,
+            }) => {
+                let present = names
+                    .get(*index)
+                    .map(|name| entries.iter().any(|(key, _)| key == name))
+                    .unwrap_or(false);
+                if !present {
+                    *index += 1;
+                }
+                present
+            }
+            Some(ReadScope::SequenceOf(values)) => !values.as_slice().is_empty(),
+            Some(ReadScope::Choice(content)) => content.is_some(),
+            None => match self.root {
+                Some(Value::Null) => {
+                    self.root = None;
+                    false
+                }
+                ref root => root.is_some(),
+            },
+        }
+    }
+
+    fn read_scoped<T, F: FnOnce(&mut Self) -> Result<T, JerError>>(
+        &mut self,
+        scope: ReadScope,
+        f: F,
+    ) -> Result<T, JerError> {
+        self.scopes.push(scope);
+        let result = f(self);
+        self.scopes.pop();
+        result
+    }
+
+    fn take_string(&mut self) -> Result<String, JerError> {
+        match self.take()? {
+            Value::String(string) => Ok(string),
+            _ => Err(JerError::UnexpectedType("string")),
+        }
+    }
+
+    fn take_number(&mut self) -> Result<String, JerError> {
+        match self.take()? {
+            Value::Number(number) => Ok(number),
+            _ => Err(JerError::UnexpectedType("number")),
+        }
+    }
+
+    /// See [`JerWriter::write_known_multiplier_string`]
+    fn read_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+    ) -> Result<String, JerError> {
+        let value = self.take_string()?;
+        if let Some(c) = charset.find_invalid(&value, permitted_alphabet) {
+            return Err(JerError::InvalidCharacter(c as u32));
+        }
+        let len = value.chars().count();
+        let min = min.unwrap_or(0);
+        let max = max.unwrap_or(usize::MAX);
+        if !extensible && (len < min || len > max) {
+            Err(JerError::SizeNotInRange(len, min, max))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl Reader for JerReader {
+    type Error = JerError;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        match self.take()? {
+            Value::Object(entries) => {
+                let scope = ReadScope::Sequence {
+                    names: C::FIELD_NAMES,
+                    index: 0,
+                    entries,
+                };
+                self.read_scoped(scope, f)
+            }
+            _ => Err(JerError::UnexpectedType("object")),
+        }
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        match self.take()? {
+            Value::Array(values) => {
+                let min = C::MIN.unwrap_or(0);
+                let max = C::MAX.unwrap_or(usize::MAX);
+                if !C::EXTENSIBLE && (values.len() < min || values.len() > max) {
+                    return Err(JerError::SizeNotInRange(values.len(), min, max));
+                }
+                let len = values.len();
+                self.read_scoped(ReadScope::SequenceOf(values.into_iter()), |r| {
+                    let mut vec = Vec::with_capacity(len);
+                    for _ in 0..len {
+                        vec.push(T::read_value(r)?);
+                    }
+                    Ok(vec)
+                })
+            }
+            _ => Err(JerError::UnexpectedType("array")),
+        }
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let name = self.take_string()?;
+        C::VARIANT_NAMES
+            .iter()
+            .position(|variant| *variant == name)
+            .and_then(C::from_choice_index)
+            .ok_or(JerError::UnknownVariant(name))
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        match self.take()? {
+            Value::Object(mut entries) if entries.len() == 1 => {
+                let (name, value) = entries.remove(0);
+                let index = C::VARIANT_NAMES.iter().position(|variant| *variant == name);
+                match index {
+                    Some(index) => self
+                        .read_scoped(ReadScope::Choice(Some(value)), |r| {
+                            C::read_content(index, r)
+                        })?
+                        .ok_or(JerError::UnknownVariant(name)),
+                    None => Err(JerError::UnknownVariant(name)),
+                }
+            }
+            _ => Err(JerError::UnexpectedType("object with a single member")),
+        }
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(
+        &mut self,
+    ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
+        if self.next_is_present() {
+            T::read_value(self).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    fn read_int(&mut self, (min, max): (i64, i64)) -> Result<i64, Self::Error> {
+        let number = self.take_number()?;
+        let value = number
+            .parse::<i64>()
+            .map_err(|_| JerError::InvalidNumber(number))?;
+        if value < min || value > max {
+            Err(JerError::ValueNotInRange(value, min, max))
+        } else {
+            Ok(value)
+        }
+    }
+
+    #[inline]
+    fn read_int_max(&mut self) -> Result<u64, Self::Error> {
+        let number = self.take_number()?;
+        number
+            .parse::<u64>()
+            .map_err(|_| JerError::InvalidNumber(number))
+    }
+
+    #[inline]
+    fn read_int_semi_constrained(&mut self, lower_bound: i64) -> Result<i64, Self::Error> {
+        self.read_int((lower_bound, i64::MAX))
+    }
+
+    #[inline]
+    fn read_int_unconstrained(&mut self) -> Result<i64, Self::Error> {
+        self.read_int((i64::MIN, i64::MAX))
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.take_string()
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        let bytes = hex_to_bytes(&self.take_string()?)?;
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && (bytes.len() < min || bytes.len() > max) {
+            Err(JerError::SizeNotInRange(bytes.len(), min, max))
+        } else {
+            Ok(bytes)
+        }
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        let (bytes, bit_len) = match self.take()? {
+            Value::String(hex) if !C::EXTENSIBLE && C::MIN.is_some() && C::MIN == C::MAX => {
+                (hex_to_bytes(&hex)?, min as u64)
+            }
+            Value::Object(mut entries) => {
+                let mut take = |name: &'static str| {
+                    entries
+                        .iter()
+                        .position(|(key, _)| key == name)
+                        .map(|index| entries.remove(index).1)
+                        .ok_or(JerError::MissingField(name))
+                };
+                let bytes = match take("value")? {
+                    Value::String(hex) => hex_to_bytes(&hex)?,
+                    _ => return Err(JerError::UnexpectedType("string")),
+                };
+                let bit_len = match take("length")? {
+                    Value::Number(number) => number
+                        .parse::<u64>()
+                        .map_err(|_| JerError::InvalidNumber(number))?,
+                    _ => return Err(JerError::UnexpectedType("number")),
+                };
+                (bytes, bit_len)
+            }
+            _ => return Err(JerError::UnexpectedType("bit string")),
+        };
+        let byte_len = (bit_len as usize).div_ceil(8);
+        if !C::EXTENSIBLE && ((bit_len as usize) < min || bit_len as usize > max) {
+            Err(JerError::SizeNotInRange(bit_len as usize, min, max))
+        } else if bytes.len() != byte_len {
+            // X.697-201708 23.3 the hex-string contains the bits padded to full octets
+            Err(JerError::SizeNotInRange(bytes.len(), byte_len, byte_len))
+        } else {
+            Ok((bytes, bit_len))
+        }
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        match self.take()? {
+            Value::Bool(value) => Ok(value),
+            _ => Err(JerError::UnexpectedType("boolean")),
+        }
+    }
+
+    #[inline]
+    fn read_null(&mut self) -> Result<Null, Self::Error> {
+        match self.take()? {
+            Value::Null => Ok(Null),
+            _ => Err(JerError::UnexpectedType("null")),
+        }
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        match self.take()? {
+            Value::Number(number) => number
+                .parse::<f64>()
+                .map_err(|_| JerError::InvalidNumber(number)),
+            Value::String(name) => match name.as_str() {
+                "INF" => Ok(f64::INFINITY),
+                "-INF" => Ok(f64::NEG_INFINITY),
+                "NaN" => Ok(f64::NAN),
+                "-0" => Ok(-0.0),
+                _ => Err(JerError::InvalidNumber(name)),
+            },
+            _ => Err(JerError::UnexpectedType("number")),
+        }
+    }
+
+    #[inline]
+    fn read_oid(&mut self) -> Result<ObjectIdentifier, Self::Error> {
+        let dotted = self.take_string()?;
+        ObjectIdentifier::parse(&dotted).ok_or(JerError::InvalidObjectIdentifier(dotted))
+    }
+
+    #[inline]
+    fn read_time<T: time::Time>(&mut self) -> Result<T, Self::Error> {
+        let string = self.take_string()?;
+        T::parse(&string).ok_or(JerError::InvalidTime(string))
+    }
+}
+
+/// The names of the special real values that cannot be represented as JSON number
+fn real_special_value_name(value: f64) -> Option<&'static str> {
+    if value.is_nan() {
+        Some("NaN")
+    } else if value == f64::INFINITY {
+        Some("INF")
+    } else if value == f64::NEG_INFINITY {
+        Some("-INF")
+    } else if value == 0.0 && value.is_sign_negative() {
+        Some("-0")
+    } else {
+        None
+    }
+}
+
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+fn hex_to_bytes(hex: &str) -> Result<Vec<u8>, JerError> {
+    if hex.len() % 2 != 0 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(JerError::InvalidHexString);
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| JerError::InvalidHexString)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_octet_string_is_hex() {
+        let mut writer = JerWriter::default();
+        writer
+            .write_octet_string::<octetstring::NoConstraint>(&[0x00, 0x13, 0x37, 0xFF])
+            .unwrap();
+        assert_eq!("\"001337FF\"", writer.json());
+        let mut reader = writer.into_reader();
+        assert_eq!(
+            vec![0x00, 0x13, 0x37, 0xFF],
+            reader
+                .read_octet_string::<octetstring::NoConstraint>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_octet_string_lowercase_hex() {
+        let mut reader = JerReader::from_json("\"0a0B\"").unwrap();
+        assert_eq!(
+            vec![0x0A, 0x0B],
+            reader
+                .read_octet_string::<octetstring::NoConstraint>()
+                .unwrap()
+        );
+        let mut reader = JerReader::from_json("\"0a0\"").unwrap();
+        assert_eq!(
+            Err(JerError::InvalidHexString),
+            reader.read_octet_string::<octetstring::NoConstraint>()
+        );
+    }
+
+    #[test]
+    fn test_bit_string_with_length() {
+        let mut writer = JerWriter::default();
+        writer
+            .write_bit_string::<bitstring::NoConstraint>(&[0xCA, 0xFF], 12)
+            .unwrap();
+        assert_eq!("{\"value\":\"CAF0\",\"length\":12}", writer.json());
+        let mut reader = writer.into_reader();
+        assert_eq!(
+            (vec![0xCA, 0xF0], 12),
+            reader.read_bit_string::<bitstring::NoConstraint>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fixed_size_bit_string_is_hex() {
+        struct Fixed;
+        impl bitstring::Constraint for Fixed {
+            const MIN: Option<usize> = Some(4);
+            const MAX: Option<usize> = Some(4);
+        }
+        let mut writer = JerWriter::default();
+        writer.write_bit_string::<Fixed>(&[0xA0], 4).unwrap();
+        assert_eq!("\"A0\"", writer.json());
+        let mut reader = writer.into_reader();
+        assert_eq!((vec![0xA0], 4), reader.read_bit_string::<Fixed>().unwrap());
+    }
+
+    #[test]
+    fn test_int_out_of_range() {
+        let mut writer = JerWriter::default();
+        assert_eq!(
+            Err(JerError::ValueNotInRange(5, 0, 4)),
+            writer.write_int(5, (0, 4))
+        );
+        let mut reader = JerReader::from_json("5").unwrap();
+        assert_eq!(
+            Err(JerError::ValueNotInRange(5, 0, 4)),
+            reader.read_int((0, 4))
+        );
+    }
+
+    #[test]
+    fn test_unexpected_type() {
+        let mut reader = JerReader::from_json("\"true\"").unwrap();
+        assert_eq!(
+            Err(JerError::UnexpectedType("boolean")),
+            reader.read_boolean::<boolean::NoConstraint>()
+        );
+    }
+}
@@ -1,5 +1,15 @@
+mod aper;
+mod ber;
+mod jer;
+mod oer;
 mod println;
 mod uper;
+mod xer;
 
+pub use aper::*;
+pub use ber::*;
+pub use jer::*;
+pub use oer::*;
 pub use println::*;
 pub use uper::*;
+pub use xer::*;
@@ -0,0 +1,1298 @@
+use crate::io::oer::Error as OerError;
+use crate::io::oer::{
+    fixed_integer_octets, read_length_determinant, read_tag, signed_content, signed_from_content,
+    unsigned_content, unsigned_from_content, write_length_determinant, write_tag,
+};
+use crate::io::uper::BYTE_LEN;
+use crate::prelude::*;
+use crate::syn::charset::Charset;
+use std::ops::Range;
+
+enum WriteScope {
+    /// The bit positions in the preamble of the current SEQUENCE for the next OPTIONAL fields
+    OptBitField(Range<usize>),
+    /// Like `OptBitField` but for an extensible SEQUENCE, additionally collecting
+    /// the encoded extension additions (`None` for absent ones)
+    ExtensibleSequence(Range<usize>, Vec<Option<Vec<u8>>>),
+    /// Within an extension addition of a single field, its presence is signalled
+    /// by the extension addition presence bitmap instead of the preamble
+    ExtensionAddition,
+}
+
+/// Encodes values with the Octet Encoding Rules (X.696). Constrained integers are
+/// written with the fixed amount of octets their bounds require, all other lengths,
+/// counts and values are written with the minimal amount of octets. The output
+/// therefore also satisfies the Canonical Octet Encoding Rules (COER).
+///
+/// The alternatives of a CHOICE are identified by their declared tag. Alternatives
+/// without a declared tag are identified by their automatic tag (the context-specific
+/// tag of their index).
+#[derive(Default)]
+pub struct OerWriter {
+    buffer: Vec<u8>,
+    scope: Option<WriteScope>,
+    /// The index of the CHOICE alternative whose tag is not yet known
+    pending_variant: Option<usize>,
+    /// The tag of the CHOICE alternative that is currently written
+    variant_tag: Option<Tag>,
+}
+
+impl OerWriter {
+    pub fn byte_content(&self) -> &[u8] {
+        &self.buffer[..]
+    }
+
+    pub fn into_bytes_vec(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    pub fn into_reader(self) -> OerReader {
+        OerReader::from_bytes(self.into_bytes_vec())
+    }
+
+    #[inline]
+    fn scope_stashed<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R {
+        let scope = self.scope.take();
+        let result = f(self);
+        self.scope = scope;
+        result
+    }
+
+    /// Sets the extension bit and writes the extension addition presence bitmap followed
+    /// by the open type encoding of each present extension addition, if any extension
+    /// addition is present (X.696-201508 16.3 - 16.5)
+    fn write_extension_additions(&mut self, extension_bit: usize, additions: Vec<Option<Vec<u8>>>) {
+        if additions.iter().any(Option::is_some) {
+            self.buffer[extension_bit / 8] |= 0x80 >> (extension_bit % 8);
+            // the bitmap is encoded like a BIT STRING
+            let mut bitmap = BitVec::with_len(additions.len() as u64);
+            for (index, addition) in additions.iter().enumerate() {
+                if addition.is_some() {
+                    bitmap.set_bit(index as u64);
+                }
+            }
+            self.write_with_length(&bitmap.to_prefixed_bytes());
+            for addition in additions.iter().flatten() {
+                self.write_with_length(addition);
+            }
+        }
+    }
+
+    /// The first value written for a CHOICE alternative determines its tag: the tag
+    /// of a surrounding [`Tagged`] or the automatic tag otherwise
+    #[inline]
+    fn resolve_variant_tag(&mut self, tag: Option<Tag>) {
+        if let Some(index) = self.pending_variant.take() {
+            self.variant_tag = Some(tag.unwrap_or(Tag::ContextSpecific(index)));
+        }
+    }
+
+    /// X.696-201508 10
+    fn write_integer(&mut self, value: i64, (min, max): (i64, i64)) -> Result<(), OerError> {
+        if value < min || value > max {
+            return Err(OerError::ValueNotInRange(value, min, max));
+        }
+        match fixed_integer_octets((min, max)) {
+            Some(octets) => {
+                self.buffer
+                    .extend_from_slice(&value.to_be_bytes()[8 - octets..]);
+            }
+            None if min >= 0 => self.write_with_length(&unsigned_content(value as u64)),
+            None => self.write_with_length(&signed_content(value)),
+        }
+        Ok(())
+    }
+
+    fn write_with_length(&mut self, content: &[u8]) {
+        write_length_determinant(&mut self.buffer, content.len());
+        self.buffer.extend_from_slice(content);
+    }
+
+    /// The quantity field of a SEQUENCE OF, X.696-201508 20.6
+    fn write_quantity(&mut self, quantity: usize) {
+        self.write_with_length(&unsigned_content(quantity as u64));
+    }
+
+    /// X.696-201508 27, a fixed size in characters needs no length determinant
+    fn write_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+        value: &str,
+    ) -> Result<(), OerError> {
+        self.resolve_variant_tag(None);
+        let len = value.chars().count();
+        let fixed_size = !extensible && min.is_some() && min == max;
+        let min = min.unwrap_or(0);
+        let max = max.unwrap_or(usize::MAX);
+        if !extensible && (len < min || len > max) {
+            return Err(OerError::SizeNotInRange(len, min, max));
+        }
+        if let Some(c) = charset.find_invalid(value, permitted_alphabet) {
+            return Err(OerError::InvalidCharacter(c as u32));
+        }
+        let content = charset.to_content(value);
+        if fixed_size {
+            self.buffer.extend_from_slice(&content);
+        } else {
+            self.write_with_length(&content);
+        }
+        Ok(())
+    }
+}
+
+impl Writer for OerWriter {
+    type Error = OerError;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        // The presence bitmap of all OPTIONAL fields precedes the field values (X.696-201508
+        // 16.2). This remembers the position of the zeroed bitmap, so that a later call of
+        // `write_opt` can set its bit. For extensible SEQUENCEs, the extension bit precedes
+        // the presence bits and is set once a present extension addition has been written.
+        let start = self.buffer.len() * 8;
+        let extensible = C::EXTENDED_AFTER_FIELD.is_some();
+        let preamble_bits = usize::from(extensible) + C::STD_OPTIONAL_FIELDS;
+        self.buffer
+            .resize(self.buffer.len() + preamble_bits.div_ceil(8), 0x00);
+        let range = start + usize::from(extensible)..start + preamble_bits;
+        let scope = if extensible {
+            WriteScope::ExtensibleSequence(range, Vec::new())
+        } else {
+            WriteScope::OptBitField(range)
+        };
+        let original = self.scope.replace(scope);
+        let result = f(self);
+        let scope = core::mem::replace(&mut self.scope, original);
+        result?;
+        if let Some(WriteScope::ExtensibleSequence(_, additions)) = scope {
+            self.write_extension_additions(start, additions);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && (slice.len() < min || slice.len() > max) {
+            return Err(OerError::SizeNotInRange(slice.len(), min, max));
+        }
+        self.write_quantity(slice.len());
+        self.scope_stashed(|w| {
+            for value in slice {
+                T::write_value(w, value)?;
+            }
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        // the number assigned to the variant (X.696-201508 11)
+        let number = enumerated.to_number();
+        if (0..0x80).contains(&number) {
+            // X.696-201508 11.3: the short form
+            self.buffer.push(number as u8);
+        } else {
+            // X.696-201508 11.4: the long form
+            let content = signed_content(number);
+            self.buffer.push(0x80 | content.len() as u8);
+            self.buffer.extend_from_slice(&content);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        let index = choice.to_choice_index();
+        // the tag of the surrounding alternative, if this CHOICE is itself an alternative
+        let outer_tag = self.variant_tag.take();
+        let outer_buffer = core::mem::take(&mut self.buffer);
+        self.pending_variant = Some(index);
+        let result = self.scope_stashed(|w| choice.write_content(w));
+        let content = core::mem::replace(&mut self.buffer, outer_buffer);
+        self.pending_variant = None;
+        let tag = core::mem::replace(&mut self.variant_tag, outer_tag);
+        result?;
+
+        write_tag(&mut self.buffer, tag.unwrap_or(Tag::ContextSpecific(index)));
+        if index >= C::STD_VARIANT_COUNT {
+            // X.696-201508 20.2: extension additions are encoded as open type
+            self.write_with_length(&content);
+        } else {
+            self.buffer.extend_from_slice(&content);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(
+        &mut self,
+        value: Option<&<T as WritableType>::Type>,
+    ) -> Result<(), Self::Error> {
+        match &mut self.scope {
+            Some(WriteScope::ExtensionAddition) => {
+                // the presence is already signalled by the extension addition presence bitmap
+            }
+            Some(WriteScope::OptBitField(range))
+            | Some(WriteScope::ExtensibleSequence(range, _)) => {
+                if range.start < range.end {
+                    if value.is_some() {
+                        self.buffer[range.start / 8] |= 0x80 >> (range.start % 8);
+                    }
+                    range.start += 1;
+                } else {
+                    return Err(OerError::OptFlagsExhausted);
+                }
+            }
+            None => self.buffer.push(if value.is_some() { 0x80 } else { 0x00 }),
+        }
+        if let Some(value) = value {
+            self.scope_stashed(|w| T::write_value(w, value))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn write_extension_addition<F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        present: bool,
+        group_optional_fields: Option<usize>,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        let addition = if present {
+            let mut writer = OerWriter::default();
+            writer.scope = Some(if let Some(optional_fields) = group_optional_fields {
+                // an extension addition group is encoded like a SEQUENCE of its fields
+                writer.buffer.resize(optional_fields.div_ceil(8), 0x00);
+                WriteScope::OptBitField(0..optional_fields)
+            } else {
+                WriteScope::ExtensionAddition
+            });
+            f(&mut writer)?;
+            Some(writer.into_bytes_vec())
+        } else {
+            None
+        };
+
+        if let Some(WriteScope::ExtensibleSequence(_, additions)) = &mut self.scope {
+            additions.push(addition);
+            Ok(())
+        } else {
+            Err(OerError::UnsupportedOperation(
+                "Extension additions can only be written within an extensible SEQUENCE".into(),
+            ))
+        }
+    }
+
+    #[inline]
+    fn write_tagged<C: tagged::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(Some(C::TAG));
+        T::write_value(self, value)
+    }
+
+    /// The encoding of the value is preceded by its length, so that the value can be
+    /// skipped without knowing its type
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        key: i64,
+        value: &C,
+    ) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        if !value.is_identified_by(key) {
+            return Err(OerError::OpenTypeKeyMismatch(key, C::NAME));
+        }
+        let content = match value.unknown_content() {
+            Some(content) => content.to_vec(),
+            None => {
+                let mut writer = OerWriter::default();
+                value.write_content(&mut writer)?;
+                writer.into_bytes_vec()
+            }
+        };
+        self.write_with_length(&content);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_int(&mut self, value: i64, range: (i64, i64)) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        self.write_integer(value, range)
+    }
+
+    #[inline]
+    fn write_int_max(&mut self, value: u64) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        self.write_with_length(&unsigned_content(value));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_int_semi_constrained(
+        &mut self,
+        value: i64,
+        lower_bound: i64,
+    ) -> Result<(), Self::Error> {
+        self.write_int(value, (lower_bound, i64::MAX))
+    }
+
+    #[inline]
+    fn write_int_unconstrained(&mut self, value: i64) -> Result<(), Self::Error> {
+        self.write_int(value, (i64::MIN, i64::MAX))
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        self.write_with_length(value.as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && (value.len() < min || value.len() > max) {
+            return Err(OerError::SizeNotInRange(value.len(), min, max));
+        }
+        if !C::EXTENSIBLE && C::MIN.is_some() && C::MIN == C::MAX {
+            // X.696-201508 15.2: a fixed size needs no length determinant
+            self.buffer.extend_from_slice(value);
+        } else {
+            self.write_with_length(value);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && ((bit_len as usize) < min || bit_len as usize > max) {
+            return Err(OerError::SizeNotInRange(bit_len as usize, min, max));
+        }
+        let bits = BitVec::from_bytes(value.to_vec(), bit_len);
+        if !C::EXTENSIBLE && C::MIN.is_some() && C::MIN == C::MAX {
+            // X.696-201508 16.2: a fixed size needs neither a length determinant nor
+            // the initial octet for the number of unused bits
+            self.buffer.extend_from_slice(bits.as_byte_slice());
+        } else {
+            // X.696-201508 16.3
+            self.write_with_length(&bits.to_prefixed_bytes());
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        // X.696-201508 9.2
+        self.buffer.push(if value { 0xFF } else { 0x00 });
+        Ok(())
+    }
+
+    #[inline]
+    fn write_null(&mut self) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        // X.696-201508 18
+        Ok(())
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        // X.696-201508 12.4
+        self.write_with_length(&real::to_content(value));
+        Ok(())
+    }
+
+    #[inline]
+    fn write_oid(&mut self, value: &ObjectIdentifier) -> Result<(), Self::Error> {
+        self.resolve_variant_tag(None);
+        // X.696-201508 26
+        self.write_with_length(&value.to_content());
+        Ok(())
+    }
+}
+
+enum ReadScope {
+    /// The bit positions in the preamble of the current SEQUENCE for the next OPTIONAL fields
+    OptBitField(Range<usize>),
+    /// Like `OptBitField` but for an extensible SEQUENCE, additionally with the value of
+    /// the extension bit and - once the extension additions are reached - the bit positions
+    /// of the next and the end of the extension addition presence bitmap
+    ExtensibleSequence {
+        opt_bit_field: Range<usize>,
+        extended: bool,
+        bitmap: Option<Range<usize>>,
+    },
+    /// Within an extension addition of a single field, its presence is given
+    /// by the extension addition presence bitmap instead of the preamble
+    ExtensionAddition(bool),
+}
+
+/// Decodes values encoded with the Octet Encoding Rules (X.696). A reader created with
+/// [`OerReader::from_canonical_bytes`] additionally rejects all encodings that violate
+/// the Canonical Octet Encoding Rules (COER).
+pub struct OerReader {
+    bytes: Vec<u8>,
+    position: usize,
+    canonical: bool,
+    scope: Option<ReadScope>,
+    /// The tag that was read for the CHOICE alternative with the given index, which is
+    /// verified by the first value read
+    pending_variant: Option<(Tag, usize)>,
+}
+
+impl OerReader {
+    pub fn from_bytes<I: Into<Vec<u8>>>(bytes: I) -> Self {
+        Self {
+            bytes: bytes.into(),
+            position: 0,
+            canonical: false,
+            scope: None,
+            pending_variant: None,
+        }
+    }
+
+    pub fn from_canonical_bytes<I: Into<Vec<u8>>>(bytes: I) -> Self {
+        Self {
+            canonical: true,
+            ..Self::from_bytes(bytes)
+        }
+    }
+
+    #[inline]
+    pub fn bytes_remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    #[inline]
+    fn scope_stashed<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R {
+        let scope = self.scope.take();
+        let result = f(self);
+        self.scope = scope;
+        result
+    }
+
+    /// Checks whether the tag that was read for the current CHOICE alternative is the
+    /// tag of a surrounding [`Tagged`] or the automatic tag otherwise
+    #[inline]
+    fn verify_variant_tag(&mut self, tag: Option<Tag>) -> Result<(), OerError> {
+        if let Some((found, index)) = self.pending_variant.take() {
+            let expected = tag.unwrap_or(Tag::ContextSpecific(index));
+            if expected != found {
+                return Err(OerError::UnexpectedTag(expected, found));
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the preamble with the given amount of bits and returns their bit positions
+    fn read_preamble(&mut self, bits: usize) -> Result<Range<usize>, OerError> {
+        let start = self.position * 8;
+        let canonical = self.canonical;
+        let preamble = self.read_octets(bits.div_ceil(8))?;
+        let used_bits = bits % 8;
+        let unused_bits = preamble.last().copied().unwrap_or_default() & (0xFF >> used_bits);
+        if canonical && used_bits > 0 && unused_bits != 0 {
+            return Err(OerError::NotCanonical("preamble"));
+        }
+        Ok(start..start + bits)
+    }
+
+    /// Reads the extension addition presence bitmap and returns its bit positions
+    fn read_extension_bitmap(&mut self) -> Result<Range<usize>, OerError> {
+        let canonical = self.canonical;
+        let length = self.read_length()?;
+        // the bitmap follows the octet with the number of unused bits
+        let start = (self.position + 1) * 8;
+        let prefixed = self.read_octets(length)?;
+        let bits = BitVec::from_prefixed_bytes(prefixed).ok_or(OerError::InvalidBitString)?;
+        if canonical && bits.as_byte_slice() != &prefixed[1..] {
+            return Err(OerError::NotCanonical("unused bits"));
+        }
+        Ok(start..start + bits.bit_len() as usize)
+    }
+
+    #[inline]
+    fn read_bit_at(&self, position: usize) -> bool {
+        self.bytes[position / 8] & (0x80 >> (position % 8)) != 0
+    }
+
+    fn read_octets(&mut self, len: usize) -> Result<&[u8], OerError> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(OerError::EndOfStream)?;
+        let octets = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(octets)
+    }
+
+    fn read_octet(&mut self) -> Result<u8, OerError> {
+        self.read_octets(1).map(|octets| octets[0])
+    }
+
+    fn read_length(&mut self) -> Result<usize, OerError> {
+        let (length, len) = read_length_determinant(&self.bytes[self.position..], self.canonical)?;
+        self.position += len;
+        Ok(length)
+    }
+
+    fn read_with_length(&mut self) -> Result<&[u8], OerError> {
+        let length = self.read_length()?;
+        self.read_octets(length)
+    }
+
+    /// X.696-201508 10
+    fn read_integer(&mut self, (min, max): (i64, i64)) -> Result<i64, OerError> {
+        let canonical = self.canonical;
+        let value = match fixed_integer_octets((min, max)) {
+            Some(octets) if min >= 0 => {
+                let value = unsigned_from_content(self.read_octets(octets)?, false)?;
+                if value > i64::MAX as u64 {
+                    return Err(OerError::ValueNotInRange(i64::MAX, min, max));
+                }
+                value as i64
+            }
+            Some(octets) => signed_from_content(self.read_octets(octets)?, false)?,
+            None if min >= 0 => {
+                let value = unsigned_from_content(self.read_with_length()?, canonical)?;
+                if value > i64::MAX as u64 {
+                    return Err(OerError::ValueNotInRange(i64::MAX, min, max));
+                }
+                value as i64
+            }
+            None => signed_from_content(self.read_with_length()?, canonical)?,
+        };
+        if value < min || value > max {
+            Err(OerError::ValueNotInRange(value, min, max))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// The quantity field of a SEQUENCE OF, X.696-201508 20.6
+    fn read_quantity(&mut self) -> Result<usize, OerError> {
+        let canonical = self.canonical;
+        let quantity = unsigned_from_content(self.read_with_length()?, canonical)?;
+        if quantity > usize::MAX as u64 {
+            Err(OerError::UnsupportedOperation(format!(
+                "The quantity {} exceeds the platform word size",
+                quantity
+            )))
+        } else {
+            Ok(quantity as usize)
+        }
+    }
+
+    /// See [`OerWriter::write_known_multiplier_string`]
+    fn read_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+    ) -> Result<String, OerError> {
+        self.verify_variant_tag(None)?;
+        let content = match (min, max) {
+            (Some(min), Some(max)) if !extensible && min == max => {
+                self.read_octets(max * charset.octets_per_character())?
+            }
+            _ => self.read_with_length()?,
+        };
+        let value = charset
+            .from_content(content)
+            .map_err(OerError::InvalidCharacter)?;
+        if let Some(c) = charset.find_invalid(&value, permitted_alphabet) {
+            return Err(OerError::InvalidCharacter(c as u32));
+        }
+        let len = value.chars().count();
+        let min = min.unwrap_or(0);
+        let max = max.unwrap_or(usize::MAX);
+        if !extensible && (len < min || len > max) {
+            Err(OerError::SizeNotInRange(len, min, max))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl Reader for OerReader {
+    type Error = OerError;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.verify_variant_tag(None)?;
+        // The presence bitmap of all OPTIONAL fields precedes the field values (X.696-201508
+        // 16.2). This remembers its position, so that a later call of `read_opt` can
+        // retrieve the bit. For extensible SEQUENCEs, the extension bit precedes the
+        // presence bits.
+        let extensible = C::EXTENDED_AFTER_FIELD.is_some();
+        let preamble = self.read_preamble(usize::from(extensible) + C::STD_OPTIONAL_FIELDS)?;
+        let scope = if extensible {
+            ReadScope::ExtensibleSequence {
+                opt_bit_field: preamble.start + 1..preamble.end,
+                extended: self.read_bit_at(preamble.start),
+                bitmap: None,
+            }
+        } else {
+            ReadScope::OptBitField(preamble)
+        };
+        let original = self.scope.replace(scope);
+        let result = f(self);
+        let scope = core::mem::replace(&mut self.scope, original);
+        let result = result?;
+
+        if let Some(ReadScope::ExtensibleSequence {
+            extended: true,
+            bitmap,
+            ..
+        }) = scope
+        {
+            // skip all extension additions unknown to this version of the definition
+            let bitmap = match bitmap {
+                Some(bitmap) => bitmap,
+                None => self.read_extension_bitmap()?,
+            };
+            for position in bitmap {
+                if self.read_bit_at(position) {
+                    self.read_with_length()?;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.verify_variant_tag(None)?;
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        let len = self.read_quantity()?;
+        if !C::EXTENSIBLE && (len < min || len > max) {
+            return Err(OerError::SizeNotInRange(len, min, max));
+        }
+        self.scope_stashed(|r| {
+            // do not trust the quantity for the initial capacity
+            let mut vec = Vec::with_capacity(len.min(r.bytes_remaining()));
+            for _ in 0..len {
+                vec.push(T::read_value(r)?);
+            }
+            Ok(vec)
+        })
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        self.verify_variant_tag(None)?;
+        let first = self.read_octet()?;
+        let number = if first & 0x80 == 0 {
+            i64::from(first)
+        } else {
+            let len = usize::from(first & 0x7F);
+            let canonical = self.canonical;
+            let value = signed_from_content(self.read_octets(len)?, canonical)?;
+            if canonical && (0..0x80).contains(&value) {
+                return Err(OerError::NotCanonical("enumerated value"));
+            }
+            value
+        };
+        C::from_number(number).ok_or(OerError::UnknownEnumeratedNumber(number, C::NAME))
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        self.verify_variant_tag(None)?;
+        let (tag, len) = read_tag(&self.bytes[self.position..])?;
+        self.position += len;
+        let start = self.position;
+
+        // the tag of each alternative is only known while reading its content
+        for index in 0..C::VARIANT_COUNT {
+            self.position = start;
+            let extensible_end = if index >= C::STD_VARIANT_COUNT {
+                let length = self.read_length()?;
+                Some(self.position + length)
+            } else {
+                None
+            };
+            self.pending_variant = Some((tag, index));
+            let result = self.scope_stashed(|r| C::read_content(index, r));
+            self.pending_variant = None;
+            match result {
+                Ok(Some(value)) => {
+                    if let Some(end) = extensible_end {
+                        if self.position > end {
+                            return Err(OerError::EndOfStream);
+                        }
+                        // skip what is unknown to this version of the type
+                        self.position = end;
+                    }
+                    return Ok(value);
+                }
+                Ok(None) => {
+                    return Err(OerError::InvalidChoiceIndex(index, C::VARIANT_COUNT));
+                }
+                Err(OerError::UnexpectedTag(_, found)) if found == tag => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(OerError::UnknownChoiceTag(tag))
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(
+        &mut self,
+    ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
+        let present = match &mut self.scope {
+            Some(ReadScope::ExtensionAddition(present)) => *present,
+            Some(ReadScope::OptBitField(range))
+            | Some(ReadScope::ExtensibleSequence {
+                opt_bit_field: range,
+                ..
+            }) => {
+                if range.start < range.end {
+                    let position = range.start;
+                    range.start += 1;
+                    self.read_bit_at(position)
+                } else {
+                    return Err(OerError::OptFlagsExhausted);
+                }
+            }
+            None => self.read_octet()? & 0x80 != 0,
+        };
+        if present {
+            self.scope_stashed(T::read_value).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    fn read_extension_addition<T, F: Fn(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        group_optional_fields: Option<usize>,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        let (extended, bitmap) = match &self.scope {
+            Some(ReadScope::ExtensibleSequence {
+                extended, bitmap, ..
+            }) => (*extended, bitmap.clone()),
+            _ => {
+                return Err(OerError::UnsupportedOperation(
+                    "Extension additions can only be read within an extensible SEQUENCE".into(),
+                ))
+            }
+        };
+
+        // the extension addition presence bitmap follows the last field of the extension root
+        let mut bitmap = match bitmap {
+            Some(bitmap) => bitmap,
+            None if extended => self.read_extension_bitmap()?,
+            None => 0..0,
+        };
+
+        // extension additions unknown to the sender are absent
+        let present = if bitmap.start < bitmap.end {
+            let present = self.read_bit_at(bitmap.start);
+            bitmap.start += 1;
+            present
+        } else {
+            false
+        };
+
+        if let Some(ReadScope::ExtensibleSequence { bitmap: scope, .. }) = &mut self.scope {
+            *scope = Some(bitmap);
+        }
+
+        let content = if present {
+            self.read_with_length()?.to_vec()
+        } else {
+            Vec::new()
+        };
+        let mut reader = OerReader {
+            canonical: self.canonical,
+            ..OerReader::from_bytes(content)
+        };
+        reader.scope = Some(match group_optional_fields {
+            // an extension addition group is encoded like a SEQUENCE of its fields
+            Some(optional_fields) if present => {
+                ReadScope::OptBitField(reader.read_preamble(optional_fields)?)
+            }
+            // OPTIONAL fields are read as absent, while reading any other field fails
+            _ => ReadScope::ExtensionAddition(present),
+        });
+        f(&mut reader)
+    }
+
+    #[inline]
+    fn read_tagged<C: tagged::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error> {
+        self.verify_variant_tag(Some(C::TAG))?;
+        T::read_value(self)
+    }
+
+    /// See [`OerWriter::write_open_type`]
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self, key: i64) -> Result<C, Self::Error> {
+        self.verify_variant_tag(None)?;
+        let content = self.read_with_length()?.to_vec();
+        let mut reader = OerReader {
+            canonical: self.canonical,
+            ..OerReader::from_bytes(content.clone())
+        };
+        match C::read_content(key, &mut reader)? {
+            Some(value) => Ok(value),
+            None => Ok(C::from_unknown(content)),
+        }
+    }
+
+    #[inline]
+    fn read_int(&mut self, range: (i64, i64)) -> Result<i64, Self::Error> {
+        self.verify_variant_tag(None)?;
+        self.read_integer(range)
+    }
+
+    #[inline]
+    fn read_int_max(&mut self) -> Result<u64, Self::Error> {
+        self.verify_variant_tag(None)?;
+        let canonical = self.canonical;
+        unsigned_from_content(self.read_with_length()?, canonical)
+    }
+
+    #[inline]
+    fn read_int_semi_constrained(&mut self, lower_bound: i64) -> Result<i64, Self::Error> {
+        self.read_int((lower_bound, i64::MAX))
+    }
+
+    #[inline]
+    fn read_int_unconstrained(&mut self) -> Result<i64, Self::Error> {
+        self.read_int((i64::MIN, i64::MAX))
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.verify_variant_tag(None)?;
+        let octets = self.read_with_length()?.to_vec();
+        String::from_utf8(octets).map_err(|_| OerError::InvalidUtf8String)
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        self.verify_variant_tag(None)?;
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        let octets = if !C::EXTENSIBLE && C::MIN.is_some() && C::MIN == C::MAX {
+            self.read_octets(min)?
+        } else {
+            self.read_with_length()?
+        };
+        if !C::EXTENSIBLE && (octets.len() < min || octets.len() > max) {
+            Err(OerError::SizeNotInRange(octets.len(), min, max))
+        } else {
+            Ok(octets.to_vec())
+        }
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        self.verify_variant_tag(None)?;
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        let (bytes, bit_len) = if !C::EXTENSIBLE && C::MIN.is_some() && C::MIN == C::MAX {
+            let bytes = self.read_octets(min.div_ceil(BYTE_LEN))?.to_vec();
+            (bytes, min as u64)
+        } else {
+            let canonical = self.canonical;
+            let prefixed = self.read_with_length()?;
+            let bits = BitVec::from_prefixed_bytes(prefixed).ok_or(OerError::InvalidBitString)?;
+            if canonical && bits.as_byte_slice() != &prefixed[1..] {
+                return Err(OerError::NotCanonical("unused bits"));
+            }
+            bits.split()
+        };
+        if !C::EXTENSIBLE && ((bit_len as usize) < min || bit_len as usize > max) {
+            Err(OerError::SizeNotInRange(bit_len as usize, min, max))
+        } else {
+            Ok((bytes, bit_len))
+        }
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        self.verify_variant_tag(None)?;
+        match self.read_octet()? {
+            0x00 => Ok(false),
+            0xFF => Ok(true),
+            // X.696-201508 9.2: any other value is only allowed in the basic variant
+            _ if self.canonical => Err(OerError::NotCanonical("boolean")),
+            _ => Ok(true),
+        }
+    }
+
+    #[inline]
+    fn read_null(&mut self) -> Result<Null, Self::Error> {
+        self.verify_variant_tag(None)?;
+        Ok(Null)
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        self.verify_variant_tag(None)?;
+        let canonical = self.canonical;
+        let content = self.read_with_length()?;
+        let value = real::from_content(content).ok_or(OerError::InvalidReal)?;
+        if canonical && real::to_content(value) != content {
+            Err(OerError::NotCanonical("real"))
+        } else {
+            Ok(value)
+        }
+    }
+
+    #[inline]
+    fn read_oid(&mut self) -> Result<ObjectIdentifier, Self::Error> {
+        self.verify_variant_tag(None)?;
+        ObjectIdentifier::from_content(self.read_with_length()?)
+            .ok_or(OerError::InvalidObjectIdentifier)
+    }
+
+    #[inline]
+    fn read_time<T: time::Time>(&mut self) -> Result<T, Self::Error> {
+        let string = self.read_visible_string::<visiblestring::NoConstraint>()?;
+        T::parse(&string).ok_or(OerError::InvalidTime(string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constrained_integers_have_fixed_size() {
+        let mut writer = OerWriter::default();
+        writer.write_int(5, (0, 255)).unwrap();
+        writer.write_int(256, (0, 65535)).unwrap();
+        writer.write_int(-2, (-100, 100)).unwrap();
+        writer.write_int(70_000, (0, 100_000)).unwrap();
+        assert_eq!(
+            &[0x05, 0x01, 0x00, 0xFE, 0x00, 0x01, 0x11, 0x70],
+            writer.byte_content()
+        );
+        let mut reader = writer.into_reader();
+        assert_eq!(5, reader.read_int((0, 255)).unwrap());
+        assert_eq!(256, reader.read_int((0, 65535)).unwrap());
+        assert_eq!(-2, reader.read_int((-100, 100)).unwrap());
+        assert_eq!(70_000, reader.read_int((0, 100_000)).unwrap());
+        assert_eq!(0, reader.bytes_remaining());
+    }
+
+    #[test]
+    fn test_semi_constrained_integers_have_length() {
+        let mut writer = OerWriter::default();
+        writer.write_int(300, (0, i64::MAX)).unwrap();
+        writer.write_int(-300, (-1000, i64::MAX)).unwrap();
+        writer.write_int_max(0).unwrap();
+        assert_eq!(
+            &[0x02, 0x01, 0x2C, 0x02, 0xFE, 0xD4, 0x01, 0x00],
+            writer.byte_content()
+        );
+        let mut reader = writer.into_reader();
+        assert_eq!(300, reader.read_int((0, i64::MAX)).unwrap());
+        assert_eq!(-300, reader.read_int((-1000, i64::MAX)).unwrap());
+        assert_eq!(0, reader.read_int_max().unwrap());
+    }
+
+    #[test]
+    fn test_integer_out_of_range() {
+        let mut writer = OerWriter::default();
+        assert_eq!(
+            Err(OerError::ValueNotInRange(256, 0, 255)),
+            writer.write_int(256, (0, 255))
+        );
+        let mut reader = OerReader::from_bytes(vec![0xC9]);
+        assert_eq!(
+            Err(OerError::ValueNotInRange(201, 0, 200)),
+            reader.read_int((0, 200))
+        );
+    }
+
+    #[test]
+    fn test_boolean_canonical() {
+        assert_eq!(
+            Ok(true),
+            OerReader::from_bytes(vec![0x01]).read_boolean::<boolean::NoConstraint>()
+        );
+        assert_eq!(
+            Err(OerError::NotCanonical("boolean")),
+            OerReader::from_canonical_bytes(vec![0x01]).read_boolean::<boolean::NoConstraint>()
+        );
+    }
+
+    #[test]
+    fn test_semi_constrained_integer_canonical() {
+        assert_eq!(
+            Ok(5),
+            OerReader::from_bytes(vec![0x02, 0x00, 0x05]).read_int((0, i64::MAX))
+        );
+        assert_eq!(
+            Err(OerError::NotCanonical("integer")),
+            OerReader::from_canonical_bytes(vec![0x02, 0x00, 0x05]).read_int((0, i64::MAX))
+        );
+    }
+
+    #[test]
+    fn test_strings_have_length() {
+        let mut writer = OerWriter::default();
+        writer
+            .write_utf8string::<utf8string::NoConstraint>("hi")
+            .unwrap();
+        writer
+            .write_octet_string::<octetstring::NoConstraint>(&[0x13, 0x37])
+            .unwrap();
+        assert_eq!(&[0x02, 0x68, 0x69, 0x02, 0x13, 0x37], writer.byte_content());
+        let mut reader = writer.into_reader();
+        assert_eq!(
+            "hi",
+            reader
+                .read_utf8string::<utf8string::NoConstraint>()
+                .unwrap()
+        );
+        assert_eq!(
+            vec![0x13, 0x37],
+            reader
+                .read_octet_string::<octetstring::NoConstraint>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fixed_size_octet_string_has_no_length() {
+        struct Fixed;
+        impl octetstring::Constraint for Fixed {
+            const MIN: Option<usize> = Some(2);
+            const MAX: Option<usize> = Some(2);
+        }
+
+        let mut writer = OerWriter::default();
+        writer.write_octet_string::<Fixed>(&[0x13, 0x37]).unwrap();
+        assert_eq!(
+            Err(OerError::SizeNotInRange(1, 2, 2)),
+            writer.write_octet_string::<Fixed>(&[0x13])
+        );
+        assert_eq!(&[0x13, 0x37], writer.byte_content());
+        let mut reader = writer.into_reader();
+        assert_eq!(
+            vec![0x13, 0x37],
+            reader.read_octet_string::<Fixed>().unwrap()
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::syn::charset::Charset;
 
 #[derive(Default)]
 pub struct PrintlnWriter(usize);
@@ -14,6 +15,31 @@ impl PrintlnWriter {
         self.0 -= 1;
         r
     }
+
+    fn write_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+        value: &str,
+    ) -> Result<(), ()> {
+        self.indented_println(format!(
+            "Writing {}({}..{}{}){}: {}",
+            charset.name(),
+            min.map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MIN")),
+            max.map(|v| format!("{}", v))
+                .unwrap_or_else(|| String::from("MAX")),
+            if extensible { ", ..." } else { "" },
+            permitted_alphabet
+                .map(|alphabet| format!(" FROM({:?})", alphabet))
+                .unwrap_or_default(),
+            value
+        ));
+        Ok(())
+    }
 }
 
 impl Writer for PrintlnWriter {
@@ -104,6 +130,14 @@ impl Writer for PrintlnWriter {
         })
     }
 
+    fn write_tagged<C: tagged::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("Writing tagged {:?}", C::TAG));
+        self.with_increased_indentation(|w| T::write_value(w, value))
+    }
+
     fn write_int(&mut self, value: i64, (min, max): (i64, i64)) -> Result<(), Self::Error> {
         self.indented_println(&format!("WRITING Integer({}..{}) {}", min, max, value));
         Ok(())
@@ -114,6 +148,29 @@ impl Writer for PrintlnWriter {
         Ok(())
     }
 
+    fn write_int_semi_constrained(
+        &mut self,
+        value: i64,
+        lower_bound: i64,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(&format!("WRITING Integer({}..MAX) {}", lower_bound, value));
+        Ok(())
+    }
+
+    fn write_int_unconstrained(&mut self, value: i64) -> Result<(), Self::Error> {
+        self.indented_println(&format!("WRITING Integer(MIN..MAX) {}", value));
+        Ok(())
+    }
+
+    fn write_int_extensible(
+        &mut self,
+        value: i64,
+        (min, max): (i64, i64),
+    ) -> Result<(), Self::Error> {
+        self.indented_println(&format!("WRITING Integer({}..{}, ...) {}", min, max, value));
+        Ok(())
+    }
+
     fn write_utf8string<C: utf8string::Constraint>(
         &mut self,
         value: &str,
@@ -131,6 +188,90 @@ impl Writer for PrintlnWriter {
         Ok(())
     }
 
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
     fn write_octet_string<C: octetstring::Constraint>(
         &mut self,
         value: &[u8],
@@ -139,8 +280,37 @@ impl Writer for PrintlnWriter {
         Ok(())
     }
 
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING BitString({}) {:?}", bit_len, value));
+        Ok(())
+    }
+
     fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
         self.indented_println(format!("WRITING Boolean {}", value));
         Ok(())
     }
+
+    fn write_null(&mut self) -> Result<(), Self::Error> {
+        self.indented_println("WRITING Null");
+        Ok(())
+    }
+
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING Real {}", value));
+        Ok(())
+    }
+
+    fn write_oid(&mut self, value: &ObjectIdentifier) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING ObjectIdentifier {}", value));
+        Ok(())
+    }
+
+    fn write_time<T: time::Time>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.indented_println(format!("WRITING {} {}", T::NAME, value));
+        Ok(())
+    }
 }
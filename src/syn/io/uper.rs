@@ -1,14 +1,30 @@
 use crate::io::buffer::BitBuffer;
 use crate::io::uper::Error as UperError;
+use crate::io::uper::PermittedAlphabet;
 use crate::io::uper::Reader as _UperReader;
 use crate::io::uper::Writer as _UperWriter;
+use crate::io::uper::BYTE_LEN;
 use crate::prelude::*;
+use crate::syn::charset::Charset;
 use std::ops::Range;
 
+const UPER_LENGTH_CONSTRAINED_LIMIT: usize = 64 * 1024;
+
+enum WriteScope {
+    /// The position of the next OPTIONAL flag and the end of the preamble
+    OptBitField(Range<usize>),
+    /// Like `OptBitField` but for an extensible SEQUENCE, additionally collecting
+    /// the encoded extension additions (`None` for absent ones)
+    ExtensibleSequence(Range<usize>, Vec<Option<Vec<u8>>>),
+    /// Within an extension addition of a single field, its presence is signalled
+    /// by the extension bitmap instead of an OPTIONAL flag
+    ExtensionAddition,
+}
+
 #[derive(Default)]
 pub struct UperWriter {
     buffer: BitBuffer,
-    scope: Option<Range<usize>>,
+    scope: Option<WriteScope>,
 }
 
 impl UperWriter {
@@ -30,23 +46,72 @@ impl UperWriter {
         UperReader::from_bits(bytes, bits)
     }
 
+    /// Calls the closure within the given scope and returns its result together with the
+    /// scope, which might have been updated by the closure
     #[inline]
-    pub fn scope_pushed<R, F: Fn(&mut Self) -> R>(&mut self, scope: Range<usize>, f: F) -> R {
+    fn scope_pushed<R, F: Fn(&mut Self) -> R>(
+        &mut self,
+        scope: WriteScope,
+        f: F,
+    ) -> (R, WriteScope) {
         let original = core::mem::replace(&mut self.scope, Some(scope));
         let result = f(self);
         let scope = core::mem::replace(&mut self.scope, original);
         let scope = scope.unwrap(); // save because this is the original from above
-        debug_assert_eq!(scope.start, scope.end);
-        result
+        if let WriteScope::OptBitField(range) | WriteScope::ExtensibleSequence(range, _) = &scope {
+            debug_assert_eq!(range.start, range.end);
+        }
+        (result, scope)
     }
 
     #[inline]
-    pub fn scope_stashed<R, F: Fn(&mut Self) -> R>(&mut self, f: F) -> R {
+    fn scope_stashed<R, F: Fn(&mut Self) -> R>(&mut self, f: F) -> R {
         let scope = self.scope.take();
         let result = f(self);
         self.scope = scope;
         result
     }
+
+    /// Sets the extension bit and writes the extension bitmap followed by the open type
+    /// encoding of each present extension addition, if any extension addition is present
+    fn write_extension_additions(
+        &mut self,
+        extension_bit: usize,
+        additions: Vec<Option<Vec<u8>>>,
+    ) -> Result<(), UperError> {
+        if additions.iter().any(Option::is_some) {
+            self.buffer
+                .with_write_position_at(extension_bit, |b| b.write_bit(true))?;
+            self.buffer.write_normally_small_length(additions.len())?;
+            for addition in &additions {
+                self.buffer.write_bit(addition.is_some())?;
+            }
+            for addition in additions.iter().flatten() {
+                self.buffer.write_length_determinant(addition.len())?;
+                self.buffer.write_bit_string_till_end(addition, 0)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+        value: &str,
+    ) -> Result<(), UperError> {
+        let len = value.chars().count();
+        check_size(len, min, max, extensible)?;
+        self.buffer
+            .write_length(len, bit_buffer_range(min, max), extensible)?;
+        self.buffer.write_known_multiplier_characters(
+            &PermittedAlphabet::new(charset, permitted_alphabet, false),
+            value,
+        )
+    }
 }
 
 impl Writer for UperWriter {
@@ -59,10 +124,13 @@ impl Writer for UperWriter {
     ) -> Result<(), Self::Error> {
         // In UPER the values for all OPTIONAL flags are written before any field
         // value is written. This remembers their position, so a later call of `write_opt`
-        // can write them to the buffer
+        // can write them to the buffer. For extensible SEQUENCEs, the extension bit precedes
+        // the OPTIONAL flags and is set once a present extension addition has been written.
         let write_pos = self.buffer.write_position;
-        let range = write_pos..write_pos + C::OPTIONAL_FIELDS; // TODO
-        for _ in 0..C::OPTIONAL_FIELDS {
+        let extensible = C::EXTENDED_AFTER_FIELD.is_some();
+        let range = write_pos + usize::from(extensible)
+            ..write_pos + usize::from(extensible) + C::STD_OPTIONAL_FIELDS;
+        for _ in write_pos..range.end {
             // insert in reverse order so that a simple pop() in `write_opt` retrieves
             // the relevant position
             if let Err(e) = self.buffer.write_bit(false) {
@@ -71,7 +139,19 @@ impl Writer for UperWriter {
             }
         }
 
-        self.scope_pushed(range, f)
+        let scope = if extensible {
+            WriteScope::ExtensibleSequence(range, Vec::new())
+        } else {
+            WriteScope::OptBitField(range)
+        };
+
+        match self.scope_pushed(scope, f) {
+            (Err(e), _) => Err(e),
+            (Ok(()), WriteScope::ExtensibleSequence(_, additions)) => {
+                self.write_extension_additions(write_pos, additions)
+            }
+            (Ok(()), _) => Ok(()),
+        }
     }
 
     #[inline]
@@ -79,13 +159,10 @@ impl Writer for UperWriter {
         &mut self,
         slice: &[T::Type],
     ) -> Result<(), Self::Error> {
-        let min = C::MIN.unwrap_or(0);
-        let max = C::MAX.unwrap_or(std::usize::MAX);
-        if slice.len() < min || slice.len() > max {
-            return Err(UperError::SizeNotInRange(slice.len(), min, max));
-        }
+        check_size(slice.len(), C::MIN, C::MAX, C::EXTENSIBLE)?;
         self.scope_stashed(|w| {
-            w.buffer.write_length_determinant(slice.len() - min)?; // TODO untested for MIN != 0
+            w.buffer
+                .write_length(slice.len(), bit_buffer_range(C::MIN, C::MAX), C::EXTENSIBLE)?;
             for value in slice {
                 T::write_value(w, value)?;
             }
@@ -143,18 +220,23 @@ impl Writer for UperWriter {
         &mut self,
         value: Option<&<T as WritableType>::Type>,
     ) -> Result<(), Self::Error> {
-        if let Some(range) = &mut self.scope {
-            if range.start < range.end {
-                let result = self
-                    .buffer
-                    .with_write_position_at(range.start, |b| b.write_bit(value.is_some()));
-                range.start += 1;
-                result?;
-            } else {
-                return Err(UperError::OptFlagsExhausted);
+        match &mut self.scope {
+            Some(WriteScope::ExtensionAddition) => {
+                // the presence is already signalled by the extension bitmap
             }
-        } else {
-            self.buffer.write_bit(value.is_some())?;
+            Some(WriteScope::OptBitField(range))
+            | Some(WriteScope::ExtensibleSequence(range, _)) => {
+                if range.start < range.end {
+                    let result = self
+                        .buffer
+                        .with_write_position_at(range.start, |b| b.write_bit(value.is_some()));
+                    range.start += 1;
+                    result?;
+                } else {
+                    return Err(UperError::OptFlagsExhausted);
+                }
+            }
+            None => self.buffer.write_bit(value.is_some())?,
         }
         if let Some(value) = value {
             self.scope_stashed(|w| T::write_value(w, value))
@@ -163,6 +245,72 @@ impl Writer for UperWriter {
         }
     }
 
+    #[inline]
+    fn write_extension_addition<F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        present: bool,
+        group_optional_fields: Option<usize>,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        let addition = if present {
+            let mut writer = UperWriter::default();
+            let scope = if let Some(optional_fields) = group_optional_fields {
+                // an extension addition group is encoded like a SEQUENCE of its fields
+                for _ in 0..optional_fields {
+                    writer.buffer.write_bit(false)?;
+                }
+                WriteScope::OptBitField(0..optional_fields)
+            } else {
+                WriteScope::ExtensionAddition
+            };
+            writer.scope_pushed(scope, f).0?;
+            let mut bytes = writer.into_bytes_vec();
+            if bytes.is_empty() {
+                // the open type consists of at least one octet
+                bytes.push(0x00);
+            }
+            Some(bytes)
+        } else {
+            None
+        };
+
+        if let Some(WriteScope::ExtensibleSequence(_, additions)) = &mut self.scope {
+            additions.push(addition);
+            Ok(())
+        } else {
+            Err(UperError::UnsupportedOperation(
+                "Extension additions can only be written within an extensible SEQUENCE".into(),
+            ))
+        }
+    }
+
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        key: i64,
+        value: &C,
+    ) -> Result<(), Self::Error> {
+        if !value.is_identified_by(key) {
+            return Err(UperError::OpenTypeKeyMismatch(key, C::NAME));
+        }
+        self.scope_stashed(|w| {
+            let mut bytes = match value.unknown_content() {
+                Some(content) => content.to_vec(),
+                None => {
+                    let mut writer = UperWriter::default();
+                    value.write_content(&mut writer)?;
+                    writer.into_bytes_vec()
+                }
+            };
+            // an empty encoding is replaced by a single zero octet (X.691-201508 11.2.1)
+            if bytes.is_empty() {
+                bytes.push(0);
+            }
+            w.buffer.write_length_determinant(bytes.len())?;
+            w.buffer.write_bit_string_till_end(&bytes, 0)
+        })
+    }
+
     #[inline]
     fn write_int(&mut self, value: i64, range: (i64, i64)) -> Result<(), Self::Error> {
         self.buffer.write_int(value, range)
@@ -173,6 +321,25 @@ impl Writer for UperWriter {
         self.buffer.write_int_max(value)
     }
 
+    #[inline]
+    fn write_int_semi_constrained(
+        &mut self,
+        value: i64,
+        lower_bound: i64,
+    ) -> Result<(), Self::Error> {
+        self.buffer.write_int_semi_constrained(value, lower_bound)
+    }
+
+    #[inline]
+    fn write_int_unconstrained(&mut self, value: i64) -> Result<(), Self::Error> {
+        self.buffer.write_int_unconstrained(value)
+    }
+
+    #[inline]
+    fn write_int_extensible(&mut self, value: i64, range: (i64, i64)) -> Result<(), Self::Error> {
+        self.buffer.write_int_extensible(value, range)
+    }
+
     #[inline]
     fn write_utf8string<C: utf8string::Constraint>(
         &mut self,
@@ -181,24 +348,166 @@ impl Writer for UperWriter {
         self.buffer.write_utf8_string(value)
     }
 
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
     #[inline]
     fn write_octet_string<C: octetstring::Constraint>(
         &mut self,
         value: &[u8],
     ) -> Result<(), Self::Error> {
+        check_size(value.len(), C::MIN, C::MAX, C::EXTENSIBLE)?;
         self.buffer
-            .write_octet_string(value, bit_buffer_range::<C>())
+            .write_length(value.len(), bit_buffer_range(C::MIN, C::MAX), C::EXTENSIBLE)?;
+        self.buffer.write_bit_string_till_end(value, 0)
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        check_size(bit_len as usize, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        self.buffer.write_length(
+            bit_len as usize,
+            bit_buffer_range(C::MIN, C::MAX),
+            C::EXTENSIBLE,
+        )?;
+        self.buffer.write_bit_string(value, 0, bit_len as usize)
     }
 
     #[inline]
     fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
         self.buffer.write_bit(value)
     }
+
+    #[inline]
+    fn write_null(&mut self) -> Result<(), Self::Error> {
+        // X.691-201508 18
+        Ok(())
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        // X.691-201508 15.2
+        self.buffer
+            .write_octet_string(&real::to_content(value), None)
+    }
+
+    #[inline]
+    fn write_oid(&mut self, value: &ObjectIdentifier) -> Result<(), Self::Error> {
+        // X.691-201508 24
+        self.buffer.write_octet_string(&value.to_content(), None)
+    }
+}
+
+enum ReadScope {
+    /// The position of the next OPTIONAL flag and the end of the preamble
+    OptBitField(Range<usize>),
+    /// Like `OptBitField` but for an extensible SEQUENCE, additionally with the value of
+    /// the extension bit and - once the extension additions are reached - the position
+    /// of the next and the end of the extension bitmap
+    ExtensibleSequence {
+        opt_bit_field: Range<usize>,
+        extended: bool,
+        bitmap: Option<Range<usize>>,
+    },
+    /// Within an extension addition of a single field, its presence is given
+    /// by the extension bitmap instead of an OPTIONAL flag
+    ExtensionAddition(bool),
 }
 
 pub struct UperReader {
     buffer: BitBuffer,
-    scope: Option<Range<usize>>,
+    scope: Option<ReadScope>,
 }
 
 impl UperReader {
@@ -214,22 +523,73 @@ impl UperReader {
         self.buffer.write_position - self.buffer.read_position
     }
 
+    /// Calls the closure within the given scope and returns its result together with the
+    /// scope, which might have been updated by the closure
     #[inline]
-    pub fn scope_pushed<R, F: Fn(&mut Self) -> R>(&mut self, scope: Range<usize>, f: F) -> R {
+    fn scope_pushed<R, F: Fn(&mut Self) -> R>(&mut self, scope: ReadScope, f: F) -> (R, ReadScope) {
         let original = core::mem::replace(&mut self.scope, Some(scope));
         let result = f(self);
         let scope = core::mem::replace(&mut self.scope, original);
-        debug_assert_eq!(scope.clone().unwrap().start, scope.unwrap().end); // save because this is the original from above
-        result
+        let scope = scope.unwrap(); // save because this is the original from above
+        if let ReadScope::OptBitField(range)
+        | ReadScope::ExtensibleSequence {
+            opt_bit_field: range,
+            ..
+        } = &scope
+        {
+            debug_assert_eq!(range.start, range.end);
+        }
+        (result, scope)
     }
 
     #[inline]
-    pub fn scope_stashed<R, F: Fn(&mut Self) -> R>(&mut self, f: F) -> R {
+    fn scope_stashed<R, F: Fn(&mut Self) -> R>(&mut self, f: F) -> R {
         let scope = self.scope.take();
         let result = f(self);
         self.scope = scope;
         result
     }
+
+    /// Skips the OPTIONAL flags and returns their position
+    fn read_opt_bit_field(&mut self, optional_fields: usize) -> Result<Range<usize>, UperError> {
+        let range = self.buffer.read_position..self.buffer.read_position + optional_fields;
+        if self.buffer.bit_len() < range.end {
+            return Err(UperError::EndOfStream);
+        }
+        self.buffer.read_position = range.end;
+        Ok(range)
+    }
+
+    /// Skips the extension bitmap and returns its position
+    fn read_extension_bitmap(&mut self) -> Result<Range<usize>, UperError> {
+        let len = self.buffer.read_normally_small_length()?;
+        self.read_opt_bit_field(len)
+    }
+
+    fn read_open_type_bytes(&mut self) -> Result<Vec<u8>, UperError> {
+        let byte_len = self.buffer.read_length_determinant()?;
+        let mut bytes = vec![0u8; byte_len];
+        self.buffer.read_bit_string_till_end(&mut bytes[..], 0)?;
+        Ok(bytes)
+    }
+
+    fn read_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+    ) -> Result<String, UperError> {
+        let len = self
+            .buffer
+            .read_length(bit_buffer_range(min, max), extensible)?;
+        check_size(len, min, max, extensible)?;
+        self.buffer.read_known_multiplier_characters(
+            &PermittedAlphabet::new(charset, permitted_alphabet, false),
+            len,
+        )
+    }
 }
 
 impl Reader for UperReader {
@@ -246,33 +606,61 @@ impl Reader for UperReader {
     ) -> Result<S, Self::Error> {
         // In UPER the values for all OPTIONAL flags are written before any field
         // value is written. This remembers their position, so a later call of `read_opt`
-        // can retrieve them from the buffer
-        let range = self.buffer.read_position..self.buffer.read_position + C::OPTIONAL_FIELDS;
-        if self.buffer.bit_len() < range.end {
-            return Err(UperError::EndOfStream);
+        // can retrieve them from the buffer. For extensible SEQUENCEs, the extension bit
+        // precedes the OPTIONAL flags.
+        let scope = if C::EXTENDED_AFTER_FIELD.is_some() {
+            let extended = self.buffer.read_bit()?;
+            ReadScope::ExtensibleSequence {
+                opt_bit_field: self.read_opt_bit_field(C::STD_OPTIONAL_FIELDS)?,
+                extended,
+                bitmap: None,
+            }
+        } else {
+            ReadScope::OptBitField(self.read_opt_bit_field(C::STD_OPTIONAL_FIELDS)?)
+        };
+
+        let (result, scope) = self.scope_pushed(scope, f);
+        let result = result?;
+
+        if let ReadScope::ExtensibleSequence {
+            extended: true,
+            bitmap,
+            ..
+        } = scope
+        {
+            // skip all extension additions unknown to this version of the definition
+            let bitmap = match bitmap {
+                Some(bitmap) => bitmap,
+                None => self.read_extension_bitmap()?,
+            };
+            for position in bitmap {
+                if self
+                    .buffer
+                    .with_read_position_at(position, |b| b.read_bit())?
+                {
+                    self.read_open_type_bytes()?;
+                }
+            }
         }
-        self.buffer.read_position = range.end; // skip optional
-        self.scope_pushed(range, f)
+
+        Ok(result)
     }
 
     #[inline]
     fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
         &mut self,
     ) -> Result<Vec<T::Type>, Self::Error> {
-        let min = C::MIN.unwrap_or(0);
-        let max = C::MAX.unwrap_or(std::usize::MAX);
-        let len = self.buffer.read_length_determinant()? + min; // TODO untested for MIN != 0
-        if len > max {
-            Err(UperError::SizeNotInRange(len, min, max))
-        } else {
-            self.scope_stashed(|w| {
-                let mut vec = Vec::with_capacity(len);
-                for _ in 0..len {
-                    vec.push(T::read_value(w)?);
-                }
-                Ok(vec)
-            })
-        }
+        let len = self
+            .buffer
+            .read_length(bit_buffer_range(C::MIN, C::MAX), C::EXTENSIBLE)?;
+        check_size(len, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        self.scope_stashed(|w| {
+            let mut vec = Vec::with_capacity(len);
+            for _ in 0..len {
+                vec.push(T::read_value(w)?);
+            }
+            Ok(vec)
+        })
     }
 
     #[inline]
@@ -302,10 +690,9 @@ impl Reader for UperReader {
                     .map(|v| v as usize)?;
                 if index >= C::STD_VARIANT_COUNT {
                     // TODO performance
-                    let byte_len = r.buffer.read_length_determinant()?;
-                    let mut bytes = vec![0u8; byte_len];
-                    r.buffer.read_bit_string_till_end(&mut bytes[..], 0)?;
-                    let mut reader = UperReader::from_bits(bytes, byte_len * 8);
+                    let bytes = r.read_open_type_bytes()?;
+                    let bits = bytes.len() * 8;
+                    let mut reader = UperReader::from_bits(bytes, bits);
                     Ok((index, C::read_content(index, &mut reader)?))
                 } else {
                     Ok((index, C::read_content(index, r)?))
@@ -326,18 +713,24 @@ impl Reader for UperReader {
     fn read_opt<T: ReadableType>(
         &mut self,
     ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
-        let value = if let Some(range) = &mut self.scope {
-            if range.start < range.end {
-                let result = self
-                    .buffer
-                    .with_read_position_at(range.start, |b| b.read_bit());
-                range.start += 1;
-                result?
-            } else {
-                return Err(UperError::OptFlagsExhausted);
+        let value = match &mut self.scope {
+            Some(ReadScope::ExtensionAddition(present)) => *present,
+            Some(ReadScope::OptBitField(range))
+            | Some(ReadScope::ExtensibleSequence {
+                opt_bit_field: range,
+                ..
+            }) => {
+                if range.start < range.end {
+                    let result = self
+                        .buffer
+                        .with_read_position_at(range.start, |b| b.read_bit());
+                    range.start += 1;
+                    result?
+                } else {
+                    return Err(UperError::OptFlagsExhausted);
+                }
             }
-        } else {
-            self.buffer.read_bit()?
+            None => self.buffer.read_bit()?,
         };
         if value {
             self.scope_stashed(T::read_value).map(Some)
@@ -346,6 +739,77 @@ impl Reader for UperReader {
         }
     }
 
+    #[inline]
+    fn read_extension_addition<T, F: Fn(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        group_optional_fields: Option<usize>,
+        f: F,
+    ) -> Result<T, Self::Error> {
+        let (extended, bitmap) = match &self.scope {
+            Some(ReadScope::ExtensibleSequence {
+                extended, bitmap, ..
+            }) => (*extended, bitmap.clone()),
+            _ => {
+                return Err(UperError::UnsupportedOperation(
+                    "Extension additions can only be read within an extensible SEQUENCE".into(),
+                ))
+            }
+        };
+
+        // the extension bitmap follows the last field of the extension root
+        let mut bitmap = match bitmap {
+            Some(bitmap) => bitmap,
+            None if extended => self.read_extension_bitmap()?,
+            None => 0..0,
+        };
+
+        // extension additions unknown to the sender are absent
+        let present = if bitmap.start < bitmap.end {
+            let present = self
+                .buffer
+                .with_read_position_at(bitmap.start, |b| b.read_bit())?;
+            bitmap.start += 1;
+            present
+        } else {
+            false
+        };
+
+        if let Some(ReadScope::ExtensibleSequence { bitmap: scope, .. }) = &mut self.scope {
+            *scope = Some(bitmap);
+        }
+
+        if present {
+            let bytes = self.read_open_type_bytes()?;
+            let bits = bytes.len() * 8;
+            let mut reader = UperReader::from_bits(bytes, bits);
+            let scope = if let Some(optional_fields) = group_optional_fields {
+                // an extension addition group is encoded like a SEQUENCE of its fields
+                ReadScope::OptBitField(reader.read_opt_bit_field(optional_fields)?)
+            } else {
+                ReadScope::ExtensionAddition(true)
+            };
+            reader.scope_pushed(scope, f).0
+        } else {
+            // OPTIONAL fields are read as absent, while reading any other field fails
+            let mut reader = UperReader::from_bits(Vec::new(), 0);
+            reader.scope = Some(ReadScope::ExtensionAddition(false));
+            f(&mut reader)
+        }
+    }
+
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self, key: i64) -> Result<C, Self::Error> {
+        self.scope_stashed(|r| {
+            let bytes = r.read_open_type_bytes()?;
+            let bits = bytes.len() * 8;
+            let mut reader = UperReader::from_bits(bytes, bits);
+            match C::read_content(key, &mut reader)? {
+                Some(value) => Ok(value),
+                None => Ok(C::from_unknown(reader.buffer.into())),
+            }
+        })
+    }
+
     #[inline]
     fn read_int(&mut self, range: (i64, i64)) -> Result<i64, Self::Error> {
         self.buffer.read_int(range)
@@ -356,29 +820,170 @@ impl Reader for UperReader {
         self.buffer.read_int_max()
     }
 
+    #[inline]
+    fn read_int_semi_constrained(&mut self, lower_bound: i64) -> Result<i64, Self::Error> {
+        self.buffer.read_int_semi_constrained(lower_bound)
+    }
+
+    #[inline]
+    fn read_int_unconstrained(&mut self) -> Result<i64, Self::Error> {
+        self.buffer.read_int_unconstrained()
+    }
+
+    #[inline]
+    fn read_int_extensible(&mut self, range: (i64, i64)) -> Result<i64, Self::Error> {
+        self.buffer.read_int_extensible(range)
+    }
+
     #[inline]
     fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
         self.buffer.read_utf8_string()
     }
 
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
     #[inline]
     fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
-        self.buffer.read_octet_string(bit_buffer_range::<C>())
+        let len = self
+            .buffer
+            .read_length(bit_buffer_range(C::MIN, C::MAX), C::EXTENSIBLE)?;
+        check_size(len, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let mut vec = vec![0_u8; len];
+        self.buffer.read_bit_string_till_end(&mut vec[..], 0)?;
+        Ok(vec)
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let bit_len = self
+            .buffer
+            .read_length(bit_buffer_range(C::MIN, C::MAX), C::EXTENSIBLE)?;
+        check_size(bit_len, C::MIN, C::MAX, C::EXTENSIBLE)?;
+        let mut bytes = vec![0_u8; bit_len.div_ceil(BYTE_LEN)];
+        self.buffer.read_bit_string(&mut bytes[..], 0, bit_len)?;
+        Ok((bytes, bit_len as u64))
     }
 
     #[inline]
     fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
         self.buffer.read_bit()
     }
+
+    #[inline]
+    fn read_null(&mut self) -> Result<Null, Self::Error> {
+        Ok(Null)
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        real::from_content(&self.buffer.read_octet_string(None)?).ok_or(UperError::InvalidReal)
+    }
+
+    #[inline]
+    fn read_oid(&mut self) -> Result<ObjectIdentifier, Self::Error> {
+        ObjectIdentifier::from_content(&self.buffer.read_octet_string(None)?)
+            .ok_or(UperError::InvalidObjectIdentifier)
+    }
+
+    #[inline]
+    fn read_time<T: time::Time>(&mut self) -> Result<T, Self::Error> {
+        let string = self.read_visible_string::<visiblestring::NoConstraint>()?;
+        T::parse(&string).ok_or(UperError::InvalidTime(string))
+    }
+}
+
+#[inline]
+fn bit_buffer_range(min: Option<usize>, max: Option<usize>) -> Option<(i64, i64)> {
+    match (min, max) {
+        // X.691-201508 11.9.4.2, without an upper bound below 64K the length is not constrained
+        (_, None) => None,
+        (_, Some(max)) if max >= UPER_LENGTH_CONSTRAINED_LIMIT => None,
+        (min, Some(max)) => Some((min.unwrap_or(0) as i64, max as i64)),
+    }
 }
 
+/// Checks the length against a non-extensible `SIZE` constraint, which is not encoded in the
+/// length determinant once the upper bound reaches 64K
 #[inline]
-fn bit_buffer_range<C: octetstring::Constraint>() -> Option<(i64, i64)> {
-    match (C::MIN, C::MAX) {
-        (None, None) => None,
-        (min, max) => Some((
-            min.unwrap_or(0) as i64,
-            max.unwrap_or(std::i64::MAX as usize) as i64, // TODO never verified!
-        )),
+fn check_size(
+    len: usize,
+    min: Option<usize>,
+    max: Option<usize>,
+    extensible: bool,
+) -> Result<(), UperError> {
+    let min = min.unwrap_or(0);
+    let max = max.unwrap_or(usize::MAX);
+    if !extensible && (len < min || len > max) {
+        Err(UperError::SizeNotInRange(len, min, max))
+    } else {
+        Ok(())
     }
 }
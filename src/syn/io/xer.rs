@@ -0,0 +1,975 @@
+use crate::io::xer::Error as XerError;
+use crate::io::xer::{escape, Element, Node};
+use crate::prelude::*;
+use crate::syn::charset::Charset;
+use std::collections::VecDeque;
+
+/// The names of the built-in types, X.680-201508 Table 5
+const XML_BIT_STRING: &str = "BIT_STRING";
+const XML_BOOLEAN: &str = "BOOLEAN";
+const XML_INTEGER: &str = "INTEGER";
+const XML_NULL: &str = "NULL";
+const XML_OBJECT_IDENTIFIER: &str = "OBJECT_IDENTIFIER";
+const XML_OCTET_STRING: &str = "OCTET_STRING";
+const XML_REAL: &str = "REAL";
+const XML_UTF8_STRING: &str = "UTF8String";
+const XML_SEQUENCE_OF: &str = "SEQUENCE_OF";
+
+/// Determines the name of the element that encloses the values written next
+enum WriteScope {
+    Sequence {
+        names: &'static [&'static str],
+        index: usize,
+    },
+    SequenceOf,
+    Choice(Option<&'static str>),
+}
+
+/// Encodes values with the BASIC variant of the XML Encoding Rules (X.693). Fields of a
+/// SEQUENCE and alternatives of a CHOICE are enclosed in elements named after them, the
+/// outermost value in an element named after its type. BOOLEAN and ENUMERATED values are
+/// written as empty element named after the value. Tags are not encoded.
+#[derive(Default)]
+pub struct XerWriter {
+    xml: String,
+    scopes: Vec<WriteScope>,
+}
+
+impl XerWriter {
+    pub fn xml(&self) -> &str {
+        &self.xml
+    }
+
+    pub fn into_xml(self) -> String {
+        self.xml
+    }
+
+    pub fn into_reader(self) -> Result<XerReader, XerError> {
+        XerReader::from_xml(&self.xml)
+    }
+
+    /// The name of the element that encloses the next value, which is the type name
+    /// unless the value is a field or an alternative. Within a SEQUENCE OF, values that
+    /// are written as list of elements (`value_list`) are not enclosed at all,
+    /// X.693-200811 9.3.3 and X.680-201508 26.3
+    fn element_name(&mut self, type_name: &'static str, value_list: bool) -> Option<&'static str> {
+        match self.scopes.last_mut() {
+            Some(WriteScope::Sequence { names, index }) => {
+                let name = names.get(*index).copied();
+                *index += 1;
+                name
+            }
+            Some(WriteScope::Choice(name)) => name.take(),
+            Some(WriteScope::SequenceOf) if value_list => None,
+            Some(WriteScope::SequenceOf) | None => Some(type_name),
+        }
+    }
+
+    fn write_element<F: FnOnce(&mut Self) -> Result<(), XerError>>(
+        &mut self,
+        name: Option<&str>,
+        f: F,
+    ) -> Result<(), XerError> {
+        if let Some(name) = name {
+            self.xml.push('<');
+            self.xml.push_str(name);
+            self.xml.push('>');
+            f(self)?;
+            self.xml.push_str("</");
+            self.xml.push_str(name);
+            self.xml.push('>');
+            Ok(())
+        } else {
+            f(self)
+        }
+    }
+
+    fn write_text(&mut self, type_name: &'static str, text: &str) -> Result<(), XerError> {
+        let name = self.element_name(type_name, false);
+        self.write_element(name, |w| {
+            w.xml.push_str(&escape(text));
+            Ok(())
+        })
+    }
+
+    fn write_empty_element(
+        &mut self,
+        type_name: &'static str,
+        value: &str,
+    ) -> Result<(), XerError> {
+        let name = self.element_name(type_name, true);
+        self.write_element(name, |w| {
+            w.xml.push('<');
+            w.xml.push_str(value);
+            w.xml.push_str("/>");
+            Ok(())
+        })
+    }
+
+    fn write_scoped<F: FnOnce(&mut Self) -> Result<(), XerError>>(
+        &mut self,
+        scope: WriteScope,
+        f: F,
+    ) -> Result<(), XerError> {
+        self.scopes.push(scope);
+        let result = f(self);
+        self.scopes.pop();
+        result
+    }
+
+    /// The size is in characters
+    fn write_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+        value: &str,
+    ) -> Result<(), XerError> {
+        let len = value.chars().count();
+        let min = min.unwrap_or(0);
+        let max = max.unwrap_or(usize::MAX);
+        if !extensible && (len < min || len > max) {
+            return Err(XerError::SizeNotInRange(len, min, max));
+        }
+        if let Some(c) = charset.find_invalid(value, permitted_alphabet) {
+            return Err(XerError::InvalidCharacter(c as u32));
+        }
+        self.write_text(charset.name(), value)
+    }
+}
+
+impl Writer for XerWriter {
+    type Error = XerError;
+
+    #[inline]
+    fn write_sequence<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        let name = self.element_name(C::NAME, false);
+        self.write_element(name, |w| {
+            let scope = WriteScope::Sequence {
+                names: C::FIELD_NAMES,
+                index: 0,
+            };
+            w.write_scoped(scope, f)
+        })
+    }
+
+    #[inline]
+    fn write_sequence_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && (slice.len() < min || slice.len() > max) {
+            return Err(XerError::SizeNotInRange(slice.len(), min, max));
+        }
+        let name = self.element_name(XML_SEQUENCE_OF, false);
+        self.write_element(name, |w| {
+            w.write_scoped(WriteScope::SequenceOf, |w| {
+                for value in slice {
+                    T::write_value(w, value)?;
+                }
+                Ok(())
+            })
+        })
+    }
+
+    #[inline]
+    fn write_enumerated<C: enumerated::Constraint>(
+        &mut self,
+        enumerated: &C,
+    ) -> Result<(), Self::Error> {
+        let index = enumerated.to_choice_index();
+        let value = C::VARIANT_NAMES
+            .get(index)
+            .ok_or_else(|| XerError::UnknownVariant(index.to_string()))?;
+        self.write_empty_element(C::NAME, value)
+    }
+
+    #[inline]
+    fn write_choice<C: choice::Constraint>(&mut self, choice: &C) -> Result<(), Self::Error> {
+        let index = choice.to_choice_index();
+        let variant = C::VARIANT_NAMES
+            .get(index)
+            .copied()
+            .ok_or_else(|| XerError::UnknownVariant(index.to_string()))?;
+        let name = self.element_name(C::NAME, true);
+        self.write_element(name, |w| {
+            w.write_scoped(WriteScope::Choice(Some(variant)), |w| {
+                choice.write_content(w)
+            })
+        })
+    }
+
+    #[inline]
+    fn write_opt<T: WritableType>(
+        &mut self,
+        value: Option<&<T as WritableType>::Type>,
+    ) -> Result<(), Self::Error> {
+        match (value, self.scopes.last_mut()) {
+            (Some(value), _) => T::write_value(self, value),
+            // an absent field has no element at all
+            (None, Some(WriteScope::Sequence { index, .. })) => {
+                *index += 1;
+                Ok(())
+            }
+            (None, _) => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn write_int(&mut self, value: i64, (min, max): (i64, i64)) -> Result<(), Self::Error> {
+        if value < min || value > max {
+            return Err(XerError::ValueNotInRange(value, min, max));
+        }
+        self.write_text(XML_INTEGER, &value.to_string())
+    }
+
+    #[inline]
+    fn write_int_max(&mut self, value: u64) -> Result<(), Self::Error> {
+        self.write_text(XML_INTEGER, &value.to_string())
+    }
+
+    #[inline]
+    fn write_int_semi_constrained(
+        &mut self,
+        value: i64,
+        lower_bound: i64,
+    ) -> Result<(), Self::Error> {
+        self.write_int(value, (lower_bound, i64::MAX))
+    }
+
+    #[inline]
+    fn write_int_unconstrained(&mut self, value: i64) -> Result<(), Self::Error> {
+        self.write_int(value, (i64::MIN, i64::MAX))
+    }
+
+    #[inline]
+    fn write_utf8string<C: utf8string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_text(XML_UTF8_STRING, value)
+    }
+
+    #[inline]
+    fn write_ia5string<C: ia5string::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error> {
+        self.write_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+            value,
+        )
+    }
+
+    #[inline]
+    fn write_octet_string<C: octetstring::Constraint>(
+        &mut self,
+        value: &[u8],
+    ) -> Result<(), Self::Error> {
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && (value.len() < min || value.len() > max) {
+            return Err(XerError::SizeNotInRange(value.len(), min, max));
+        }
+        let hex = value
+            .iter()
+            .map(|b| format!("{:02X}", b))
+            .collect::<String>();
+        self.write_text(XML_OCTET_STRING, &hex)
+    }
+
+    #[inline]
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error> {
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && ((bit_len as usize) < min || bit_len as usize > max) {
+            return Err(XerError::SizeNotInRange(bit_len as usize, min, max));
+        }
+        // X.693-200811 8.3.4: one character per bit
+        let bits = BitVec::from_bytes(value.to_vec(), bit_len);
+        let text = (0..bit_len)
+            .map(|bit| if bits.is_bit_set(bit) { '1' } else { '0' })
+            .collect::<String>();
+        self.write_text(XML_BIT_STRING, &text)
+    }
+
+    #[inline]
+    fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error> {
+        self.write_empty_element(XML_BOOLEAN, if value { "true" } else { "false" })
+    }
+
+    #[inline]
+    fn write_null(&mut self) -> Result<(), Self::Error> {
+        if let Some(name) = self.element_name(XML_NULL, false) {
+            self.xml.push('<');
+            self.xml.push_str(name);
+            self.xml.push_str("/>");
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error> {
+        // X.693-200811 11.3, the special values are empty elements
+        let special = if value.is_nan() {
+            Some("NOT-A-NUMBER")
+        } else if value == f64::INFINITY {
+            Some("PLUS-INFINITY")
+        } else if value == f64::NEG_INFINITY {
+            Some("MINUS-INFINITY")
+        } else {
+            None
+        };
+        match special {
+            Some(special) => {
+                let name = self.element_name(XML_REAL, false);
+                self.write_element(name, |w| {
+                    w.xml.push('<');
+                    w.xml.push_str(special);
+                    w.xml.push_str("/>");
+                    Ok(())
+                })
+            }
+            None if value == 0.0 && value.is_sign_negative() => self.write_text(XML_REAL, "-0"),
+            None => self.write_text(XML_REAL, &format!("{:?}", value)),
+        }
+    }
+
+    #[inline]
+    fn write_oid(&mut self, value: &ObjectIdentifier) -> Result<(), Self::Error> {
+        self.write_text(XML_OBJECT_IDENTIFIER, &value.to_string())
+    }
+
+    #[inline]
+    fn write_time<T: time::Time>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.write_text(T::NAME, &value.to_string())
+    }
+}
+
+/// Provides the content of the elements that enclose the values read next
+enum ReadScope {
+    Sequence {
+        names: &'static [&'static str],
+        index: usize,
+        elements: Vec<Element>,
+    },
+    SequenceOf(VecDeque<Element>),
+    Choice(Option<Element>),
+}
+
+/// Decodes values encoded with the BASIC variant of the XML Encoding Rules (X.693), as
+/// written by the [`XerWriter`]. The name of the outermost element is not verified and
+/// whitespace between elements is ignored.
+pub struct XerReader {
+    scopes: Vec<ReadScope>,
+    root: Option<Element>,
+}
+
+impl XerReader {
+    pub fn from_xml(xml: &str) -> Result<Self, XerError> {
+        Element::parse(xml).map(Self::from_element)
+    }
+
+    pub fn from_element(element: Element) -> Self {
+        Self {
+            scopes: Vec::default(),
+            root: Some(element),
+        }
+    }
+
+    /// The content of the element that encloses the next value. Values that are not
+    /// enclosed within a SEQUENCE OF (`value_list`) are their own content.
+    fn take_content(&mut self, value_list: bool) -> Result<Vec<Node>, XerError> {
+        match self.scopes.last_mut() {
+            Some(ReadScope::Sequence {
+                names,
+                index,
+                elements,
+            }) => {
+                let name = *names.get(*index).ok_or(XerError::EndOfStream)?;
+                *index += 1;
+                elements
+                    .iter()
+                    .position(|element| element.name == name)
+                    .map(|position| elements.remove(position).children)
+                    .ok_or(XerError::MissingElement(name))
+            }
+            Some(ReadScope::SequenceOf(elements)) => {
+                let element = elements.pop_front().ok_or(XerError::EndOfStream)?;
+                if value_list {
+                    Ok(vec![Node::Element(element)])
+                } else {
+                    Ok(element.children)
+                }
+            }
+            Some(ReadScope::Choice(element)) => element
+                .take()
+                .map(|element| element.children)
+                .ok_or(XerError::EndOfStream),
+            None => self
+                .root
+                .take()
+                .map(|element| element.children)
+                .ok_or(XerError::EndOfStream),
+        }
+    }
+
+    /// Whether the next value is present. Within a sequence an absent field is skipped.
+    fn next_is_present(&mut self) -> bool {
+        match self.scopes.last_mut() {
+            Some(ReadScope::Sequence {
+                names,
+                index,
+                elements,
+            }) => {
+                let present = names
+                    .get(*index)
+                    .map(|name| elements.iter().any(|element| element.name == *name))
+                    .unwrap_or(false);
+                if !present {
+                    *index += 1;
+                }
+                present
+            }
+            Some(ReadScope::SequenceOf(elements)) => !elements.is_empty(),
+            Some(ReadScope::Choice(element)) => element.is_some(),
+            None => self.root.is_some(),
+        }
+    }
+
+    fn read_scoped<T, F: FnOnce(&mut Self) -> Result<T, XerError>>(
+        &mut self,
+        scope: ReadScope,
+        f: F,
+    ) -> Result<T, XerError> {
+        self.scopes.push(scope);
+        let result = f(self);
+        self.scopes.pop();
+        result
+    }
+
+    fn take_elements(&mut self, value_list: bool) -> Result<Vec<Element>, XerError> {
+        self.take_content(value_list)?
+            .into_iter()
+            .filter_map(|node| match node {
+                Node::Element(element) => Some(Ok(element)),
+                Node::Text(text) if text.trim().is_empty() => None,
+                Node::Text(_) => Some(Err(XerError::ExpectedText)),
+            })
+            .collect()
+    }
+
+    fn take_text(&mut self) -> Result<String, XerError> {
+        let mut text = String::new();
+        for node in self.take_content(false)? {
+            match node {
+                Node::Text(value) => text.push_str(&value),
+                Node::Element(element) => return Err(XerError::UnexpectedElement(element.name)),
+            }
+        }
+        Ok(text)
+    }
+
+    /// The name of the single empty element that represents the next value
+    fn take_empty_element(&mut self) -> Result<String, XerError> {
+        let mut elements = self.take_elements(true)?;
+        if elements.len() != 1 {
+            return Err(XerError::EndOfStream);
+        }
+        let element = elements.remove(0);
+        if element.children.is_empty() {
+            Ok(element.name)
+        } else {
+            Err(XerError::UnexpectedElement(element.name))
+        }
+    }
+
+    /// See [`XerWriter::write_known_multiplier_string`]
+    fn read_known_multiplier_string(
+        &mut self,
+        charset: Charset,
+        permitted_alphabet: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+        extensible: bool,
+    ) -> Result<String, XerError> {
+        let value = self.take_text()?;
+        if let Some(c) = charset.find_invalid(&value, permitted_alphabet) {
+            return Err(XerError::InvalidCharacter(c as u32));
+        }
+        let len = value.chars().count();
+        let min = min.unwrap_or(0);
+        let max = max.unwrap_or(usize::MAX);
+        if !extensible && (len < min || len > max) {
+            Err(XerError::SizeNotInRange(len, min, max))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl Reader for XerReader {
+    type Error = XerError;
+
+    #[inline]
+    fn read_sequence<
+        C: sequence::Constraint,
+        S: Sized,
+        F: Fn(&mut Self) -> Result<S, Self::Error>,
+    >(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        let elements = self.take_elements(false)?;
+        let scope = ReadScope::Sequence {
+            names: C::FIELD_NAMES,
+            index: 0,
+            elements,
+        };
+        self.read_scoped(scope, f)
+    }
+
+    #[inline]
+    fn read_sequence_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<<T as ReadableType>::Type>, Self::Error> {
+        let elements = self.take_elements(false)?;
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && (elements.len() < min || elements.len() > max) {
+            return Err(XerError::SizeNotInRange(elements.len(), min, max));
+        }
+        let len = elements.len();
+        self.read_scoped(ReadScope::SequenceOf(elements.into()), |r| {
+            let mut vec = Vec::with_capacity(len);
+            for _ in 0..len {
+                vec.push(T::read_value(r)?);
+            }
+            Ok(vec)
+        })
+    }
+
+    #[inline]
+    fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let name = self.take_empty_element()?;
+        C::VARIANT_NAMES
+            .iter()
+            .position(|variant| *variant == name)
+            .and_then(C::from_choice_index)
+            .ok_or(XerError::UnknownVariant(name))
+    }
+
+    #[inline]
+    fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error> {
+        let mut elements = self.take_elements(true)?;
+        if elements.len() != 1 {
+            return Err(XerError::EndOfStream);
+        }
+        let element = elements.remove(0);
+        match C::VARIANT_NAMES
+            .iter()
+            .position(|variant| *variant == element.name)
+        {
+            Some(index) => {
+                let name = element.name.clone();
+                self.read_scoped(ReadScope::Choice(Some(element)), |r| {
+                    C::read_content(index, r)
+                })?
+                .ok_or(XerError::UnknownVariant(name))
+            }
+            None => Err(XerError::UnknownVariant(element.name)),
+        }
+    }
+
+    #[inline]
+    fn read_opt<T: ReadableType>(
+        &mut self,
+    ) -> Result<Option<<T as ReadableType>::Type>, Self::Error> {
+        if self.next_is_present() {
+            T::read_value(self).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    fn read_int(&mut self, (min, max): (i64, i64)) -> Result<i64, Self::Error> {
+        let text = self.take_text()?;
+        let value = text
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| XerError::InvalidNumber(text))?;
+        if value < min || value > max {
+            Err(XerError::ValueNotInRange(value, min, max))
+        } else {
+            Ok(value)
+        }
+    }
+
+    #[inline]
+    fn read_int_max(&mut self) -> Result<u64, Self::Error> {
+        let text = self.take_text()?;
+        text.trim()
+            .parse::<u64>()
+            .map_err(|_| XerError::InvalidNumber(text))
+    }
+
+    #[inline]
+    fn read_int_semi_constrained(&mut self, lower_bound: i64) -> Result<i64, Self::Error> {
+        self.read_int((lower_bound, i64::MAX))
+    }
+
+    #[inline]
+    fn read_int_unconstrained(&mut self) -> Result<i64, Self::Error> {
+        self.read_int((i64::MIN, i64::MAX))
+    }
+
+    #[inline]
+    fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.take_text()
+    }
+
+    #[inline]
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Ia5,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Numeric,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Printable,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Visible,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Bmp,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error> {
+        self.read_known_multiplier_string(
+            Charset::Universal,
+            C::PERMITTED_ALPHABET,
+            C::MIN,
+            C::MAX,
+            C::EXTENSIBLE,
+        )
+    }
+
+    #[inline]
+    fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error> {
+        // X.693-200811 8.3.5: whitespace may appear anywhere within the hex-string
+        let hex = self
+            .take_text()?
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>();
+        if hex.len() % 2 != 0 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(XerError::InvalidHexString);
+        }
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| XerError::InvalidHexString)?;
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && (bytes.len() < min || bytes.len() > max) {
+            Err(XerError::SizeNotInRange(bytes.len(), min, max))
+        } else {
+            Ok(bytes)
+        }
+    }
+
+    #[inline]
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
+        let mut bits = BitVec::default();
+        for c in self.take_text()?.chars().filter(|c| !c.is_whitespace()) {
+            match c {
+                '0' => bits.reset_bit(bits.bit_len()),
+                '1' => bits.set_bit(bits.bit_len()),
+                _ => return Err(XerError::InvalidBinaryString),
+            }
+        }
+        let min = C::MIN.unwrap_or(0);
+        let max = C::MAX.unwrap_or(usize::MAX);
+        if !C::EXTENSIBLE && ((bits.bit_len() as usize) < min || bits.bit_len() as usize > max) {
+            Err(XerError::SizeNotInRange(bits.bit_len() as usize, min, max))
+        } else {
+            Ok(bits.split())
+        }
+    }
+
+    #[inline]
+    fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error> {
+        match &self.take_empty_element()?[..] {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            other => Err(XerError::UnknownVariant(other.to_string())),
+        }
+    }
+
+    #[inline]
+    fn read_null(&mut self) -> Result<Null, Self::Error> {
+        if self.take_text()?.trim().is_empty() {
+            Ok(Null)
+        } else {
+            Err(XerError::UnexpectedElement(XML_NULL.to_string()))
+        }
+    }
+
+    #[inline]
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error> {
+        let mut content = self
+            .take_content(false)?
+            .into_iter()
+            .filter(|node| !matches!(node, Node::Text(text) if text.trim().is_empty()))
+            .collect::<Vec<_>>();
+        match content.pop() {
+            Some(Node::Element(element)) if content.is_empty() => match element.name.as_str() {
+                "PLUS-INFINITY" if element.children.is_empty() => Ok(f64::INFINITY),
+                "MINUS-INFINITY" if element.children.is_empty() => Ok(f64::NEG_INFINITY),
+                "NOT-A-NUMBER" if element.children.is_empty() => Ok(f64::NAN),
+                _ => Err(XerError::UnexpectedElement(element.name)),
+            },
+            Some(Node::Text(text)) if content.is_empty() => text
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| XerError::InvalidNumber(text)),
+            _ => Err(XerError::ExpectedText),
+        }
+    }
+
+    #[inline]
+    fn read_oid(&mut self) -> Result<ObjectIdentifier, Self::Error> {
+        let text = self.take_text()?;
+        ObjectIdentifier::parse(text.trim()).ok_or(XerError::InvalidObjectIdentifier(text))
+    }
+
+    #[inline]
+    fn read_time<T: time::Time>(&mut self) -> Result<T, Self::Error> {
+        let text = self.take_text()?;
+        T::parse(text.trim()).ok_or(XerError::InvalidTime(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_type_names() {
+        let mut writer = XerWriter::default();
+        writer.write_int(-5, (-10, 10)).unwrap();
+        assert_eq!("<INTEGER>-5</INTEGER>", writer.xml());
+
+        let mut writer = XerWriter::default();
+        writer
+            .write_octet_string::<octetstring::NoConstraint>(&[0xCA, 0xFE])
+            .unwrap();
+        assert_eq!("<OCTET_STRING>CAFE</OCTET_STRING>", writer.xml());
+
+        let mut writer = XerWriter::default();
+        writer.write_boolean::<boolean::NoConstraint>(true).unwrap();
+        assert_eq!("<BOOLEAN><true/></BOOLEAN>", writer.xml());
+        assert_eq!(
+            true,
+            writer
+                .into_reader()
+                .unwrap()
+                .read_boolean::<boolean::NoConstraint>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_text_is_escaped() {
+        let mut writer = XerWriter::default();
+        writer
+            .write_utf8string::<utf8string::NoConstraint>("<&>")
+            .unwrap();
+        assert_eq!("<UTF8String>&lt;&amp;&gt;</UTF8String>", writer.xml());
+        assert_eq!(
+            "<&>",
+            writer
+                .into_reader()
+                .unwrap()
+                .read_utf8string::<utf8string::NoConstraint>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_octet_string_with_whitespace() {
+        let mut reader = XerReader::from_xml("<OCTET_STRING> ca fe\n 01 </OCTET_STRING>").unwrap();
+        assert_eq!(
+            vec![0xCA, 0xFE, 0x01],
+            reader
+                .read_octet_string::<octetstring::NoConstraint>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_bit_string_is_binary_text() {
+        let mut writer = XerWriter::default();
+        writer
+            .write_bit_string::<bitstring::NoConstraint>(&[0xA5, 0x80], 10)
+            .unwrap();
+        assert_eq!("<BIT_STRING>1010010110</BIT_STRING>", writer.xml());
+
+        let mut reader = XerReader::from_xml("<BIT_STRING> 10100101\n 10 </BIT_STRING>").unwrap();
+        assert_eq!(
+            (vec![0xA5, 0x80], 10),
+            reader.read_bit_string::<bitstring::NoConstraint>().unwrap()
+        );
+
+        let mut reader = XerReader::from_xml("<BIT_STRING>012</BIT_STRING>").unwrap();
+        assert_eq!(
+            Err(XerError::InvalidBinaryString),
+            reader.read_bit_string::<bitstring::NoConstraint>()
+        );
+    }
+
+    #[test]
+    fn test_int_out_of_range() {
+        let mut reader = XerReader::from_xml("<INTEGER> 5 </INTEGER>").unwrap();
+        assert_eq!(
+            Err(XerError::ValueNotInRange(5, 0, 4)),
+            reader.read_int((0, 4))
+        );
+    }
+}
@@ -1,29 +1,65 @@
 use std::marker::PhantomData;
 
+pub mod bitstring;
+pub mod bmpstring;
 pub mod boolean;
+pub mod boxed;
+pub mod charset;
 pub mod choice;
 pub mod complex;
+pub mod default;
 pub mod enumerated;
+pub mod ia5string;
 pub mod io;
+pub mod null;
 pub mod numbers;
+pub mod numericstring;
+pub mod objectidentifier;
 pub mod octetstring;
+pub mod opentype;
 pub mod optional;
+pub mod printablestring;
+pub mod real;
 pub mod sequence;
 pub mod sequenceof;
+pub mod set;
+pub mod setof;
+pub mod tag;
+pub mod tagged;
+pub mod time;
+pub mod universalstring;
 pub mod utf8string;
+pub mod visiblestring;
 
+pub use bitstring::{BitString, BitVec};
+pub use bmpstring::BmpString;
 pub use boolean::Boolean;
+pub use boxed::Boxed;
 pub use choice::Choice;
 pub use complex::Complex;
+pub use default::DefaultValue;
 pub use enumerated::Enumerated;
+pub use ia5string::Ia5String;
+pub use null::Null;
 pub use numbers::Integer;
+pub use numericstring::NumericString;
+pub use objectidentifier::ObjectIdentifier;
 pub use octetstring::OctetString;
+pub use printablestring::PrintableString;
+pub use real::Real;
 pub use sequence::Sequence;
 pub use sequenceof::SequenceOf;
+pub use set::Set;
+pub use setof::SetOf;
+pub use tag::Tag;
+pub use tagged::Tagged;
+pub use time::{GeneralizedTime, UtcTime};
+pub use universalstring::UniversalString;
 pub use utf8string::Utf8String;
+pub use visiblestring::VisibleString;
 
 pub trait Reader {
-    type Error;
+    type Error: numbers::IntegerError;
 
     #[inline]
     fn read<T: Readable>(&mut self) -> Result<T, Self::Error>
@@ -46,21 +82,127 @@ pub trait Reader {
         &mut self,
     ) -> Result<Vec<T::Type>, Self::Error>;
 
+    /// Reads the fields of a `SET` in the canonical order of their tags. Encodings that read
+    /// a `SET` like a `SEQUENCE` can rely on the default implementation.
+    #[inline]
+    fn read_set<C: sequence::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<S, Self::Error> {
+        self.read_sequence::<C, S, F>(f)
+    }
+
+    /// Encodings that read a `SET OF` like a `SEQUENCE OF` can rely on the default
+    /// implementation.
+    #[inline]
+    fn read_set_of<C: sequenceof::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<Vec<T::Type>, Self::Error> {
+        self.read_sequence_of::<C, T>()
+    }
+
     fn read_enumerated<C: enumerated::Constraint>(&mut self) -> Result<C, Self::Error>;
 
     fn read_choice<C: choice::Constraint>(&mut self) -> Result<C, Self::Error>;
 
     fn read_opt<T: ReadableType>(&mut self) -> Result<Option<T::Type>, Self::Error>;
 
+    /// Reads an extension addition of an extensible SEQUENCE. This is either a single
+    /// field or - if the number of its OPTIONAL fields is given - an extension addition group.
+    /// Encodings that read extension additions like any other field can rely on the
+    /// default implementation.
+    #[inline]
+    fn read_extension_addition<T, F: Fn(&mut Self) -> Result<T, Self::Error>>(
+        &mut self,
+        _group_optional_fields: Option<usize>,
+        f: F,
+    ) -> Result<T, Self::Error>
+    where
+        Self: Sized,
+    {
+        f(self)
+    }
+
+    /// Reads the inner type `T` that is tagged with `C::TAG` instead of its default tag.
+    /// Encodings that do not transmit tags can rely on the default implementation.
+    #[inline]
+    fn read_tagged<C: tagged::Constraint, T: ReadableType>(
+        &mut self,
+    ) -> Result<T::Type, Self::Error>
+    where
+        Self: Sized,
+    {
+        T::read_value(self)
+    }
+
+    /// Reads the open type, whose actual type is identified by the given key. Encodings that
+    /// read the content of an open type like any other value can rely on the default
+    /// implementation, which reads the content of an unknown type as `OCTET STRING`.
+    #[inline]
+    fn read_open_type<C: opentype::Constraint>(&mut self, key: i64) -> Result<C, Self::Error>
+    where
+        Self: Sized,
+    {
+        match C::read_content(key, self)? {
+            Some(value) => Ok(value),
+            None => Ok(C::from_unknown(
+                self.read_octet_string::<octetstring::NoConstraint>()?,
+            )),
+        }
+    }
+
     fn read_int(&mut self, range: (i64, i64)) -> Result<i64, Self::Error>;
 
     fn read_int_max(&mut self) -> Result<u64, Self::Error>;
 
+    /// Reads an `INTEGER` that only has a lower bound (`lb..MAX`)
+    fn read_int_semi_constrained(&mut self, lower_bound: i64) -> Result<i64, Self::Error>;
+
+    /// Reads an `INTEGER` without a lower bound
+    fn read_int_unconstrained(&mut self) -> Result<i64, Self::Error>;
+
+    /// Reads an `INTEGER` with an extensible range (`lb..ub, ...`), whose value might be
+    /// outside of that range. Encodings in which the range of an extensible `INTEGER` is not
+    /// visible can rely on the default implementation, which reads an unconstrained value.
+    #[inline]
+    fn read_int_extensible(&mut self, _range: (i64, i64)) -> Result<i64, Self::Error> {
+        self.read_int_unconstrained()
+    }
+
     fn read_utf8string<C: utf8string::Constraint>(&mut self) -> Result<String, Self::Error>;
 
+    fn read_ia5string<C: ia5string::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_numeric_string<C: numericstring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_printable_string<C: printablestring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error>;
+
+    fn read_visible_string<C: visiblestring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_bmp_string<C: bmpstring::Constraint>(&mut self) -> Result<String, Self::Error>;
+
+    fn read_universal_string<C: universalstring::Constraint>(
+        &mut self,
+    ) -> Result<String, Self::Error>;
+
     fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error>;
 
+    /// Reads the bytes of a `BIT STRING` and its length in bits
+    fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error>;
+
     fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error>;
+
+    fn read_null(&mut self) -> Result<Null, Self::Error>;
+
+    fn read_real<C: real::Constraint>(&mut self) -> Result<f64, Self::Error>;
+
+    fn read_oid(&mut self) -> Result<ObjectIdentifier, Self::Error>;
+
+    /// Reads the string form of `GeneralizedTime` or `UTCTime`, which is invalid if it
+    /// cannot be parsed by [`time::Time::parse`]
+    fn read_time<T: time::Time>(&mut self) -> Result<T, Self::Error>;
 }
 
 pub trait Readable: Sized {
@@ -88,7 +230,7 @@ impl<T: Readable> ReadableType for T {
 }
 
 pub trait Writer {
-    type Error;
+    type Error: numbers::IntegerError + opentype::KeyError;
 
     #[inline]
     fn write<T: Writable>(&mut self, value: &T) -> Result<(), Self::Error>
@@ -108,6 +250,26 @@ pub trait Writer {
         slice: &[T::Type],
     ) -> Result<(), Self::Error>;
 
+    /// Writes the fields of a `SET` in the canonical order of their tags. Encodings that write
+    /// a `SET` like a `SEQUENCE` can rely on the default implementation.
+    #[inline]
+    fn write_set<C: sequence::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        f: F,
+    ) -> Result<(), Self::Error> {
+        self.write_sequence::<C, F>(f)
+    }
+
+    /// Encodings that write a `SET OF` like a `SEQUENCE OF` can rely on the default
+    /// implementation.
+    #[inline]
+    fn write_set_of<C: sequenceof::Constraint, T: WritableType>(
+        &mut self,
+        slice: &[T::Type],
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_of::<C, T>(slice)
+    }
+
     fn write_enumerated<C: enumerated::Constraint>(
         &mut self,
         enumerated: &C,
@@ -117,21 +279,138 @@ pub trait Writer {
 
     fn write_opt<T: WritableType>(&mut self, value: Option<&T::Type>) -> Result<(), Self::Error>;
 
+    /// Writes an extension addition of an extensible SEQUENCE. This is either a single
+    /// field or - if the number of its OPTIONAL fields is given - an extension addition group.
+    /// Encodings that write extension additions like any other field can rely on the
+    /// default implementation.
+    #[inline]
+    fn write_extension_addition<F: Fn(&mut Self) -> Result<(), Self::Error>>(
+        &mut self,
+        _present: bool,
+        _group_optional_fields: Option<usize>,
+        f: F,
+    ) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+    {
+        f(self)
+    }
+
+    /// Writes the inner type `T` tagged with `C::TAG` instead of its default tag.
+    /// Encodings that do not transmit tags can rely on the default implementation.
+    #[inline]
+    fn write_tagged<C: tagged::Constraint, T: WritableType>(
+        &mut self,
+        value: &T::Type,
+    ) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+    {
+        T::write_value(self, value)
+    }
+
+    /// Writes the content of the open type, whose actual type must be identified by the given
+    /// key. Encodings that write the content of an open type like any other value can rely on
+    /// the default implementation, which writes the content of an unknown type as
+    /// `OCTET STRING`.
+    #[inline]
+    fn write_open_type<C: opentype::Constraint>(
+        &mut self,
+        key: i64,
+        value: &C,
+    ) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+    {
+        if !value.is_identified_by(key) {
+            return Err(opentype::KeyError::key_mismatch(key, C::NAME));
+        }
+        match value.unknown_content() {
+            Some(content) => self.write_octet_string::<octetstring::NoConstraint>(content),
+            None => value.write_content(self),
+        }
+    }
+
     fn write_int(&mut self, value: i64, range: (i64, i64)) -> Result<(), Self::Error>;
 
     fn write_int_max(&mut self, value: u64) -> Result<(), Self::Error>;
 
+    /// Writes an `INTEGER` that only has a lower bound (`lb..MAX`)
+    fn write_int_semi_constrained(
+        &mut self,
+        value: i64,
+        lower_bound: i64,
+    ) -> Result<(), Self::Error>;
+
+    /// Writes an `INTEGER` without a lower bound
+    fn write_int_unconstrained(&mut self, value: i64) -> Result<(), Self::Error>;
+
+    /// Writes an `INTEGER` with an extensible range (`lb..ub, ...`), whose value might be
+    /// outside of that range. Encodings in which the range of an extensible `INTEGER` is not
+    /// visible can rely on the default implementation, which writes an unconstrained value.
+    #[inline]
+    fn write_int_extensible(&mut self, value: i64, _range: (i64, i64)) -> Result<(), Self::Error> {
+        self.write_int_unconstrained(value)
+    }
+
     fn write_utf8string<C: utf8string::Constraint>(
         &mut self,
         value: &str,
     ) -> Result<(), Self::Error>;
 
+    fn write_ia5string<C: ia5string::Constraint>(&mut self, value: &str)
+        -> Result<(), Self::Error>;
+
+    fn write_numeric_string<C: numericstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_printable_string<C: printablestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_visible_string<C: visiblestring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_bmp_string<C: bmpstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn write_universal_string<C: universalstring::Constraint>(
+        &mut self,
+        value: &str,
+    ) -> Result<(), Self::Error>;
+
     fn write_octet_string<C: octetstring::Constraint>(
         &mut self,
         value: &[u8],
     ) -> Result<(), Self::Error>;
 
+    fn write_bit_string<C: bitstring::Constraint>(
+        &mut self,
+        value: &[u8],
+        bit_len: u64,
+    ) -> Result<(), Self::Error>;
+
     fn write_boolean<C: boolean::Constraint>(&mut self, value: bool) -> Result<(), Self::Error>;
+
+    fn write_null(&mut self) -> Result<(), Self::Error>;
+
+    fn write_real<C: real::Constraint>(&mut self, value: f64) -> Result<(), Self::Error>;
+
+    fn write_oid(&mut self, value: &ObjectIdentifier) -> Result<(), Self::Error>;
+
+    /// Writes the string form of `GeneralizedTime` or `UTCTime`. Encodings that write it like
+    /// any other `VisibleString` can rely on the default implementation.
+    #[inline]
+    fn write_time<T: time::Time>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.write_visible_string::<visiblestring::NoConstraint>(&value.to_string())
+    }
 }
 
 pub trait Writable {
@@ -174,6 +453,7 @@ mod tests {
         impl sequence::Constraint for Whatever {
             const NAME: &'static str = "Whatever";
             const OPTIONAL_FIELDS: usize = 2;
+            const FIELD_NAMES: &'static [&'static str] = &["name", "opt", "some"];
 
             fn read_seq<R: Reader>(reader: &mut R) -> Result<Self, <R as Reader>::Error>
             where
@@ -0,0 +1,24 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+
+/// The value of a `NULL`. As there is nothing to constrain, it is its own type
+/// declaration as well.
+#[derive(Debug, Default, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct Null;
+
+impl WritableType for Null {
+    type Type = Null;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, _value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_null()
+    }
+}
+
+impl ReadableType for Null {
+    type Type = Null;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_null()
+    }
+}
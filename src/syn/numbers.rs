@@ -13,6 +13,8 @@ impl<T: Copy, C: Constraint<T>> Default for Integer<T, C> {
 pub trait Constraint<T: Copy> {
     const MIN: Option<T> = None;
     const MAX: Option<T> = None;
+    /// Whether values outside of `MIN..MAX` are valid as well (`lb..ub, ...`)
+    const EXTENSIBLE: bool = false;
 }
 
 #[derive(Default)]
@@ -20,8 +22,28 @@ pub struct NoConstraint;
 
 impl<T: Copy> Constraint<T> for NoConstraint {}
 
+/// The errors of a [`Reader`] or [`Writer`] for `INTEGER` values, which do not fit into the
+/// range of the constraint, of the Rust type or of the encoding
+pub trait IntegerError {
+    /// The value, which is not within the inclusive range of the given bounds
+    fn value_not_in_range(value: i64, min: i64, max: i64) -> Self;
+
+    /// The value, which exceeds the values that can be encoded
+    fn value_too_large(value: u64) -> Self;
+}
+
+impl IntegerError for () {
+    fn value_not_in_range(_value: i64, _min: i64, _max: i64) -> Self {}
+
+    fn value_too_large(_value: u64) -> Self {}
+}
+
+/// The `$implicit_min` is the lower bound that applies if the [`Constraint`] has none. Unsigned
+/// types are implicitly semi-constrained, while signed types are unconstrained without a
+/// lower bound (X.691-201508 10.7 and 10.8). Without a lower bound, the upper bound is not
+/// visible in the encoding and therefore checked beforehand.
 macro_rules! read_write {
-    ( $($T:ident),+ ) => {$(
+    ( $implicit_min:expr => $($T:ident),+ ) => {$(
 
         impl<C: Constraint<$T>> WritableType for Integer<$T, C> {
             type Type = $T;
@@ -31,17 +53,17 @@ macro_rules! read_write {
                 writer: &mut W,
                 value: &Self::Type,
             ) -> Result<(), <W as Writer>::Error> {
-                let value = *value;
-                if C::MIN.is_none() && C::MAX.is_none() {
-                    writer.write_int_max(value as u64)
-                } else {
-                    writer.write_int(
-                        i64::from(value),
-                        (
-                            C::MIN.map(i64::from).unwrap_or(0),
-                            C::MAX.map(i64::from).unwrap_or_else(i64::max_value),
-                        ),
-                    )
+                let value = i64::from(*value);
+                match (C::MIN.map(i64::from).or($implicit_min), C::MAX.map(i64::from)) {
+                    (Some(min), Some(max)) if C::EXTENSIBLE => {
+                        writer.write_int_extensible(value, (min, max))
+                    }
+                    (Some(min), Some(max)) => writer.write_int(value, (min, max)),
+                    (Some(min), None) => writer.write_int_semi_constrained(value, min),
+                    (None, Some(max)) if value > max && !C::EXTENSIBLE => Err(
+                        W::Error::value_not_in_range(value, i64::from($T::MIN), max),
+                    ),
+                    (None, _) => writer.write_int_unconstrained(value),
                 }
             }
         }
@@ -51,24 +73,36 @@ macro_rules! read_write {
 
             #[inline]
             fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
-                if C::MIN.is_none() && C::MAX.is_none() {
-                    Ok(reader.read_int_max()? as $T)
-                } else {
-                    Ok(reader
-                        .read_int((
-                            C::MIN.map(i64::from).unwrap_or(0),
-                            C::MAX.map(i64::from).unwrap_or_else(i64::max_value),
-                        ))? as $T
-                    )
-                }
+                let value = match (C::MIN.map(i64::from).or($implicit_min), C::MAX.map(i64::from)) {
+                    (Some(min), Some(max)) if C::EXTENSIBLE => {
+                        reader.read_int_extensible((min, max))?
+                    }
+                    (Some(min), Some(max)) => reader.read_int((min, max))?,
+                    (Some(min), None) => reader.read_int_semi_constrained(min)?,
+                    (None, Some(max)) if !C::EXTENSIBLE => match reader.read_int_unconstrained()? {
+                        value if value > max => {
+                            return Err(R::Error::value_not_in_range(
+                                value,
+                                i64::from($T::MIN),
+                                max,
+                            ))
+                        }
+                        value => value,
+                    },
+                    (None, _) => reader.read_int_unconstrained()?,
+                };
+                // values outside of an extensible range might not fit into the Rust type
+                <$T>::try_from(value).map_err(|_| {
+                    R::Error::value_not_in_range(value, i64::from($T::MIN), i64::from($T::MAX))
+                })
             }
         }
      )*
     }
 }
 
-read_write!(i8, i16, i32, i64);
-read_write!(u8, u16, u32);
+read_write!(None => i8, i16, i32, i64);
+read_write!(Some(0) => u8, u16, u32);
 
 impl<C: Constraint<u64>> WritableType for Integer<u64, C> {
     type Type = u64;
@@ -79,19 +113,18 @@ impl<C: Constraint<u64>> WritableType for Integer<u64, C> {
         value: &Self::Type,
     ) -> Result<(), <W as Writer>::Error> {
         let value = *value;
-        if C::MIN.is_none() && C::MAX.is_none() {
-            writer.write_int_max(value)
-        } else {
-            let value = i64::try_from(value).unwrap();
-            writer.write_int(
-                value,
-                (
-                    C::MIN.map(|v| i64::try_from(v).unwrap()).unwrap_or(0),
-                    C::MAX
-                        .map(|v| i64::try_from(v).unwrap())
-                        .unwrap_or_else(i64::max_value),
-                ),
-            )
+        let int = |value: u64| i64::try_from(value).map_err(|_| W::Error::value_too_large(value));
+        match (C::MIN, C::MAX) {
+            (None, None) | (Some(0), None) => writer.write_int_max(value),
+            (Some(min), None) => writer.write_int_semi_constrained(int(value)?, int(min)?),
+            (min, Some(max)) => {
+                let range = (int(min.unwrap_or(0))?, int(max)?);
+                if C::EXTENSIBLE {
+                    writer.write_int_extensible(int(value)?, range)
+                } else {
+                    writer.write_int(int(value)?, range)
+                }
+            }
         }
     }
 }
@@ -101,15 +134,16 @@ impl<C: Constraint<u64>> ReadableType for Integer<u64, C> {
 
     #[inline]
     fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
-        if C::MIN.is_none() && C::MAX.is_none() {
-            Ok(reader.read_int_max()?)
-        } else {
-            Ok(reader.read_int((
-                C::MIN.map(|v| i64::try_from(v).unwrap()).unwrap_or(0),
-                C::MAX
-                    .map(|v| i64::try_from(v).unwrap())
-                    .unwrap_or_else(i64::max_value),
-            ))? as u64)
-        }
+        let int = |value: u64| i64::try_from(value).map_err(|_| R::Error::value_too_large(value));
+        let value = match (C::MIN, C::MAX) {
+            (None, None) | (Some(0), None) => return reader.read_int_max(),
+            (Some(min), None) => reader.read_int_semi_constrained(int(min)?)?,
+            (min, Some(max)) if C::EXTENSIBLE => {
+                reader.read_int_extensible((int(min.unwrap_or(0))?, int(max)?))?
+            }
+            (min, Some(max)) => reader.read_int((int(min.unwrap_or(0))?, int(max)?))?,
+        };
+        // values outside of an extensible range might be negative
+        u64::try_from(value).map_err(|_| R::Error::value_not_in_range(value, 0, i64::MAX))
     }
 }
@@ -0,0 +1,183 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+
+/// The value of an `OBJECT IDENTIFIER`, a sequence of arcs. As there is nothing to
+/// constrain, it is its own type declaration as well.
+///
+/// The default value has no arcs. It is no valid `OBJECT IDENTIFIER` but is encoded as
+/// empty content nonetheless, so that default values of generated types can be transmitted.
+#[derive(Debug, Default, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct ObjectIdentifier(Vec<u64>);
+
+impl ObjectIdentifier {
+    /// Requires at least two arcs, the first being `0`, `1` or `2`. For the first two root
+    /// arcs, the second arc must be less than `40`, see X.660-201107 A.2 and A.3
+    pub fn new(arcs: Vec<u64>) -> Option<Self> {
+        match arcs.get(..2) {
+            Some([0, second]) | Some([1, second]) if *second < 40 => Some(Self(arcs)),
+            Some([2, second]) if *second <= u64::MAX - 80 => Some(Self(arcs)),
+            _ => None,
+        }
+    }
+
+    /// Parses the dotted notation, such as `1.3.6.1`
+    pub fn parse(dotted: &str) -> Option<Self> {
+        if dotted.is_empty() {
+            Some(Self::default())
+        } else {
+            dotted
+                .split('.')
+                .map(|arc| arc.parse::<u64>().ok())
+                .collect::<Option<Vec<_>>>()
+                .and_then(Self::new)
+        }
+    }
+
+    pub fn arcs(&self) -> &[u64] {
+        &self.0[..]
+    }
+
+    pub fn into_arcs(self) -> Vec<u64> {
+        self.0
+    }
+
+    /// The content octets, with the first two arcs combined to one subidentifier and
+    /// each subidentifier in base 128 (X.690-201508 8.19)
+    pub fn to_content(&self) -> Vec<u8> {
+        let mut content = Vec::with_capacity(self.0.len() * 2);
+        if let [first, second, rest @ ..] = &self.0[..] {
+            write_subidentifier(&mut content, first * 40 + second);
+            for arc in rest {
+                write_subidentifier(&mut content, *arc);
+            }
+        }
+        content
+    }
+
+    /// Interprets the given content octets, see [`ObjectIdentifier::to_content`]. Returns
+    /// `None` if the content is invalid or a subidentifier exceeds 64 bits.
+    pub fn from_content(content: &[u8]) -> Option<Self> {
+        let mut subidentifiers = Vec::new();
+        let mut subidentifier = 0_u64;
+        let mut first_octet = true;
+        for byte in content {
+            // X.690-201508 8.19.2, the leading octet must not be 0x80
+            if (first_octet && *byte == 0x80) || subidentifier.leading_zeros() < 7 {
+                return None;
+            }
+            subidentifier = (subidentifier << 7) | u64::from(byte & 0x7F);
+            first_octet = byte & 0x80 == 0;
+            if first_octet {
+                subidentifiers.push(core::mem::take(&mut subidentifier));
+            }
+        }
+        if !first_octet {
+            return None;
+        }
+
+        let mut subidentifiers = subidentifiers.into_iter();
+        Some(Self(match subidentifiers.next() {
+            None => Vec::new(),
+            Some(first) => {
+                // X.690-201508 8.19.4
+                let root = (first / 40).min(2);
+                core::iter::once(root)
+                    .chain(core::iter::once(first - root * 40))
+                    .chain(subidentifiers)
+                    .collect()
+            }
+        }))
+    }
+}
+
+impl core::fmt::Display for ObjectIdentifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (index, arc) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", arc)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_subidentifier(content: &mut Vec<u8>, value: u64) {
+    let septets = (64 - value.leading_zeros() as usize).div_ceil(7).max(1);
+    for index in (0..septets).rev() {
+        let septet = ((value >> (index * 7)) & 0x7F) as u8;
+        content.push(if index > 0 { septet | 0x80 } else { septet });
+    }
+}
+
+impl WritableType for ObjectIdentifier {
+    type Type = ObjectIdentifier;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_oid(value)
+    }
+}
+
+impl ReadableType for ObjectIdentifier {
+    type Type = ObjectIdentifier;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_oid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_validates_root_arcs() {
+        assert!(ObjectIdentifier::new(vec![1, 3, 6, 1]).is_some());
+        assert!(ObjectIdentifier::new(vec![2, 999]).is_some());
+        assert!(ObjectIdentifier::new(vec![1, 40]).is_none());
+        assert!(ObjectIdentifier::new(vec![3, 1]).is_none());
+        assert!(ObjectIdentifier::new(vec![1]).is_none());
+    }
+
+    #[test]
+    fn test_dotted_notation() {
+        let oid = ObjectIdentifier::parse("1.3.6.1.4.1").unwrap();
+        assert_eq!(&[1, 3, 6, 1, 4, 1], oid.arcs());
+        assert_eq!("1.3.6.1.4.1", oid.to_string());
+        assert_eq!(
+            Some(ObjectIdentifier::default()),
+            ObjectIdentifier::parse("")
+        );
+        assert_eq!(None, ObjectIdentifier::parse("1..3"));
+        assert_eq!(None, ObjectIdentifier::parse("1.x"));
+    }
+
+    #[test]
+    fn test_content() {
+        // the example of X.690-201508 8.19.5
+        let oid = ObjectIdentifier::new(vec![2, 999, 3]).unwrap();
+        assert_eq!(vec![0x88, 0x37, 0x03], oid.to_content());
+        assert_eq!(
+            Some(oid),
+            ObjectIdentifier::from_content(&[0x88, 0x37, 0x03])
+        );
+
+        let oid = ObjectIdentifier::parse("1.2.840.113549").unwrap();
+        assert_eq!(vec![0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D], oid.to_content());
+        let content = oid.to_content();
+        assert_eq!(Some(oid), ObjectIdentifier::from_content(&content));
+
+        assert_eq!(
+            Some(ObjectIdentifier::default()),
+            ObjectIdentifier::from_content(&[])
+        );
+    }
+
+    #[test]
+    fn test_invalid_content() {
+        assert_eq!(None, ObjectIdentifier::from_content(&[0x2A, 0x86]));
+        assert_eq!(None, ObjectIdentifier::from_content(&[0x2A, 0x80, 0x01]));
+        assert_eq!(None, ObjectIdentifier::from_content(&[0xFF; 11]));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::syn::{Reader, Writer};
+
+/// An open type, whose actual type is identified by the value of a key field of the
+/// surrounding `SEQUENCE`, as defined by the object set of an information object class
+pub trait Constraint: Sized {
+    const NAME: &'static str;
+    /// The keys identifying the types known to this definition
+    const KEYS: &'static [i64];
+
+    /// The key identifying the type of this value or `None` for the content of an unknown type
+    fn key(&self) -> Option<i64>;
+
+    /// Whether the given key identifies the type of this value, which for the content of an
+    /// unknown type is the case for any key that does not identify a known type
+    #[inline]
+    fn is_identified_by(&self, key: i64) -> bool {
+        match self.key() {
+            Some(own) => own == key,
+            None => !Self::KEYS.contains(&key),
+        }
+    }
+
+    fn write_content<W: Writer>(&self, writer: &mut W) -> Result<(), W::Error>;
+
+    /// Reads the content of the type identified by the given key or `None` for unknown keys
+    fn read_content<R: Reader>(key: i64, reader: &mut R) -> Result<Option<Self>, R::Error>;
+
+    /// The variant holding the encoded content of a type that is unknown to this definition
+    fn from_unknown(content: Vec<u8>) -> Self;
+
+    fn unknown_content(&self) -> Option<&[u8]>;
+}
+
+/// The errors of a [`Writer`] for open type values, whose type is not identified by the
+/// value of the key field
+pub trait KeyError {
+    /// The key, which does not identify the type of the value of the named open type
+    fn key_mismatch(key: i64, name: &'static str) -> Self;
+}
+
+impl KeyError for () {
+    fn key_mismatch(_key: i64, _name: &'static str) -> Self {}
+}
+
+/// The value of a key field, which identifies the actual type of an open type
+pub trait Key {
+    fn key(&self) -> i64;
+}
+
+macro_rules! impl_key {
+    ($($t:ty),*) => {
+        $(
+            impl Key for $t {
+                #[inline]
+                fn key(&self) -> i64 {
+                    *self as i64
+                }
+            }
+        )*
+    };
+}
+
+impl_key!(i8, u8, i16, u16, i32, u32, i64, u64);
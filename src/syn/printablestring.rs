@@ -0,0 +1,41 @@
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+pub struct PrintableString<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for PrintableString<C> {
+    fn default() -> Self {
+        PrintableString(Default::default())
+    }
+}
+
+pub trait Constraint {
+    const MIN: Option<usize> = None;
+    const MAX: Option<usize> = None;
+    /// Whether the `SIZE` constraint is extensible, permitting sizes beyond `MIN` and `MAX`
+    const EXTENSIBLE: bool = false;
+    /// The characters of a `FROM` constraint
+    const PERMITTED_ALPHABET: Option<&'static str> = None;
+}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for PrintableString<C> {
+    type Type = String;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_printable_string::<C>(value.as_str())
+    }
+}
+
+impl<C: Constraint> ReadableType for PrintableString<C> {
+    type Type = String;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_printable_string::<C>()
+    }
+}
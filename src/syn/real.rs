@@ -0,0 +1,231 @@
+use crate::io::ber::{integer_content, integer_from_content};
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+pub struct Real<C: Constraint = NoConstraint>(PhantomData<C>);
+
+impl<C: Constraint> Default for Real<C> {
+    fn default() -> Self {
+        Real(Default::default())
+    }
+}
+
+pub trait Constraint {}
+
+#[derive(Default)]
+pub struct NoConstraint;
+impl Constraint for NoConstraint {}
+
+impl<C: Constraint> WritableType for Real<C> {
+    type Type = f64;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_real::<C>(*value)
+    }
+}
+
+impl<C: Constraint> ReadableType for Real<C> {
+    type Type = f64;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_real::<C>()
+    }
+}
+
+const BINARY_ENCODING: u8 = 0x80;
+const SPECIAL_REAL_VALUE: u8 = 0x40;
+const NEGATIVE: u8 = 0x40;
+const PLUS_INFINITY: u8 = 0x40;
+const MINUS_INFINITY: u8 = 0x41;
+const NOT_A_NUMBER: u8 = 0x42;
+const MINUS_ZERO: u8 = 0x43;
+
+/// The content octets of the given value as required by the canonical and distinguished
+/// encoding rules (X.690-201508 11.3.1): base 2, a scaling factor of zero and an odd
+/// mantissa. These are also the octets PER and OER transmit for a `REAL`.
+pub fn to_content(value: f64) -> Vec<u8> {
+    if value == 0.0 {
+        // X.690-201508 8.5.2 and 8.5.3
+        if value.is_sign_negative() {
+            vec![MINUS_ZERO]
+        } else {
+            Vec::new()
+        }
+    } else if value.is_nan() {
+        vec![NOT_A_NUMBER]
+    } else if value.is_infinite() {
+        vec![if value > 0.0 {
+            PLUS_INFINITY
+        } else {
+            MINUS_INFINITY
+        }]
+    } else {
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7FF) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mut mantissa, mut exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+        let trailing_zeros = mantissa.trailing_zeros();
+        mantissa >>= trailing_zeros;
+        exponent += i64::from(trailing_zeros);
+
+        let exponent = integer_content(exponent);
+        let mantissa = mantissa.to_be_bytes();
+        let mantissa = &mantissa[mantissa.iter().take_while(|b| **b == 0x00).count()..];
+
+        // X.690-201508 8.5.7, the exponent of a f64 always fits into two octets
+        let mut content = Vec::with_capacity(1 + exponent.len() + mantissa.len());
+        content.push(
+            BINARY_ENCODING
+                | if value.is_sign_negative() {
+                    NEGATIVE
+                } else {
+                    0
+                }
+                | (exponent.len() - 1) as u8,
+        );
+        content.extend_from_slice(&exponent);
+        content.extend_from_slice(mantissa);
+        content
+    }
+}
+
+/// Interprets the given content octets, X.690-201508 8.5. Besides the binary encoding
+/// with any base and scaling factor, this also accepts the decimal encoding (8.5.8).
+/// Returns `None` if the content is invalid or its mantissa exceeds 64 bits.
+pub fn from_content(content: &[u8]) -> Option<f64> {
+    let (first, rest) = match content.split_first() {
+        None => return Some(0.0),
+        Some(split) => split,
+    };
+    if first & BINARY_ENCODING != 0 {
+        let base_bits = match (first >> 4) & 0x03 {
+            0 => 1,
+            1 => 3,
+            2 => 4,
+            _ => return None,
+        };
+        let scaling_factor = i64::from((first >> 2) & 0x03);
+        let (exponent_len, rest) = match first & 0x03 {
+            0x03 => {
+                let (len, rest) = rest.split_first()?;
+                (usize::from(*len), rest)
+            }
+            len => (usize::from(len) + 1, rest),
+        };
+        if rest.len() <= exponent_len || rest.len() - exponent_len > 8 {
+            return None;
+        }
+        let (exponent, mantissa) = rest.split_at(exponent_len);
+        let exponent = integer_from_content(exponent)
+            .ok()?
+            .checked_mul(base_bits)?
+            .checked_add(scaling_factor)?;
+        let mantissa = mantissa
+            .iter()
+            .fold(0_u64, |mantissa, byte| (mantissa << 8) | u64::from(*byte));
+        let value = scale_by_power_of_two(mantissa as f64, exponent);
+        Some(if first & NEGATIVE != 0 { -value } else { value })
+    } else if first & SPECIAL_REAL_VALUE != 0 {
+        if !rest.is_empty() {
+            return None;
+        }
+        match *first {
+            PLUS_INFINITY => Some(f64::INFINITY),
+            MINUS_INFINITY => Some(f64::NEG_INFINITY),
+            NOT_A_NUMBER => Some(f64::NAN),
+            MINUS_ZERO => Some(-0.0),
+            _ => None,
+        }
+    } else {
+        // ISO 6093 NR1, NR2 or NR3 form, which allow a comma as decimal mark
+        core::str::from_utf8(rest)
+            .ok()?
+            .trim()
+            .replace(',', ".")
+            .parse()
+            .ok()
+    }
+}
+
+fn scale_by_power_of_two(mut value: f64, exponent: i64) -> f64 {
+    // anything beyond these bounds results in zero or infinity anyway
+    let mut exponent = exponent.clamp(-2200, 2200) as i32;
+    while exponent > 1000 {
+        value *= 2_f64.powi(1000);
+        exponent -= 1000;
+    }
+    while exponent < -1000 {
+        value *= 2_f64.powi(-1000);
+        exponent += 1000;
+    }
+    value * 2_f64.powi(exponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_content() {
+        assert_eq!(Vec::<u8>::new(), to_content(0.0));
+        assert_eq!(vec![0x43], to_content(-0.0));
+        assert_eq!(vec![0x40], to_content(f64::INFINITY));
+        assert_eq!(vec![0x41], to_content(f64::NEG_INFINITY));
+        assert_eq!(vec![0x42], to_content(f64::NAN));
+        assert_eq!(vec![0x80, 0x00, 0x01], to_content(1.0));
+        assert_eq!(vec![0x80, 0xFF, 0x01], to_content(0.5));
+        assert_eq!(vec![0xC0, 0x00, 0x03], to_content(-3.0));
+        assert_eq!(vec![0x80, 0xFC, 0x4B], to_content(4.6875));
+    }
+
+    #[test]
+    fn test_content_round_trip() {
+        for value in &[
+            1.0,
+            -1.5,
+            0.1,
+            core::f64::consts::PI,
+            1e300,
+            -1e-300,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            f64::MIN_POSITIVE / 1024.0,
+            f64::from_bits(1),
+            f64::INFINITY,
+            -0.0,
+        ] {
+            let decoded = from_content(&to_content(*value)).unwrap();
+            assert_eq!(value.to_bits(), decoded.to_bits(), "{}", value);
+        }
+        assert!(from_content(&to_content(f64::NAN)).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_non_canonical_content() {
+        // base 16, scaling factor 1, 16^1 * 2^1 * 3
+        assert_eq!(Some(96.0), from_content(&[0xA4, 0x01, 0x03]));
+        // base 8 with the exponent length given in a separate octet, 8^-1 * 4
+        assert_eq!(Some(0.5), from_content(&[0x93, 0x01, 0xFF, 0x04]));
+        // NR2 and NR3 decimal forms
+        assert_eq!(Some(1.5), from_content(b"\x02 1,5"));
+        assert_eq!(Some(-120.0), from_content(b"\x03-1.2E+2"));
+    }
+
+    #[test]
+    fn test_invalid_content() {
+        assert_eq!(None, from_content(&[0xB0, 0x00, 0x01]));
+        assert_eq!(None, from_content(&[0x80, 0x00]));
+        assert_eq!(None, from_content(&[0x44]));
+        assert_eq!(None, from_content(&[0x40, 0x00]));
+        assert_eq!(None, from_content(b"\x01abc"));
+    }
+}
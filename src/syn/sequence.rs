@@ -12,6 +12,12 @@ impl<T: Constraint> Default for Sequence<T> {
 pub trait Constraint {
     const NAME: &'static str;
     const OPTIONAL_FIELDS: usize;
+    /// The number of OPTIONAL fields in the extension root
+    const STD_OPTIONAL_FIELDS: usize = Self::OPTIONAL_FIELDS;
+    /// The index of the last field of the extension root, if the SEQUENCE is extensible
+    const EXTENDED_AFTER_FIELD: Option<usize> = None;
+    /// The ASN.1 names of all fields in the order they are read and written
+    const FIELD_NAMES: &'static [&'static str];
 
     fn read_seq<R: Reader>(reader: &mut R) -> Result<Self, R::Error>
     where
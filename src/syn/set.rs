@@ -0,0 +1,37 @@
+use crate::syn::sequence::Constraint;
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+/// A `SET`, whose fields are read and written in the canonical order of their tags. Its
+/// constraint is the same as the one of a [`Sequence`](crate::syn::Sequence).
+pub struct Set<T: Constraint>(PhantomData<T>);
+
+impl<T: Constraint> Default for Set<T> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<C: Constraint> WritableType for Set<C> {
+    type Type = C;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        writer.write_set::<C, _>(|w| value.write_seq::<W>(w))
+    }
+}
+
+impl<C: Constraint> ReadableType for Set<C>
+where
+    C: Sized,
+{
+    type Type = C;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_set::<C, Self::Type, _>(C::read_seq)
+    }
+}
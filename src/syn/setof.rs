@@ -0,0 +1,31 @@
+use crate::syn::sequenceof::{Constraint, NoConstraint};
+use crate::syn::{ReadableType, Reader, WritableType, Writer};
+use core::marker::PhantomData;
+
+/// A `SET OF`, whose elements are kept in a `Vec`. Its constraint is the same as the one
+/// of a [`SequenceOf`](crate::syn::SequenceOf).
+pub struct SetOf<T, C: Constraint = NoConstraint>(PhantomData<T>, PhantomData<C>);
+
+impl<C: Constraint> Default for SetOf<C> {
+    fn default() -> Self {
+        Self(Default::default(), Default::default())
+    }
+}
+
+impl<T: WritableType, C: Constraint> WritableType for SetOf<T, C> {
+    type Type = Vec<T::Type>;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_set_of::<C, T>(value.as_slice())
+    }
+}
+
+impl<T: ReadableType, C: Constraint> ReadableType for SetOf<T, C> {
+    type Type = Vec<T::Type>;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_set_of::<C, T>()
+    }
+}
@@ -0,0 +1,41 @@
+/// The class and number of an ASN.1 tag, see ITU-T X.680 8.1
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq, Hash)]
+pub enum Tag {
+    Universal(usize),
+    Application(usize),
+    Private(usize),
+    ContextSpecific(usize),
+}
+
+impl Tag {
+    pub const DEFAULT_BOOLEAN: Tag = Tag::Universal(1);
+    pub const DEFAULT_INTEGER: Tag = Tag::Universal(2);
+    pub const DEFAULT_BIT_STRING: Tag = Tag::Universal(3);
+    pub const DEFAULT_OCTET_STRING: Tag = Tag::Universal(4);
+    pub const DEFAULT_NULL: Tag = Tag::Universal(5);
+    pub const DEFAULT_OBJECT_IDENTIFIER: Tag = Tag::Universal(6);
+    pub const DEFAULT_REAL: Tag = Tag::Universal(9);
+    pub const DEFAULT_ENUMERATED: Tag = Tag::Universal(10);
+    pub const DEFAULT_UTF8_STRING: Tag = Tag::Universal(12);
+    pub const DEFAULT_SEQUENCE: Tag = Tag::Universal(16);
+    pub const DEFAULT_SEQUENCE_OF: Tag = Tag::Universal(16);
+    pub const DEFAULT_SET: Tag = Tag::Universal(17);
+    pub const DEFAULT_SET_OF: Tag = Tag::Universal(17);
+    pub const DEFAULT_NUMERIC_STRING: Tag = Tag::Universal(18);
+    pub const DEFAULT_PRINTABLE_STRING: Tag = Tag::Universal(19);
+    pub const DEFAULT_IA5_STRING: Tag = Tag::Universal(22);
+    pub const DEFAULT_UTC_TIME: Tag = Tag::Universal(23);
+    pub const DEFAULT_GENERALIZED_TIME: Tag = Tag::Universal(24);
+    pub const DEFAULT_VISIBLE_STRING: Tag = Tag::Universal(26);
+    pub const DEFAULT_UNIVERSAL_STRING: Tag = Tag::Universal(28);
+    pub const DEFAULT_BMP_STRING: Tag = Tag::Universal(30);
+
+    pub const fn value(self) -> usize {
+        match self {
+            Tag::Universal(value)
+            | Tag::Application(value)
+            | Tag::Private(value)
+            | Tag::ContextSpecific(value) => value,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::syn::{ReadableType, Reader, Tag, WritableType, Writer};
+use core::marker::PhantomData;
+
+/// Replaces the tag of the inner type `T` with the tag given by the [`Constraint`] or,
+/// if the tag is explicit, encloses the inner type in a value with that tag
+pub struct Tagged<T, C: Constraint>(PhantomData<T>, PhantomData<C>);
+
+impl<T, C: Constraint> Default for Tagged<T, C> {
+    fn default() -> Self {
+        Self(Default::default(), Default::default())
+    }
+}
+
+pub trait Constraint {
+    const TAG: Tag;
+    /// Whether the tag is encoded in addition to the tag of the inner type (`EXPLICIT`)
+    /// instead of replacing it (`IMPLICIT`)
+    const EXPLICIT: bool = false;
+}
+
+impl<T: WritableType, C: Constraint> WritableType for Tagged<T, C> {
+    type Type = T::Type;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_tagged::<C, T>(value)
+    }
+}
+
+impl<T: ReadableType, C: Constraint> ReadableType for Tagged<T, C> {
+    type Type = T::Type;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_tagged::<C, T>()
+    }
+}
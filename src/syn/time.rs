@@ -0,0 +1,501 @@
+use crate::syn::{ReadableType, Reader, Tag, WritableType, Writer};
+use core::fmt::{Display, Formatter};
+
+/// The useful time types, which are restricted `VisibleString`s (X.680-201508 46 and 47)
+/// with a string form that is parsed to and formatted from the value of the type.
+pub trait Time: Sized + Display {
+    const TAG: Tag;
+    /// The name of the type, as used by XER
+    const NAME: &'static str;
+
+    /// Parses the string form, returns `None` if it is malformed or not a valid point in time
+    fn parse(string: &str) -> Option<Self>;
+}
+
+/// The value of a `GeneralizedTime` (X.680-201508 46), a calendar date with the time of day
+/// in seconds and optional fractions of a second.
+///
+/// The string form `YYYYMMDDHH[MM[SS[.f...]]]` is optionally followed by `Z` for UTC or by
+/// the offset to UTC (`+HH[MM]` / `-HH[MM]`), fractions of hours and minutes are not
+/// supported. Values are always formatted with minutes and seconds and without trailing
+/// zeros in the fraction of a second, as required by DER (X.690-201508 11.7).
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct GeneralizedTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    /// `60` for a leap second
+    pub second: u8,
+    pub nanosecond: u32,
+    /// The offset to UTC in minutes, `None` for local time
+    pub offset: Option<i16>,
+}
+
+impl GeneralizedTime {
+    /// The start of the unix epoch, `19700101000000Z`
+    pub const UNIX_EPOCH: GeneralizedTime = GeneralizedTime {
+        year: 1970,
+        month: 1,
+        day: 1,
+        hour: 0,
+        minute: 0,
+        second: 0,
+        nanosecond: 0,
+        offset: Some(0),
+    };
+
+    /// The microseconds since the unix epoch, local time is interpreted as UTC
+    pub fn unix_timestamp_micros(&self) -> i64 {
+        let days = days_from_civil(i64::from(self.year), self.month, self.day);
+        let seconds = days * 86_400
+            + i64::from(self.hour) * 3_600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second)
+            - i64::from(self.offset.unwrap_or(0)) * 60;
+        seconds * 1_000_000 + i64::from(self.nanosecond / 1_000)
+    }
+
+    /// The UTC time of the given microseconds since the unix epoch, `None` if the year
+    /// cannot be represented with four digits
+    pub fn from_unix_timestamp_micros(micros: i64) -> Option<Self> {
+        let seconds = micros.div_euclid(1_000_000);
+        let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+        let second_of_day = seconds.rem_euclid(86_400);
+        if !(0..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self {
+            year: year as u16,
+            month,
+            day,
+            hour: (second_of_day / 3_600) as u8,
+            minute: (second_of_day / 60 % 60) as u8,
+            second: (second_of_day % 60) as u8,
+            nanosecond: micros.rem_euclid(1_000_000) as u32 * 1_000,
+            offset: Some(0),
+        })
+    }
+}
+
+impl Default for GeneralizedTime {
+    fn default() -> Self {
+        Self::UNIX_EPOCH
+    }
+}
+
+impl Time for GeneralizedTime {
+    const TAG: Tag = Tag::DEFAULT_GENERALIZED_TIME;
+    const NAME: &'static str = "GeneralizedTime";
+
+    fn parse(string: &str) -> Option<Self> {
+        let mut cursor = Cursor(string.as_bytes());
+        let year = cursor.digits(4)? as u16;
+        let month = cursor.digits(2)? as u8;
+        let day = cursor.digits(2)? as u8;
+        let hour = cursor.digits(2)? as u8;
+        let minute = cursor.opt_digits(2).unwrap_or(0) as u8;
+        let seconds = cursor.opt_digits(2);
+        let mut nanosecond = 0;
+        if seconds.is_some() && cursor.opt_separator(b'.', b',') {
+            nanosecond = cursor.fraction()?;
+        }
+        let offset = cursor.offset()?;
+        let time = Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second: seconds.unwrap_or(0) as u8,
+            nanosecond,
+            offset,
+        };
+        if cursor.0.is_empty() && is_valid(year, month, day, hour, minute, time.second) {
+            Some(time)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for GeneralizedTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:04}{:02}{:02}{:02}{:02}{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        if self.nanosecond > 0 {
+            let fraction = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        if let Some(offset) = self.offset {
+            write_offset(f, offset)?;
+        }
+        Ok(())
+    }
+}
+
+/// The value of an `UTCTime` (X.680-201508 47), with a two-digit year and the time of day
+/// in seconds.
+///
+/// The string form `YYMMDDhhmm[ss]` is followed by either `Z` for UTC or the offset to UTC
+/// (`+hhmm` / `-hhmm`). Values are always formatted with seconds.
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct UtcTime {
+    /// The last two digits of the year
+    pub year: u8,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    /// The offset to UTC in minutes
+    pub offset: i16,
+}
+
+impl UtcTime {
+    /// The full year, with the two-digit year being in the range from 1950 to 2049
+    /// (as in RFC 5280 4.1.2.5.1)
+    pub fn full_year(&self) -> u16 {
+        if self.year >= 50 {
+            1900 + u16::from(self.year)
+        } else {
+            2000 + u16::from(self.year)
+        }
+    }
+
+    /// The given time, if its year is within the range from 1950 to 2049, see
+    /// [`UtcTime::full_year`]
+    pub fn from_generalized_time(time: &GeneralizedTime) -> Option<Self> {
+        if (1950..2050).contains(&time.year) {
+            Some(Self {
+                year: (time.year % 100) as u8,
+                month: time.month,
+                day: time.day,
+                hour: time.hour,
+                minute: time.minute,
+                second: time.second,
+                offset: time.offset.unwrap_or(0),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for UtcTime {
+    fn default() -> Self {
+        Self::from_generalized_time(&GeneralizedTime::UNIX_EPOCH).unwrap()
+    }
+}
+
+impl From<&UtcTime> for GeneralizedTime {
+    fn from(time: &UtcTime) -> Self {
+        Self {
+            year: time.full_year(),
+            month: time.month,
+            day: time.day,
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            nanosecond: 0,
+            offset: Some(time.offset),
+        }
+    }
+}
+
+impl Time for UtcTime {
+    const TAG: Tag = Tag::DEFAULT_UTC_TIME;
+    const NAME: &'static str = "UTCTime";
+
+    fn parse(string: &str) -> Option<Self> {
+        let mut cursor = Cursor(string.as_bytes());
+        let time = Self {
+            year: cursor.digits(2)? as u8,
+            month: cursor.digits(2)? as u8,
+            day: cursor.digits(2)? as u8,
+            hour: cursor.digits(2)? as u8,
+            minute: cursor.digits(2)? as u8,
+            second: cursor.opt_digits(2).unwrap_or(0) as u8,
+            offset: cursor.offset()??,
+        };
+        if cursor.0.is_empty()
+            && time.second < 60
+            && is_valid(
+                time.full_year(),
+                time.month,
+                time.day,
+                time.hour,
+                time.minute,
+                time.second,
+            )
+        {
+            Some(time)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for UtcTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:02}{:02}{:02}{:02}{:02}{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        write_offset(f, self.offset)
+    }
+}
+
+fn write_offset(f: &mut Formatter<'_>, offset: i16) -> core::fmt::Result {
+    if offset == 0 {
+        write!(f, "Z")
+    } else {
+        write!(
+            f,
+            "{}{:02}{:02}",
+            if offset < 0 { '-' } else { '+' },
+            offset.abs() / 60,
+            offset.abs() % 60
+        )
+    }
+}
+
+fn is_valid(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> bool {
+    let leap_year = match (year % 4, year % 100, year % 400) {
+        (_, _, 0) => true,
+        (_, 0, _) => false,
+        (0, _, _) => true,
+        _ => false,
+    };
+    let days_in_month = match month {
+        2 if leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    (1..=12).contains(&month)
+        && (1..=days_in_month).contains(&day)
+        && hour < 24
+        && minute < 60
+        && second <= 60
+}
+
+/// The days since the unix epoch of the given date of the proleptic gregorian calendar
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (i64::from(month) + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The date of the proleptic gregorian calendar for the given days since the unix epoch
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month as u8, day as u8)
+}
+
+/// The remaining bytes of a string form that is being parsed
+struct Cursor<'a>(&'a [u8]);
+
+impl Cursor<'_> {
+    fn digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.0.get(..count)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.0 = &self.0[count..];
+        Some(
+            digits
+                .iter()
+                .fold(0, |value, digit| value * 10 + u32::from(digit - b'0')),
+        )
+    }
+
+    fn opt_digits(&mut self, count: usize) -> Option<u32> {
+        match self.0.first() {
+            Some(byte) if byte.is_ascii_digit() => self.digits(count),
+            _ => None,
+        }
+    }
+
+    fn opt_separator(&mut self, a: u8, b: u8) -> bool {
+        match self.0.first() {
+            Some(byte) if *byte == a || *byte == b => {
+                self.0 = &self.0[1..];
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The fraction of a second in nanoseconds, which has at least one and at most
+    /// nine digits
+    fn fraction(&mut self) -> Option<u32> {
+        let count = self.0.iter().take_while(|b| b.is_ascii_digit()).count();
+        if count == 0 || count > 9 {
+            return None;
+        }
+        Some(self.digits(count)? * 10_u32.pow(9 - count as u32))
+    }
+
+    /// The offset to UTC in minutes, `Some(None)` if there is none, `None` if it is malformed
+    fn offset(&mut self) -> Option<Option<i16>> {
+        if self.opt_separator(b'Z', b'Z') {
+            return Some(Some(0));
+        }
+        let sign = match self.0.first() {
+            Some(b'+') => 1,
+            Some(b'-') => -1,
+            _ => return Some(None),
+        };
+        self.0 = &self.0[1..];
+        let hours = self.digits(2)? as i16;
+        let minutes = self.opt_digits(2).unwrap_or(0) as i16;
+        if hours < 24 && minutes < 60 {
+            Some(Some(sign * (hours * 60 + minutes)))
+        } else {
+            None
+        }
+    }
+}
+
+impl WritableType for GeneralizedTime {
+    type Type = GeneralizedTime;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_time(value)
+    }
+}
+
+impl ReadableType for GeneralizedTime {
+    type Type = GeneralizedTime;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, R::Error> {
+        reader.read_time::<GeneralizedTime>()
+    }
+}
+
+impl WritableType for UtcTime {
+    type Type = UtcTime;
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_time(value)
+    }
+}
+
+impl ReadableType for UtcTime {
+    type Type = UtcTime;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, R::Error> {
+        reader.read_time::<UtcTime>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generalized_time_string_forms() {
+        let time = GeneralizedTime::parse("20201017123456.120Z").unwrap();
+        assert_eq!(
+            GeneralizedTime {
+                year: 2020,
+                month: 10,
+                day: 17,
+                hour: 12,
+                minute: 34,
+                second: 56,
+                nanosecond: 120_000_000,
+                offset: Some(0),
+            },
+            time
+        );
+        assert_eq!("20201017123456.12Z", time.to_string());
+
+        let local = GeneralizedTime::parse("2020101712").unwrap();
+        assert_eq!(None, local.offset);
+        assert_eq!("20201017120000", local.to_string());
+
+        let offset = GeneralizedTime::parse("20201017123400,5-0130").unwrap();
+        assert_eq!(Some(-90), offset.offset);
+        assert_eq!(500_000_000, offset.nanosecond);
+        assert_eq!("20201017123400.5-0130", offset.to_string());
+        assert_eq!(
+            "20201017123400+0200",
+            GeneralizedTime::parse("202010171234+02")
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_invalid_generalized_time() {
+        assert_eq!(None, GeneralizedTime::parse("2020101"));
+        assert_eq!(None, GeneralizedTime::parse("20201317120000Z"));
+        assert_eq!(None, GeneralizedTime::parse("20210229120000Z"));
+        assert_eq!(None, GeneralizedTime::parse("21000229120000Z"));
+        assert_eq!(None, GeneralizedTime::parse("20201017120000Y"));
+        assert_eq!(None, GeneralizedTime::parse("20201017120000.Z"));
+        assert_eq!(None, GeneralizedTime::parse("202010171200,5Z"));
+        assert!(GeneralizedTime::parse("20200229120000Z").is_some());
+        assert!(GeneralizedTime::parse("20000229120000Z").is_some());
+    }
+
+    #[test]
+    fn test_utc_time_string_forms() {
+        let time = UtcTime::parse("9912312359Z").unwrap();
+        assert_eq!(1999, time.full_year());
+        assert_eq!("991231235900Z", time.to_string());
+        assert_eq!(
+            "201017123456-0500",
+            UtcTime::parse("201017123456-0500").unwrap().to_string()
+        );
+        assert_eq!(None, UtcTime::parse("201017123456"));
+        assert_eq!(None, UtcTime::parse("201017123460Z"));
+    }
+
+    #[test]
+    fn test_unix_timestamp() {
+        assert_eq!(0, GeneralizedTime::UNIX_EPOCH.unix_timestamp_micros());
+        let time = GeneralizedTime::parse("20201017123456.5+0100").unwrap();
+        assert_eq!(1_602_934_496_500_000, time.unix_timestamp_micros());
+        assert_eq!(
+            "20201017113456.5Z",
+            GeneralizedTime::from_unix_timestamp_micros(1_602_934_496_500_000)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "19691231235959Z",
+            GeneralizedTime::from_unix_timestamp_micros(-1_000_000)
+                .unwrap()
+                .to_string()
+        );
+    }
+}
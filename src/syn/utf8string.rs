@@ -12,6 +12,8 @@ impl<C: Constraint> Default for Utf8String<C> {
 pub trait Constraint {
     const MIN: Option<usize> = None;
     const MAX: Option<usize> = None;
+    /// Whether the `SIZE` constraint is extensible, permitting sizes beyond `MIN` and `MAX`
+    const EXTENSIBLE: bool = false;
 }
 
 #[derive(Default)]
@@ -0,0 +1,199 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicAper DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Potato ::= SEQUENCE {
+        size INTEGER,
+        size2 INTEGER,
+        size3 INTEGER(12..128),
+        string UTF8String
+    }
+
+    Topping ::= ENUMERATED {
+        not_pineapple,
+        even_less_pineapple,
+        no_pineapple_at_all
+    }
+
+    Pizza ::= SEQUENCE {
+        size INTEGER(1..4),
+        topping Topping
+    }
+
+    WhatToEat ::= CHOICE {
+        potato Potato,
+        pizza Pizza
+    }
+
+    Extensible ::= CHOICE {
+        abc UTF8String,
+        def INTEGER,
+        ...,
+        ghi BOOLEAN
+    }
+
+    Wide ::= SEQUENCE {
+        flag BOOLEAN,
+        value INTEGER(0..65535),
+        binary OCTET STRING
+    }
+
+    Root ::= SEQUENCE {
+        abc INTEGER(0..255),
+        def BOOLEAN OPTIONAL,
+        ...
+    }
+
+    Extended ::= SEQUENCE {
+        abc INTEGER(0..255),
+        def BOOLEAN OPTIONAL,
+        ...,
+        ghi INTEGER(0..255) OPTIONAL,
+        [[ jkl BOOLEAN OPTIONAL, mno UTF8String OPTIONAL ]]
+    }
+
+    END"
+);
+
+fn serialize_and_deserialize_aper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = AperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_pizza_is_not_aligned() {
+    // https://asn1.io/asn1playground/
+    serialize_and_deserialize_aper(
+        5,
+        &[0xC8],
+        &WhatToEat::Pizza(Pizza {
+            size: 3,
+            topping: Topping::EvenLessPineapple,
+        }),
+    );
+}
+
+#[test]
+fn test_potato_is_aligned() {
+    // https://asn1.io/asn1playground/
+    serialize_and_deserialize_aper(
+        24 * 8,
+        &[
+            0x00, 0x01, 0x0D, 0x01, 0x25, 0x3C, 0x11, 0x73, 0x75, 0x63, 0x68, 0x20, 0x74, 0x61,
+            0x73, 0x74, 0x79, 0x20, 0x70, 0x6F, 0x74, 0x61, 0x74, 0x6F,
+        ],
+        &WhatToEat::Potato(Potato {
+            size: 13,
+            size2: 37,
+            size3: 42,
+            string: "such tasty potato".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn test_extensible_choice() {
+    serialize_and_deserialize_aper(24, &[0x40, 0x01, 0x05], &Extensible::Def(5));
+    // extension bit, normally small index, aligned open type length and content
+    serialize_and_deserialize_aper(24, &[0x80, 0x01, 0x80], &Extensible::Ghi(true));
+}
+
+#[test]
+fn test_wide_sequence() {
+    serialize_and_deserialize_aper(
+        6 * 8,
+        &[0x80, 0x05, 0x39, 0x02, 0x13, 0x37],
+        &Wide {
+            flag: true,
+            value: 1337,
+            binary: vec![0x13, 0x37],
+        },
+    );
+}
+
+fn extended() -> Extended {
+    Extended {
+        abc: 5,
+        def: None,
+        ghi: None,
+        jkl: None,
+        mno: None,
+    }
+}
+
+#[test]
+fn test_extensible_sequence_without_extension_additions() {
+    // extension bit, def-flag, aligned abc, def
+    serialize_and_deserialize_aper(
+        17,
+        &[0x40, 0x05, 0x80],
+        &Root {
+            abc: 5,
+            def: Some(true),
+        },
+    );
+    serialize_and_deserialize_aper(
+        17,
+        &[0x40, 0x05, 0x80],
+        &Extended {
+            def: Some(true),
+            ..extended()
+        },
+    );
+}
+
+#[test]
+fn test_extension_addition() {
+    // extension bit, def-flag, aligned abc, bitmap length - 1, bitmap, ghi as aligned open type
+    serialize_and_deserialize_aper(
+        48,
+        &[0x80, 0x05, 0x03, 0x00, 0x01, 0x07],
+        &Extended {
+            ghi: Some(7),
+            ..extended()
+        },
+    );
+}
+
+#[test]
+fn test_extension_addition_group() {
+    // the group is encoded like a SEQUENCE with a preamble for jkl and mno
+    serialize_and_deserialize_aper(
+        48,
+        &[0x80, 0x05, 0x02, 0x80, 0x01, 0xA0],
+        &Extended {
+            jkl: Some(true),
+            ..extended()
+        },
+    );
+}
+
+#[test]
+fn test_unknown_extension_additions_are_skipped() {
+    let mut reader =
+        AperReader::from_bits(vec![0x80, 0x05, 0x03, 0x80, 0x01, 0x07, 0x01, 0xA0], 64);
+    assert_eq!(Ok(Root { abc: 5, def: None }), reader.read::<Root>());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_missing_extension_additions_are_absent() {
+    let mut reader = AperReader::from_bits(vec![0x40, 0x05, 0x00], 17);
+    assert_eq!(
+        Ok(Extended {
+            def: Some(false),
+            ..extended()
+        }),
+        reader.read::<Extended>()
+    );
+}
@@ -0,0 +1,173 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicBitString DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Permissions ::= BIT STRING { readable(0), writable(1), executable(2) } (SIZE(3))
+
+    Mask ::= BIT STRING (SIZE(1..16))
+
+    Unconstrained ::= BIT STRING
+
+    Message ::= SEQUENCE {
+        flags BIT STRING { urgent(0), secret(3) },
+        mask Mask OPTIONAL
+    }
+
+    END"
+);
+
+fn permissions() -> Permissions {
+    let mut permissions = Permissions::default();
+    permissions.set_readable(true);
+    permissions.set_executable(true);
+    permissions
+}
+
+fn mask() -> Mask {
+    Mask(BitVec::from_bytes(vec![0xCC, 0xFF], 10))
+}
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = UperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_named_bits() {
+    let mut permissions = permissions();
+    assert!(permissions.is_readable_set());
+    assert!(!permissions.is_writable_set());
+    assert!(permissions.is_executable_set());
+    assert_eq!(3, permissions.0.bit_len());
+
+    permissions.set_readable(false);
+    assert!(!permissions.is_readable_set());
+    assert_eq!(&[0x20], permissions.0.as_byte_slice());
+}
+
+#[test]
+fn test_named_bits_of_field() {
+    let mut message = Message::default();
+    message.set_flags_secret(true);
+    assert!(message.is_flags_secret_set());
+    assert!(!message.is_flags_urgent_set());
+    assert_eq!(4, message.flags.bit_len());
+}
+
+#[test]
+fn test_uper_fixed_size_has_no_length() {
+    serialize_and_deserialize_uper(3, &[0xA0], &permissions());
+}
+
+#[test]
+fn test_uper_constrained_length() {
+    // 4 bits for the length of 10 within 1..16, followed by the bits
+    serialize_and_deserialize_uper(14, &[0x9C, 0xCC], &mask());
+}
+
+#[test]
+fn test_uper_unconstrained_length() {
+    serialize_and_deserialize_uper(
+        8 + 12,
+        &[0x0C, 0xCA, 0xF0],
+        &Unconstrained(BitVec::from_bytes(vec![0xCA, 0xFE], 12)),
+    );
+}
+
+#[test]
+fn test_uper_sequence() {
+    let mut message = Message {
+        flags: BitVec::default(),
+        mask: Some(mask()),
+    };
+    message.set_flags_urgent(true);
+    serialize_and_deserialize_uper(1 + 8 + 1 + 14, &[0x80, 0xE7, 0x33], &message);
+}
+
+#[test]
+fn test_uper_size_not_in_range() {
+    let mut writer = UperWriter::default();
+    assert!(writer
+        .write(&Mask(BitVec::from_bytes(vec![0xFF; 3], 17)))
+        .is_err());
+}
+
+#[test]
+fn test_aper_aligns_variable_size() {
+    let mut writer = AperWriter::default();
+    writer.write(&mask()).unwrap();
+    assert_eq!(
+        (18, &[0x90, 0xCC, 0xC0][..]),
+        (writer.bit_len(), writer.byte_content())
+    );
+    assert_eq!(mask(), writer.into_reader().read::<Mask>().unwrap());
+}
+
+#[test]
+fn test_der_has_unused_bits_octet() {
+    let mut writer = DerWriter::default();
+    writer.write(&permissions()).unwrap();
+    assert_eq!(&[0x03, 0x02, 0x05, 0xA0], writer.byte_content());
+    let mut reader = BerReader::from_bytes(writer.into_bytes_vec());
+    assert_eq!(permissions(), reader.read::<Permissions>().unwrap());
+}
+
+#[test]
+fn test_oer() {
+    let mut writer = OerWriter::default();
+    writer.write(&permissions()).unwrap();
+    writer.write(&mask()).unwrap();
+    assert_eq!(&[0xA0, 0x03, 0x06, 0xCC, 0xC0], writer.byte_content());
+    let mut reader = OerReader::from_canonical_bytes(writer.into_bytes_vec());
+    assert_eq!(permissions(), reader.read::<Permissions>().unwrap());
+    assert_eq!(mask(), reader.read::<Mask>().unwrap());
+}
+
+#[test]
+fn test_jer() {
+    let mut writer = JerWriter::default();
+    writer.write(&permissions()).unwrap();
+    assert_eq!("\"A0\"", writer.json());
+    assert_eq!(
+        permissions(),
+        writer.into_reader().read::<Permissions>().unwrap()
+    );
+
+    let mut writer = JerWriter::default();
+    writer.write(&mask()).unwrap();
+    assert_eq!(r#"{"value":"CCC0","length":10}"#, writer.json());
+    assert_eq!(mask(), writer.into_reader().read::<Mask>().unwrap());
+}
+
+#[test]
+fn test_xer() {
+    let mut writer = XerWriter::default();
+    writer.write(&permissions()).unwrap();
+    assert_eq!("<BIT_STRING>101</BIT_STRING>", writer.xml());
+    let mut reader = XerReader::from_xml(writer.xml()).unwrap();
+    assert_eq!(permissions(), reader.read::<Permissions>().unwrap());
+}
+
+#[test]
+fn test_protobuf() {
+    let mut message = Message::default();
+    message.set_flags_urgent(true);
+
+    let mut buffer = Vec::default();
+    let writer = &mut buffer as &mut dyn ProtobufWriter;
+    message.write_protobuf(writer).unwrap();
+    assert_eq!(&[0x0A, 0x02, 0x07, 0x80_u8], &buffer[..]);
+
+    let reader = &mut &buffer[..] as &mut dyn ProtobufReader;
+    assert_eq!(message, Message::read_protobuf(reader).unwrap());
+}
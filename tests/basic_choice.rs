@@ -114,7 +114,7 @@ fn test_extensible_choice_inner_complex() {
 fn test_basic_variants_parsed() {
     let _abc = Basic::Abc(String::default());
     let _def = Basic::Def(String::default());
-    let _ghi = Basic::Ghi(123_u64);
+    let _ghi = Basic::Ghi(123_i64);
 
     match Basic::Abc(String::default()) {
         // this does not compile if there are additional unexpected variants
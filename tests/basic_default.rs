@@ -0,0 +1,106 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r#"BasicDefault DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Color ::= ENUMERATED { red, green, blue }
+
+    Defaults ::= SEQUENCE {
+        number INTEGER (0..255) DEFAULT 42,
+        flag BOOLEAN DEFAULT TRUE,
+        color Color DEFAULT green,
+        name UTF8String DEFAULT "abc"
+    }
+
+    END"#
+);
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = UperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_default_values_and_constants() {
+    assert_eq!(
+        (42, true, Color::Green, "abc"),
+        (
+            Defaults::DEFAULT_NUMBER,
+            Defaults::DEFAULT_FLAG,
+            Defaults::DEFAULT_COLOR,
+            Defaults::DEFAULT_NAME
+        )
+    );
+    assert_eq!(
+        Defaults {
+            number: 42,
+            flag: true,
+            color: Color::Green,
+            name: "abc".to_string(),
+        },
+        Defaults::default()
+    );
+}
+
+#[test]
+fn test_uper_omits_default_values() {
+    // only the presence bits, all of which are unset
+    serialize_and_deserialize_uper(4, &[0x00], &Defaults::default());
+}
+
+#[test]
+fn test_uper_number_other_than_default() {
+    serialize_and_deserialize_uper(
+        12,
+        &[0x80, 0x70],
+        &Defaults {
+            number: 7,
+            ..Defaults::default()
+        },
+    );
+}
+
+#[test]
+fn test_uper_flag_other_than_default() {
+    serialize_and_deserialize_uper(
+        5,
+        &[0x40],
+        &Defaults {
+            flag: false,
+            ..Defaults::default()
+        },
+    );
+}
+
+#[test]
+fn test_uper_color_other_than_default() {
+    serialize_and_deserialize_uper(
+        6,
+        &[0x28],
+        &Defaults {
+            color: Color::Blue,
+            ..Defaults::default()
+        },
+    );
+}
+
+#[test]
+fn test_uper_name_other_than_default() {
+    serialize_and_deserialize_uper(
+        20,
+        &[0x10, 0x17, 0x80],
+        &Defaults {
+            name: "x".to_string(),
+            ..Defaults::default()
+        },
+    );
+}
@@ -0,0 +1,246 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicDer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Potato ::= SEQUENCE {
+        size INTEGER,
+        size2 INTEGER,
+        size3 INTEGER(12..128),
+        string UTF8String
+    }
+
+    Topping ::= ENUMERATED {
+        not_pineapple,
+        even_less_pineapple,
+        no_pineapple_at_all
+    }
+
+    Pizza ::= SEQUENCE {
+        size INTEGER(1..4),
+        topping Topping
+    }
+
+    WhatToEat ::= CHOICE {
+        potato [0] Potato,
+        pizza [1] Pizza
+    }
+
+    Container ::= SEQUENCE {
+        flag [0] BOOLEAN OPTIONAL,
+        value [1] INTEGER,
+        name [APPLICATION 2] UTF8String,
+        what [3] WhatToEat
+    }
+
+    Vegetables ::= SEQUENCE OF UTF8String
+
+    Color ::= ENUMERATED {
+        red,
+        green,
+        darkBlue(5)
+    }
+
+    Alt ::= CHOICE {
+        firstOne BOOLEAN,
+        secondOne BOOLEAN
+    }
+
+    Msg ::= SEQUENCE {
+        timeStamp INTEGER(0..10),
+        color Color,
+        alt Alt
+    }
+
+    TbsCertificate ::= SEQUENCE {
+        version [0] EXPLICIT INTEGER(0..2),
+        serialNumber INTEGER
+    }
+
+    END"
+);
+
+fn serialize_and_deserialize_der<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = DerWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!(data, writer.byte_content());
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bytes_remaining());
+}
+
+fn potato() -> Potato {
+    Potato {
+        size: 13,
+        size2: 37,
+        size3: 42,
+        string: "such tasty potato".to_string(),
+    }
+}
+
+#[test]
+fn test_automatically_tagged_sequence() {
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x1C, 0x80, 0x01, 0x0D, 0x81, 0x01, 0x25, 0x82, 0x01, 0x2A, 0x83, 0x11, 0x73,
+            0x75, 0x63, 0x68, 0x20, 0x74, 0x61, 0x73, 0x74, 0x79, 0x20, 0x70, 0x6F, 0x74, 0x61,
+            0x74, 0x6F,
+        ],
+        &potato(),
+    );
+}
+
+#[test]
+fn test_tagged_fields_and_variants() {
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x11, // SEQUENCE
+            0x81, 0x01, 0x05, // [1] IMPLICIT INTEGER
+            0x42, 0x02, 0x68, 0x69, // [APPLICATION 2] IMPLICIT UTF8String
+            0xA3, 0x08, // [3] EXPLICIT, because CHOICE
+            0xA1, 0x06, // [1] IMPLICIT SEQUENCE
+            0x80, 0x01, 0x03, // [0] IMPLICIT INTEGER, tagged automatically
+            0x81, 0x01, 0x01, // [1] IMPLICIT ENUMERATED, tagged automatically
+        ],
+        &Container {
+            flag: None,
+            value: 5,
+            name: "hi".to_string(),
+            what: WhatToEat::Pizza(Pizza {
+                size: 3,
+                topping: Topping::EvenLessPineapple,
+            }),
+        },
+    );
+}
+
+#[test]
+fn test_tagged_optional_present() {
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x14, 0x80, 0x01, 0xFF, 0x81, 0x01, 0x05, 0x42, 0x02, 0x68, 0x69, 0xA3, 0x08,
+            0xA1, 0x06, 0x80, 0x01, 0x03, 0x81, 0x01, 0x01,
+        ],
+        &Container {
+            flag: Some(true),
+            value: 5,
+            name: "hi".to_string(),
+            what: WhatToEat::Pizza(Pizza {
+                size: 3,
+                topping: Topping::EvenLessPineapple,
+            }),
+        },
+    );
+}
+
+#[test]
+fn test_ber_indefinite_length() {
+    let mut reader = BerReader::from_bytes(vec![
+        0x30, 0x80, // SEQUENCE, indefinite length
+        0x81, 0x01, 0x05, // [1] IMPLICIT INTEGER
+        0x42, 0x02, 0x68, 0x69, // [APPLICATION 2] IMPLICIT UTF8String
+        0xA3, 0x80, // [3] EXPLICIT, indefinite length
+        0xA1, 0x80, // [1] IMPLICIT SEQUENCE, indefinite length
+        0x80, 0x01, 0x03, // [0] IMPLICIT INTEGER
+        0x81, 0x01, 0x01, // [1] IMPLICIT ENUMERATED
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ]);
+    assert_eq!(
+        Container {
+            flag: None,
+            value: 5,
+            name: "hi".to_string(),
+            what: WhatToEat::Pizza(Pizza {
+                size: 3,
+                topping: Topping::EvenLessPineapple,
+            }),
+        },
+        reader.read::<Container>().unwrap()
+    );
+    assert_eq!(0, reader.bytes_remaining());
+}
+
+#[test]
+fn test_ber_skips_unknown_trailing_values() {
+    let mut reader = BerReader::from_bytes(vec![
+        0x30, 0x08, 0x80, 0x01, 0x03, 0x81, 0x01, 0x01, 0x85, 0x00,
+    ]);
+    assert_eq!(
+        Pizza {
+            size: 3,
+            topping: Topping::EvenLessPineapple,
+        },
+        reader.read::<Pizza>().unwrap()
+    );
+    assert_eq!(0, reader.bytes_remaining());
+}
+
+#[test]
+fn test_transparent_type_has_no_sequence() {
+    serialize_and_deserialize_der(&[0x30, 0x00], &Vegetables(Vec::new()));
+}
+
+#[test]
+fn test_automatically_tagged_enumerated_and_choice() {
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x0B, // SEQUENCE
+            0x80, 0x01, 0x03, // [0] IMPLICIT INTEGER
+            0x81, 0x01, 0x01, // [1] IMPLICIT ENUMERATED
+            0xA2, 0x03, // [2] EXPLICIT, because CHOICE
+            0x81, 0x01, 0xFF, // [1] IMPLICIT BOOLEAN
+        ],
+        &Msg {
+            time_stamp: 3,
+            color: Color::Green,
+            alt: Alt::SecondOne(true),
+        },
+    );
+}
+
+#[test]
+fn test_explicitly_tagged_field() {
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x08, // SEQUENCE
+            0xA0, 0x03, // [0] EXPLICIT
+            0x02, 0x01, 0x02, // INTEGER
+            0x02, 0x01, 0x05, // INTEGER
+        ],
+        &TbsCertificate {
+            version: 2,
+            serial_number: 5,
+        },
+    );
+}
+
+#[test]
+fn test_enumerated_number() {
+    serialize_and_deserialize_der(
+        &[
+            0x30, 0x0B, // SEQUENCE
+            0x80, 0x01, 0x03, // [0] IMPLICIT INTEGER
+            0x81, 0x01, 0x05, // [1] IMPLICIT ENUMERATED, the number of darkBlue
+            0xA2, 0x03, // [2] EXPLICIT, because CHOICE
+            0x80, 0x01, 0xFF, // [0] IMPLICIT BOOLEAN
+        ],
+        &Msg {
+            time_stamp: 3,
+            color: Color::DarkBlue,
+            alt: Alt::FirstOne(true),
+        },
+    );
+}
+
+#[test]
+fn test_ber_unknown_enumerated_number() {
+    let mut reader = BerReader::from_bytes(vec![0x0A, 0x01, 0x02]);
+    assert_eq!(
+        Err(asn1rs::io::ber::Error::UnknownEnumeratedNumber(2, "Color")),
+        reader.read::<Color>()
+    );
+}
@@ -1,5 +1,7 @@
+use asn1rs::io::uper::Error as UperError;
 use asn1rs::prelude::*;
 use asn1rs::syn::io::UperWriter as NewUperWriter;
+use asn1rs::syn::numbers::Constraint;
 
 asn_to_rust!(
     r"BasicInteger DEFINITIONS AUTOMATIC TAGS ::=
@@ -9,14 +11,69 @@ asn_to_rust!(
     
     NotRanged ::= Integer
     
+    SemiConstrained ::= INTEGER (-5..MAX)
+    
+    UpperBounded ::= INTEGER (MIN..7)
+    
+    Extensible ::= INTEGER (0..255, ...)
+    
     END"
 );
 
+/// `INTEGER (0..255, ...)` for Rust types other than the generated `i64`
+struct ExtensibleByte;
+
+impl Constraint<i64> for ExtensibleByte {
+    const MIN: Option<i64> = Some(0);
+    const MAX: Option<i64> = Some(255);
+    const EXTENSIBLE: bool = true;
+}
+
+impl Constraint<u8> for ExtensibleByte {
+    const MIN: Option<u8> = Some(0);
+    const MAX: Option<u8> = Some(255);
+    const EXTENSIBLE: bool = true;
+}
+
+impl Constraint<u64> for ExtensibleByte {
+    const MIN: Option<u64> = Some(0);
+    const MAX: Option<u64> = Some(255);
+    const EXTENSIBLE: bool = true;
+}
+
+/// `INTEGER (1..MAX)`
+struct PositiveU64;
+
+impl Constraint<u64> for PositiveU64 {
+    const MIN: Option<u64> = Some(1);
+}
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = NewUperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
 #[test]
 fn test_default_range() {
-    assert_eq!(RangedMax::value_min(), NotRanged::value_min());
-    assert_eq!(RangedMax::value_max(), NotRanged::value_max());
-    let _ = NotRanged(123_u64); // does not compile if the inner type differs
+    let _ = RangedMax(123_u64); // does not compile if the inner type differs
+    let _ = NotRanged(-123_i64); // does not compile if the inner type differs
+    let _ = SemiConstrained(-5_i64); // does not compile if the inner type differs
+    let _ = UpperBounded(-5_i64); // does not compile if the inner type differs
+    assert_eq!(i64::MIN, NotRanged::value_min());
+    assert_eq!(i64::MAX, NotRanged::value_max());
+    assert_eq!(-5, SemiConstrained::value_min());
+    assert_eq!(7, UpperBounded::value_max());
+    let _ = Extensible(256_i64); // does not compile if the inner type differs
+    assert_eq!(0, Extensible::value_min());
+    assert_eq!(255, Extensible::value_max());
 }
 
 #[test]
@@ -46,3 +103,144 @@ fn test_protobuf() {
     RangedMax(66_000).write_protobuf(writer).unwrap();
     assert_eq!(&[0x08, 0x80 | 80_u8, 0x80 | 3, 4], &buffer[..]);
 }
+
+#[test]
+fn test_uper_unconstrained_twos_complement() {
+    serialize_and_deserialize_uper(16, &[0x01, 0xFF], &NotRanged(-1));
+    serialize_and_deserialize_uper(24, &[0x02, 0x00, 0x80], &NotRanged(128));
+    serialize_and_deserialize_uper(
+        72,
+        &[0x08, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        &NotRanged(i64::MIN),
+    );
+}
+
+#[test]
+fn test_uper_semi_constrained_offset() {
+    serialize_and_deserialize_uper(16, &[0x01, 0x00], &SemiConstrained(-5));
+    // 305 above the lower bound
+    serialize_and_deserialize_uper(24, &[0x02, 0x01, 0x31], &SemiConstrained(300));
+}
+
+#[test]
+fn test_uper_semi_constrained_below_lower_bound() {
+    let mut writer = NewUperWriter::default();
+    assert!(writer.write(&SemiConstrained(-6)).is_err());
+}
+
+#[test]
+fn test_uper_upper_bound_only_is_unconstrained() {
+    serialize_and_deserialize_uper(24, &[0x02, 0xFC, 0x18], &UpperBounded(-1000));
+}
+
+#[test]
+fn test_uper_extensible_within_range() {
+    // the extension bit and 8 bits for the value within 0..255
+    serialize_and_deserialize_uper(9, &[0x02, 0x80], &Extensible(5));
+}
+
+#[test]
+fn test_uper_extensible_outside_of_range() {
+    // the extension bit followed by the value as unconstrained whole number
+    serialize_and_deserialize_uper(25, &[0x81, 0x00, 0x80, 0x00], &Extensible(256));
+    serialize_and_deserialize_uper(17, &[0x80, 0xFF, 0x80], &Extensible(-1));
+}
+
+#[test]
+fn test_uper_upper_bound_only_is_checked() {
+    let mut writer = NewUperWriter::default();
+    assert_eq!(
+        Err(UperError::ValueNotInRange(8, i64::MIN, 7)),
+        writer.write(&UpperBounded(8))
+    );
+
+    let mut writer = NewUperWriter::default();
+    writer.write(&NotRanged(8)).unwrap();
+    assert_eq!(
+        Err(UperError::ValueNotInRange(8, i64::MIN, 7)),
+        writer.into_reader().read::<UpperBounded>()
+    );
+}
+
+#[test]
+fn test_uper_value_outside_of_extensible_range_does_not_fit_into_u8() {
+    let mut writer = NewUperWriter::default();
+    Integer::<i64, ExtensibleByte>::write_value(&mut writer, &300).unwrap();
+    assert_eq!(
+        Err(UperError::ValueNotInRange(300, 0, 255)),
+        Integer::<u8, ExtensibleByte>::read_value(&mut writer.into_reader())
+    );
+}
+
+#[test]
+fn test_uper_u64_above_i64_max_is_too_large() {
+    let mut writer = NewUperWriter::default();
+    assert_eq!(
+        Err(UperError::ValueTooLarge(u64::MAX)),
+        Integer::<u64, PositiveU64>::write_value(&mut writer, &u64::MAX)
+    );
+}
+
+fn serialize_and_deserialize_uper_u64(bits: usize, data: &[u8], value: u64) {
+    let mut writer = NewUperWriter::default();
+    Integer::<u64, ExtensibleByte>::write_value(&mut writer, &value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(
+        Ok(value),
+        Integer::<u64, ExtensibleByte>::read_value(&mut reader)
+    );
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_uper_extensible_at_the_bounds_of_the_range() {
+    serialize_and_deserialize_uper(9, &[0x00, 0x00], &Extensible(0));
+    serialize_and_deserialize_uper(9, &[0x7F, 0x80], &Extensible(255));
+}
+
+#[test]
+fn test_uper_extensible_below_the_range() {
+    serialize_and_deserialize_uper(17, &[0x80, 0xFF, 0x80], &Extensible(-1));
+    serialize_and_deserialize_uper(
+        73,
+        &[0x84, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        &Extensible(i64::MIN),
+    );
+}
+
+#[test]
+fn test_uper_extensible_above_the_range() {
+    serialize_and_deserialize_uper(25, &[0x81, 0x00, 0x80, 0x00], &Extensible(256));
+    serialize_and_deserialize_uper(
+        73,
+        &[0x84, 0x3F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x80],
+        &Extensible(i64::MAX),
+    );
+}
+
+#[test]
+fn test_uper_extensible_u64() {
+    serialize_and_deserialize_uper_u64(9, &[0x00, 0x00], 0);
+    serialize_and_deserialize_uper_u64(9, &[0x7F, 0x80], 255);
+    serialize_and_deserialize_uper_u64(25, &[0x81, 0x00, 0x80, 0x00], 256);
+}
+
+#[test]
+fn test_uper_extensible_u64_above_i64_max_is_too_large() {
+    let mut writer = NewUperWriter::default();
+    assert_eq!(
+        Err(UperError::ValueTooLarge(u64::MAX)),
+        Integer::<u64, ExtensibleByte>::write_value(&mut writer, &u64::MAX)
+    );
+}
+
+#[test]
+fn test_uper_negative_value_outside_of_extensible_range_does_not_fit_into_u64() {
+    let mut writer = NewUperWriter::default();
+    writer.write(&Extensible(-1)).unwrap();
+    assert_eq!(
+        Err(UperError::ValueNotInRange(-1, 0, i64::MAX)),
+        Integer::<u64, ExtensibleByte>::read_value(&mut writer.into_reader())
+    );
+}
@@ -0,0 +1,132 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicJer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Topping ::= ENUMERATED {
+        notPineapple,
+        evenLessPineapple,
+        noPineappleAtAll
+    }
+
+    Pizza ::= SEQUENCE {
+        size INTEGER(1..4),
+        topping Topping
+    }
+
+    WhatToEat ::= CHOICE {
+        pizza Pizza,
+        water OCTET STRING
+    }
+
+    Order ::= SEQUENCE {
+        id INTEGER,
+        note UTF8String OPTIONAL,
+        paid BOOLEAN,
+        items SEQUENCE OF WhatToEat
+    }
+
+    Drink ::= SEQUENCE {
+        sugar-free BOOLEAN,
+        type UTF8String
+    }
+
+    END"
+);
+
+fn serialize_and_deserialize_jer<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    json: &str,
+    value: &T,
+) {
+    let mut writer = JerWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!(json, writer.json());
+    let mut reader = JerReader::from_json(json).unwrap();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+}
+
+fn order() -> Order {
+    Order {
+        id: 1337,
+        note: None,
+        paid: true,
+        items: vec![
+            WhatToEat::Pizza(Pizza {
+                size: 3,
+                topping: Topping::EvenLessPineapple,
+            }),
+            WhatToEat::Water(vec![0xCA, 0xFE]),
+        ],
+    }
+}
+
+#[test]
+fn test_enumerated_is_identifier() {
+    serialize_and_deserialize_jer(r#""noPineappleAtAll""#, &Topping::NoPineappleAtAll);
+}
+
+#[test]
+fn test_members_are_named_as_in_the_definition() {
+    serialize_and_deserialize_jer(
+        r#"{"sugar-free":true,"type":"Lemonade"}"#,
+        &Drink {
+            sugar_free: true,
+            type_: "Lemonade".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_sequence_omits_absent_optional() {
+    serialize_and_deserialize_jer(
+        r#"{"id":1337,"paid":true,"items":[{"pizza":{"size":3,"topping":"evenLessPineapple"}},{"water":"CAFE"}]}"#,
+        &order(),
+    );
+}
+
+#[test]
+fn test_sequence_with_present_optional() {
+    serialize_and_deserialize_jer(
+        r#"{"id":1337,"note":"extra \"cheesy\"","paid":true,"items":[]}"#,
+        &Order {
+            note: Some("extra \"cheesy\"".to_string()),
+            items: Vec::new(),
+            ..order()
+        },
+    );
+}
+
+#[test]
+fn test_member_order_and_unknown_members_are_ignored() {
+    let mut reader =
+        JerReader::from_json(r#" { "paid" : false, "unknown": [1, 2], "items": [], "id": 5 } "#)
+            .unwrap();
+    assert_eq!(
+        Order {
+            id: 5,
+            note: None,
+            paid: false,
+            items: Vec::new(),
+        },
+        reader.read::<Order>().unwrap()
+    );
+}
+
+#[test]
+fn test_missing_mandatory_field() {
+    let mut reader = JerReader::from_json(r#"{"size":3}"#).unwrap();
+    assert_eq!(
+        Err(asn1rs::io::jer::Error::MissingField("topping")),
+        reader.read::<Pizza>()
+    );
+}
+
+#[test]
+fn test_unknown_variant() {
+    let mut reader = JerReader::from_json(r#"{"Potato":{}}"#).unwrap();
+    assert_eq!(
+        Err(asn1rs::io::jer::Error::UnknownVariant("Potato".to_string())),
+        reader.read::<WhatToEat>()
+    );
+}
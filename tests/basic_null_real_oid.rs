@@ -0,0 +1,187 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicNullRealOid DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Reading ::= SEQUENCE {
+        sensor OBJECT IDENTIFIER,
+        value REAL
+    }
+
+    MaybeReading ::= CHOICE {
+        absent NULL,
+        reading Reading
+    }
+
+    Temperature ::= REAL
+
+    END"
+);
+
+fn reading() -> Reading {
+    Reading {
+        sensor: ObjectIdentifier::parse("1.3.6.1").unwrap(),
+        value: 1.0,
+    }
+}
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = UperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_uper_sequence() {
+    serialize_and_deserialize_uper(
+        64,
+        &[0x03, 0x2B, 0x06, 0x01, 0x03, 0x80, 0x00, 0x01],
+        &reading(),
+    );
+}
+
+#[test]
+fn test_uper_null_has_no_bits() {
+    serialize_and_deserialize_uper(1, &[0x00], &MaybeReading::Absent(Null));
+}
+
+#[test]
+fn test_uper_special_real_values() {
+    serialize_and_deserialize_uper(8, &[0x00], &Temperature(0.0));
+    serialize_and_deserialize_uper(16, &[0x01, 0x40], &Temperature(f64::INFINITY));
+    serialize_and_deserialize_uper(16, &[0x01, 0x43], &Temperature(-0.0));
+}
+
+#[test]
+fn test_aper() {
+    let mut writer = AperWriter::default();
+    writer.write(&MaybeReading::Reading(reading())).unwrap();
+    assert_eq!(
+        &[0x80, 0x03, 0x2B, 0x06, 0x01, 0x03, 0x80, 0x00, 0x01],
+        writer.byte_content()
+    );
+    assert_eq!(
+        MaybeReading::Reading(reading()),
+        writer.into_reader().read::<MaybeReading>().unwrap()
+    );
+}
+
+#[test]
+fn test_der() {
+    let mut writer = DerWriter::default();
+    writer.write(&Temperature(-3.0)).unwrap();
+    assert_eq!(&[0x09, 0x03, 0xC0, 0x00, 0x03], writer.byte_content());
+    let mut reader = BerReader::from_bytes(writer.into_bytes_vec());
+    assert_eq!(Temperature(-3.0), reader.read::<Temperature>().unwrap());
+
+    let mut writer = DerWriter::default();
+    writer.write(&MaybeReading::Absent(Null)).unwrap();
+    writer.write(&reading()).unwrap();
+    let mut reader = BerReader::from_bytes(writer.into_bytes_vec());
+    assert_eq!(
+        MaybeReading::Absent(Null),
+        reader.read::<MaybeReading>().unwrap()
+    );
+    assert_eq!(reading(), reader.read::<Reading>().unwrap());
+}
+
+#[test]
+fn test_oer() {
+    let mut writer = OerWriter::default();
+    writer.write(&reading()).unwrap();
+    assert_eq!(
+        &[0x03, 0x2B, 0x06, 0x01, 0x03, 0x80, 0x00, 0x01],
+        writer.byte_content()
+    );
+    let mut reader = OerReader::from_canonical_bytes(writer.into_bytes_vec());
+    assert_eq!(reading(), reader.read::<Reading>().unwrap());
+
+    // the decimal form of 1.0 is valid in OER but not in COER
+    let decimal = vec![0x04, 0x01, b'1', b'.', b'0'];
+    let mut reader = OerReader::from_bytes(decimal.clone());
+    assert_eq!(Temperature(1.0), reader.read::<Temperature>().unwrap());
+    let mut reader = OerReader::from_canonical_bytes(decimal);
+    assert!(reader.read::<Temperature>().is_err());
+}
+
+#[test]
+fn test_jer() {
+    let mut writer = JerWriter::default();
+    writer.write(&reading()).unwrap();
+    assert_eq!(r#"{"sensor":"1.3.6.1","value":1.0}"#, writer.json());
+    assert_eq!(reading(), writer.into_reader().read::<Reading>().unwrap());
+
+    let mut writer = JerWriter::default();
+    writer.write(&MaybeReading::Absent(Null)).unwrap();
+    assert_eq!(r#"{"absent":null}"#, writer.json());
+    assert_eq!(
+        MaybeReading::Absent(Null),
+        writer.into_reader().read::<MaybeReading>().unwrap()
+    );
+
+    let mut writer = JerWriter::default();
+    writer.write(&Temperature(f64::NEG_INFINITY)).unwrap();
+    assert_eq!(r#""-INF""#, writer.json());
+    assert_eq!(
+        Temperature(f64::NEG_INFINITY),
+        writer.into_reader().read::<Temperature>().unwrap()
+    );
+}
+
+#[test]
+fn test_xer() {
+    let mut writer = XerWriter::default();
+    writer.write(&MaybeReading::Reading(reading())).unwrap();
+    assert_eq!(
+        "<MaybeReading><reading><sensor>1.3.6.1</sensor><value>1.0</value></reading></MaybeReading>",
+        writer.xml()
+    );
+    let mut reader = XerReader::from_xml(writer.xml()).unwrap();
+    assert_eq!(
+        MaybeReading::Reading(reading()),
+        reader.read::<MaybeReading>().unwrap()
+    );
+
+    let mut writer = XerWriter::default();
+    writer.write(&MaybeReading::Absent(Null)).unwrap();
+    assert_eq!("<MaybeReading><absent/></MaybeReading>", writer.xml());
+    let mut reader = XerReader::from_xml(writer.xml()).unwrap();
+    assert_eq!(
+        MaybeReading::Absent(Null),
+        reader.read::<MaybeReading>().unwrap()
+    );
+
+    let mut writer = XerWriter::default();
+    writer.write(&Temperature(f64::INFINITY)).unwrap();
+    assert_eq!("<REAL><PLUS-INFINITY/></REAL>", writer.xml());
+    let mut reader = XerReader::from_xml(writer.xml()).unwrap();
+    assert_eq!(
+        Temperature(f64::INFINITY),
+        reader.read::<Temperature>().unwrap()
+    );
+}
+
+#[test]
+fn test_protobuf() {
+    let mut buffer = Vec::default();
+    let writer = &mut buffer as &mut dyn ProtobufWriter;
+    reading().write_protobuf(writer).unwrap();
+    assert_eq!(
+        &[
+            0x0A, 0x07, b'1', b'.', b'3', b'.', b'6', b'.', b'1', 0x11, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xF0, 0x3F
+        ],
+        &buffer[..]
+    );
+
+    let reader = &mut &buffer[..] as &mut dyn ProtobufReader;
+    assert_eq!(reading(), Reading::read_protobuf(reader).unwrap());
+}
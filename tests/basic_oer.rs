@@ -0,0 +1,280 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicOer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Potato ::= SEQUENCE {
+        size INTEGER,
+        size2 INTEGER,
+        size3 INTEGER(12..128),
+        string UTF8String
+    }
+
+    Topping ::= ENUMERATED {
+        not_pineapple,
+        even_less_pineapple,
+        no_pineapple_at_all
+    }
+
+    Pizza ::= SEQUENCE {
+        size INTEGER(1..4),
+        topping Topping
+    }
+
+    WhatToEat ::= CHOICE {
+        potato Potato,
+        pizza Pizza
+    }
+
+    Extensible ::= CHOICE {
+        abc UTF8String,
+        def INTEGER,
+        ...,
+        ghi BOOLEAN
+    }
+
+    Payload ::= CHOICE {
+        raw [3] OCTET STRING,
+        pizza Pizza
+    }
+
+    Header ::= SEQUENCE {
+        version INTEGER(0..255),
+        flag BOOLEAN OPTIONAL,
+        id INTEGER(0..4294967295),
+        note UTF8String OPTIONAL,
+        payload Payload
+    }
+
+    Vegetables ::= SEQUENCE OF UTF8String
+
+    Level ::= ENUMERATED {
+        low,
+        high(200)
+    }
+
+    Root ::= SEQUENCE {
+        abc INTEGER(0..255),
+        def BOOLEAN OPTIONAL,
+        ...
+    }
+
+    Extended ::= SEQUENCE {
+        abc INTEGER(0..255),
+        def BOOLEAN OPTIONAL,
+        ...,
+        ghi INTEGER(0..255) OPTIONAL,
+        [[ jkl BOOLEAN OPTIONAL, mno UTF8String OPTIONAL ]]
+    }
+
+    END"
+);
+
+fn serialize_and_deserialize_oer<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = OerWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!(data, writer.byte_content());
+    let mut reader = OerReader::from_canonical_bytes(writer.into_bytes_vec());
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bytes_remaining());
+}
+
+fn header() -> Header {
+    Header {
+        version: 3,
+        flag: Some(true),
+        id: 1337,
+        note: None,
+        payload: Payload::Raw(vec![0xCA, 0xFE]),
+    }
+}
+
+#[test]
+fn test_potato() {
+    serialize_and_deserialize_oer(
+        &[
+            0x01, 0x0D, 0x01, 0x25, 0x2A, 0x11, 0x73, 0x75, 0x63, 0x68, 0x20, 0x74, 0x61, 0x73,
+            0x74, 0x79, 0x20, 0x70, 0x6F, 0x74, 0x61, 0x74, 0x6F,
+        ],
+        &Potato {
+            size: 13,
+            size2: 37,
+            size3: 42,
+            string: "such tasty potato".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_choice_uses_automatic_tag() {
+    serialize_and_deserialize_oer(
+        &[0x81, 0x03, 0x01],
+        &WhatToEat::Pizza(Pizza {
+            size: 3,
+            topping: Topping::EvenLessPineapple,
+        }),
+    );
+}
+
+#[test]
+fn test_choice_extension_is_open_type() {
+    serialize_and_deserialize_oer(&[0x81, 0x01, 0x05], &Extensible::Def(5));
+    serialize_and_deserialize_oer(&[0x82, 0x01, 0xFF], &Extensible::Ghi(true));
+}
+
+#[test]
+fn test_preamble_and_fixed_size_integers() {
+    serialize_and_deserialize_oer(
+        &[
+            0x80, // preamble: flag present, note absent
+            0x03, // version in one octet
+            0xFF, // flag
+            0x00, 0x00, 0x05, 0x39, // id in four octets
+            0x83, 0x02, 0xCA, 0xFE, // payload with the declared tag [3]
+        ],
+        &header(),
+    );
+}
+
+#[test]
+fn test_all_optional_fields_present() {
+    serialize_and_deserialize_oer(
+        &[
+            0xC0, 0x03, 0x00, 0x00, 0x00, 0x05, 0x39, 0x02, 0x68, 0x69, 0x81, 0x03, 0x01,
+        ],
+        &Header {
+            flag: Some(false),
+            note: Some("hi".to_string()),
+            payload: Payload::Pizza(Pizza {
+                size: 3,
+                topping: Topping::EvenLessPineapple,
+            }),
+            ..header()
+        },
+    );
+}
+
+#[test]
+fn test_sequence_of_has_quantity() {
+    serialize_and_deserialize_oer(
+        &[0x01, 0x02, 0x01, 0x61, 0x02, 0x62, 0x63],
+        &Vegetables(vec!["a".to_string(), "bc".to_string()]),
+    );
+}
+
+#[test]
+fn test_unknown_choice_tag() {
+    let mut reader = OerReader::from_bytes(vec![0x85, 0x00]);
+    assert_eq!(
+        Err(asn1rs::io::oer::Error::UnknownChoiceTag(
+            Tag::ContextSpecific(5)
+        )),
+        reader.read::<WhatToEat>()
+    );
+}
+
+#[test]
+fn test_coer_rejects_unused_preamble_bits() {
+    let bytes = vec![0x01, 0x03, 0x00, 0x00, 0x05, 0x39, 0x83, 0x00];
+    assert!(OerReader::from_bytes(bytes.clone())
+        .read::<Header>()
+        .is_ok());
+    assert_eq!(
+        Err(asn1rs::io::oer::Error::NotCanonical("preamble")),
+        OerReader::from_canonical_bytes(bytes).read::<Header>()
+    );
+}
+
+#[test]
+fn test_enumerated_numbers() {
+    serialize_and_deserialize_oer(&[0x00], &Level::Low);
+    // the long form, because the number of the variant is not below 128
+    serialize_and_deserialize_oer(&[0x82, 0x00, 0xC8], &Level::High);
+}
+
+#[test]
+fn test_unknown_enumerated_number() {
+    let mut reader = OerReader::from_bytes(vec![0x01]);
+    assert_eq!(
+        Err(asn1rs::io::oer::Error::UnknownEnumeratedNumber(1, "Level")),
+        reader.read::<Level>()
+    );
+}
+
+fn extended() -> Extended {
+    Extended {
+        abc: 5,
+        def: None,
+        ghi: None,
+        jkl: None,
+        mno: None,
+    }
+}
+
+#[test]
+fn test_extensible_sequence_without_extension_additions() {
+    // the extension bit precedes the presence bit of def in the preamble
+    serialize_and_deserialize_oer(
+        &[0x40, 0x05, 0xFF],
+        &Root {
+            abc: 5,
+            def: Some(true),
+        },
+    );
+    serialize_and_deserialize_oer(
+        &[0x40, 0x05, 0xFF],
+        &Extended {
+            def: Some(true),
+            ..extended()
+        },
+    );
+}
+
+#[test]
+fn test_extension_addition() {
+    // preamble, abc, the presence bitmap as BIT STRING and ghi as open type
+    serialize_and_deserialize_oer(
+        &[0x80, 0x05, 0x02, 0x06, 0x80, 0x01, 0x07],
+        &Extended {
+            ghi: Some(7),
+            ..extended()
+        },
+    );
+}
+
+#[test]
+fn test_extension_addition_group() {
+    // the group is encoded like a SEQUENCE with a preamble for jkl and mno
+    serialize_and_deserialize_oer(
+        &[0x80, 0x05, 0x02, 0x06, 0x40, 0x02, 0x80, 0xFF],
+        &Extended {
+            jkl: Some(true),
+            ..extended()
+        },
+    );
+}
+
+#[test]
+fn test_unknown_extension_additions_are_skipped() {
+    let mut reader = OerReader::from_bytes(vec![
+        0x80, 0x05, 0x02, 0x06, 0xC0, 0x01, 0x07, 0x02, 0x80, 0xFF,
+    ]);
+    assert_eq!(Ok(Root { abc: 5, def: None }), reader.read::<Root>());
+    assert_eq!(0, reader.bytes_remaining());
+}
+
+#[test]
+fn test_missing_extension_additions_are_absent() {
+    let mut reader = OerReader::from_bytes(vec![0x40, 0x05, 0x00]);
+    assert_eq!(
+        Ok(Extended {
+            def: Some(false),
+            ..extended()
+        }),
+        reader.read::<Extended>()
+    );
+}
@@ -0,0 +1,146 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicOpenType DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    MESSAGE-ID-AND-TYPE ::= CLASS {
+        &id MessageId UNIQUE,
+        &Type
+    } WITH SYNTAX {
+        ID &id TYPE &Type
+    }
+
+    MessageId ::= INTEGER (0..255)
+
+    position MessageId ::= 1
+
+    Position ::= INTEGER (0..255)
+
+    MessageTypes MESSAGE-ID-AND-TYPE ::= {
+        { ID position TYPE Position } |
+        { ID 2 TYPE BOOLEAN },
+        ...
+    }
+
+    MessageFrame ::= SEQUENCE {
+        messageId MESSAGE-ID-AND-TYPE.&id ({MessageTypes}),
+        value MESSAGE-ID-AND-TYPE.&Type ({MessageTypes}{@messageId})
+    }
+
+    END"
+);
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = UperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+fn serialize_and_deserialize_oer<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = OerWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!(data, writer.byte_content());
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bytes_remaining());
+}
+
+#[test]
+fn test_uper_open_type_referenced_by_value_reference() {
+    // 8 bits for the id, followed by the length (1 octet) and the octet of the position
+    serialize_and_deserialize_uper(
+        24,
+        &[0x01, 0x01, 0x05],
+        &MessageFrame {
+            message_id: MessageId(1),
+            value: MessageFrameValue::Position(Position(5)),
+        },
+    );
+}
+
+#[test]
+fn test_uper_open_type_content_is_padded_to_octets() {
+    serialize_and_deserialize_uper(
+        24,
+        &[0x02, 0x01, 0x80],
+        &MessageFrame {
+            message_id: MessageId(2),
+            value: MessageFrameValue::Type2(true),
+        },
+    );
+}
+
+#[test]
+fn test_uper_open_type_with_unknown_key() {
+    serialize_and_deserialize_uper(
+        32,
+        &[0x09, 0x02, 0xAB, 0xCD],
+        &MessageFrame {
+            message_id: MessageId(9),
+            value: MessageFrameValue::Unknown(vec![0xAB, 0xCD]),
+        },
+    );
+}
+
+#[test]
+fn test_uper_open_type_with_mismatched_key_is_an_error() {
+    let mut writer = UperWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::uper::Error::OpenTypeKeyMismatch(
+            2,
+            "MessageFrameValue"
+        )),
+        writer.write(&MessageFrame {
+            message_id: MessageId(2),
+            value: MessageFrameValue::Position(Position(5)),
+        })
+    );
+}
+
+#[test]
+fn test_uper_open_type_with_unknown_content_for_known_key_is_an_error() {
+    let mut writer = UperWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::uper::Error::OpenTypeKeyMismatch(
+            1,
+            "MessageFrameValue"
+        )),
+        writer.write(&MessageFrame {
+            message_id: MessageId(1),
+            value: MessageFrameValue::Unknown(vec![0x05]),
+        })
+    );
+}
+
+#[test]
+fn test_oer_open_type_is_preceded_by_its_length() {
+    serialize_and_deserialize_oer(
+        &[0x01, 0x01, 0x05],
+        &MessageFrame {
+            message_id: MessageId(1),
+            value: MessageFrameValue::Position(Position(5)),
+        },
+    );
+}
+
+#[test]
+fn test_oer_open_type_with_unknown_key() {
+    serialize_and_deserialize_oer(
+        &[0x09, 0x02, 0xAB, 0xCD],
+        &MessageFrame {
+            message_id: MessageId(9),
+            value: MessageFrameValue::Unknown(vec![0xAB, 0xCD]),
+        },
+    );
+}
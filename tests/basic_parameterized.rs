@@ -0,0 +1,64 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicParameterized DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Container {ElementType, INTEGER : maxSize} ::= SEQUENCE (SIZE(1..maxSize)) OF ElementType
+
+    Tagged {Payload} ::= SEQUENCE {
+        id INTEGER (0..255),
+        payload Payload
+    }
+
+    Point ::= INTEGER (0..255)
+
+    Points ::= Container {Point, 4}
+
+    Record ::= SEQUENCE {
+        points Container {Point, 2},
+        flags Tagged {Container {BOOLEAN, 3}}
+    }
+
+    END"
+);
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = UperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_uper_instantiated_type() {
+    // 2 bits for the length of 1 within 1..4, followed by 8 bits for the point
+    serialize_and_deserialize_uper(10, &[0x00, 0xC0], &Points(ContainerPoint4(vec![Point(3)])));
+}
+
+#[test]
+fn test_uper_instantiated_types_within_sequence() {
+    serialize_and_deserialize_uper(
+        21,
+        &[0x00, 0x83, 0xB0],
+        &Record {
+            points: ContainerPoint2(vec![Point(1)]),
+            flags: TaggedContainerBOOLEAN3 {
+                id: 7,
+                payload: ContainerBOOLEAN3(vec![true, false]),
+            },
+        },
+    );
+}
+
+#[test]
+fn test_uper_size_exceeds_actual_parameter() {
+    let mut writer = UperWriter::default();
+    assert!(writer.write(&ContainerPoint2(vec![Point(0); 3])).is_err());
+}
@@ -4,9 +4,9 @@ use asn1rs::prelude::*;
 #[derive(Debug, Default, PartialOrd, PartialEq)]
 pub struct Potato {
     #[asn(integer)]
-    size: u64,
+    size: i64,
     #[asn(integer(min..max))]
-    size2: u64,
+    size2: i64,
     #[asn(integer(12..128), tag(APPLICATION(4)))]
     size3: u8,
     #[asn(utf8string, tag(4))]
@@ -254,7 +254,7 @@ fn are_we_binary_yet_uper() {
 #[derive(Debug, PartialOrd, PartialEq)]
 pub struct Optional {
     #[asn(option(integer))]
-    value: Option<u64>,
+    value: Option<i64>,
 }
 
 #[test]
@@ -274,7 +274,7 @@ fn test_optional_uper() {
 #[derive(Debug, PartialOrd, PartialEq)]
 pub struct CrazyList {
     #[asn(sequence_of(option(option(sequence_of(integer)))))]
-    values: Vec<Option<Option<Vec<u64>>>>,
+    values: Vec<Option<Option<Vec<i64>>>>,
 }
 
 #[test]
@@ -292,13 +292,13 @@ fn test_crazy_list_println() {
     //     Writing OPTIONAL
     //      Some
     //       Writing sequence-of (MIN..MAX)
-    //        WRITING Integer 13
+    //        WRITING Integer(MIN..MAX) 13
     //   Writing OPTIONAL
     //    Some
     //     Writing OPTIONAL
     //      Some
     //       Writing sequence-of (MIN..MAX)
-    //        WRITING Integer 37
+    //        WRITING Integer(MIN..MAX) 37
     //   Writing OPTIONAL
     //    Some
     //     Writing OPTIONAL
@@ -355,15 +355,15 @@ fn test_crazy_list_uper() {
 
 #[asn(transparent)]
 #[derive(Debug, PartialOrd, PartialEq)]
-pub struct FlatList(#[asn(sequence_of(integer))] Vec<u64>);
+pub struct FlatList(#[asn(sequence_of(integer))] Vec<i64>);
 
 #[test]
 fn test_flat_list_println() {
     // Writing sequence FlatList
     //  Writing sequence-of (MIN..MAX)
-    //   WRITING Integer 13
-    //   WRITING Integer 37
-    //   WRITING Integer 42
+    //   WRITING Integer(MIN..MAX) 13
+    //   WRITING Integer(MIN..MAX) 37
+    //   WRITING Integer(MIN..MAX) 42
     PrintlnWriter::default()
         .write(&FlatList(vec![13, 37, 42]))
         .unwrap();
@@ -387,15 +387,15 @@ fn test_flat_list_uper() {
 
 #[asn(transparent)]
 #[derive(Debug, PartialOrd, PartialEq)]
-pub struct Important(#[asn(option(integer))] Option<u64>);
+pub struct Important(#[asn(option(integer))] Option<i64>);
 
 #[test]
 fn test_transparent_important_println() {
     // Writing sequence FlatList
     //  Writing sequence-of (MIN..MAX)
-    //   WRITING Integer 13
-    //   WRITING Integer 37
-    //   WRITING Integer 42
+    //   WRITING Integer(MIN..MAX) 13
+    //   WRITING Integer(MIN..MAX) 37
+    //   WRITING Integer(MIN..MAX) 42
     PrintlnWriter::default()
         .write(&Important(Some(42)))
         .unwrap();
@@ -474,3 +474,21 @@ fn test_bool_container_uper() {
 #[asn(transparent)]
 #[derive(Debug, Default, PartialOrd, PartialEq)]
 pub struct NegativeRangeMin(#[asn(integer(- 12..12))] i8);
+
+#[asn(transparent)]
+#[derive(Debug, Default, PartialOrd, PartialEq)]
+pub struct ExtensibleRange(#[asn(integer(0..255, ...))] i64);
+
+#[test]
+fn test_extensible_range_uper() {
+    let mut uper = UperWriter::default();
+    let v = ExtensibleRange(256);
+    uper.write(&v).unwrap();
+    // the extension bit followed by the length and the value
+    assert_eq!(&[0x81, 0x00, 0x80, 0x00], uper.byte_content());
+    assert_eq!(25, uper.bit_len());
+
+    let mut uper = uper.into_reader();
+    assert_eq!(v, uper.read::<ExtensibleRange>().unwrap());
+    assert_eq!(0, uper.bits_remaining());
+}
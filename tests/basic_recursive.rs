@@ -0,0 +1,107 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicRecursive DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Node ::= SEQUENCE {
+        value INTEGER (0..255),
+        next Node OPTIONAL
+    }
+
+    Expression ::= CHOICE {
+        value INTEGER (0..255),
+        negated Negation
+    }
+
+    Negation ::= SEQUENCE {
+        expression Expression
+    }
+
+    Tree ::= SEQUENCE {
+        children SEQUENCE (SIZE(0..3)) OF Tree
+    }
+
+    END"
+);
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = UperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_uper_optional_self_reference() {
+    // presence bit and 8 bits for the value, for each of the two nodes
+    serialize_and_deserialize_uper(
+        18,
+        &[0x80, 0x80, 0x80],
+        &Node {
+            value: 1,
+            next: Some(Box::new(Node {
+                value: 2,
+                next: None,
+            })),
+        },
+    );
+}
+
+#[test]
+fn test_uper_indirect_self_reference() {
+    serialize_and_deserialize_uper(
+        10,
+        &[0x81, 0x40],
+        &Expression::Negated(Negation {
+            expression: Box::new(Expression::Value(5)),
+        }),
+    );
+}
+
+#[test]
+fn test_uper_self_reference_in_sequence_of() {
+    serialize_and_deserialize_uper(
+        4,
+        &[0x40],
+        &Tree {
+            children: vec![Tree {
+                children: Vec::default(),
+            }],
+        },
+    );
+}
+
+#[test]
+fn test_protobuf_self_reference() {
+    let expression = Expression::Negated(Negation {
+        expression: Box::new(Expression::Value(5)),
+    });
+    let node = Node {
+        value: 1,
+        next: Some(Box::new(Node {
+            value: 2,
+            next: None,
+        })),
+    };
+
+    let mut buffer = Vec::default();
+    let writer = &mut buffer as &mut dyn ProtobufWriter;
+    expression.write_protobuf(writer).unwrap();
+    let reader = &mut &buffer[..] as &mut dyn ProtobufReader;
+    assert_eq!(expression, Expression::read_protobuf(reader).unwrap());
+
+    let mut buffer = Vec::default();
+    let writer = &mut buffer as &mut dyn ProtobufWriter;
+    node.write_protobuf(writer).unwrap();
+    let reader = &mut &buffer[..] as &mut dyn ProtobufReader;
+    let read = Node::read_protobuf(reader).unwrap();
+    assert_eq!(node, read);
+    assert!(node.protobuf_eq(&read));
+}
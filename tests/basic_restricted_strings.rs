@@ -0,0 +1,191 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r#"BasicRestrictedStrings DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Contact ::= SEQUENCE {
+        digits NumericString,
+        name IA5String,
+        code PrintableString (FROM("A".."F" | "0123456789")),
+        label VisibleString,
+        wide BMPString,
+        universal UniversalString
+    }
+
+    Digits ::= NumericString
+
+    Name ::= IA5String
+
+    Code ::= IA5String (FROM("AB"))
+
+    Wide ::= BMPString
+
+    END"#
+);
+
+fn contact() -> Contact {
+    Contact {
+        digits: "0 42".to_string(),
+        name: "Jane\tDoe".to_string(),
+        code: "C0FFEE".to_string(),
+        label: "Hello World!".to_string(),
+        wide: "Grüße €".to_string(),
+        universal: "🦀".to_string(),
+    }
+}
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = UperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_uper_numeric_string_is_indexed_with_4_bits() {
+    // ' ' is 0, '0' is 1, '1' is 2 and '2' is 3
+    serialize_and_deserialize_uper(16, &[0x02, 0x23], &Digits("12".to_string()));
+}
+
+#[test]
+fn test_uper_ia5string_has_7_bits() {
+    serialize_and_deserialize_uper(22, &[0x02, 0x91, 0xA4], &Name("Hi".to_string()));
+}
+
+#[test]
+fn test_uper_permitted_alphabet_has_1_bit() {
+    serialize_and_deserialize_uper(12, &[0x04, 0x60], &Code("ABBA".to_string()));
+}
+
+#[test]
+fn test_uper_bmp_string_has_16_bits() {
+    serialize_and_deserialize_uper(24, &[0x01, 0x20, 0xAC], &Wide("€".to_string()));
+}
+
+#[test]
+fn test_uper_sequence() {
+    let mut writer = UperWriter::default();
+    writer.write(&contact()).unwrap();
+    let mut reader = writer.into_reader();
+    assert_eq!(contact(), reader.read::<Contact>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_uper_rejects_characters_not_permitted() {
+    let mut writer = UperWriter::default();
+    assert!(writer.write(&Code("ABC".to_string())).is_err());
+    let mut writer = UperWriter::default();
+    assert!(writer.write(&Name("ä".to_string())).is_err());
+    let mut writer = UperWriter::default();
+    assert!(writer.write(&Digits("1a".to_string())).is_err());
+}
+
+#[test]
+fn test_aper() {
+    let mut writer = AperWriter::default();
+    writer.write(&Name("Hi".to_string())).unwrap();
+    writer.write(&Digits("12".to_string())).unwrap();
+    assert_eq!(&[0x02, 0x48, 0x69, 0x02, 0x23], writer.byte_content());
+    let mut reader = writer.into_reader();
+    assert_eq!(Name("Hi".to_string()), reader.read::<Name>().unwrap());
+    assert_eq!(Digits("12".to_string()), reader.read::<Digits>().unwrap());
+
+    let mut writer = AperWriter::default();
+    writer.write(&contact()).unwrap();
+    assert_eq!(contact(), writer.into_reader().read::<Contact>().unwrap());
+}
+
+#[test]
+fn test_der() {
+    let mut writer = DerWriter::default();
+    writer.write(&Name("Hi".to_string())).unwrap();
+    writer.write(&Digits("12".to_string())).unwrap();
+    writer.write(&Wide("€".to_string())).unwrap();
+    assert_eq!(
+        &[0x16, 0x02, b'H', b'i', 0x12, 0x02, b'1', b'2', 0x1E, 0x02, 0x20, 0xAC],
+        writer.byte_content()
+    );
+    let mut reader = BerReader::from_bytes(writer.into_bytes_vec());
+    assert_eq!(Name("Hi".to_string()), reader.read::<Name>().unwrap());
+    assert_eq!(Digits("12".to_string()), reader.read::<Digits>().unwrap());
+    assert_eq!(Wide("€".to_string()), reader.read::<Wide>().unwrap());
+
+    let mut writer = DerWriter::default();
+    writer.write(&contact()).unwrap();
+    let mut reader = BerReader::from_bytes(writer.into_bytes_vec());
+    assert_eq!(contact(), reader.read::<Contact>().unwrap());
+
+    let mut reader = BerReader::from_bytes(vec![0x16, 0x01, 0x80]);
+    assert!(reader.read::<Name>().is_err());
+}
+
+#[test]
+fn test_oer() {
+    let mut writer = OerWriter::default();
+    writer.write(&Wide("€".to_string())).unwrap();
+    assert_eq!(&[0x02, 0x20, 0xAC], writer.byte_content());
+    let mut reader = OerReader::from_canonical_bytes(writer.into_bytes_vec());
+    assert_eq!(Wide("€".to_string()), reader.read::<Wide>().unwrap());
+
+    let mut writer = OerWriter::default();
+    writer.write(&contact()).unwrap();
+    let mut reader = OerReader::from_canonical_bytes(writer.into_bytes_vec());
+    assert_eq!(contact(), reader.read::<Contact>().unwrap());
+
+    let mut writer = OerWriter::default();
+    assert!(writer.write(&Code("ABC".to_string())).is_err());
+}
+
+#[test]
+fn test_jer() {
+    let mut writer = JerWriter::default();
+    writer.write(&Name("Hi".to_string())).unwrap();
+    assert_eq!(r#""Hi""#, writer.json());
+    assert_eq!(
+        Name("Hi".to_string()),
+        writer.into_reader().read::<Name>().unwrap()
+    );
+
+    let mut writer = JerWriter::default();
+    writer.write(&contact()).unwrap();
+    assert_eq!(contact(), writer.into_reader().read::<Contact>().unwrap());
+
+    let mut writer = JerWriter::default();
+    assert!(writer.write(&Digits("1a".to_string())).is_err());
+}
+
+#[test]
+fn test_xer() {
+    let mut writer = XerWriter::default();
+    writer.write(&Code("AB".to_string())).unwrap();
+    assert_eq!("<IA5String>AB</IA5String>", writer.xml());
+    let mut reader = XerReader::from_xml(writer.xml()).unwrap();
+    assert_eq!(Code("AB".to_string()), reader.read::<Code>().unwrap());
+
+    let mut writer = XerWriter::default();
+    writer.write(&contact()).unwrap();
+    let mut reader = XerReader::from_xml(writer.xml()).unwrap();
+    assert_eq!(contact(), reader.read::<Contact>().unwrap());
+}
+
+#[test]
+fn test_protobuf() {
+    let mut buffer = Vec::default();
+    let writer = &mut buffer as &mut dyn ProtobufWriter;
+    Name("Hi".to_string()).write_protobuf(writer).unwrap();
+    assert_eq!(&[0x0A, 0x02, b'H', b'i'], &buffer[..]);
+
+    let mut buffer = Vec::default();
+    let writer = &mut buffer as &mut dyn ProtobufWriter;
+    contact().write_protobuf(writer).unwrap();
+    let reader = &mut &buffer[..] as &mut dyn ProtobufReader;
+    assert_eq!(contact(), Contact::read_protobuf(reader).unwrap());
+}
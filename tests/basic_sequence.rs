@@ -0,0 +1,128 @@
+use asn1rs::prelude::*;
+use asn1rs::syn::io::UperReader as NewUperReader;
+use asn1rs::syn::io::UperWriter as NewUperWriter;
+
+asn_to_rust!(
+    r"BasicSequence DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Root ::= SEQUENCE {
+        abc INTEGER(0..255),
+        def BOOLEAN OPTIONAL,
+        ...
+    }
+
+    Extensible ::= SEQUENCE {
+        abc INTEGER(0..255),
+        def BOOLEAN OPTIONAL,
+        ...,
+        ghi INTEGER(0..255) OPTIONAL,
+        [[ jkl BOOLEAN OPTIONAL, mno UTF8String OPTIONAL ]]
+    }
+
+    END"
+);
+
+fn serialize_uper(to_uper: &impl Writable) -> (usize, Vec<u8>) {
+    let mut writer = NewUperWriter::default();
+    writer.write(to_uper).unwrap();
+    let bits = writer.bit_len();
+    (bits, writer.into_bytes_vec())
+}
+
+fn deserialize_uper<T: Readable>(data: &[u8], bits: usize) -> T {
+    let mut reader = NewUperReader::from_bits(data, bits);
+    let value = reader.read::<T>().unwrap();
+    assert_eq!(0, reader.bits_remaining());
+    value
+}
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    uper: &T,
+) {
+    let serialized = serialize_uper(uper);
+    assert_eq!((bits, data), (serialized.0, &serialized.1[..]));
+    assert_eq!(uper, &deserialize_uper::<T>(data, bits));
+}
+
+fn extensible() -> Extensible {
+    Extensible {
+        abc: 5,
+        def: None,
+        ghi: None,
+        jkl: None,
+        mno: None,
+    }
+}
+
+#[test]
+fn test_without_extension_additions() {
+    // extension bit, def-flag, abc, def
+    serialize_and_deserialize_uper(
+        11,
+        &[0x41, 0x60],
+        &Extensible {
+            def: Some(true),
+            ..extensible()
+        },
+    );
+    serialize_and_deserialize_uper(
+        11,
+        &[0x41, 0x60],
+        &Root {
+            abc: 5,
+            def: Some(true),
+        },
+    );
+}
+
+#[test]
+fn test_extension_addition() {
+    // extension bit, def-flag, abc, bitmap length - 1, bitmap, ghi as open type
+    serialize_and_deserialize_uper(
+        35,
+        &[0x81, 0x40, 0xC0, 0x20, 0xE0],
+        &Extensible {
+            ghi: Some(7),
+            ..extensible()
+        },
+    );
+}
+
+#[test]
+fn test_extension_addition_group() {
+    // the group is encoded like a SEQUENCE with a preamble for jkl and mno
+    serialize_and_deserialize_uper(
+        35,
+        &[0x81, 0x40, 0xA0, 0x34, 0x00],
+        &Extensible {
+            jkl: Some(true),
+            ..extensible()
+        },
+    );
+}
+
+#[test]
+fn test_unknown_extension_additions_are_skipped() {
+    assert_eq!(
+        Root { abc: 5, def: None },
+        deserialize_uper(&[0x81, 0x40, 0xC0, 0x20, 0xE0], 35)
+    );
+    assert_eq!(
+        Root { abc: 5, def: None },
+        deserialize_uper(&[0x81, 0x40, 0xA0, 0x34, 0x00], 35)
+    );
+}
+
+#[test]
+fn test_missing_extension_additions_are_absent() {
+    assert_eq!(
+        Extensible {
+            def: Some(false),
+            ..extensible()
+        },
+        deserialize_uper(&[0x41, 0x40], 11)
+    );
+}
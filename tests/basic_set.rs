@@ -0,0 +1,120 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicSet DEFINITIONS ::=
+    BEGIN
+
+    Unordered ::= SET {
+        name UTF8String,
+        count INTEGER (0..255) OPTIONAL,
+        flag BOOLEAN
+    }
+
+    Numbered ::= SET {
+        second [1] BOOLEAN,
+        first [0] INTEGER (0..15)
+    }
+
+    Flags ::= SET (SIZE(0..3)) OF BOOLEAN
+
+    END"
+);
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = UperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_uper_set_fields_in_canonical_order() {
+    // flag (BOOLEAN) before count (INTEGER) before name (UTF8String)
+    serialize_and_deserialize_uper(
+        34,
+        &[0xC1, 0x40, 0x9A, 0x1A, 0x40],
+        &Unordered {
+            name: "hi".to_string(),
+            count: Some(5),
+            flag: true,
+        },
+    );
+}
+
+#[test]
+fn test_uper_set_without_optional_field() {
+    serialize_and_deserialize_uper(
+        10,
+        &[0x40, 0x00],
+        &Unordered {
+            name: String::default(),
+            count: None,
+            flag: true,
+        },
+    );
+}
+
+#[test]
+fn test_uper_set_fields_ordered_by_tag() {
+    serialize_and_deserialize_uper(
+        5,
+        &[0x38],
+        &Numbered {
+            second: true,
+            first: 3,
+        },
+    );
+}
+
+#[test]
+fn test_uper_set_of() {
+    serialize_and_deserialize_uper(5, &[0xE8], &Flags(vec![true, false, true]));
+}
+
+#[test]
+fn test_der_set() {
+    let mut writer = DerWriter::default();
+    writer
+        .write(&Unordered {
+            name: "hi".to_string(),
+            count: Some(5),
+            flag: true,
+        })
+        .unwrap();
+    assert_eq!(
+        &[
+            0x31, 0x0A, // SET
+            0x01, 0x01, 0xFF, // BOOLEAN
+            0x02, 0x01, 0x05, // INTEGER
+            0x0C, 0x02, 0x68, 0x69, // UTF8String
+        ],
+        writer.byte_content()
+    );
+}
+
+#[test]
+fn test_der_set_of_elements_are_sorted() {
+    let mut writer = DerWriter::default();
+    writer.write(&Flags(vec![true, false, true])).unwrap();
+    assert_eq!(
+        &[
+            0x31, 0x09, // SET OF
+            0x01, 0x01, 0x00, // FALSE
+            0x01, 0x01, 0xFF, // TRUE
+            0x01, 0x01, 0xFF, // TRUE
+        ],
+        writer.byte_content()
+    );
+    let mut reader = writer.into_reader();
+    assert_eq!(
+        Flags(vec![false, true, true]),
+        reader.read::<Flags>().unwrap()
+    );
+    assert_eq!(0, reader.bytes_remaining());
+}
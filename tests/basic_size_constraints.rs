@@ -0,0 +1,115 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicSizeConstraints DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Hash ::= OCTET STRING (SIZE(4))
+
+    Values ::= SEQUENCE (SIZE(1..4)) OF INTEGER (0..255)
+
+    Code ::= IA5String (SIZE(2..3, ...))
+
+    Blob ::= OCTET STRING (SIZE(5..100000))
+
+    AnyBlob ::= OCTET STRING
+
+    Label ::= IA5String (SIZE(3..100000))
+
+    Record ::= SEQUENCE {
+        name UTF8String (SIZE(1..16)),
+        values SEQUENCE SIZE(1..4) OF INTEGER (0..255)
+    }
+
+    END"
+);
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = UperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_uper_fixed_size_octet_string_has_no_length() {
+    serialize_and_deserialize_uper(32, &[0x01, 0x02, 0x03, 0x04], &Hash(vec![1, 2, 3, 4]));
+}
+
+#[test]
+fn test_uper_sequence_of_constrained_length() {
+    // 2 bits for the length of 3 within 1..4, followed by 8 bits per value
+    serialize_and_deserialize_uper(26, &[0x80, 0x40, 0x80, 0xC0], &Values(vec![1, 2, 3]));
+}
+
+#[test]
+fn test_uper_sequence_of_size_not_in_range() {
+    let mut writer = UperWriter::default();
+    assert!(writer.write(&Values(Vec::new())).is_err());
+    assert!(writer.write(&Values(vec![1, 2, 3, 4, 5])).is_err());
+}
+
+#[test]
+fn test_uper_extensible_size_within_range() {
+    // the extension bit, 1 bit for the length of 2 within 2..3 and 7 bits per character
+    serialize_and_deserialize_uper(16, &[0x20, 0xC2], &Code("AB".to_string()));
+}
+
+#[test]
+fn test_uper_extensible_size_outside_of_range() {
+    // the extension bit, the length determinant and 7 bits per character
+    serialize_and_deserialize_uper(
+        37,
+        &[0x82, 0x41, 0x85, 0x0E, 0x20],
+        &Code("ABCD".to_string()),
+    );
+}
+
+#[test]
+fn test_uper_utf8string_size_is_not_per_visible() {
+    serialize_and_deserialize_uper(
+        34,
+        &[0x02, 0x61, 0x62, 0x01, 0xC0],
+        &Record {
+            name: "ab".to_string(),
+            values: vec![7],
+        },
+    );
+}
+
+#[test]
+fn test_uper_size_not_in_range_with_unconstrained_length() {
+    // from 64K upwards the length is encoded as unconstrained, but the lower bound still applies
+    let mut writer = UperWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::uper::Error::SizeNotInRange(2, 5, 100_000)),
+        writer.write(&Blob(vec![1, 2]))
+    );
+    assert_eq!(
+        Err(asn1rs::io::uper::Error::SizeNotInRange(2, 3, 100_000)),
+        writer.write(&Label("AB".to_string()))
+    );
+
+    let mut writer = UperWriter::default();
+    writer.write(&AnyBlob(vec![1, 2])).unwrap();
+    assert_eq!(
+        Err(asn1rs::io::uper::Error::SizeNotInRange(2, 5, 100_000)),
+        writer.into_reader().read::<Blob>()
+    );
+}
+
+#[test]
+fn test_uper_size_within_range_with_unconstrained_length() {
+    // the length determinant and 8 bits per octet
+    serialize_and_deserialize_uper(
+        48,
+        &[0x05, 0x01, 0x02, 0x03, 0x04, 0x05],
+        &Blob(vec![1, 2, 3, 4, 5]),
+    );
+}
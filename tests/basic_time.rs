@@ -0,0 +1,114 @@
+use asn1rs::prelude::*;
+use asn1rs::syn::time::Time;
+
+asn_to_rust!(
+    r"BasicTime DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    AuditEntry ::= SEQUENCE {
+        created GeneralizedTime,
+        expires UTCTime OPTIONAL
+    }
+
+    Created ::= GeneralizedTime
+
+    Expires ::= UTCTime
+
+    Label ::= VisibleString
+
+    END"
+);
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = UperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_uper_generalized_time_is_visible_string() {
+    // the length of 15 characters, followed by 7 bits for each of "20201017123456Z"
+    serialize_and_deserialize_uper(
+        113,
+        &[
+            0x0F, 0x64, 0xC1, 0x93, 0x06, 0x2C, 0x18, 0xB7, 0x62, 0xC9, 0x9B, 0x46, 0xAD, 0xAD,
+            0x00,
+        ],
+        &Created(GeneralizedTime::parse("20201017123456Z").unwrap()),
+    );
+}
+
+#[test]
+fn test_uper_utc_time_is_visible_string() {
+    serialize_and_deserialize_uper(
+        99,
+        &[
+            0x0D, 0x64, 0xC1, 0x8B, 0x06, 0x2D, 0xD8, 0xB2, 0x66, 0xD1, 0xAB, 0x6B, 0x40,
+        ],
+        &Expires(UtcTime::parse("201017123456Z").unwrap()),
+    );
+}
+
+#[test]
+fn test_uper_time_is_written_in_canonical_form() {
+    // the minutes, seconds and the offset to UTC are written even if they are not given
+    let created = Created(GeneralizedTime::parse("2020101712+0130").unwrap());
+    let mut writer = UperWriter::default();
+    writer.write(&created).unwrap();
+    let mut reader = writer.into_reader();
+    assert_eq!(
+        "20201017120000+0130",
+        reader.read::<Label>().unwrap().0.as_str()
+    );
+}
+
+#[test]
+fn test_uper_sequence() {
+    let entry = AuditEntry {
+        created: GeneralizedTime::parse("20201017123456.25Z").unwrap(),
+        expires: Some(UtcTime::parse("491231235959Z").unwrap()),
+    };
+    let mut writer = UperWriter::default();
+    writer.write(&entry).unwrap();
+    let mut reader = writer.into_reader();
+    assert_eq!(entry, reader.read::<AuditEntry>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_uper_rejects_invalid_time() {
+    let mut writer = UperWriter::default();
+    writer.write(&Label("20201317123456Z".to_string())).unwrap();
+    let mut reader = writer.into_reader();
+    assert!(reader.read::<Created>().is_err());
+}
+
+#[test]
+fn test_der() {
+    let mut writer = DerWriter::default();
+    writer
+        .write(&Created(GeneralizedTime::parse("20201017123456Z").unwrap()))
+        .unwrap();
+    writer
+        .write(&Expires(UtcTime::parse("201017123456Z").unwrap()))
+        .unwrap();
+    let mut expected = vec![0x18, 0x0F];
+    expected.extend_from_slice(b"20201017123456Z");
+    expected.extend_from_slice(&[0x17, 0x0D]);
+    expected.extend_from_slice(b"201017123456Z");
+    assert_eq!(&expected[..], writer.byte_content());
+
+    let mut reader = BerReader::from_bytes(writer.into_bytes_vec());
+    assert_eq!(
+        "20201017123456Z",
+        reader.read::<Created>().unwrap().0.to_string()
+    );
+    assert_eq!(2020, reader.read::<Expires>().unwrap().0.full_year());
+}
@@ -0,0 +1,47 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicValueReferences DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    maxNumberOfPoints INTEGER ::= 4
+
+    Points ::= SEQUENCE (SIZE(1..maxNumberOfPoints)) OF INTEGER (minValue..maxValue)
+
+    minValue INTEGER ::= -5
+    maxValue INTEGER (0..255) ::= 5
+
+    END"
+);
+
+fn serialize_and_deserialize_uper<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    bits: usize,
+    data: &[u8],
+    value: &T,
+) {
+    let mut writer = UperWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!((bits, data), (writer.bit_len(), writer.byte_content()));
+    let mut reader = writer.into_reader();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+    assert_eq!(0, reader.bits_remaining());
+}
+
+#[test]
+fn test_value_references_are_constants() {
+    assert_eq!(4_i64, MAX_NUMBER_OF_POINTS);
+    assert_eq!(-5_i64, MIN_VALUE);
+    assert_eq!(5_u8, MAX_VALUE);
+}
+
+#[test]
+fn test_uper_with_referenced_size_and_range() {
+    // 2 bits for the length of 2 within 1..4, followed by 4 bits per value within -5..5
+    serialize_and_deserialize_uper(10, &[0x42, 0x80], &Points(vec![-5, 5]));
+}
+
+#[test]
+fn test_uper_size_exceeds_referenced_value() {
+    let mut writer = UperWriter::default();
+    assert!(writer.write(&Points(vec![0; 5])).is_err());
+}
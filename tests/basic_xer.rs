@@ -0,0 +1,155 @@
+use asn1rs::prelude::*;
+
+asn_to_rust!(
+    r"BasicXer DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Topping ::= ENUMERATED {
+        notPineapple,
+        evenLessPineapple,
+        noPineappleAtAll
+    }
+
+    Pizza ::= SEQUENCE {
+        size INTEGER(1..4),
+        topping Topping
+    }
+
+    WhatToEat ::= CHOICE {
+        pizza Pizza,
+        water OCTET STRING
+    }
+
+    Order ::= SEQUENCE {
+        id INTEGER,
+        note UTF8String OPTIONAL,
+        paid BOOLEAN,
+        items SEQUENCE OF WhatToEat,
+        toppings SEQUENCE OF Topping,
+        pizzas SEQUENCE OF Pizza
+    }
+
+    Drink ::= SEQUENCE {
+        sugar-free BOOLEAN,
+        type UTF8String,
+        served-with CHOICE {
+            ice-cubes INTEGER,
+            lemon-slice NULL
+        }
+    }
+
+    END"
+);
+
+fn serialize_and_deserialize_xer<T: Readable + Writable + std::fmt::Debug + PartialEq>(
+    xml: &str,
+    value: &T,
+) {
+    let mut writer = XerWriter::default();
+    writer.write(value).unwrap();
+    assert_eq!(xml, writer.xml());
+    let mut reader = XerReader::from_xml(xml).unwrap();
+    assert_eq!(value, &reader.read::<T>().unwrap());
+}
+
+fn pizza() -> Pizza {
+    Pizza {
+        size: 3,
+        topping: Topping::EvenLessPineapple,
+    }
+}
+
+#[test]
+fn test_enumerated_is_empty_element() {
+    serialize_and_deserialize_xer(
+        "<Topping><noPineappleAtAll/></Topping>",
+        &Topping::NoPineappleAtAll,
+    );
+}
+
+#[test]
+fn test_sequence() {
+    serialize_and_deserialize_xer(
+        "<Pizza><size>3</size><topping><evenLessPineapple/></topping></Pizza>",
+        &pizza(),
+    );
+}
+
+#[test]
+fn test_elements_are_named_as_in_the_definition() {
+    serialize_and_deserialize_xer(
+        "<Drink><sugar-free><true/></sugar-free><type>Lemonade</type><served-with><ice-cubes>3</ice-cubes></served-with></Drink>",
+        &Drink {
+            sugar_free: true,
+            type_: "Lemonade".to_string(),
+            served_with: DrinkServedWith::IceCubes(3),
+        },
+    );
+}
+
+#[test]
+fn test_choice() {
+    serialize_and_deserialize_xer(
+        "<WhatToEat><water>CAFE</water></WhatToEat>",
+        &WhatToEat::Water(vec![0xCA, 0xFE]),
+    );
+}
+
+#[test]
+fn test_sequence_of_value_lists_and_omitted_optional() {
+    serialize_and_deserialize_xer(
+        concat!(
+            "<Order><id>1337</id><paid><true/></paid>",
+            "<items><pizza><size>3</size><topping><evenLessPineapple/></topping></pizza><water></water></items>",
+            "<toppings><notPineapple/><noPineappleAtAll/></toppings>",
+            "<pizzas><Pizza><size>3</size><topping><evenLessPineapple/></topping></Pizza></pizzas>",
+            "</Order>"
+        ),
+        &Order {
+            id: 1337,
+            note: None,
+            paid: true,
+            items: vec![WhatToEat::Pizza(pizza()), WhatToEat::Water(Vec::new())],
+            toppings: vec![Topping::NotPineapple, Topping::NoPineappleAtAll],
+            pizzas: vec![pizza()],
+        },
+    );
+}
+
+#[test]
+fn test_read_formatted_reference_document() {
+    let mut reader = XerReader::from_xml(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+        <Order>
+            <id>5</id>
+            <note>extra &lt;cheesy&gt;</note>
+            <paid><false/></paid>
+            <items/>
+            <toppings>
+                <evenLessPineapple/>
+            </toppings>
+            <pizzas></pizzas>
+        </Order>"#,
+    )
+    .unwrap();
+    assert_eq!(
+        Order {
+            id: 5,
+            note: Some("extra <cheesy>".to_string()),
+            paid: false,
+            items: Vec::new(),
+            toppings: vec![Topping::EvenLessPineapple],
+            pizzas: Vec::new(),
+        },
+        reader.read::<Order>().unwrap()
+    );
+}
+
+#[test]
+fn test_missing_mandatory_field() {
+    let mut reader = XerReader::from_xml("<Pizza><size>3</size></Pizza>").unwrap();
+    assert_eq!(
+        Err(asn1rs::io::xer::Error::MissingElement("topping")),
+        reader.read::<Pizza>()
+    );
+}
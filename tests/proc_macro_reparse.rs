@@ -96,6 +96,22 @@ END"#,
     )
 }
 
+#[test]
+fn test_extensible_sequence() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MyType ::= SEQUENCE {
+    abc Utf8String,
+    ...,
+    def INTEGER OPTIONAL,
+    [[ ghi BOOLEAN, jkl Utf8String OPTIONAL ]]
+  }
+  
+END"#,
+    )
+}
+
 #[test]
 fn test_standard_sequence_of() {
     parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
@@ -107,10 +123,174 @@ END"#,
     )
 }
 
+#[test]
+fn test_bit_strings() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MyType ::= SEQUENCE {
+    plain BIT STRING,
+    fixed BIT STRING (SIZE(8)),
+    ranged BIT STRING (SIZE(1..16)) OPTIONAL,
+    flags BIT STRING { readable(0), writable(1) } (SIZE(2..MAX))
+  }
+
+  MyFlags ::= [3] BIT STRING { on(0) }
+  
+END"#,
+    )
+}
+
+#[test]
+fn test_null_real_and_object_identifier() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MyType ::= SEQUENCE {
+    sensor OBJECT IDENTIFIER,
+    value REAL OPTIONAL
+  }
+
+  MyChoice ::= CHOICE {
+    nothing NULL,
+    something [APPLICATION 2] REAL
+  }
+  
+END"#,
+    )
+}
+
+#[test]
+fn test_restricted_character_strings() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MyType ::= SEQUENCE {
+    digits NumericString,
+    code PrintableString (FROM("A".."Z" | "0123456789")),
+    label VisibleString OPTIONAL,
+    name IA5String (FROM("a""b\")),
+    wide BMPString,
+    universal UniversalString
+  }
+
+END"#,
+    )
+}
+
+#[test]
+fn test_size_constraints() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MyType ::= SEQUENCE {
+    name UTF8String (SIZE(1..16)),
+    hash OCTET STRING (SIZE(32)),
+    code IA5String (SIZE(2..8, ...)) (FROM("ABC")),
+    values SEQUENCE (SIZE(0..8)) OF INTEGER (0..255),
+    labels SEQUENCE SIZE(1..MAX, ...) OF UTF8String (SIZE(4))
+  }
+
+END"#,
+    )
+}
+
+#[test]
+fn test_unconstrained_and_semi_constrained_integers() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  MyType ::= SEQUENCE {
+    unconstrained INTEGER,
+    semi INTEGER (-5..MAX),
+    positive INTEGER (0..MAX),
+    upper INTEGER (MIN..7),
+    extensible INTEGER (0..255, ...)
+  }
+
+END"#,
+    )
+}
+
+#[test]
+fn test_default_values() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  Color ::= ENUMERATED { red, dark-blue }
+
+  MyType ::= SEQUENCE {
+    number INTEGER (0..255) DEFAULT 42,
+    negative INTEGER (-10..10) DEFAULT -3,
+    flag BOOLEAN DEFAULT TRUE,
+    name UTF8String DEFAULT "some ""name""",
+    color Color DEFAULT dark-blue
+  }
+
+END"#,
+    )
+}
+
+#[test]
+fn test_recursive_types() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  Node ::= SEQUENCE {
+    value INTEGER (0..255),
+    next Node OPTIONAL
+  }
+
+  Expression ::= CHOICE {
+    value INTEGER (0..255),
+    negated Negation
+  }
+
+  Negation ::= SEQUENCE {
+    expression Expression
+  }
+
+END"#,
+    )
+}
+
+#[test]
+fn test_set_and_set_of() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS ::= BEGIN
+
+  Unordered ::= SET {
+    name UTF8String,
+    flag BOOLEAN,
+    values SET (SIZE(1..4)) OF INTEGER (0..255)
+  }
+
+  Flags ::= SET OF BOOLEAN
+
+END"#,
+    )
+}
+
+#[test]
+fn test_generalized_time_and_utc_time() {
+    parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
+        r#"BasicSchema DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+
+  AuditEntry ::= SEQUENCE {
+    created GeneralizedTime,
+    expires UTCTime OPTIONAL
+  }
+
+  Created ::= GeneralizedTime
+
+END"#,
+    )
+}
+
 fn parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_check_equal(
     asn: &str,
 ) {
-    let tokens = Tokenizer::default().parse(asn);
+    let tokens = Tokenizer::default().parse(asn).unwrap();
     let asn_model = Model::try_from(tokens).unwrap();
     let rust_model = asn_model.to_rust();
 
@@ -140,6 +320,14 @@ fn parse_asn_map_to_rust_map_to_stringify_with_proc_macro_annotation_re_parse_ch
             name: rust_model.name.clone(),
             imports: rust_model.imports.clone(),
             definitions: vec![re_parsed],
+            value_references: rust_model.value_references.clone(),
+            tag_default: rust_model.tag_default,
+            oid: rust_model.oid.clone(),
+            exports: rust_model.exports.clone(),
+            extensibility_implied: rust_model.extensibility_implied,
+            definition_spans: Default::default(),
+            component_spans: Default::default(),
+            imports_span: Default::default(),
         };
 
         assert_eq!(vec![definition], re_parsed_model.to_rust().definitions);
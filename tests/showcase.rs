@@ -34,7 +34,7 @@ mod what_is_being_generated {
     #[derive(Default, Debug, Clone, PartialEq, Hash)]
     pub struct Pizza {
         #[asn(integer(min..max))]
-        pub price: u64,
+        pub price: i64,
         #[asn(integer(1..4))]
         pub size: u8,
         #[asn(option(utf8string))]